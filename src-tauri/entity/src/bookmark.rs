@@ -7,6 +7,8 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    pub uuid: String,
     pub name: String,
     pub url: String,
     pub tags: String,
@@ -14,10 +16,21 @@ pub struct Model {
     pub group_id: i32,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
+    pub read_state: String,
+    pub reading_at: Option<DateTimeUtc>,
+    pub read_at: Option<DateTimeUtc>,
+    pub archived_at: Option<DateTimeUtc>,
+    pub opened_at: Option<DateTimeUtc>,
+    pub broken_at: Option<DateTimeUtc>,
+    pub open_count: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_one = "super::bookmark_content::Entity")]
+    BookmarkContent,
+    #[sea_orm(has_many = "super::bookmark_snapshot::Entity")]
+    BookmarkSnapshot,
     #[sea_orm(
         belongs_to = "super::groups::Entity",
         from = "Column::GroupId",
@@ -28,6 +41,18 @@ pub enum Relation {
     Groups,
 }
 
+impl Related<super::bookmark_content::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookmarkContent.def()
+    }
+}
+
+impl Related<super::bookmark_snapshot::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BookmarkSnapshot.def()
+    }
+}
+
 impl Related<super::groups::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Groups.def()
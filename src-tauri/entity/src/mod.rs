@@ -2,8 +2,17 @@
 
 pub mod prelude;
 
+pub mod app_setting;
+pub mod audit_event;
 pub mod bookmark;
+pub mod bookmark_content;
+pub mod bookmark_snapshot;
 pub mod groups;
 pub mod organization;
+pub mod organization_member;
+pub mod saved_search;
+pub mod sync_change;
+pub mod sync_identity;
 pub mod user;
 pub mod workspace;
+pub mod workspace_template;
@@ -7,6 +7,8 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    pub uuid: String,
     pub name: String,
     pub user_id: i32,
     pub created_at: DateTimeUtc,
@@ -25,6 +27,8 @@ pub enum Relation {
     User,
     #[sea_orm(has_many = "super::workspace::Entity")]
     Workspace,
+    #[sea_orm(has_many = "super::workspace_template::Entity")]
+    WorkspaceTemplate,
 }
 
 impl Related<super::user::Entity> for Entity {
@@ -39,4 +43,10 @@ impl Related<super::workspace::Entity> for Entity {
     }
 }
 
+impl Related<super::workspace_template::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WorkspaceTemplate.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
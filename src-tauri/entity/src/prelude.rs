@@ -1,7 +1,16 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.13
 
+pub use super::app_setting::Entity as AppSetting;
+pub use super::audit_event::Entity as AuditEvent;
 pub use super::bookmark::Entity as Bookmark;
+pub use super::bookmark_content::Entity as BookmarkContent;
+pub use super::bookmark_snapshot::Entity as BookmarkSnapshot;
 pub use super::groups::Entity as Groups;
 pub use super::organization::Entity as Organization;
+pub use super::organization_member::Entity as OrganizationMember;
+pub use super::saved_search::Entity as SavedSearch;
+pub use super::sync_change::Entity as SyncChange;
+pub use super::sync_identity::Entity as SyncIdentity;
 pub use super::user::Entity as User;
 pub use super::workspace::Entity as Workspace;
+pub use super::workspace_template::Entity as WorkspaceTemplate;
@@ -7,6 +7,8 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    pub uuid: String,
     pub name: String,
     pub email: String,
     pub created_at: DateTimeUtc,
@@ -7,6 +7,8 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    #[sea_orm(unique)]
+    pub uuid: String,
     pub name: String,
     pub organization_id: i32,
     pub created_at: DateTimeUtc,
@@ -25,6 +27,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Organization,
+    #[sea_orm(has_many = "super::saved_search::Entity")]
+    SavedSearch,
 }
 
 impl Related<super::groups::Entity> for Entity {
@@ -39,4 +43,10 @@ impl Related<super::organization::Entity> for Entity {
     }
 }
 
+impl Related<super::saved_search::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SavedSearch.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
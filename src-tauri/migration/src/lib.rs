@@ -5,6 +5,22 @@ mod m20250711_115936_create_groups;
 mod m20250711_115943_create_organization;
 mod m20250711_115948_create_workspace;
 mod m20250712_104206_create_user;
+mod m20250801_090000_add_bookmark_read_state;
+mod m20250802_090000_create_bookmark_snapshot;
+mod m20250803_090000_create_bookmark_content;
+mod m20250804_090000_add_smart_groups;
+mod m20250805_090000_create_saved_search;
+mod m20250806_090000_create_app_setting;
+mod m20250807_090000_add_bookmark_opened_at;
+mod m20250808_090000_create_organization_member;
+mod m20250809_090000_create_audit_event;
+mod m20250810_090000_create_sync;
+mod m20250811_090000_add_uuid;
+mod m20250812_090000_create_workspace_template;
+mod m20250813_090000_add_bookmark_link_stats;
+mod m20250814_090000_add_audit_event_organization;
+mod m20250815_090000_add_snapshot_version_index;
+mod m20250816_090000_refresh_audit_triggers;
 
 pub struct Migrator;
 
@@ -17,6 +33,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20250711_115943_create_organization::Migration),
             Box::new(m20250711_115948_create_workspace::Migration),
             Box::new(m20250712_104206_create_user::Migration),
+            Box::new(m20250801_090000_add_bookmark_read_state::Migration),
+            Box::new(m20250802_090000_create_bookmark_snapshot::Migration),
+            Box::new(m20250803_090000_create_bookmark_content::Migration),
+            Box::new(m20250804_090000_add_smart_groups::Migration),
+            Box::new(m20250805_090000_create_saved_search::Migration),
+            Box::new(m20250806_090000_create_app_setting::Migration),
+            Box::new(m20250807_090000_add_bookmark_opened_at::Migration),
+            Box::new(m20250808_090000_create_organization_member::Migration),
+            Box::new(m20250809_090000_create_audit_event::Migration),
+            Box::new(m20250810_090000_create_sync::Migration),
+            Box::new(m20250811_090000_add_uuid::Migration),
+            Box::new(m20250812_090000_create_workspace_template::Migration),
+            Box::new(m20250813_090000_add_bookmark_link_stats::Migration),
+            Box::new(m20250814_090000_add_audit_event_organization::Migration),
+            Box::new(m20250815_090000_add_snapshot_version_index::Migration),
+            Box::new(m20250816_090000_refresh_audit_triggers::Migration),
         ]
     }
 }
@@ -42,7 +42,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Bookmark {
+pub enum Bookmark {
     Table,
     Id,
     Name,
@@ -0,0 +1,75 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite only supports one column per ALTER TABLE statement
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookmark::Table)
+                    .add_column(string(Bookmark::ReadState).default("unread"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookmark::Table)
+                    .add_column(timestamp_null(Bookmark::ReadingAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookmark::Table)
+                    .add_column(timestamp_null(Bookmark::ReadAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bookmark::Table)
+                    .add_column(timestamp_null(Bookmark::ArchivedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Bookmark::ReadState,
+            Bookmark::ReadingAt,
+            Bookmark::ReadAt,
+            Bookmark::ArchivedAt,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Bookmark::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Bookmark {
+    Table,
+    ReadState,
+    ReadingAt,
+    ReadAt,
+    ArchivedAt,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250711_115931_create_bookmark::Bookmark;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookmarkSnapshot::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BookmarkSnapshot::Id))
+                    .col(integer(BookmarkSnapshot::BookmarkId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_bookmark_snapshot_bookmark")
+                            .from(BookmarkSnapshot::Table, BookmarkSnapshot::BookmarkId)
+                            .to(Bookmark::Table, Bookmark::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(BookmarkSnapshot::Version))
+                    .col(string(BookmarkSnapshot::SourceUrl))
+                    .col(string(BookmarkSnapshot::FilePath))
+                    .col(boolean(BookmarkSnapshot::IsSingleFile))
+                    .col(big_integer(BookmarkSnapshot::ByteSize))
+                    .col(big_integer(BookmarkSnapshot::CompressedSize))
+                    .col(timestamp(BookmarkSnapshot::CreatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookmarkSnapshot::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BookmarkSnapshot {
+    Table,
+    Id,
+    BookmarkId,
+    Version,
+    SourceUrl,
+    FilePath,
+    IsSingleFile,
+    ByteSize,
+    CompressedSize,
+    CreatedAt,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250711_115931_create_bookmark::Bookmark;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BookmarkContent::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BookmarkContent::Id))
+                    .col(integer_uniq(BookmarkContent::BookmarkId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_bookmark_content_bookmark")
+                            .from(BookmarkContent::Table, BookmarkContent::BookmarkId)
+                            .to(Bookmark::Table, Bookmark::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string_null(BookmarkContent::Title))
+                    .col(text(BookmarkContent::Text))
+                    .col(integer(BookmarkContent::WordCount))
+                    .col(timestamp(BookmarkContent::ExtractedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BookmarkContent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BookmarkContent {
+    Table,
+    Id,
+    BookmarkId,
+    Title,
+    Text,
+    WordCount,
+    ExtractedAt,
+}
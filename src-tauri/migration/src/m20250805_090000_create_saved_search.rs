@@ -0,0 +1,57 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250711_115948_create_workspace::Workspace;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SavedSearch::Table)
+                    .if_not_exists()
+                    .col(pk_auto(SavedSearch::Id))
+                    .col(integer(SavedSearch::WorkspaceId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_saved_search_workspace")
+                            .from(SavedSearch::Table, SavedSearch::WorkspaceId)
+                            .to(Workspace::Table, Workspace::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(SavedSearch::Name))
+                    .col(string(SavedSearch::Query))
+                    .col(boolean(SavedSearch::IsPinned).default(false))
+                    .col(integer(SavedSearch::CachedCount).default(0))
+                    .col(timestamp_null(SavedSearch::CountedAt))
+                    .col(timestamp(SavedSearch::CreatedAt))
+                    .col(timestamp(SavedSearch::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SavedSearch::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SavedSearch {
+    Table,
+    Id,
+    WorkspaceId,
+    Name,
+    Query,
+    IsPinned,
+    CachedCount,
+    CountedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,101 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250711_115943_create_organization::Organization, m20250712_104206_create_user::User,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationMember::Table)
+                    .if_not_exists()
+                    .col(pk_auto(OrganizationMember::Id))
+                    .col(integer(OrganizationMember::OrganizationId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_organization_member_organization")
+                            .from(
+                                OrganizationMember::Table,
+                                OrganizationMember::OrganizationId,
+                            )
+                            .to(Organization::Table, Organization::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(integer(OrganizationMember::UserId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_organization_member_user")
+                            .from(OrganizationMember::Table, OrganizationMember::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(OrganizationMember::Role))
+                    .col(timestamp(OrganizationMember::CreatedAt))
+                    .col(timestamp(OrganizationMember::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organization_member_unique")
+                    .table(OrganizationMember::Table)
+                    .col(OrganizationMember::OrganizationId)
+                    .col(OrganizationMember::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Whoever created an organization so far becomes its owner
+        let backfill = Query::insert()
+            .into_table(OrganizationMember::Table)
+            .columns([
+                OrganizationMember::OrganizationId,
+                OrganizationMember::UserId,
+                OrganizationMember::Role,
+                OrganizationMember::CreatedAt,
+                OrganizationMember::UpdatedAt,
+            ])
+            .select_from(
+                Query::select()
+                    .column(Organization::Id)
+                    .column(Organization::UserId)
+                    .expr(Expr::val("owner"))
+                    .column(Organization::CreatedAt)
+                    .column(Organization::UpdatedAt)
+                    .from(Organization::Table)
+                    .to_owned(),
+            )
+            .map_err(|e| DbErr::Migration(e.to_string()))?
+            .to_owned();
+
+        manager.exec_stmt(backfill).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrganizationMember::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum OrganizationMember {
+    Table,
+    Id,
+    OrganizationId,
+    UserId,
+    Role,
+    CreatedAt,
+    UpdatedAt,
+}
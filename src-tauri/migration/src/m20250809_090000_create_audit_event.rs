@@ -0,0 +1,239 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// A table whose changes are recorded, with the columns copied into the before/after JSON
+pub(crate) struct AuditedTable {
+    pub table: &'static str,
+    pub entity_type: &'static str,
+    pub columns: &'static [&'static str],
+    /// Stored as 0/1, written to the JSON as true/false
+    pub booleans: &'static [&'static str],
+}
+
+pub(crate) const AUDITED_TABLES: &[AuditedTable] = &[
+    AuditedTable {
+        table: "user",
+        entity_type: "user",
+        columns: &["id", "name", "email", "created_at", "updated_at"],
+        booleans: &[],
+    },
+    AuditedTable {
+        table: "organization",
+        entity_type: "organization",
+        columns: &["id", "name", "user_id", "created_at", "updated_at"],
+        booleans: &[],
+    },
+    AuditedTable {
+        table: "organization_member",
+        entity_type: "organization_member",
+        columns: &[
+            "id",
+            "organization_id",
+            "user_id",
+            "role",
+            "created_at",
+            "updated_at",
+        ],
+        booleans: &[],
+    },
+    AuditedTable {
+        table: "workspace",
+        entity_type: "workspace",
+        columns: &["id", "name", "organization_id", "created_at", "updated_at"],
+        booleans: &[],
+    },
+    AuditedTable {
+        table: "groups",
+        entity_type: "group",
+        columns: &[
+            "id",
+            "name",
+            "workspace_id",
+            "kind",
+            "filter",
+            "created_at",
+            "updated_at",
+        ],
+        booleans: &[],
+    },
+    AuditedTable {
+        table: "bookmark",
+        entity_type: "bookmark",
+        columns: &[
+            "id",
+            "name",
+            "url",
+            "tags",
+            "is_favorite",
+            "group_id",
+            "read_state",
+            "reading_at",
+            "read_at",
+            "archived_at",
+            "opened_at",
+            "created_at",
+            "updated_at",
+        ],
+        booleans: &["is_favorite"],
+    },
+];
+
+const ACTIONS: [(&str, &str); 3] = [
+    ("INSERT", "create"),
+    ("UPDATE", "update"),
+    ("DELETE", "delete"),
+];
+
+impl AuditedTable {
+    /// `json_object` of the `OLD` or `NEW` row
+    fn row_json(&self, row: &str, columns: &[&str]) -> String {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                let value = if self.booleans.contains(column) {
+                    format!("CASE WHEN {row}.\"{column}\" THEN json('true') ELSE json('false') END")
+                } else {
+                    format!("{row}.\"{column}\"")
+                };
+                format!("'{column}', {value}")
+            })
+            .collect();
+
+        format!("json_object({})", fields.join(", "))
+    }
+
+    fn trigger_name(&self, action: &str) -> String {
+        format!("audit_{}_{}", self.table, action)
+    }
+
+    /// The triggers write the audit row inside the statement that changed the table, so both
+    /// are committed or rolled back together. The actor is the user signed in through
+    /// `session.signIn`, which is kept in `app_setting`.
+    pub fn create_triggers(&self) -> Vec<String> {
+        self.create_triggers_with(&[])
+    }
+
+    /// Triggers that also record `extra`, for columns added after the audit log
+    pub fn create_triggers_with(&self, extra: &[&str]) -> Vec<String> {
+        let mut columns = self.columns.to_vec();
+        columns.extend(extra);
+
+        ACTIONS
+            .iter()
+            .map(|(event, action)| {
+                let (row, before, after) = match *event {
+                    "INSERT" => ("NEW", "NULL".to_string(), self.row_json("NEW", &columns)),
+                    "UPDATE" => (
+                        "NEW",
+                        self.row_json("OLD", &columns),
+                        self.row_json("NEW", &columns),
+                    ),
+                    _ => ("OLD", self.row_json("OLD", &columns), "NULL".to_string()),
+                };
+
+                format!(
+                    "CREATE TRIGGER IF NOT EXISTS \"{trigger}\" AFTER {event} ON \"{table}\" \
+                     BEGIN \
+                     INSERT INTO \"audit_event\" \
+                     (\"actor_user_id\", \"entity_type\", \"entity_id\", \"action\", \"before\", \"after\", \"created_at\") \
+                     VALUES ( \
+                     (SELECT CAST(NULLIF(\"value\", '') AS INTEGER) FROM \"app_setting\" WHERE \"key\" = 'session.user_id'), \
+                     '{entity_type}', {row}.\"id\", '{action}', {before}, {after}, \
+                     strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')); \
+                     END",
+                    trigger = self.trigger_name(action),
+                    table = self.table,
+                    entity_type = self.entity_type,
+                )
+            })
+            .collect()
+    }
+
+    pub fn drop_triggers(&self) -> Vec<String> {
+        ACTIONS
+            .iter()
+            .map(|(_, action)| format!("DROP TRIGGER IF EXISTS \"{}\"", self.trigger_name(action)))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEvent::Table)
+                    .if_not_exists()
+                    .col(pk_auto(AuditEvent::Id))
+                    // No foreign key, the history outlives deleted users
+                    .col(integer_null(AuditEvent::ActorUserId))
+                    .col(string(AuditEvent::EntityType))
+                    .col(integer(AuditEvent::EntityId))
+                    .col(string(AuditEvent::Action))
+                    .col(text_null(AuditEvent::Before))
+                    .col(text_null(AuditEvent::After))
+                    .col(timestamp(AuditEvent::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_event_entity")
+                    .table(AuditEvent::Table)
+                    .col(AuditEvent::EntityType)
+                    .col(AuditEvent::EntityId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_event_created_at")
+                    .table(AuditEvent::Table)
+                    .col(AuditEvent::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.create_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        manager
+            .drop_table(Table::drop().table(AuditEvent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AuditEvent {
+    Table,
+    Id,
+    ActorUserId,
+    EntityType,
+    EntityId,
+    Action,
+    Before,
+    After,
+    CreatedAt,
+}
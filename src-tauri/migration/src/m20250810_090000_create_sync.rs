@@ -0,0 +1,116 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Stable id of a synced row, the same on every instance that shares it
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncIdentity::Table)
+                    .if_not_exists()
+                    .col(pk_auto(SyncIdentity::Id))
+                    .col(string(SyncIdentity::EntityType))
+                    .col(integer(SyncIdentity::LocalId))
+                    .col(string_uniq(SyncIdentity::Uuid))
+                    .col(string(SyncIdentity::WorkspaceUuid))
+                    .col(boolean(SyncIdentity::Deleted).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_identity_local")
+                    .table(SyncIdentity::Table)
+                    .col(SyncIdentity::EntityType)
+                    .col(SyncIdentity::LocalId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Append-only, one row per changed field of a synced row
+        manager
+            .create_table(
+                Table::create()
+                    .table(SyncChange::Table)
+                    .if_not_exists()
+                    .col(pk_auto(SyncChange::Id))
+                    .col(string_uniq(SyncChange::ChangeUuid))
+                    .col(string(SyncChange::InstanceId))
+                    .col(integer(SyncChange::Seq))
+                    .col(string(SyncChange::EntityType))
+                    .col(string(SyncChange::EntityUuid))
+                    .col(string(SyncChange::WorkspaceUuid))
+                    .col(string(SyncChange::Action))
+                    .col(string_null(SyncChange::Field))
+                    .col(text_null(SyncChange::Value))
+                    .col(timestamp(SyncChange::ChangedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_change_entity")
+                    .table(SyncChange::Table)
+                    .col(SyncChange::EntityUuid)
+                    .col(SyncChange::Field)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sync_change_workspace")
+                    .table(SyncChange::Table)
+                    .col(SyncChange::WorkspaceUuid)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SyncChange::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SyncIdentity::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SyncIdentity {
+    Table,
+    Id,
+    EntityType,
+    LocalId,
+    Uuid,
+    WorkspaceUuid,
+    Deleted,
+}
+
+#[derive(DeriveIden)]
+pub enum SyncChange {
+    Table,
+    Id,
+    ChangeUuid,
+    InstanceId,
+    Seq,
+    EntityType,
+    EntityUuid,
+    WorkspaceUuid,
+    Action,
+    Field,
+    Value,
+    ChangedAt,
+}
@@ -0,0 +1,125 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250809_090000_create_audit_event::AUDITED_TABLES;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables that get a `uuid`, with the entity type sync already gave identities to
+const TABLES: [(&str, Option<&str>); 7] = [
+    ("user", None),
+    ("organization", None),
+    ("organization_member", None),
+    ("workspace", Some("workspace")),
+    ("groups", Some("group")),
+    ("bookmark", Some("bookmark")),
+    ("saved_search", None),
+];
+
+/// A random version 4 UUID, SQLite has no function for it
+const RANDOM_UUID: &str = "lower(hex(randomblob(4))) || '-' || lower(hex(randomblob(2))) \
+    || '-4' || substr(lower(hex(randomblob(2))), 2) \
+    || '-' || substr('89ab', 1 + abs(random()) % 4, 1) || substr(lower(hex(randomblob(2))), 2) \
+    || '-' || lower(hex(randomblob(6)))";
+
+fn index_name(table: &str) -> String {
+    format!("idx_{table}_uuid")
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Filling the new column is not a change worth auditing
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        for (table, synced) in TABLES {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .add_column(string(Uuid).default(""))
+                        .to_owned(),
+                )
+                .await?;
+
+            // Synced rows keep the UUID the other instances already know them by
+            if let Some(entity_type) = synced {
+                db.execute_unprepared(&format!(
+                    "UPDATE \"{table}\" SET \"uuid\" = (SELECT \"uuid\" FROM \"sync_identity\" \
+                     WHERE \"entity_type\" = '{entity_type}' AND \"local_id\" = \"{table}\".\"id\") \
+                     WHERE EXISTS (SELECT 1 FROM \"sync_identity\" \
+                     WHERE \"entity_type\" = '{entity_type}' AND \"local_id\" = \"{table}\".\"id\")"
+                ))
+                .await?;
+            }
+            db.execute_unprepared(&format!(
+                "UPDATE \"{table}\" SET \"uuid\" = {RANDOM_UUID} WHERE \"uuid\" = ''"
+            ))
+            .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index_name(table))
+                        .table(Alias::new(table))
+                        .col(Uuid)
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        for table in AUDITED_TABLES {
+            for trigger in table.create_triggers_with(&["uuid"]) {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        for (table, _) in TABLES {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(index_name(table))
+                        .table(Alias::new(table))
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .drop_column(Uuid)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        for table in AUDITED_TABLES {
+            for trigger in table.create_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+struct Uuid;
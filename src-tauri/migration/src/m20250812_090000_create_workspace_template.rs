@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250711_115943_create_organization::Organization;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorkspaceTemplate::Table)
+                    .if_not_exists()
+                    .col(pk_auto(WorkspaceTemplate::Id))
+                    .col(string_uniq(WorkspaceTemplate::Uuid))
+                    .col(integer(WorkspaceTemplate::OrganizationId))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .name("fk_workspace_template_organization")
+                            .from(WorkspaceTemplate::Table, WorkspaceTemplate::OrganizationId)
+                            .to(Organization::Table, Organization::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(WorkspaceTemplate::Name))
+                    // Groups and bookmarks as JSON, see `TemplateLayout`
+                    .col(text(WorkspaceTemplate::Layout))
+                    .col(timestamp(WorkspaceTemplate::CreatedAt))
+                    .col(timestamp(WorkspaceTemplate::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WorkspaceTemplate::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum WorkspaceTemplate {
+    Table,
+    Id,
+    Uuid,
+    OrganizationId,
+    Name,
+    Layout,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,229 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20250809_090000_create_audit_event::{AuditedTable, AUDITED_TABLES};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const INDEX_NAME: &str = "idx_audit_event_organization_id";
+
+/// Organizations of the events written so far, parents before children so groups and
+/// bookmarks can fall back to what their parents' events recorded
+const BACKFILL: [&str; 4] = [
+    r#"UPDATE "audit_event" SET "organization_id" = "entity_id"
+       WHERE "entity_type" = 'organization'"#,
+    r#"UPDATE "audit_event"
+       SET "organization_id" = json_extract(COALESCE("after", "before"), '$.organization_id')
+       WHERE "entity_type" IN ('organization_member', 'workspace')"#,
+    r#"UPDATE "audit_event" SET "organization_id" = COALESCE(
+           (SELECT "organization_id" FROM "workspace"
+            WHERE "id" = json_extract(COALESCE("audit_event"."after", "audit_event"."before"), '$.workspace_id')),
+           (SELECT "w"."organization_id" FROM "audit_event" AS "w"
+            WHERE "w"."entity_type" = 'workspace'
+            AND "w"."entity_id" = json_extract(COALESCE("audit_event"."after", "audit_event"."before"), '$.workspace_id')
+            AND "w"."organization_id" IS NOT NULL ORDER BY "w"."id" DESC LIMIT 1))
+       WHERE "entity_type" = 'group'"#,
+    r#"UPDATE "audit_event" SET "organization_id" = COALESCE(
+           (SELECT "workspace"."organization_id" FROM "groups"
+            JOIN "workspace" ON "workspace"."id" = "groups"."workspace_id"
+            WHERE "groups"."id" = json_extract(COALESCE("audit_event"."after", "audit_event"."before"), '$.group_id')),
+           (SELECT "g"."organization_id" FROM "audit_event" AS "g"
+            WHERE "g"."entity_type" = 'group'
+            AND "g"."entity_id" = json_extract(COALESCE("audit_event"."after", "audit_event"."before"), '$.group_id')
+            AND "g"."organization_id" IS NOT NULL ORDER BY "g"."id" DESC LIMIT 1))
+       WHERE "entity_type" = 'bookmark'"#,
+];
+
+/// Organization each audited table's `{row}` belongs to. Parents deleted in the same
+/// cascade are found through their last audit row.
+const ORGANIZATIONS: [(&str, &str); 6] = [
+    ("user", "NULL"),
+    ("organization", r#"{row}."id""#),
+    ("organization_member", r#"{row}."organization_id""#),
+    ("workspace", r#"{row}."organization_id""#),
+    (
+        "groups",
+        r#"COALESCE(
+            (SELECT "organization_id" FROM "workspace" WHERE "id" = {row}."workspace_id"),
+            (SELECT "organization_id" FROM "audit_event"
+             WHERE "entity_type" = 'workspace' AND "entity_id" = {row}."workspace_id"
+             AND "organization_id" IS NOT NULL ORDER BY "id" DESC LIMIT 1))"#,
+    ),
+    (
+        "bookmark",
+        r#"COALESCE(
+            (SELECT "workspace"."organization_id" FROM "groups"
+             JOIN "workspace" ON "workspace"."id" = "groups"."workspace_id"
+             WHERE "groups"."id" = {row}."group_id"),
+            (SELECT "organization_id" FROM "audit_event"
+             WHERE "entity_type" = 'group' AND "entity_id" = {row}."group_id"
+             AND "organization_id" IS NOT NULL ORDER BY "id" DESC LIMIT 1))"#,
+    ),
+];
+
+const ACTIONS: [(&str, &str); 3] = [
+    ("INSERT", "create"),
+    ("UPDATE", "update"),
+    ("DELETE", "delete"),
+];
+
+/// `json_object` of the `OLD` or `NEW` row, as the earlier triggers write it
+fn row_json(table: &AuditedTable, row: &str, columns: &[&str]) -> String {
+    let fields: Vec<String> = columns
+        .iter()
+        .map(|column| {
+            let value = if table.booleans.contains(column) {
+                format!("CASE WHEN {row}.\"{column}\" THEN json('true') ELSE json('false') END")
+            } else {
+                format!("{row}.\"{column}\"")
+            };
+            format!("'{column}', {value}")
+        })
+        .collect();
+
+    format!("json_object({})", fields.join(", "))
+}
+
+/// The triggers of `m20250811_090000_add_uuid`, also recording the organization of the row
+pub(crate) fn scoped_triggers(table: &AuditedTable) -> Vec<String> {
+    let mut columns = table.columns.to_vec();
+    columns.push("uuid");
+
+    scoped_triggers_with(table, &columns, &[])
+}
+
+/// Triggers recording `columns` of the row and its organization. Updates that change nothing
+/// but the `unaudited` columns are left out of the log.
+pub(crate) fn scoped_triggers_with(
+    table: &AuditedTable,
+    columns: &[&str],
+    unaudited: &[&str],
+) -> Vec<String> {
+    let organization = ORGANIZATIONS
+        .iter()
+        .find(|(name, _)| *name == table.table)
+        .map_or("NULL", |(_, organization)| organization);
+    let audited_change = columns
+        .iter()
+        .filter(|column| !unaudited.contains(column))
+        .map(|column| format!("OLD.\"{column}\" IS NOT NEW.\"{column}\""))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    ACTIONS
+        .iter()
+        .map(|(event, action)| {
+            let (row, before, after) = match *event {
+                "INSERT" => ("NEW", "NULL".to_string(), row_json(table, "NEW", columns)),
+                "UPDATE" => (
+                    "NEW",
+                    row_json(table, "OLD", columns),
+                    row_json(table, "NEW", columns),
+                ),
+                _ => ("OLD", row_json(table, "OLD", columns), "NULL".to_string()),
+            };
+            let when = if *event == "UPDATE" && !unaudited.is_empty() {
+                format!("WHEN {audited_change} ")
+            } else {
+                String::new()
+            };
+
+            format!(
+                "CREATE TRIGGER IF NOT EXISTS \"audit_{table}_{action}\" AFTER {event} ON \"{table}\" \
+                 {when}BEGIN \
+                 INSERT INTO \"audit_event\" \
+                 (\"actor_user_id\", \"entity_type\", \"entity_id\", \"action\", \"before\", \"after\", \"created_at\", \"organization_id\") \
+                 VALUES ( \
+                 (SELECT CAST(NULLIF(\"value\", '') AS INTEGER) FROM \"app_setting\" WHERE \"key\" = 'session.user_id'), \
+                 '{entity_type}', {row}.\"id\", '{action}', {before}, {after}, \
+                 strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now'), {organization}); \
+                 END",
+                table = table.table,
+                entity_type = table.entity_type,
+                organization = organization.replace("{row}", row),
+            )
+        })
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        // `None` for users, they don't belong to an organization
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditEvent::Table)
+                    .add_column(integer_null(AuditEvent::OrganizationId))
+                    .to_owned(),
+            )
+            .await?;
+        for statement in BACKFILL {
+            db.execute_unprepared(statement).await?;
+        }
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(AuditEvent::Table)
+                    .col(AuditEvent::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in AUDITED_TABLES {
+            for trigger in scoped_triggers(table) {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_NAME)
+                    .table(AuditEvent::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditEvent::Table)
+                    .drop_column(AuditEvent::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in AUDITED_TABLES {
+            for trigger in table.create_triggers_with(&["uuid"]) {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEvent {
+    Table,
+    OrganizationId,
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const INDEX_NAME: &str = "idx_bookmark_snapshot_bookmark_id_version";
+
+/// Snapshots that raced for the same version before the index existed move past the latest
+/// version of their bookmark, the id keeps the moved ones apart
+const RENUMBER_DUPLICATES: &str = r#"UPDATE "bookmark_snapshot"
+    SET "version" = "id" + (SELECT MAX("latest"."version") FROM "bookmark_snapshot" AS "latest"
+                            WHERE "latest"."bookmark_id" = "bookmark_snapshot"."bookmark_id")
+    WHERE EXISTS (SELECT 1 FROM "bookmark_snapshot" AS "earlier"
+                  WHERE "earlier"."bookmark_id" = "bookmark_snapshot"."bookmark_id"
+                  AND "earlier"."version" = "bookmark_snapshot"."version"
+                  AND "earlier"."id" < "bookmark_snapshot"."id")"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(RENUMBER_DUPLICATES)
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(BookmarkSnapshot::Table)
+                    .col(BookmarkSnapshot::BookmarkId)
+                    .col(BookmarkSnapshot::Version)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_NAME)
+                    .table(BookmarkSnapshot::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BookmarkSnapshot {
+    Table,
+    BookmarkId,
+    Version,
+}
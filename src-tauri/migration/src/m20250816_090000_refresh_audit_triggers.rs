@@ -0,0 +1,72 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+use crate::{
+    m20250809_090000_create_audit_event::AUDITED_TABLES,
+    m20250814_090000_add_audit_event_organization::{scoped_triggers, scoped_triggers_with},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Columns that change whenever a row is used rather than edited, an update touching only
+/// these is not recorded
+const UNAUDITED: [(&str, &[&str]); 1] = [("bookmark", &["open_count", "opened_at"])];
+
+/// Current columns of `table`, so a column added later is recorded without listing it here
+async fn columns_of<C: ConnectionTrait>(db: &C, table: &str) -> Result<Vec<String>, DbErr> {
+    let rows = db
+        .query_all(Statement::from_string(
+            db.get_database_backend(),
+            format!("SELECT \"name\" FROM pragma_table_info('{table}') ORDER BY \"cid\""),
+        ))
+        .await?;
+
+    rows.iter().map(|row| row.try_get("", "name")).collect()
+}
+
+/// Rebuilds the audit triggers from the tables as they are now. Migrations that add columns to
+/// an audited table call this after altering it.
+pub(crate) async fn recreate_audit_triggers<C: ConnectionTrait>(db: &C) -> Result<(), DbErr> {
+    for table in AUDITED_TABLES {
+        for trigger in table.drop_triggers() {
+            db.execute_unprepared(&trigger).await?;
+        }
+
+        let columns = columns_of(db, table.table).await?;
+        let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+        let skipped = UNAUDITED
+            .iter()
+            .find(|(name, _)| *name == table.table)
+            .map_or(&[][..], |(_, columns)| columns);
+
+        for trigger in scoped_triggers_with(table, &columns, skipped) {
+            db.execute_unprepared(&trigger).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        recreate_audit_triggers(manager.get_connection()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for table in AUDITED_TABLES {
+            for trigger in table.drop_triggers() {
+                db.execute_unprepared(&trigger).await?;
+            }
+            for trigger in scoped_triggers(table) {
+                db.execute_unprepared(&trigger).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
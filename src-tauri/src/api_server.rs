@@ -0,0 +1,72 @@
+//! Starts the localhost HTTP API on launch when enabled and lets the settings screen control it
+
+use std::sync::Arc;
+
+use tauri::{App, Manager, State};
+
+use crate::domain::{
+    http_api::{HttpApiController, HttpApiStatus},
+    router::ContextRouter,
+};
+
+/// Manage the controller and bring the server up if it was left enabled
+pub fn setup(app: &App, context: ContextRouter) -> Result<(), Box<dyn std::error::Error>> {
+    let controller = Arc::new(HttpApiController::new());
+    app.manage(Arc::clone(&controller));
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = controller.apply(context).await {
+            eprintln!("Failed to start HTTP API: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_http_api_status(
+    ctx: State<'_, ContextRouter>,
+    controller: State<'_, Arc<HttpApiController>>,
+) -> Result<HttpApiStatus, String> {
+    controller.status(ctx.inner().clone()).await
+}
+
+#[tauri::command]
+pub async fn set_http_api_enabled(
+    ctx: State<'_, ContextRouter>,
+    controller: State<'_, Arc<HttpApiController>>,
+    enabled: bool,
+) -> Result<HttpApiStatus, String> {
+    let service = Arc::clone(&ctx.services.settings);
+    service
+        .set_http_api_enabled(ctx.inner().clone(), enabled)
+        .await?;
+
+    controller.apply(ctx.inner().clone()).await
+}
+
+#[tauri::command]
+pub async fn set_http_api_port(
+    ctx: State<'_, ContextRouter>,
+    controller: State<'_, Arc<HttpApiController>>,
+    port: u16,
+) -> Result<HttpApiStatus, String> {
+    let service = Arc::clone(&ctx.services.settings);
+    service.set_http_api_port(ctx.inner().clone(), port).await?;
+
+    controller.apply(ctx.inner().clone()).await
+}
+
+/// Restarts a running server so the old token stops working right away
+#[tauri::command]
+pub async fn regenerate_http_api_token(
+    ctx: State<'_, ContextRouter>,
+    controller: State<'_, Arc<HttpApiController>>,
+) -> Result<HttpApiStatus, String> {
+    let service = Arc::clone(&ctx.services.settings);
+    service
+        .regenerate_http_api_token(ctx.inner().clone())
+        .await?;
+
+    controller.apply(ctx.inner().clone()).await
+}
@@ -0,0 +1,260 @@
+//! Headless command line client, runs the domain services against the app's database
+//! without starting Tauri.
+//!
+//! ```text
+//! rayzen add https://doc.rust-lang.org/book --group Articles --tags rust,books
+//! rayzen search rust --json
+//! rayzen open 42
+//! rayzen export > bookmarks.json
+//! rayzen export --format csv --workspace 3 > reading.csv
+//! rayzen export --format markdown --group Articles > articles.md
+//! ```
+
+use std::{future::Future, process::ExitCode, sync::Arc};
+
+use clap::{Parser, Subcommand};
+use rayzen_lib::domain::{
+    context::{database_uri, ContextRouter},
+    dto::{
+        bookmark::bookmark_dto::{BookmarkDto, CreateBookmarkDto},
+        groups::groups_dto::GroupsDto,
+    },
+    export::{ExportDto, ExportScope},
+    types::pagination::{ListParams, Page, MAX_PER_PAGE},
+    util::normalize_tags,
+};
+use serde::Serialize;
+
+/// Not an exporter, `export` without a format dumps the raw bookmarks
+const JSON_FORMAT: &str = "json";
+
+#[derive(Parser)]
+#[command(
+    name = "rayzen",
+    version,
+    about = "Manage rayzen bookmarks from the terminal"
+)]
+struct Cli {
+    /// Print machine readable JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Save a bookmark into a group
+    Add {
+        url: String,
+        /// Group id or name
+        #[arg(long, short)]
+        group: String,
+        /// Comma separated tags
+        #[arg(long, short, default_value = "")]
+        tags: String,
+        /// Defaults to the url
+        #[arg(long, short)]
+        name: Option<String>,
+        #[arg(long)]
+        favorite: bool,
+    },
+    /// Search names, tags and page text
+    Search { query: String },
+    /// Open a bookmark in the default browser
+    Open { id: i32 },
+    /// List groups with their ids
+    Groups,
+    /// Dump every bookmark as JSON, or a workspace, group or search in another format
+    Export {
+        /// json, or one of the export formats: csv, markdown, opml
+        #[arg(long, short, default_value = "json")]
+        format: String,
+        #[arg(long, short)]
+        workspace: Option<i32>,
+        /// Group id or name
+        #[arg(long, short, conflicts_with = "workspace")]
+        group: Option<String>,
+        /// Only bookmarks of the workspace matching this search
+        #[arg(long, short, requires = "workspace")]
+        search: Option<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), String> {
+    let ctx = ContextRouter::connect(&database_uri()?).await?;
+    let bookmarks = Arc::clone(&ctx.services.bookmarks);
+
+    match cli.command {
+        Command::Add {
+            url,
+            group,
+            tags,
+            name,
+            favorite,
+        } => {
+            let groups = Arc::clone(&ctx.services.groups);
+            let group = groups.find_group(ctx.clone(), &group).await?;
+            let bookmark = bookmarks
+                .create_bookmark(
+                    ctx.clone(),
+                    CreateBookmarkDto {
+                        name: name.unwrap_or_else(|| url.clone()),
+                        url,
+                        tags: normalize_tags(&tags),
+                        is_favorite: favorite,
+                        group_id: group.base.id,
+                    },
+                )
+                .await?;
+
+            print(cli.json, &bookmark, || {
+                format!("Added #{} to {}", bookmark.base.id, group.name)
+            })
+        }
+        Command::Search { query } => {
+            let results = search_all(&ctx, &query).await?;
+
+            print(cli.json, &results, || {
+                results
+                    .iter()
+                    .map(bookmark_line)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        Command::Open { id } => {
+            // Only a bookmark that actually opened counts as a visit
+            let bookmark = bookmarks.get_bookmark_by_id(ctx.clone(), id).await?;
+            open::that(&bookmark.url)
+                .map_err(|e| format!("Failed to open {}: {}", bookmark.url, e))?;
+            let bookmark = bookmarks.mark_opened(ctx.clone(), id).await?;
+
+            print(cli.json, &bookmark, || format!("Opened {}", bookmark.url))
+        }
+        Command::Groups => {
+            let groups = all_groups(&ctx).await?;
+
+            print(cli.json, &groups, || {
+                groups
+                    .iter()
+                    .map(|group| format!("{:>6}  {}", group.base.id, group.name))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        }
+        Command::Export {
+            format,
+            workspace,
+            group,
+            search,
+        } if format != JSON_FORMAT => {
+            let scope = match (workspace, group, search) {
+                (_, Some(group), _) => {
+                    let groups = Arc::clone(&ctx.services.groups);
+                    let group = groups.find_group(ctx.clone(), &group).await?;
+                    ExportScope::Group {
+                        group_id: group.base.id,
+                    }
+                }
+                (Some(workspace_id), None, Some(query)) => ExportScope::Search {
+                    workspace_id,
+                    query,
+                },
+                (Some(workspace_id), None, None) => ExportScope::Workspace { workspace_id },
+                (None, None, _) => {
+                    return Err(format!(
+                        "Pick a --workspace or --group to export as {}",
+                        format
+                    ))
+                }
+            };
+
+            let exports = Arc::clone(&ctx.services.exports);
+            let file = exports
+                .export(ctx.clone(), ExportDto { format, scope })
+                .await?;
+            print!("{}", file.content);
+            Ok(())
+        }
+        Command::Export {
+            workspace: None,
+            group: None,
+            ..
+        } => {
+            let all = collect_pages(|params| bookmarks.list_bookmark(ctx.clone(), params)).await?;
+
+            // Exports are always JSON, `--json` only matters for the other commands
+            print(true, &all, String::new)
+        }
+        Command::Export { .. } => {
+            Err("JSON exports hold every bookmark, pick another --format to export a part".into())
+        }
+    }
+}
+
+fn print<T: Serialize>(json: bool, value: &T, text: impl FnOnce() -> String) -> Result<(), String> {
+    if json {
+        let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+        println!("{}", json);
+    } else {
+        let text = text();
+        if !text.is_empty() {
+            println!("{}", text);
+        }
+    }
+    Ok(())
+}
+
+fn bookmark_line(bookmark: &BookmarkDto) -> String {
+    let star = if bookmark.is_favorite { "*" } else { " " };
+    format!(
+        "{:>6} {} {}  {}",
+        bookmark.base.id, star, bookmark.name, bookmark.url
+    )
+}
+
+async fn search_all(ctx: &ContextRouter, query: &str) -> Result<Vec<BookmarkDto>, String> {
+    let bookmarks = Arc::clone(&ctx.services.bookmarks);
+    collect_pages(|params| bookmarks.search_bookmarks(ctx.clone(), query, params)).await
+}
+
+async fn all_groups(ctx: &ContextRouter) -> Result<Vec<GroupsDto>, String> {
+    let groups = Arc::clone(&ctx.services.groups);
+    collect_pages(|params| groups.list_groups(ctx.clone(), params)).await
+}
+
+/// Follows `next_cursor` from the first page to the last, fetching the largest pages allowed
+async fn collect_pages<T, F, Fut>(mut fetch: F) -> Result<Vec<T>, String>
+where
+    F: FnMut(ListParams) -> Fut,
+    Fut: Future<Output = Result<Page<T>, String>>,
+{
+    let mut all = Vec::new();
+    let mut params = ListParams {
+        per_page: Some(MAX_PER_PAGE as u32),
+        ..Default::default()
+    };
+    loop {
+        let page = fetch(params.clone()).await?;
+        all.extend(page.items);
+        match page.next_cursor {
+            Some(cursor) => params.cursor = Some(cursor),
+            None => return Ok(all),
+        }
+    }
+}
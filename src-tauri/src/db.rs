@@ -30,12 +30,12 @@ pub async fn establish_connection(database_uri: &str) -> Result<DbConn, DbErr> {
 
     match database {
         Ok(db) => {
-            println!("Connected to database");
+            eprintln!("Connected to database");
             migration::Migrator::up(&db, None).await?;
             Ok(db.into())
         }
         Err(err) => {
-            println!("Failed to connect to database");
+            eprintln!("Failed to connect to database");
             Err(err)
         }
     }
@@ -0,0 +1,54 @@
+//! Hands `rayzen://` links from the OS to the main window, which resolves them with
+//! `deepLink.resolve`. On Windows and Linux the link arrives as the launch argument of the app,
+//! the single instance plugin passes it on when the app is already running.
+
+use std::sync::Mutex;
+
+use tauri::{App, AppHandle, Emitter, Manager, State};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+use crate::tray;
+
+/// Event the main window listens to for links received while it is running
+pub const DEEP_LINK_EVENT: &str = "deep-link";
+
+/// Links that arrived before the main window was ready to listen
+#[derive(Default)]
+pub struct PendingDeepLinks(Mutex<Vec<String>>);
+
+pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
+    // Installed builds are registered by the bundler, this covers dev builds and portable installs
+    #[cfg(any(windows, target_os = "linux"))]
+    app.deep_link().register_all()?;
+
+    let startup_links = app
+        .deep_link()
+        .get_current()?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|url| url.to_string())
+        .collect();
+    app.manage(PendingDeepLinks(Mutex::new(startup_links)));
+
+    let handle = app.handle().clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            forward(&handle, url.to_string());
+        }
+    });
+
+    Ok(())
+}
+
+fn forward(app: &AppHandle, link: String) {
+    tray::show_main_window(app);
+    if let Err(e) = app.emit_to("main", DEEP_LINK_EVENT, link) {
+        eprintln!("Failed to forward deep link: {}", e);
+    }
+}
+
+/// Called once by the main window on mount, so links that launched the app aren't lost
+#[tauri::command]
+pub fn take_pending_deep_links(pending: State<'_, PendingDeepLinks>) -> Vec<String> {
+    std::mem::take(&mut *pending.0.lock().unwrap_or_else(|p| p.into_inner()))
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+/// Raw response of a fetched page or page asset
+#[derive(Debug, Clone)]
+pub struct FetchedResource {
+    pub url: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// Source of page content, swappable so the archiver can run without the network
+#[async_trait]
+pub trait PageFetcher: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<FetchedResource, String>;
+}
+
+pub struct HttpPageFetcher {
+    client: reqwest::Client,
+}
+
+impl HttpPageFetcher {
+    pub fn new() -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("rayzen/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+        Ok(HttpPageFetcher { client })
+    }
+}
+
+#[async_trait]
+impl PageFetcher for HttpPageFetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedResource, String> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .error_for_status()
+            .map_err(|e| e.to_string())?;
+
+        let final_url = response.url().to_string();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let body = response.bytes().await.map_err(|e| e.to_string())?;
+
+        Ok(FetchedResource {
+            url: final_url,
+            content_type,
+            body: body.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use scraper::{Html, Selector};
+use url::Url;
+
+use super::fetcher::PageFetcher;
+
+/// Rewrites stylesheet and image references into data URIs so the page renders offline
+pub async fn inline_assets(
+    fetcher: &dyn PageFetcher,
+    base_url: &str,
+    html: &str,
+) -> Result<String, String> {
+    let base = Url::parse(base_url).map_err(|e| e.to_string())?;
+    let references = collect_asset_references(html);
+
+    let mut inlined = html.to_string();
+    for (reference, fallback_type) in references {
+        let Ok(asset_url) = base.join(&reference) else {
+            continue;
+        };
+
+        // A missing asset keeps its original reference rather than failing the whole snapshot
+        let Ok(asset) = fetcher.fetch(asset_url.as_str()).await else {
+            continue;
+        };
+
+        let content_type = asset
+            .content_type
+            .as_deref()
+            .and_then(|value| value.split(';').next())
+            .unwrap_or(fallback_type)
+            .trim()
+            .to_string();
+        let data_uri = format!(
+            "data:{};base64,{}",
+            content_type,
+            STANDARD.encode(&asset.body)
+        );
+
+        inlined = inlined
+            .replace(&format!("\"{}\"", reference), &format!("\"{}\"", data_uri))
+            .replace(&format!("'{}'", reference), &format!("'{}'", data_uri));
+    }
+
+    Ok(inlined)
+}
+
+fn collect_asset_references(html: &str) -> Vec<(String, &'static str)> {
+    let document = Html::parse_document(html);
+    let stylesheets = Selector::parse("link[rel~=\"stylesheet\"][href]").unwrap();
+    let images = Selector::parse("img[src]").unwrap();
+
+    let mut references: Vec<(String, &'static str)> = Vec::new();
+
+    for element in document.select(&stylesheets) {
+        if let Some(href) = element.value().attr("href") {
+            references.push((href.to_string(), "text/css"));
+        }
+    }
+    for element in document.select(&images) {
+        if let Some(src) = element.value().attr("src") {
+            references.push((src.to_string(), "application/octet-stream"));
+        }
+    }
+
+    references.retain(|(reference, _)| !reference.is_empty() && !reference.starts_with("data:"));
+    references.sort();
+    references.dedup();
+    references
+}
@@ -0,0 +1,81 @@
+pub mod fetcher;
+pub mod inliner;
+pub mod storage;
+
+use std::sync::Arc;
+
+use fetcher::PageFetcher;
+use storage::SnapshotStorage;
+
+/// Result of archiving a page to disk
+#[derive(Debug, Clone)]
+pub struct ArchivedPage {
+    pub source_url: String,
+    /// Pending file the page was written to, see `PageArchiver::keep`
+    pub file_path: String,
+    pub byte_size: i64,
+    pub compressed_size: i64,
+    /// HTML as fetched, before any asset inlining
+    pub page_html: String,
+}
+
+pub struct PageArchiver {
+    pub fetcher: Arc<dyn PageFetcher>,
+    pub storage: SnapshotStorage,
+}
+
+impl PageArchiver {
+    pub fn new(fetcher: Arc<dyn PageFetcher>, storage: SnapshotStorage) -> Self {
+        PageArchiver { fetcher, storage }
+    }
+
+    /// Fetches and writes the page to a pending file. It becomes a snapshot's file through
+    /// `keep` once the snapshot is saved, or is removed with `discard`
+    pub async fn archive(
+        &self,
+        bookmark_id: i32,
+        url: &str,
+        single_file: bool,
+    ) -> Result<ArchivedPage, String> {
+        let page = self.fetcher.fetch(url).await?;
+        let page_html = String::from_utf8_lossy(&page.body).into_owned();
+
+        let html = if single_file {
+            inliner::inline_assets(self.fetcher.as_ref(), &page.url, &page_html).await?
+        } else {
+            page_html.clone()
+        };
+
+        let path = self.storage.pending_path_for(bookmark_id);
+        let compressed_size = self.storage.write(&path, &html).await?;
+
+        Ok(ArchivedPage {
+            source_url: page.url,
+            file_path: path.to_string_lossy().into_owned(),
+            byte_size: html.len() as i64,
+            compressed_size: compressed_size as i64,
+            page_html,
+        })
+    }
+
+    /// Where version `version` of the bookmark's snapshots is kept
+    pub fn path_for(&self, bookmark_id: i32, version: i32) -> String {
+        self.storage
+            .path_for(bookmark_id, version)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Moves an archived page to `file_path`, the path of the snapshot saved for it
+    pub async fn keep(&self, archived: &ArchivedPage, file_path: &str) -> Result<(), String> {
+        self.storage.rename(&archived.file_path, file_path).await
+    }
+
+    pub async fn discard(&self, archived: &ArchivedPage) {
+        self.storage.remove(&archived.file_path).await;
+    }
+
+    pub async fn open(&self, file_path: &str) -> Result<String, String> {
+        self.storage.read(file_path).await
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use uuid::Uuid;
+
+/// Root directory for rayzen's on-disk data, overridable with `RAYZEN_DATA_DIR`
+pub fn app_data_dir() -> PathBuf {
+    if let Ok(dir) = std::env::var("RAYZEN_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("com.me.rayzen")
+}
+
+pub struct SnapshotStorage {
+    pub root: PathBuf,
+}
+
+impl SnapshotStorage {
+    pub fn new(root: PathBuf) -> Self {
+        SnapshotStorage { root }
+    }
+
+    /// Snapshots live under `<root>/<bookmark_id>/v<version>.html.gz`
+    pub fn path_for(&self, bookmark_id: i32, version: i32) -> PathBuf {
+        self.root
+            .join(bookmark_id.to_string())
+            .join(format!("v{}.html.gz", version))
+    }
+
+    /// Where a page waits until its snapshot is saved and has a version, unique per call
+    pub fn pending_path_for(&self, bookmark_id: i32) -> PathBuf {
+        self.root
+            .join(bookmark_id.to_string())
+            .join(format!("pending-{}.html.gz", Uuid::new_v4()))
+    }
+
+    pub async fn rename(&self, from: &str, to: &str) -> Result<(), String> {
+        tokio::fs::rename(from, to).await.map_err(|e| e.to_string())
+    }
+
+    /// Removes a page that never became a snapshot, one that is already gone is fine
+    pub async fn remove(&self, path: &str) {
+        let _ = tokio::fs::remove_file(path).await;
+    }
+
+    /// Returns the compressed size in bytes
+    pub async fn write(&self, path: &PathBuf, html: &str) -> Result<u64, String> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(html.as_bytes())
+            .map_err(|e| e.to_string())?;
+        let compressed = encoder.finish().map_err(|e| e.to_string())?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        tokio::fs::write(path, &compressed)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(compressed.len() as u64)
+    }
+
+    pub async fn read(&self, path: &str) -> Result<String, String> {
+        let compressed = tokio::fs::read(path).await.map_err(|e| e.to_string())?;
+
+        let mut html = String::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut html)
+            .map_err(|e| e.to_string())?;
+
+        Ok(html)
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::{Arc, Mutex};
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    db,
+    domain::{
+        archiver::{
+            fetcher::HttpPageFetcher,
+            storage::{app_data_dir, SnapshotStorage},
+            PageArchiver,
+        },
+        service::container::{Repositories, Services},
+    },
+};
+
+/// Database and services every operation runs against, whether it comes from rspc, the HTTP API
+/// or the command line
+#[derive(Clone)]
+pub struct ContextRouter {
+    pub db: Arc<DatabaseConnection>,
+    pub message: Arc<Mutex<String>>,
+    pub services: Arc<Services>,
+}
+
+impl ContextRouter {
+    pub fn new(db: Arc<DatabaseConnection>, services: Arc<Services>) -> Self {
+        Self {
+            db: db.clone(),
+            message: Arc::new(Mutex::new("Hello World".to_string())),
+            services,
+        }
+    }
+
+    /// Connects and migrates the database, then wires up the SeaORM repositories and services
+    pub async fn connect(database_uri: &str) -> Result<Self, String> {
+        let db = db::establish_connection(database_uri)
+            .await
+            .map_err(|e| format!("Failed to establish database connection: {}", e))?;
+
+        // Page snapshots are stored next to the rest of the app data
+        let archiver = Arc::new(PageArchiver::new(
+            Arc::new(HttpPageFetcher::new()?),
+            SnapshotStorage::new(app_data_dir().join("snapshots")),
+        ));
+
+        // Repositories and services are built once and shared by every request
+        let services = Arc::new(Services::new(Repositories::sea_orm(), archiver));
+
+        let context = ContextRouter::new(Arc::new(db), services);
+        let sessions = Arc::clone(&context.services.sessions);
+        sessions.restore(context.clone()).await?;
+
+        Ok(context)
+    }
+}
+
+/// `DATABASE_URL` from the environment or a `.env` file, shared by the app and the CLI
+pub fn database_uri() -> Result<String, String> {
+    dotenvy::dotenv().ok();
+
+    std::env::var("DATABASE_URL")
+        .map_err(|_| "DATABASE_URL must be set in the environment".to_string())
+}
@@ -0,0 +1,119 @@
+//! Parses `rayzen://` links, independent of how the OS hands them to the app
+//!
+//! ```text
+//! rayzen://add?url=https%3A%2F%2Fexample.com&group=Articles&name=Example&tags=a,b
+//! rayzen://open/workspace/3
+//! rayzen://open/group/12
+//! rayzen://open/bookmark/42
+//! ```
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use url::Url;
+
+use crate::domain::{
+    dto::{
+        bookmark::bookmark_dto::{BookmarkDto, CreateBookmarkDto},
+        groups::groups_dto::GroupsDto,
+        workspace::workspace_dto::WorkspaceDto,
+    },
+    util::normalize_tags,
+};
+
+pub const SCHEME: &str = "rayzen";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    /// `group` is a group id or name, resolved when the link is dispatched
+    Add {
+        url: String,
+        group: String,
+        name: Option<String>,
+        tags: String,
+    },
+    OpenWorkspace(i32),
+    OpenGroup(i32),
+    OpenBookmark(i32),
+}
+
+pub fn parse_deep_link(link: &str) -> Result<DeepLink, String> {
+    let parsed = Url::parse(link.trim()).map_err(|e| format!("Invalid link: {}", e))?;
+    if parsed.scheme() != SCHEME {
+        return Err(format!("Not a {}:// link", SCHEME));
+    }
+
+    match parsed.host_str() {
+        Some("add") => parse_add(&parsed),
+        Some("open") => parse_open(&parsed),
+        Some(action) => Err(format!("Unsupported link action \"{}\"", action)),
+        None => Err("Link has no action".to_string()),
+    }
+}
+
+fn parse_add(link: &Url) -> Result<DeepLink, String> {
+    let param = |key: &str| {
+        link.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    let url = param("url").ok_or_else(|| "Add link is missing a url".to_string())?;
+    let target = Url::parse(&url).map_err(|_| format!("\"{}\" is not a valid url", url))?;
+    if !matches!(target.scheme(), "http" | "https") {
+        return Err("Only http and https urls can be added".to_string());
+    }
+    let group = param("group").ok_or_else(|| "Add link is missing a group".to_string())?;
+
+    Ok(DeepLink::Add {
+        url,
+        group,
+        name: param("name"),
+        tags: normalize_tags(&param("tags").unwrap_or_default()),
+    })
+}
+
+fn parse_open(link: &Url) -> Result<DeepLink, String> {
+    let segments: Vec<&str> = link
+        .path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default();
+
+    let [kind, id] = segments.as_slice() else {
+        return Err(
+            "Open links look like rayzen://open/<workspace|group|bookmark>/<id>".to_string(),
+        );
+    };
+    let id = id
+        .parse::<i32>()
+        .ok()
+        .filter(|id| *id > 0)
+        .ok_or_else(|| format!("\"{}\" is not a valid id", id))?;
+
+    match *kind {
+        "workspace" => Ok(DeepLink::OpenWorkspace(id)),
+        "group" => Ok(DeepLink::OpenGroup(id)),
+        "bookmark" => Ok(DeepLink::OpenBookmark(id)),
+        kind => Err(format!("Cannot open \"{}\"", kind)),
+    }
+}
+
+/// What the app should do with a link once it has been checked against the database
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeepLinkAction {
+    /// Nothing is saved yet, the app asks the user before calling `bookmark.create` with it
+    ConfirmAdd {
+        bookmark: CreateBookmarkDto,
+        group: GroupsDto,
+    },
+    OpenWorkspace {
+        workspace: WorkspaceDto,
+    },
+    OpenGroup {
+        group: GroupsDto,
+    },
+    OpenBookmark {
+        bookmark: BookmarkDto,
+    },
+}
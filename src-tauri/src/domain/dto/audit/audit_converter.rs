@@ -0,0 +1,19 @@
+use crate::domain::dto::audit::audit_dto::AuditEventDto;
+use entity::audit_event;
+
+/// Convert SeaORM AuditEvent Model to DTO, the actor name is filled in by the service
+impl From<audit_event::Model> for AuditEventDto {
+    fn from(model: audit_event::Model) -> Self {
+        AuditEventDto {
+            id: model.id,
+            actor_user_id: model.actor_user_id,
+            actor_name: None,
+            entity_type: model.entity_type,
+            entity_id: model.entity_id,
+            action: model.action,
+            before: model.before,
+            after: model.after,
+            created_at: model.created_at,
+        }
+    }
+}
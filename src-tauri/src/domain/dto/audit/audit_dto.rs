@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::domain::types::pagination::ListParams;
+
+/// One recorded change, `before` and `after` are the row as JSON text
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AuditEventDto {
+    pub id: i32,
+    /// `None` for changes made while nobody was signed in
+    pub actor_user_id: Option<i32>,
+    pub actor_name: Option<String>,
+    /// `user`, `organization`, `organization_member`, `workspace`, `group` or `bookmark`
+    pub entity_type: String,
+    pub entity_id: i32,
+    /// `create`, `update` or `delete`
+    pub action: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Filters of `audit.list`, dates go in `params.filter.created_after`/`created_before`.
+/// Newest events come first unless `params.sort` says otherwise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct AuditQueryDto {
+    /// Only changes to this organization and its members, workspaces, groups and bookmarks
+    pub organization_id: i32,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub params: ListParams,
+}
@@ -0,0 +1,2 @@
+pub mod audit_converter;
+pub mod audit_dto;
@@ -1,4 +1,8 @@
 use super::bookmark_dto::{BookmarkDto, CreateBookmarkDto, UpdateBookmarkDto};
+use crate::domain::{
+    types::read_later::ReadState,
+    util::{new_uuid, BaseEntity},
+};
 use entity::bookmark::{ActiveModel as BookmarkActiveModel, Model as BookmarkModel};
 use sea_orm::ActiveValue::Set;
 
@@ -6,14 +10,20 @@ use sea_orm::ActiveValue::Set;
 impl From<BookmarkModel> for BookmarkDto {
     fn from(model: BookmarkModel) -> Self {
         BookmarkDto {
-            id: model.id,
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
             name: model.name,
             url: model.url,
             tags: model.tags,
             is_favorite: model.is_favorite,
             group_id: model.group_id,
-            created_at: model.created_at,
-            updated_at: model.updated_at,
+            read_state: ReadState::from_db(&model.read_state),
+            reading_at: model.reading_at,
+            read_at: model.read_at,
+            archived_at: model.archived_at,
+            opened_at: model.opened_at,
+            open_count: model.open_count,
+            broken_at: model.broken_at,
+            reading_time_minutes: None,
         }
     }
 }
@@ -22,11 +32,13 @@ impl From<BookmarkModel> for BookmarkDto {
 impl From<CreateBookmarkDto> for BookmarkActiveModel {
     fn from(dto: CreateBookmarkDto) -> Self {
         BookmarkActiveModel {
+            uuid: Set(new_uuid()),
             name: Set(dto.name),
             url: Set(dto.url),
             tags: Set(dto.tags),
             is_favorite: Set(dto.is_favorite),
             group_id: Set(dto.group_id),
+            read_state: Set(ReadState::Unread.as_str().to_string()),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..Default::default()
@@ -2,17 +2,31 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use crate::domain::types::read_later::ReadState;
+use crate::domain::util::BaseEntity;
+
 /// Bookmark DTO for frontend communication
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct BookmarkDto {
-    pub id: i32,
+    #[serde(flatten)]
+    pub base: BaseEntity,
     pub name: String,
     pub url: String,
     pub tags: String,
     pub is_favorite: bool,
     pub group_id: i32,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+    pub read_state: ReadState,
+    pub reading_at: Option<DateTime<Utc>>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Last time the bookmark was opened from the app, launcher or tray
+    pub opened_at: Option<DateTime<Utc>>,
+    /// How many times the bookmark was opened
+    pub open_count: i32,
+    /// Set when the last snapshot could not fetch the page, cleared by the next one that can
+    pub broken_at: Option<DateTime<Utc>>,
+    /// Estimated from extracted page text, `None` until the page has been fetched
+    pub reading_time_minutes: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -33,3 +47,10 @@ pub struct UpdateBookmarkDto {
     pub is_favorite: Option<bool>,
     pub group_id: Option<i32>,
 }
+
+/// Copies a bookmark into another group, the original stays where it is
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CopyBookmarkDto {
+    pub id: i32,
+    pub group_id: i32,
+}
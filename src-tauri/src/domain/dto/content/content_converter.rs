@@ -0,0 +1,31 @@
+use super::content_dto::ReaderContentDto;
+use crate::domain::extractor::{reading_time_minutes, ExtractedContent};
+use entity::bookmark_content;
+use sea_orm::ActiveValue::Set;
+
+/// Convert SeaORM Content Model to DTO
+impl From<bookmark_content::Model> for ReaderContentDto {
+    fn from(model: bookmark_content::Model) -> Self {
+        ReaderContentDto {
+            bookmark_id: model.bookmark_id,
+            title: model.title,
+            text: model.text,
+            word_count: model.word_count,
+            reading_time_minutes: reading_time_minutes(model.word_count),
+            extracted_at: model.extracted_at,
+        }
+    }
+}
+
+impl ExtractedContent {
+    pub fn into_active_model(self, bookmark_id: i32) -> bookmark_content::ActiveModel {
+        bookmark_content::ActiveModel {
+            bookmark_id: Set(bookmark_id),
+            title: Set(self.title),
+            text: Set(self.text),
+            word_count: Set(self.word_count),
+            extracted_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Reader-mode text of an archived page
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ReaderContentDto {
+    pub bookmark_id: i32,
+    pub title: Option<String>,
+    pub text: String,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+    pub extracted_at: DateTime<Utc>,
+}
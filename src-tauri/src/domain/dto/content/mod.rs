@@ -0,0 +1,2 @@
+pub mod content_converter;
+pub mod content_dto;
@@ -1,4 +1,8 @@
-use super::groups_dto::{CreateGroupsDto, GroupsDto, UpdateGroupsDto};
+use super::groups_dto::{CreateGroupsDto, CreateSmartGroupDto, GroupsDto, UpdateGroupsDto};
+use crate::domain::{
+    types::groups::{GroupKind, SmartGroupFilter},
+    util::{new_uuid, BaseEntity},
+};
 use entity::groups;
 use sea_orm::ActiveValue::Set;
 
@@ -6,11 +10,14 @@ use sea_orm::ActiveValue::Set;
 impl From<groups::Model> for GroupsDto {
     fn from(model: groups::Model) -> Self {
         GroupsDto {
-            id: model.id,
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
             name: model.name,
             workspace_id: model.workspace_id,
-            created_at: model.created_at,
-            updated_at: model.updated_at,
+            kind: GroupKind::from_db(&model.kind),
+            filter: model
+                .filter
+                .as_deref()
+                .and_then(|filter| SmartGroupFilter::from_json(filter).ok()),
         }
     }
 }
@@ -19,8 +26,26 @@ impl From<groups::Model> for GroupsDto {
 impl From<CreateGroupsDto> for groups::ActiveModel {
     fn from(request: CreateGroupsDto) -> Self {
         groups::ActiveModel {
+            uuid: Set(new_uuid()),
             name: Set(request.name),
             workspace_id: Set(request.workspace_id),
+            kind: Set(GroupKind::Static.as_str().to_string()),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Convert CreateSmartGroupDto to SeaORM ActiveModel
+impl From<CreateSmartGroupDto> for groups::ActiveModel {
+    fn from(request: CreateSmartGroupDto) -> Self {
+        groups::ActiveModel {
+            uuid: Set(new_uuid()),
+            name: Set(request.name),
+            workspace_id: Set(request.workspace_id),
+            kind: Set(GroupKind::Smart.as_str().to_string()),
+            filter: Set(Some(request.filter.to_json())),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..Default::default()
@@ -1,14 +1,17 @@
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use crate::domain::types::groups::{GroupKind, SmartGroupFilter};
+use crate::domain::util::BaseEntity;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct GroupsDto {
-    pub id: i32,
+    #[serde(flatten)]
+    pub base: BaseEntity,
     pub name: String,
     pub workspace_id: i32,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+    pub kind: GroupKind,
+    pub filter: Option<SmartGroupFilter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -23,3 +26,33 @@ pub struct UpdateGroupsDto {
     pub name: Option<String>,
     pub workspace_id: Option<i32>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateSmartGroupDto {
+    pub name: String,
+    pub workspace_id: i32,
+    pub filter: SmartGroupFilter,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateSmartGroupFilterDto {
+    pub id: i32,
+    pub filter: SmartGroupFilter,
+}
+
+/// Moves a group with every bookmark in it to another workspace, which may belong to another
+/// organization
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MoveGroupDto {
+    pub id: i32,
+    pub workspace_id: i32,
+}
+
+/// Copies a group with every bookmark in it, into its own workspace unless another is given
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DuplicateGroupDto {
+    pub id: i32,
+    pub workspace_id: Option<i32>,
+    /// Defaults to the original name, with " (copy)" when it stays in the same workspace
+    pub name: Option<String>,
+}
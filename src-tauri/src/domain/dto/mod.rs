@@ -1,5 +1,12 @@
+pub mod audit;
 pub mod bookmark;
+pub mod content;
 pub mod groups;
 pub mod organization;
+pub mod read_later;
+pub mod saved_search;
+pub mod snapshot;
+pub mod stats;
 pub mod user;
 pub mod workspace;
+pub mod workspace_template;
@@ -1,18 +1,20 @@
-use crate::domain::dto::organization::organization_dto::{
-    CreateOrganizationDto, OrganizationDto, UpdateOrganizationDto,
+use crate::domain::{
+    dto::organization::organization_dto::{
+        CreateOrganizationDto, OrganizationDto, OrganizationMemberDto, UpdateOrganizationDto,
+    },
+    types::membership::MemberRole,
+    util::{new_uuid, parse_uuid, BaseEntity},
 };
-use entity::organization;
+use entity::{organization, organization_member, user};
 use sea_orm::ActiveValue::Set;
 
 /// Convert SeaORM Organization Model to DTO
 impl From<organization::Model> for OrganizationDto {
     fn from(model: organization::Model) -> Self {
         OrganizationDto {
-            id: model.id,
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
             name: model.name,
             user_id: model.user_id,
-            created_at: model.created_at,
-            updated_at: model.updated_at,
         }
     }
 }
@@ -21,6 +23,7 @@ impl From<organization::Model> for OrganizationDto {
 impl From<CreateOrganizationDto> for organization::ActiveModel {
     fn from(dto: CreateOrganizationDto) -> Self {
         organization::ActiveModel {
+            uuid: Set(new_uuid()),
             name: Set(dto.name),
             user_id: Set(dto.user_id),
             created_at: Set(chrono::Utc::now()),
@@ -53,3 +56,36 @@ impl From<UpdateOrganizationDto> for organization::ActiveModel {
         dto.apply_to_model(model)
     }
 }
+
+/// Convert a membership and its user to a DTO
+impl From<(organization_member::Model, user::Model)> for OrganizationMemberDto {
+    fn from((member, user): (organization_member::Model, user::Model)) -> Self {
+        OrganizationMemberDto {
+            id: member.id,
+            uuid: parse_uuid(&member.uuid),
+            organization_id: member.organization_id,
+            user_id: member.user_id,
+            name: user.name,
+            email: user.email,
+            role: MemberRole::from_db(&member.role),
+            created_at: member.created_at,
+        }
+    }
+}
+
+/// New membership of a user in an organization
+pub fn new_member(
+    organization_id: i32,
+    user_id: i32,
+    role: MemberRole,
+) -> organization_member::ActiveModel {
+    organization_member::ActiveModel {
+        uuid: Set(new_uuid()),
+        organization_id: Set(organization_id),
+        user_id: Set(user_id),
+        role: Set(role.as_str().to_string()),
+        created_at: Set(chrono::Utc::now()),
+        updated_at: Set(chrono::Utc::now()),
+        ..Default::default()
+    }
+}
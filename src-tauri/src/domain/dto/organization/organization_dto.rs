@@ -1,14 +1,17 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use uuid::Uuid;
+
+use crate::domain::types::membership::MemberRole;
+use crate::domain::util::BaseEntity;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct OrganizationDto {
-    pub id: i32,
+    #[serde(flatten)]
+    pub base: BaseEntity,
     pub name: String,
     pub user_id: i32,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -23,3 +26,30 @@ pub struct UpdateOrganizationDto {
     pub name: Option<String>,
     pub user_id: Option<i32>,
 }
+
+/// A user's membership in an organization with their name and email
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct OrganizationMemberDto {
+    pub id: i32,
+    pub uuid: Uuid,
+    pub organization_id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub email: String,
+    pub role: MemberRole,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Adds an existing user, found by email, to an organization
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct InviteMemberDto {
+    pub organization_id: i32,
+    pub email: String,
+    pub role: MemberRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateMemberRoleDto {
+    pub id: i32,
+    pub role: MemberRole,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::domain::types::{pagination::ListParams, read_later::ReadState};
+
+/// Oldest bookmarks come first unless `params.sort` says otherwise
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ReadLaterQueueDto {
+    pub group_id: Option<i32>,
+    pub states: Option<Vec<ReadState>>,
+    pub params: ListParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SetReadStateDto {
+    pub id: i32,
+    pub state: ReadState,
+}
+
+/// Per-group read state counts used for sidebar badges
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct ReadLaterCountDto {
+    pub group_id: i32,
+    pub unread: i32,
+    pub reading: i32,
+    pub read: i32,
+    pub archived: i32,
+}
@@ -0,0 +1,2 @@
+pub mod saved_search_converter;
+pub mod saved_search_dto;
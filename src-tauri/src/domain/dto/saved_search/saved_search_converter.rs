@@ -0,0 +1,53 @@
+use super::saved_search_dto::{CreateSavedSearchDto, SavedSearchDto, UpdateSavedSearchDto};
+use crate::domain::util::{new_uuid, BaseEntity};
+use entity::saved_search;
+use sea_orm::ActiveValue::Set;
+
+/// Convert SeaORM SavedSearch Model to DTO
+impl From<saved_search::Model> for SavedSearchDto {
+    fn from(model: saved_search::Model) -> Self {
+        SavedSearchDto {
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
+            workspace_id: model.workspace_id,
+            name: model.name,
+            query: model.query,
+            is_pinned: model.is_pinned,
+            cached_count: model.cached_count,
+            counted_at: model.counted_at,
+        }
+    }
+}
+
+/// Convert CreateSavedSearchDto to SeaORM ActiveModel
+impl From<CreateSavedSearchDto> for saved_search::ActiveModel {
+    fn from(dto: CreateSavedSearchDto) -> Self {
+        saved_search::ActiveModel {
+            uuid: Set(new_uuid()),
+            workspace_id: Set(dto.workspace_id),
+            name: Set(dto.name),
+            query: Set(dto.query),
+            is_pinned: Set(dto.is_pinned),
+            cached_count: Set(0),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Convert UpdateSavedSearchDto to partial SeaORM ActiveModel
+impl UpdateSavedSearchDto {
+    pub fn apply_to_model(self, mut model: saved_search::ActiveModel) -> saved_search::ActiveModel {
+        if let Some(name) = self.name {
+            model.name = Set(name);
+        }
+        if let Some(query) = self.query {
+            model.query = Set(query);
+        }
+        if let Some(is_pinned) = self.is_pinned {
+            model.is_pinned = Set(is_pinned);
+        }
+        model.updated_at = Set(chrono::Utc::now());
+        model
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::domain::dto::bookmark::bookmark_dto::BookmarkDto;
+use crate::domain::util::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SavedSearchDto {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub workspace_id: i32,
+    pub name: String,
+    pub query: String,
+    pub is_pinned: bool,
+    pub cached_count: i32,
+    pub counted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateSavedSearchDto {
+    pub workspace_id: i32,
+    pub name: String,
+    pub query: String,
+    pub is_pinned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateSavedSearchDto {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+    pub query: Option<String>,
+    pub is_pinned: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SavedSearchResultDto {
+    pub saved_search: SavedSearchDto,
+    pub results: Vec<BookmarkDto>,
+}
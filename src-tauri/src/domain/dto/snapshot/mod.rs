@@ -0,0 +1,2 @@
+pub mod snapshot_converter;
+pub mod snapshot_dto;
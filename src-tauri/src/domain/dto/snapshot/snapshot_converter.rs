@@ -0,0 +1,48 @@
+use super::snapshot_dto::SnapshotDto;
+use crate::domain::archiver::ArchivedPage;
+use entity::bookmark_snapshot;
+use sea_orm::ActiveValue::Set;
+
+/// Sizes are stored as `i64`, anything past 4 GiB is reported as `u32::MAX`
+fn saturating_size(bytes: i64) -> u32 {
+    u32::try_from(bytes.max(0)).unwrap_or(u32::MAX)
+}
+
+/// Convert SeaORM Snapshot Model to DTO
+impl From<bookmark_snapshot::Model> for SnapshotDto {
+    fn from(model: bookmark_snapshot::Model) -> Self {
+        SnapshotDto {
+            id: model.id,
+            bookmark_id: model.bookmark_id,
+            version: model.version,
+            source_url: model.source_url,
+            is_single_file: model.is_single_file,
+            byte_size: saturating_size(model.byte_size),
+            compressed_size: saturating_size(model.compressed_size),
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl ArchivedPage {
+    /// Row of the snapshot once its page is kept at `file_path`
+    pub fn to_active_model(
+        &self,
+        bookmark_id: i32,
+        version: i32,
+        file_path: String,
+        is_single_file: bool,
+    ) -> bookmark_snapshot::ActiveModel {
+        bookmark_snapshot::ActiveModel {
+            bookmark_id: Set(bookmark_id),
+            version: Set(version),
+            source_url: Set(self.source_url.clone()),
+            file_path: Set(file_path),
+            is_single_file: Set(is_single_file),
+            byte_size: Set(self.byte_size),
+            compressed_size: Set(self.compressed_size),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+    }
+}
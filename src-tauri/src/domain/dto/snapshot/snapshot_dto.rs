@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SnapshotDto {
+    pub id: i32,
+    pub bookmark_id: i32,
+    pub version: i32,
+    pub source_url: String,
+    pub is_single_file: bool,
+    pub byte_size: u32,
+    pub compressed_size: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateSnapshotDto {
+    pub bookmark_id: i32,
+    pub single_file: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SnapshotContentDto {
+    pub snapshot: SnapshotDto,
+    pub html: String,
+}
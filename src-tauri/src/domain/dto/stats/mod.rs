@@ -0,0 +1 @@
+pub mod stats_dto;
@@ -0,0 +1,71 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// What a stats query covers, an organization counts every one of its workspaces
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StatsScope {
+    Organization { organization_id: i32 },
+    Workspace { workspace_id: i32 },
+}
+
+/// Input of `stats.topTags`, `stats.topHosts` and `stats.mostVisited`, 10 rows unless `limit`
+/// says otherwise
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StatsQueryDto {
+    pub scope: StatsScope,
+    pub limit: Option<u32>,
+}
+
+/// Input of `stats.addedPerWeek`, the last 12 weeks unless `weeks` says otherwise
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WeeklyStatsQueryDto {
+    pub scope: StatsScope,
+    pub weeks: Option<u32>,
+}
+
+/// Archived bookmarks are counted too
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct StatsCountsDto {
+    pub groups: u32,
+    pub bookmarks: u32,
+    pub favorites: u32,
+    /// Bookmarks whose last snapshot could not fetch the page
+    pub broken_links: u32,
+    pub untagged: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WorkspaceStatsDto {
+    pub workspace_id: i32,
+    pub name: String,
+    pub counts: StatsCountsDto,
+}
+
+/// Totals of the organization next to the counts of each workspace, ordered by name
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct OrganizationStatsDto {
+    pub organization_id: i32,
+    pub totals: StatsCountsDto,
+    pub workspaces: Vec<WorkspaceStatsDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TagCountDto {
+    pub tag: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct HostCountDto {
+    pub host: String,
+    pub count: u32,
+}
+
+/// Bookmarks created in the week starting on the Monday `week_start`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WeekCountDto {
+    pub week_start: NaiveDate,
+    pub count: u32,
+}
@@ -1,4 +1,5 @@
 use super::user_dto::{CreateUserDto, UpdateUserDto, UserDto};
+use crate::domain::util::{new_uuid, BaseEntity};
 use entity::user;
 use sea_orm::ActiveValue::Set;
 
@@ -6,11 +7,9 @@ use sea_orm::ActiveValue::Set;
 impl From<user::Model> for UserDto {
     fn from(model: user::Model) -> Self {
         UserDto {
-            id: model.id,
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
             name: model.name,
             email: model.email,
-            created_at: model.created_at,
-            updated_at: model.updated_at,
         }
     }
 }
@@ -19,6 +18,7 @@ impl From<user::Model> for UserDto {
 impl From<CreateUserDto> for user::ActiveModel {
     fn from(request: CreateUserDto) -> Self {
         user::ActiveModel {
+            uuid: Set(new_uuid()),
             name: Set(request.name),
             email: Set(request.email),
             created_at: Set(chrono::Utc::now()),
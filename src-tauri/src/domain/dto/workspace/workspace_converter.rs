@@ -1,6 +1,7 @@
 use crate::domain::dto::workspace::workspace_dto::{
     CreateWorkspaceDto, UpdateWorkspaceDto, WorkspaceDto,
 };
+use crate::domain::util::{new_uuid, BaseEntity};
 use entity::workspace::{self, ActiveModel, Model};
 use sea_orm::ActiveValue::Set;
 
@@ -8,11 +9,9 @@ use sea_orm::ActiveValue::Set;
 impl From<Model> for WorkspaceDto {
     fn from(model: Model) -> Self {
         Self {
-            id: model.id,
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
             name: model.name,
             organization_id: model.organization_id,
-            created_at: model.created_at,
-            updated_at: model.updated_at,
         }
     }
 }
@@ -21,6 +20,7 @@ impl From<Model> for WorkspaceDto {
 impl From<CreateWorkspaceDto> for ActiveModel {
     fn from(dto: CreateWorkspaceDto) -> Self {
         ActiveModel {
+            uuid: Set(new_uuid()),
             name: Set(dto.name),
             organization_id: Set(dto.organization_id),
             created_at: Set(chrono::Utc::now()),
@@ -1,14 +1,14 @@
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use crate::domain::util::BaseEntity;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct WorkspaceDto {
-    pub id: i32,
+    #[serde(flatten)]
+    pub base: BaseEntity,
     pub name: String,
     pub organization_id: i32,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
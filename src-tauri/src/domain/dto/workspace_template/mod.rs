@@ -0,0 +1,2 @@
+pub mod workspace_template_converter;
+pub mod workspace_template_dto;
@@ -0,0 +1,15 @@
+use super::workspace_template_dto::{TemplateLayout, WorkspaceTemplateDto};
+use crate::domain::util::BaseEntity;
+use entity::workspace_template;
+
+/// Convert SeaORM WorkspaceTemplate Model to DTO, a layout that no longer parses shows as empty
+impl From<workspace_template::Model> for WorkspaceTemplateDto {
+    fn from(model: workspace_template::Model) -> Self {
+        WorkspaceTemplateDto {
+            base: BaseEntity::new(model.id, &model.uuid, model.created_at, model.updated_at),
+            organization_id: model.organization_id,
+            name: model.name,
+            layout: TemplateLayout::from_json(&model.layout).unwrap_or_default(),
+        }
+    }
+}
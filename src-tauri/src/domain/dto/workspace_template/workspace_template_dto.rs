@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use crate::domain::types::groups::{GroupKind, SmartGroupFilter};
+use crate::domain::util::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WorkspaceTemplateDto {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub organization_id: i32,
+    pub name: String,
+    pub layout: TemplateLayout,
+}
+
+/// Groups a workspace created from a template starts with, in the order they were saved
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct TemplateLayout {
+    pub groups: Vec<TemplateGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TemplateGroup {
+    pub name: String,
+    pub kind: GroupKind,
+    pub filter: Option<SmartGroupFilter>,
+    /// Empty unless the template was saved with its bookmarks
+    #[serde(default)]
+    pub bookmarks: Vec<TemplateBookmark>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TemplateBookmark {
+    pub name: String,
+    pub url: String,
+    pub tags: String,
+    pub is_favorite: bool,
+}
+
+impl TemplateLayout {
+    pub fn from_json(value: &str) -> Result<Self, String> {
+        serde_json::from_str(value).map_err(|e| format!("Invalid template layout: {}", e))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Template layout is always serializable")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SaveWorkspaceTemplateDto {
+    pub workspace_id: i32,
+    pub name: String,
+    /// Archived bookmarks are left out either way
+    pub include_bookmarks: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateFromTemplateDto {
+    pub template_id: i32,
+    /// May differ from the organization the template was saved in
+    pub organization_id: i32,
+    pub name: String,
+}
+
+/// What `exportTemplate` writes, rows are referred to by UUID so the file means the same on
+/// every instance
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TemplateExport {
+    pub version: u32,
+    pub uuid: Uuid,
+    pub organization_uuid: Uuid,
+    pub name: String,
+    pub exported_at: DateTime<Utc>,
+    pub layout: TemplateLayout,
+}
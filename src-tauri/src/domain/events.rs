@@ -0,0 +1,276 @@
+use async_stream::stream;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::domain::dto::{
+    bookmark::bookmark_dto::BookmarkDto, groups::groups_dto::GroupsDto,
+    workspace::workspace_dto::WorkspaceDto,
+};
+
+/// How many events a slow subscriber can fall behind before it starts missing some
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    /// The subscriber fell behind and missed changes, everything it shows should be fetched again
+    Resync,
+}
+
+/// A bookmark changed in `workspace_id`, `bookmark` is `None` once it has been deleted and
+/// `bookmark_id` is 0 on a resync
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BookmarkChangedEvent {
+    pub workspace_id: i32,
+    pub bookmark_id: i32,
+    pub kind: ChangeKind,
+    pub bookmark: Option<BookmarkDto>,
+}
+
+impl BookmarkChangedEvent {
+    pub fn created(workspace_id: i32, bookmark: BookmarkDto) -> Self {
+        Self::changed(workspace_id, ChangeKind::Created, bookmark)
+    }
+
+    pub fn updated(workspace_id: i32, bookmark: BookmarkDto) -> Self {
+        Self::changed(workspace_id, ChangeKind::Updated, bookmark)
+    }
+
+    pub fn deleted(workspace_id: i32, bookmark_id: i32) -> Self {
+        BookmarkChangedEvent {
+            workspace_id,
+            bookmark_id,
+            kind: ChangeKind::Deleted,
+            bookmark: None,
+        }
+    }
+
+    pub fn resync(workspace_id: i32) -> Self {
+        BookmarkChangedEvent {
+            workspace_id,
+            bookmark_id: 0,
+            kind: ChangeKind::Resync,
+            bookmark: None,
+        }
+    }
+
+    fn changed(workspace_id: i32, kind: ChangeKind, bookmark: BookmarkDto) -> Self {
+        BookmarkChangedEvent {
+            workspace_id,
+            bookmark_id: bookmark.base.id,
+            kind,
+            bookmark: Some(bookmark),
+        }
+    }
+}
+
+/// A group changed in `workspace_id`, `group` is `None` once it has been deleted and `group_id`
+/// is 0 on a resync
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct GroupChangedEvent {
+    pub workspace_id: i32,
+    pub group_id: i32,
+    pub kind: ChangeKind,
+    pub group: Option<GroupsDto>,
+}
+
+impl GroupChangedEvent {
+    pub fn created(group: GroupsDto) -> Self {
+        Self::changed(ChangeKind::Created, group)
+    }
+
+    pub fn updated(group: GroupsDto) -> Self {
+        Self::changed(ChangeKind::Updated, group)
+    }
+
+    pub fn deleted(workspace_id: i32, group_id: i32) -> Self {
+        GroupChangedEvent {
+            workspace_id,
+            group_id,
+            kind: ChangeKind::Deleted,
+            group: None,
+        }
+    }
+
+    pub fn resync(workspace_id: i32) -> Self {
+        GroupChangedEvent {
+            workspace_id,
+            group_id: 0,
+            kind: ChangeKind::Resync,
+            group: None,
+        }
+    }
+
+    fn changed(kind: ChangeKind, group: GroupsDto) -> Self {
+        GroupChangedEvent {
+            workspace_id: group.workspace_id,
+            group_id: group.base.id,
+            kind,
+            group: Some(group),
+        }
+    }
+}
+
+/// A workspace changed in `organization_id`, `workspace` is `None` once it has been deleted and
+/// `workspace_id` is 0 on a resync
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WorkspaceChangedEvent {
+    pub organization_id: i32,
+    pub workspace_id: i32,
+    pub kind: ChangeKind,
+    pub workspace: Option<WorkspaceDto>,
+}
+
+impl WorkspaceChangedEvent {
+    pub fn created(workspace: WorkspaceDto) -> Self {
+        Self::changed(ChangeKind::Created, workspace)
+    }
+
+    pub fn updated(workspace: WorkspaceDto) -> Self {
+        Self::changed(ChangeKind::Updated, workspace)
+    }
+
+    pub fn deleted(organization_id: i32, workspace_id: i32) -> Self {
+        WorkspaceChangedEvent {
+            organization_id,
+            workspace_id,
+            kind: ChangeKind::Deleted,
+            workspace: None,
+        }
+    }
+
+    pub fn resync(organization_id: i32) -> Self {
+        WorkspaceChangedEvent {
+            organization_id,
+            workspace_id: 0,
+            kind: ChangeKind::Resync,
+            workspace: None,
+        }
+    }
+
+    fn changed(kind: ChangeKind, workspace: WorkspaceDto) -> Self {
+        WorkspaceChangedEvent {
+            organization_id: workspace.organization_id,
+            workspace_id: workspace.base.id,
+            kind,
+            workspace: Some(workspace),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ChangeEvent {
+    Bookmark(BookmarkChangedEvent),
+    Group(GroupChangedEvent),
+    Workspace(WorkspaceChangedEvent),
+    /// Sent to a subscriber in place of the changes it fell too far behind to receive
+    Resync,
+}
+
+impl From<BookmarkChangedEvent> for ChangeEvent {
+    fn from(event: BookmarkChangedEvent) -> Self {
+        ChangeEvent::Bookmark(event)
+    }
+}
+
+impl From<GroupChangedEvent> for ChangeEvent {
+    fn from(event: GroupChangedEvent) -> Self {
+        ChangeEvent::Group(event)
+    }
+}
+
+impl From<WorkspaceChangedEvent> for ChangeEvent {
+    fn from(event: WorkspaceChangedEvent) -> Self {
+        ChangeEvent::Workspace(event)
+    }
+}
+
+/// In-process bus every mutation publishes to, rspc subscriptions stream from it
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<ChangeEvent>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        EventBus { sender }
+    }
+
+    /// Events published while nobody is listening are dropped
+    pub fn publish(&self, event: impl Into<ChangeEvent>) {
+        let _ = self.sender.send(event.into());
+    }
+
+    pub fn bookmarks(&self, workspace_id: i32) -> impl Stream<Item = BookmarkChangedEvent> {
+        self.listen(
+            move |event| match event {
+                ChangeEvent::Bookmark(event) if event.workspace_id == workspace_id => Some(event),
+                _ => None,
+            },
+            move || BookmarkChangedEvent::resync(workspace_id),
+        )
+    }
+
+    pub fn groups(&self, workspace_id: i32) -> impl Stream<Item = GroupChangedEvent> {
+        self.listen(
+            move |event| match event {
+                ChangeEvent::Group(event) if event.workspace_id == workspace_id => Some(event),
+                _ => None,
+            },
+            move || GroupChangedEvent::resync(workspace_id),
+        )
+    }
+
+    pub fn workspaces(&self, organization_id: i32) -> impl Stream<Item = WorkspaceChangedEvent> {
+        self.listen(
+            move |event| match event {
+                ChangeEvent::Workspace(event) if event.organization_id == organization_id => {
+                    Some(event)
+                }
+                _ => None,
+            },
+            move || WorkspaceChangedEvent::resync(organization_id),
+        )
+    }
+
+    /// Every change regardless of scope, for app-wide listeners like the tray
+    pub fn all(&self) -> impl Stream<Item = ChangeEvent> {
+        self.listen(Some, || ChangeEvent::Resync)
+    }
+
+    fn listen<T, F, R>(&self, select: F, resync: R) -> impl Stream<Item = T>
+    where
+        T: Send + 'static,
+        F: Fn(ChangeEvent) -> Option<T> + Send + 'static,
+        R: Fn() -> T + Send + 'static,
+    {
+        let mut receiver = self.sender.subscribe();
+
+        stream! {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if let Some(event) = select(event) {
+                            yield event;
+                        }
+                    }
+                    // A subscriber that fell behind skips ahead and is told to fetch everything again
+                    Err(RecvError::Lagged(_)) => yield resync(),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,73 @@
+//! One row per bookmark with the workspace and group it belongs to, the columns match what
+//! `import::csv_file` needs so an export can be imported again. The UUID column lets that
+//! import recognize bookmarks it already has.
+
+use super::{ExportDocument, ExportGroup, Exporter};
+
+pub const CSV_COLUMNS: [&str; 9] = [
+    "workspace",
+    "group",
+    "name",
+    "url",
+    "tags",
+    "is_favorite",
+    "read_state",
+    "created_at",
+    "uuid",
+];
+
+pub struct CsvExporter;
+
+impl Exporter for CsvExporter {
+    fn name(&self) -> &'static str {
+        "csv"
+    }
+
+    fn label(&self) -> &'static str {
+        "CSV"
+    }
+
+    fn extension(&self) -> &'static str {
+        "csv"
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "text/csv"
+    }
+
+    fn export(&self, document: &ExportDocument) -> Result<String, String> {
+        write_bookmarks_csv(&document.groups)
+    }
+}
+
+pub fn write_bookmarks_csv(groups: &[ExportGroup]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(CSV_COLUMNS)
+        .map_err(|e| e.to_string())?;
+
+    for group in groups {
+        for bookmark in &group.bookmarks {
+            writer
+                .write_record([
+                    group.workspace.as_str(),
+                    group.name.as_str(),
+                    bookmark.name.as_str(),
+                    bookmark.url.as_str(),
+                    bookmark.tags.as_str(),
+                    if bookmark.is_favorite {
+                        "true"
+                    } else {
+                        "false"
+                    },
+                    bookmark.read_state.as_str(),
+                    &bookmark.base.created_at.to_rfc3339(),
+                    &bookmark.base.uuid.to_string(),
+                ])
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
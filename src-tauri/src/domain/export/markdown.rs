@@ -0,0 +1,86 @@
+//! A heading per group, nested groups one level deeper, and a bullet link per bookmark with
+//! its tags as inline code
+
+use super::{outline, ExportDocument, Exporter, OutlineNode};
+
+pub struct MarkdownExporter;
+
+impl Exporter for MarkdownExporter {
+    fn name(&self) -> &'static str {
+        "markdown"
+    }
+
+    fn label(&self) -> &'static str {
+        "Markdown"
+    }
+
+    fn extension(&self) -> &'static str {
+        "md"
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "text/markdown"
+    }
+
+    fn export(&self, document: &ExportDocument) -> Result<String, String> {
+        let mut out = format!("# {}\n", escape(&document.title));
+        for node in outline(&document.groups) {
+            write_node(&mut out, &node, 2);
+        }
+        Ok(out)
+    }
+}
+
+fn write_node(out: &mut String, node: &OutlineNode, level: usize) {
+    // Markdown stops at six levels, deeper groups share the last one
+    out.push_str(&format!(
+        "\n{} {}\n",
+        "#".repeat(level.min(6)),
+        escape(node.name)
+    ));
+
+    if !node.bookmarks.is_empty() {
+        out.push('\n');
+    }
+    for bookmark in &node.bookmarks {
+        out.push_str(&format!(
+            "- [{}]({})",
+            escape(&bookmark.name),
+            link(&bookmark.url)
+        ));
+        for tag in bookmark
+            .tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+        {
+            out.push_str(&format!(" `{}`", tag.replace('`', "")));
+        }
+        out.push('\n');
+    }
+
+    for child in &node.children {
+        write_node(out, child, level + 1);
+    }
+}
+
+/// Backslash escapes the characters that would turn text into markup
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '[' | ']' | '*' | '_' | '`' | '#' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Urls with spaces or parentheses only survive inside angle brackets
+fn link(url: &str) -> String {
+    if url.contains([' ', '(', ')']) {
+        format!("<{}>", url)
+    } else {
+        url.to_string()
+    }
+}
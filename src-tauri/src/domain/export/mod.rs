@@ -0,0 +1,198 @@
+//! Writes bookmarks out in formats other tools read, the inverse of `import`. Each format is
+//! an [`Exporter`] registered in [`Exporters`], procedures look them up by name so adding a
+//! format doesn't touch the routers.
+
+pub mod csv_file;
+pub mod markdown;
+pub mod opml;
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::domain::{dto::bookmark::bookmark_dto::BookmarkDto, import::FOLDER_SEPARATOR};
+
+/// Bookmarks of one group together with where the group lives
+#[derive(Debug, Clone)]
+pub struct ExportGroup {
+    pub workspace: String,
+    /// Nested groups from imports keep their ` / ` separated path
+    pub name: String,
+    pub bookmarks: Vec<BookmarkDto>,
+}
+
+/// What gets exported, `title` names the group, workspace or search it came from
+#[derive(Debug, Clone)]
+pub struct ExportDocument {
+    pub title: String,
+    pub groups: Vec<ExportGroup>,
+}
+
+pub trait Exporter: Send + Sync {
+    /// Name procedures select the format by, like `markdown`
+    fn name(&self) -> &'static str;
+    fn label(&self) -> &'static str;
+    fn extension(&self) -> &'static str;
+    fn mime_type(&self) -> &'static str;
+    fn export(&self, document: &ExportDocument) -> Result<String, String>;
+}
+
+/// Formats available to the export procedures
+#[derive(Clone)]
+pub struct Exporters {
+    exporters: BTreeMap<&'static str, Arc<dyn Exporter>>,
+}
+
+impl Exporters {
+    pub fn empty() -> Self {
+        Exporters {
+            exporters: BTreeMap::new(),
+        }
+    }
+
+    pub fn with(mut self, exporter: Arc<dyn Exporter>) -> Self {
+        self.exporters.insert(exporter.name(), exporter);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Result<Arc<dyn Exporter>, String> {
+        self.exporters
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Unknown export format \"{}\"", name))
+    }
+
+    pub fn formats(&self) -> Vec<ExportFormat> {
+        self.exporters
+            .values()
+            .map(|exporter| ExportFormat {
+                name: exporter.name().to_string(),
+                label: exporter.label().to_string(),
+                extension: exporter.extension().to_string(),
+            })
+            .collect()
+    }
+}
+
+impl Default for Exporters {
+    fn default() -> Self {
+        Exporters::empty()
+            .with(Arc::new(csv_file::CsvExporter))
+            .with(Arc::new(markdown::MarkdownExporter))
+            .with(Arc::new(opml::OpmlExporter))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExportFormat {
+    pub name: String,
+    pub label: String,
+    pub extension: String,
+}
+
+/// Which bookmarks to export
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportScope {
+    /// What the group shows, smart groups are evaluated
+    Group {
+        group_id: i32,
+    },
+    /// Every bookmark of the workspace, archived ones included
+    Workspace {
+        workspace_id: i32,
+    },
+    Search {
+        workspace_id: i32,
+        query: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExportDto {
+    pub format: String,
+    pub scope: ExportScope,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExportFile {
+    pub file_name: String,
+    pub mime_type: String,
+    pub content: String,
+}
+
+/// Group in the nested layout Markdown and OPML write, `Rust / Databases` is a `Databases`
+/// node below `Rust`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OutlineNode<'a> {
+    pub name: &'a str,
+    pub bookmarks: Vec<&'a BookmarkDto>,
+    pub children: Vec<OutlineNode<'a>>,
+}
+
+/// Builds the nested layout, empty groups are left out and everything keeps the order it was
+/// first seen in
+pub(crate) fn outline(groups: &[ExportGroup]) -> Vec<OutlineNode<'_>> {
+    let mut roots = Vec::new();
+    for group in groups {
+        let path: Vec<&str> = group
+            .name
+            .split(FOLDER_SEPARATOR)
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        insert(&mut roots, &path, &group.bookmarks);
+    }
+    prune(&mut roots);
+    roots
+}
+
+fn insert<'a>(nodes: &mut Vec<OutlineNode<'a>>, path: &[&'a str], bookmarks: &'a [BookmarkDto]) {
+    let Some((name, rest)) = path.split_first() else {
+        return;
+    };
+    let index = match nodes.iter().position(|node| node.name == *name) {
+        Some(index) => index,
+        None => {
+            nodes.push(OutlineNode {
+                name,
+                ..Default::default()
+            });
+            nodes.len() - 1
+        }
+    };
+
+    let node = &mut nodes[index];
+    if rest.is_empty() {
+        node.bookmarks.extend(bookmarks);
+    } else {
+        insert(&mut node.children, rest, bookmarks);
+    }
+}
+
+fn prune(nodes: &mut Vec<OutlineNode>) {
+    for node in nodes.iter_mut() {
+        prune(&mut node.children);
+    }
+    nodes.retain(|node| !node.bookmarks.is_empty() || !node.children.is_empty());
+}
+
+/// Name of a downloaded file, anything that isn't safe in a file name becomes `-`
+pub fn file_name(title: &str, extension: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let stem = stem
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if stem.is_empty() {
+        format!("bookmarks.{}", extension)
+    } else {
+        format!("{}.{}", stem.to_lowercase(), extension)
+    }
+}
@@ -0,0 +1,75 @@
+//! OPML 2.0 outline, one outline per group and a `link` outline per bookmark with its tags
+//! in `category`
+
+use super::{outline, ExportDocument, Exporter, OutlineNode};
+
+pub struct OpmlExporter;
+
+impl Exporter for OpmlExporter {
+    fn name(&self) -> &'static str {
+        "opml"
+    }
+
+    fn label(&self) -> &'static str {
+        "OPML"
+    }
+
+    fn extension(&self) -> &'static str {
+        "opml"
+    }
+
+    fn mime_type(&self) -> &'static str {
+        "text/x-opml"
+    }
+
+    fn export(&self, document: &ExportDocument) -> Result<String, String> {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<opml version=\"2.0\">\n");
+        out.push_str("  <head>\n");
+        out.push_str(&format!("    <title>{}</title>\n", escape(&document.title)));
+        out.push_str("  </head>\n");
+        out.push_str("  <body>\n");
+        for node in outline(&document.groups) {
+            write_node(&mut out, &node, 2);
+        }
+        out.push_str("  </body>\n");
+        out.push_str("</opml>\n");
+        Ok(out)
+    }
+}
+
+fn write_node(out: &mut String, node: &OutlineNode, depth: usize) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&format!(
+        "{}<outline text=\"{}\">\n",
+        indent,
+        escape(node.name)
+    ));
+
+    for bookmark in &node.bookmarks {
+        out.push_str(&format!(
+            "{}  <outline text=\"{}\" type=\"link\" url=\"{}\" created=\"{}\"",
+            indent,
+            escape(&bookmark.name),
+            escape(&bookmark.url),
+            bookmark.base.created_at.to_rfc2822()
+        ));
+        if !bookmark.tags.is_empty() {
+            out.push_str(&format!(" category=\"{}\"", escape(&bookmark.tags)));
+        }
+        out.push_str("/>\n");
+    }
+    for child in &node.children {
+        write_node(out, child, depth + 1);
+    }
+
+    out.push_str(&format!("{}</outline>\n", indent));
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,207 @@
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Average adult reading speed used for reading time estimates
+pub const WORDS_PER_MINUTE: i32 = 200;
+
+const BOILERPLATE_TAGS: &[&str] = &[
+    "script", "style", "noscript", "nav", "header", "footer", "aside", "form", "iframe", "svg",
+    "button", "template",
+];
+
+const BOILERPLATE_HINTS: &[&str] = &[
+    "ad",
+    "ads",
+    "advert",
+    "banner",
+    "breadcrumb",
+    "breadcrumbs",
+    "comment",
+    "comments",
+    "cookie",
+    "footer",
+    "menu",
+    "nav",
+    "navbar",
+    "popup",
+    "promo",
+    "related",
+    "share",
+    "sidebar",
+    "social",
+    "sponsor",
+    "subscribe",
+    "widget",
+];
+
+const BLOCK_TAGS: &[&str] = &[
+    "article",
+    "blockquote",
+    "br",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "tr",
+    "ul",
+];
+
+/// Readable text pulled out of a page
+#[derive(Debug, Clone)]
+pub struct ExtractedContent {
+    pub title: Option<String>,
+    pub text: String,
+    pub word_count: i32,
+}
+
+/// Extracts the main readable text of a page, dropping navigation, ads and scripts
+pub fn extract(html: &str) -> ExtractedContent {
+    let document = Html::parse_document(html);
+
+    let title = first_text(&document, "title").or_else(|| first_text(&document, "h1"));
+
+    let candidates = Selector::parse("article, main, [role=\"main\"], section, div, td").unwrap();
+    let best = document
+        .select(&candidates)
+        .filter(|element| !is_boilerplate(element) && !has_boilerplate_ancestor(element))
+        .map(|element| (score(&element), element))
+        .filter(|(score, _)| *score > 0.0)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, element)| element);
+
+    let body = Selector::parse("body").unwrap();
+    let root = best
+        .or_else(|| document.select(&body).next())
+        .unwrap_or_else(|| document.root_element());
+
+    let mut raw = String::new();
+    render_text(&root, &mut raw);
+
+    let text = raw
+        .split("\n\n")
+        .map(|block| block.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let word_count = text.split_whitespace().count() as i32;
+
+    ExtractedContent {
+        title,
+        text,
+        word_count,
+    }
+}
+
+pub fn reading_time_minutes(word_count: i32) -> i32 {
+    if word_count <= 0 {
+        return 0;
+    }
+
+    (word_count + WORDS_PER_MINUTE - 1) / WORDS_PER_MINUTE
+}
+
+fn first_text(document: &Html, selector: &str) -> Option<String> {
+    let selector = Selector::parse(selector).unwrap();
+
+    document
+        .select(&selector)
+        .next()
+        .map(|element| element.text().collect::<Vec<_>>().join(" "))
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty())
+}
+
+fn is_boilerplate(element: &ElementRef) -> bool {
+    let value = element.value();
+    if BOILERPLATE_TAGS.contains(&value.name()) {
+        return true;
+    }
+
+    let hints = [value.attr("class"), value.attr("id"), value.attr("role")];
+    hints.into_iter().flatten().any(|hint| {
+        hint.split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|token| BOILERPLATE_HINTS.contains(&token.to_ascii_lowercase().as_str()))
+    })
+}
+
+fn has_boilerplate_ancestor(element: &ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| is_boilerplate(&ancestor))
+}
+
+/// Readability-style score: long, comma-rich paragraphs count for more, links count against
+fn score(element: &ElementRef) -> f64 {
+    let paragraphs = Selector::parse("p, pre, blockquote").unwrap();
+
+    let mut score = 0.0;
+    for paragraph in element.select(&paragraphs) {
+        if has_boilerplate_ancestor(&paragraph) {
+            continue;
+        }
+
+        let text = paragraph.text().collect::<String>();
+        let length = text.trim().len();
+        if length < 25 {
+            continue;
+        }
+
+        score += 1.0 + text.matches(',').count() as f64 + (length as f64 / 100.0).min(3.0);
+    }
+
+    score * (1.0 - link_density(element))
+}
+
+fn link_density(element: &ElementRef) -> f64 {
+    let links = Selector::parse("a").unwrap();
+
+    let total = element.text().map(str::len).sum::<usize>();
+    if total == 0 {
+        return 1.0;
+    }
+
+    let linked = element
+        .select(&links)
+        .flat_map(|link| link.text())
+        .map(str::len)
+        .sum::<usize>();
+
+    linked as f64 / total as f64
+}
+
+fn render_text(element: &ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(_) => {
+                let Some(child) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                if is_boilerplate(&child) {
+                    continue;
+                }
+
+                let is_block = BLOCK_TAGS.contains(&child.value().name());
+                if is_block {
+                    out.push_str("\n\n");
+                }
+                render_text(&child, out);
+                if is_block {
+                    out.push_str("\n\n");
+                }
+            }
+            _ => {}
+        }
+    }
+}
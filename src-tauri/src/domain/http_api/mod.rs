@@ -0,0 +1,140 @@
+//! Opt-in HTTP API on 127.0.0.1 so scripts and browser extensions can reach the bookmark
+//! services. Requests and responses use the same DTOs as the rspc procedures.
+
+pub mod routes;
+
+use std::net::{Ipv4Addr, SocketAddr};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tokio::{
+    net::TcpListener,
+    sync::{oneshot, Mutex},
+    task::JoinHandle,
+};
+
+use crate::domain::{context::ContextRouter, types::settings::HttpApiSettings};
+
+/// A server accepting requests until it is stopped
+pub struct RunningServer {
+    address: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl RunningServer {
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Stops accepting connections and waits for in-flight requests to finish
+    pub async fn stop(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.task.await;
+    }
+}
+
+/// Binds to loopback only, port 0 picks a free port which `RunningServer::address` reports
+pub async fn serve(
+    context: ContextRouter,
+    port: u16,
+    token: String,
+) -> Result<RunningServer, String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .await
+        .map_err(|e| format!("Failed to bind HTTP API to port {}: {}", port, e))?;
+    let address = listener.local_addr().map_err(|e| e.to_string())?;
+    let app = routes::create_routes(context, token);
+    let (shutdown, signal) = oneshot::channel::<()>();
+
+    let task = tokio::spawn(async move {
+        let result = axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                let _ = signal.await;
+            })
+            .await;
+
+        if let Err(e) = result {
+            eprintln!("HTTP API stopped unexpectedly: {}", e);
+        }
+    });
+
+    Ok(RunningServer {
+        address,
+        shutdown,
+        task,
+    })
+}
+
+/// What the settings screen shows about the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct HttpApiStatus {
+    pub enabled: bool,
+    pub running: bool,
+    pub port: u16,
+    pub token: String,
+    /// Base url clients should call, `None` while the server is stopped
+    pub address: Option<String>,
+}
+
+impl HttpApiStatus {
+    fn new(settings: HttpApiSettings, server: Option<&RunningServer>) -> Self {
+        HttpApiStatus {
+            enabled: settings.enabled,
+            running: server.is_some(),
+            port: settings.port,
+            token: settings.token,
+            address: server.map(|running| format!("http://{}", running.address())),
+        }
+    }
+}
+
+/// Owns the running server, if any, and keeps it in line with the persisted settings
+#[derive(Default)]
+pub struct HttpApiController {
+    server: Mutex<Option<RunningServer>>,
+}
+
+impl HttpApiController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts, restarts or stops the server to match the saved settings.
+    /// Restarting picks up a changed port or token.
+    pub async fn apply(&self, context: ContextRouter) -> Result<HttpApiStatus, String> {
+        let settings = context
+            .services
+            .settings
+            .get_http_api_settings(context.clone())
+            .await?;
+
+        let mut server = self.server.lock().await;
+        if let Some(running) = server.take() {
+            running.stop().await;
+        }
+
+        if settings.enabled {
+            *server = Some(serve(context, settings.port, settings.token.clone()).await?);
+        }
+
+        Ok(HttpApiStatus::new(settings, server.as_ref()))
+    }
+
+    pub async fn status(&self, context: ContextRouter) -> Result<HttpApiStatus, String> {
+        let settings = context
+            .services
+            .settings
+            .get_http_api_settings(context.clone())
+            .await?;
+        let server = self.server.lock().await;
+
+        Ok(HttpApiStatus::new(settings, server.as_ref()))
+    }
+
+    pub async fn stop(&self) {
+        if let Some(running) = self.server.lock().await.take() {
+            running.stop().await;
+        }
+    }
+}
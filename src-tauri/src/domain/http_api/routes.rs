@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, Request, State},
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::{
+        bookmark::bookmark_dto::{BookmarkDto, CreateBookmarkDto},
+        groups::groups_dto::GroupsDto,
+    },
+    permissions::is_forbidden,
+    types::pagination::{ListFilter, ListParams, Page},
+};
+
+#[derive(Clone)]
+struct ApiState {
+    context: ContextRouter,
+    token: Arc<str>,
+}
+
+/// Error body is `{ "error": message }`, service errors are reported as bad requests and
+/// permission errors as forbidden
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: String) -> Self {
+        let status = if is_forbidden(&message) {
+            StatusCode::FORBIDDEN
+        } else {
+            StatusCode::BAD_REQUEST
+        };
+
+        ApiError { status, message }
+    }
+
+    fn unauthorized() -> Self {
+        ApiError {
+            status: StatusCode::UNAUTHORIZED,
+            message: "Missing or invalid bearer token".to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(json!({ "error": self.message }))).into_response()
+    }
+}
+
+/// Flat query string version of `ListParams`
+#[derive(Debug, Default, Deserialize)]
+pub struct ListQuery {
+    pub cursor: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    pub search: Option<String>,
+}
+
+impl From<ListQuery> for ListParams {
+    fn from(query: ListQuery) -> Self {
+        ListParams {
+            cursor: query.cursor,
+            page: query.page,
+            per_page: query.per_page,
+            sort: None,
+            filter: query.search.map(|search| ListFilter {
+                search: Some(search),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+}
+
+/// Every route requires `Authorization: Bearer <token>`
+pub fn create_routes(context: ContextRouter, token: String) -> Router {
+    let state = ApiState {
+        context,
+        token: Arc::from(token),
+    };
+
+    Router::new()
+        .route("/bookmarks", get(list_bookmarks).post(create_bookmark))
+        .route("/bookmarks/search", get(search_bookmarks))
+        .route("/groups", get(list_groups))
+        .route("/groups/{id}/bookmarks", get(group_bookmarks))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+/// Compares every byte so the response time doesn't reveal how much of the token matched
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+async fn require_token(
+    State(state): State<ApiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| tokens_match(given.trim(), &state.token));
+
+    if !authorized {
+        return Err(ApiError::unauthorized());
+    }
+
+    Ok(next.run(request).await)
+}
+
+async fn list_bookmarks(
+    State(state): State<ApiState>,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Page<BookmarkDto>>, ApiError> {
+    let service = Arc::clone(&state.context.services.bookmarks);
+    service
+        .list_bookmark(state.context, query.into())
+        .await
+        .map(Json)
+        .map_err(ApiError::bad_request)
+}
+
+async fn create_bookmark(
+    State(state): State<ApiState>,
+    Json(dto): Json<CreateBookmarkDto>,
+) -> Result<(StatusCode, Json<BookmarkDto>), ApiError> {
+    let service = Arc::clone(&state.context.services.bookmarks);
+    let bookmark = service
+        .create_bookmark(state.context, dto)
+        .await
+        .map_err(ApiError::bad_request)?;
+
+    Ok((StatusCode::CREATED, Json(bookmark)))
+}
+
+async fn search_bookmarks(
+    State(state): State<ApiState>,
+    Query(query): Query<SearchQuery>,
+    Query(list): Query<ListQuery>,
+) -> Result<Json<Page<BookmarkDto>>, ApiError> {
+    let service = Arc::clone(&state.context.services.bookmarks);
+    service
+        .search_bookmarks(state.context, &query.q, list.into())
+        .await
+        .map(Json)
+        .map_err(ApiError::bad_request)
+}
+
+async fn list_groups(
+    State(state): State<ApiState>,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Page<GroupsDto>>, ApiError> {
+    let service = Arc::clone(&state.context.services.groups);
+    service
+        .list_groups(state.context, query.into())
+        .await
+        .map(Json)
+        .map_err(ApiError::bad_request)
+}
+
+async fn group_bookmarks(
+    State(state): State<ApiState>,
+    Path(id): Path<i32>,
+    Query(query): Query<ListQuery>,
+) -> Result<Json<Page<BookmarkDto>>, ApiError> {
+    let service = Arc::clone(&state.context.services.bookmarks);
+    service
+        .get_by_group(state.context, id, query.into())
+        .await
+        .map(Json)
+        .map_err(ApiError::bad_request)
+}
@@ -0,0 +1,113 @@
+//! Chrome, Edge, Brave and other Chromium browsers keep bookmarks in a JSON file named
+//! `Bookmarks` inside the profile directory
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{folder_group_name, ImportedBookmark, ParsedImport};
+
+pub const BOOKMARKS_FILE: &str = "Bookmarks";
+
+/// Microseconds between 1601-01-01, where Chromium timestamps start, and the unix epoch
+const WINDOWS_EPOCH_OFFSET_MICROS: i64 = 11_644_473_600_000_000;
+
+#[derive(Deserialize)]
+struct BookmarksFile {
+    roots: Roots,
+}
+
+#[derive(Deserialize)]
+struct Roots {
+    bookmark_bar: Option<Node>,
+    other: Option<Node>,
+    synced: Option<Node>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Node {
+    Url {
+        name: String,
+        url: String,
+        date_added: Option<String>,
+    },
+    Folder {
+        name: String,
+        #[serde(default)]
+        children: Vec<Node>,
+    },
+}
+
+/// Accepts the `Bookmarks` file itself or the profile directory containing it
+pub fn bookmarks_path(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        path.join(BOOKMARKS_FILE)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+pub async fn read_chromium_bookmarks(path: &Path) -> Result<ParsedImport, String> {
+    let path = bookmarks_path(path);
+    let json = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    parse_chromium_bookmarks(&json)
+}
+
+pub fn parse_chromium_bookmarks(json: &str) -> Result<ParsedImport, String> {
+    let file: BookmarksFile =
+        serde_json::from_str(json).map_err(|e| format!("Not a Chromium bookmarks file: {}", e))?;
+
+    let mut parsed = ParsedImport::default();
+    for root in [file.roots.bookmark_bar, file.roots.other, file.roots.synced]
+        .into_iter()
+        .flatten()
+    {
+        if let Node::Folder { name, children } = root {
+            collect(&mut parsed, &name, &mut Vec::new(), children);
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn collect(parsed: &mut ParsedImport, root: &str, folders: &mut Vec<String>, nodes: Vec<Node>) {
+    for node in nodes {
+        match node {
+            Node::Url {
+                name,
+                url,
+                date_added,
+            } => parsed.push(ImportedBookmark {
+                name: if name.trim().is_empty() {
+                    url.clone()
+                } else {
+                    name
+                },
+                url,
+                group: folder_group_name(root, folders),
+                tags: String::new(),
+                is_favorite: false,
+                created_at: date_added.as_deref().and_then(parse_timestamp),
+                uuid: None,
+                row: parsed.next_row(),
+                read_state: None,
+            }),
+            Node::Folder { name, children } => {
+                folders.push(name);
+                collect(parsed, root, folders, children);
+                folders.pop();
+            }
+        }
+    }
+}
+
+/// Timestamps are stored as a string of microseconds since 1601-01-01
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let micros = value.parse::<i64>().ok()?;
+    DateTime::from_timestamp_micros(micros - WINDOWS_EPOCH_OFFSET_MICROS)
+}
@@ -0,0 +1,174 @@
+//! Spreadsheets exported as CSV, the caller says which columns hold the url, name, tags and
+//! group, and for rayzen's own exports the UUID. Bad rows are collected with their line number
+//! instead of failing the whole file.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use super::{
+    is_web_url, join_tags, read_export_file, ImportRowError, ImportedBookmark, ParsedImport,
+};
+
+/// Header names of the columns to read, only the url is required
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CsvColumnMapping {
+    pub url: String,
+    pub name: Option<String>,
+    pub tags: Option<String>,
+    pub group: Option<String>,
+    /// Rows with a UUID already in the workspace are skipped, so an export can be imported
+    /// again
+    #[serde(default)]
+    pub uuid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CsvImportDto {
+    pub path: String,
+    pub workspace_id: i32,
+    pub mapping: CsvColumnMapping,
+    /// Group for rows without a group column or with an empty cell
+    pub default_group: String,
+}
+
+pub async fn read_csv_columns(path: &Path) -> Result<Vec<String>, String> {
+    parse_csv_columns(&read_export_file(path).await?)
+}
+
+pub fn parse_csv_columns(content: &str) -> Result<Vec<String>, String> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Not a CSV file: {}", e))?;
+
+    Ok(headers
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect())
+}
+
+pub async fn read_csv_import(
+    path: &Path,
+    mapping: &CsvColumnMapping,
+    default_group: &str,
+) -> Result<ParsedImport, String> {
+    parse_csv_import(&read_export_file(path).await?, mapping, default_group)
+}
+
+pub fn parse_csv_import(
+    content: &str,
+    mapping: &CsvColumnMapping,
+    default_group: &str,
+) -> Result<ParsedImport, String> {
+    let default_group = default_group.trim();
+    if default_group.is_empty() {
+        return Err("A default group is required".to_string());
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Not a CSV file: {}", e))?
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+
+    let find = |column: &str| {
+        headers
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(column.trim()))
+            .ok_or_else(|| {
+                format!(
+                    "Column \"{}\" not found, the file has {}",
+                    column,
+                    headers.join(", ")
+                )
+            })
+    };
+    let url_column = find(&mapping.url)?;
+    let name_column = mapping.name.as_deref().map(find).transpose()?;
+    let tags_column = mapping.tags.as_deref().map(find).transpose()?;
+    let group_column = mapping.group.as_deref().map(find).transpose()?;
+    let uuid_column = mapping.uuid.as_deref().map(find).transpose()?;
+
+    let mut parsed = ParsedImport::default();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                parsed.errors.push(ImportRowError {
+                    row: e.position().map(|p| p.line() as u32).unwrap_or_default(),
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let row = record
+            .position()
+            .map(|p| p.line() as u32)
+            .unwrap_or_default();
+        let cell = |column: Option<usize>| {
+            column
+                .and_then(|column| record.get(column))
+                .map(str::trim)
+                .unwrap_or_default()
+        };
+
+        let url = cell(Some(url_column));
+        if url.is_empty() {
+            parsed.errors.push(ImportRowError {
+                row,
+                message: "The url is empty".to_string(),
+            });
+            continue;
+        }
+        if !is_web_url(url) {
+            parsed.errors.push(ImportRowError {
+                row,
+                message: format!("\"{}\" is not a web link", url),
+            });
+            continue;
+        }
+
+        let uuid = match cell(uuid_column) {
+            "" => None,
+            uuid => match Uuid::parse_str(uuid) {
+                Ok(uuid) => Some(uuid),
+                Err(_) => {
+                    parsed.errors.push(ImportRowError {
+                        row,
+                        message: format!("\"{}\" is not a UUID", uuid),
+                    });
+                    continue;
+                }
+            },
+        };
+
+        let name = match cell(name_column) {
+            "" => url,
+            name => name,
+        };
+        let group = match cell(group_column) {
+            "" => default_group,
+            group => group,
+        };
+        parsed.push(ImportedBookmark {
+            name: name.to_string(),
+            url: url.to_string(),
+            group: group.to_string(),
+            tags: join_tags(cell(tags_column).split([',', ';'])),
+            is_favorite: false,
+            created_at: None,
+            read_state: None,
+            uuid,
+            row,
+        });
+    }
+
+    Ok(parsed)
+}
@@ -0,0 +1,216 @@
+//! Firefox keeps bookmarks in the `places.sqlite` database of the profile: folders and
+//! bookmarks in `moz_bookmarks`, their urls in `moz_places`. Tags are folders below the tags
+//! root holding one bookmark per tagged url.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, Database, DbBackend, QueryResult, Statement};
+use uuid::Uuid;
+
+use super::{folder_group_name, ImportedBookmark, ParsedImport};
+
+pub const PLACES_FILE: &str = "places.sqlite";
+
+const TYPE_BOOKMARK: i32 = 1;
+const TYPE_FOLDER: i32 = 2;
+
+const TAGS_ROOT_GUID: &str = "tags________";
+
+/// Display names of the roots, Firefox stores them untranslated as `menu`, `toolbar`, ...
+const ROOTS: [(&str, &str); 4] = [
+    ("menu________", "Bookmarks Menu"),
+    ("toolbar_____", "Bookmarks Toolbar"),
+    ("unfiled_____", "Other Bookmarks"),
+    ("mobile______", "Mobile Bookmarks"),
+];
+
+const BOOKMARKS_QUERY: &str = r#"
+    SELECT b.id, b.type, b.parent, b.title, b.dateAdded AS date_added, b.guid,
+           p.url, p.title AS place_title
+    FROM moz_bookmarks b
+    LEFT JOIN moz_places p ON p.id = b.fk
+    ORDER BY b.parent, b.position
+"#;
+
+struct Row {
+    id: i64,
+    kind: i32,
+    parent: i64,
+    title: Option<String>,
+    date_added: Option<i64>,
+    guid: Option<String>,
+    url: Option<String>,
+    place_title: Option<String>,
+}
+
+impl Row {
+    fn read(row: &QueryResult) -> Result<Self, String> {
+        let read = |e: sea_orm::DbErr| format!("Unexpected places.sqlite layout: {}", e);
+
+        Ok(Row {
+            id: row.try_get("", "id").map_err(read)?,
+            kind: row.try_get("", "type").map_err(read)?,
+            parent: row.try_get("", "parent").map_err(read)?,
+            title: row.try_get("", "title").map_err(read)?,
+            date_added: row.try_get("", "date_added").map_err(read)?,
+            guid: row.try_get("", "guid").map_err(read)?,
+            url: row.try_get("", "url").map_err(read)?,
+            place_title: row.try_get("", "place_title").map_err(read)?,
+        })
+    }
+}
+
+/// Accepts `places.sqlite` itself or the profile directory containing it
+pub fn places_path(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        path.join(PLACES_FILE)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Files SQLite keeps next to a database in WAL mode, recent writes only live in `-wal`
+const SIDECARS: [&str; 2] = ["-wal", "-shm"];
+
+/// `places.sqlite-wal` for `places.sqlite`
+fn sidecar(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Firefox keeps the database locked while it runs, so a copy is read instead
+pub async fn read_firefox_bookmarks(path: &Path) -> Result<ParsedImport, String> {
+    let path = places_path(path);
+    let copy = std::env::temp_dir().join(format!("rayzen-places-{}.sqlite", Uuid::new_v4()));
+
+    let rows = match copy_places(&path, &copy).await {
+        Ok(()) => query_rows(&copy).await,
+        Err(e) => Err(e),
+    };
+    let _ = tokio::fs::remove_file(&copy).await;
+    for suffix in SIDECARS {
+        let _ = tokio::fs::remove_file(sidecar(&copy, suffix)).await;
+    }
+
+    Ok(build_import(rows?))
+}
+
+/// Copies the database with its WAL, without it bookmarks added since Firefox's last
+/// checkpoint would be missing
+async fn copy_places(path: &Path, copy: &Path) -> Result<(), String> {
+    tokio::fs::copy(path, copy)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    for suffix in SIDECARS {
+        let source = sidecar(path, suffix);
+        if tokio::fs::try_exists(&source).await.unwrap_or(false) {
+            tokio::fs::copy(&source, sidecar(copy, suffix))
+                .await
+                .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn query_rows(path: &Path) -> Result<Vec<Row>, String> {
+    let db = Database::connect(format!("sqlite://{}?mode=ro", path.display()))
+        .await
+        .map_err(|e| format!("Not a Firefox places database: {}", e))?;
+
+    let rows = db
+        .query_all(Statement::from_string(DbBackend::Sqlite, BOOKMARKS_QUERY))
+        .await
+        .map_err(|e| format!("Not a Firefox places database: {}", e))?;
+    let _ = db.close().await;
+
+    rows.iter().map(Row::read).collect()
+}
+
+fn build_import(rows: Vec<Row>) -> ParsedImport {
+    let folders: HashMap<i64, &Row> = rows
+        .iter()
+        .filter(|row| row.kind == TYPE_FOLDER)
+        .map(|row| (row.id, row))
+        .collect();
+    let root_name = |row: &Row| {
+        ROOTS
+            .iter()
+            .find(|(guid, _)| row.guid.as_deref() == Some(*guid))
+            .map(|(_, name)| *name)
+    };
+
+    // Tag names per url, read from the bookmarks kept inside the tag folders
+    let tags_root = rows
+        .iter()
+        .find(|row| row.guid.as_deref() == Some(TAGS_ROOT_GUID))
+        .map(|row| row.id);
+    let mut tags: HashMap<&str, Vec<&str>> = HashMap::new();
+    for row in rows.iter().filter(|row| row.kind == TYPE_BOOKMARK) {
+        let Some(tag_folder) = folders.get(&row.parent) else {
+            continue;
+        };
+        if Some(tag_folder.parent) == tags_root {
+            if let (Some(url), Some(tag)) = (row.url.as_deref(), tag_folder.title.as_deref()) {
+                tags.entry(url).or_default().push(tag);
+            }
+        }
+    }
+
+    let mut parsed = ParsedImport::default();
+    for row in rows.iter().filter(|row| row.kind == TYPE_BOOKMARK) {
+        let Some(url) = row.url.clone() else {
+            continue;
+        };
+
+        // Walk up to the root, remembering the folder names on the way
+        let mut path = Vec::new();
+        let mut root = None;
+        let mut parent = folders.get(&row.parent);
+        while let Some(folder) = parent {
+            if let Some(name) = root_name(folder) {
+                root = Some(name);
+                break;
+            }
+            path.push(folder.title.clone().unwrap_or_default());
+            parent = folders.get(&folder.parent);
+        }
+        // Tag entries and anything else outside the regular roots
+        let Some(root) = root else {
+            continue;
+        };
+        path.reverse();
+
+        let name = [&row.title, &row.place_title]
+            .into_iter()
+            .flatten()
+            .find(|title| !title.trim().is_empty())
+            .cloned()
+            .unwrap_or_else(|| url.clone());
+
+        parsed.push(ImportedBookmark {
+            name,
+            tags: tags
+                .get(url.as_str())
+                .map(|tags| tags.join(","))
+                .unwrap_or_default(),
+            url,
+            group: folder_group_name(root, &path),
+            is_favorite: false,
+            created_at: row
+                .date_added
+                .and_then(DateTime::<Utc>::from_timestamp_micros),
+            uuid: None,
+            row: parsed.next_row(),
+            read_state: None,
+        });
+    }
+
+    parsed
+}
@@ -0,0 +1,282 @@
+//! Reads bookmarks saved by other apps so they can be previewed before anything is written.
+//! Every source goes through the same pipeline: parse into [`ParsedImport`], preview, drop
+//! urls that are already saved or repeated, then commit what is left.
+
+pub mod chromium;
+pub mod csv_file;
+pub mod firefox;
+pub mod pinboard;
+pub mod pocket;
+pub mod raindrop;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+use crate::domain::types::read_later::ReadState;
+
+/// Separates nested folder names in the group an imported bookmark lands in
+pub const FOLDER_SEPARATOR: &str = " / ";
+
+/// A bookmark read from another app, not saved yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ImportedBookmark {
+    pub name: String,
+    pub url: String,
+    /// Name of the group it is saved into, derived from the folder it was in
+    pub group: String,
+    pub tags: String,
+    pub is_favorite: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Only set by services that track reading, browsers leave it to the default
+    pub read_state: Option<ReadState>,
+    /// Set when the file came from a rayzen export, the bookmark keeps it when imported
+    pub uuid: Option<Uuid>,
+    /// Line of the row in CSV files, position of the entry starting at 1 for other sources
+    pub row: u32,
+}
+
+/// Everything read from a source, including what couldn't be imported
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedImport {
+    pub bookmarks: Vec<ImportedBookmark>,
+    /// Urls that rayzen can't open, like `chrome://` pages or Firefox smart folders
+    pub skipped: Vec<String>,
+    /// Rows that couldn't be read, only CSV based sources report them
+    pub errors: Vec<ImportRowError>,
+}
+
+/// A row left out of the import, `row` is the line number in the file, or the position of the
+/// entry for sources without lines
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ImportRowError {
+    pub row: u32,
+    pub message: String,
+}
+
+impl ParsedImport {
+    /// Only web links are kept, everything else is reported as skipped
+    pub fn push(&mut self, bookmark: ImportedBookmark) {
+        if is_web_url(&bookmark.url) {
+            self.bookmarks.push(bookmark);
+        } else {
+            self.skipped.push(bookmark.url);
+        }
+    }
+
+    /// Row of the next entry pushed, skipped entries are counted too
+    pub fn next_row(&self) -> u32 {
+        (self.bookmarks.len() + self.skipped.len() + 1) as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportSource {
+    Chromium,
+    Firefox,
+    /// `part_000000.csv` from the current export or `ril_export.html` from the old one
+    Pocket,
+    /// JSON from `https://api.pinboard.in/v1/posts/all?format=json` or the backup page
+    Pinboard,
+    Raindrop,
+}
+
+/// Runs the parser of the source on a file, or a profile directory for browsers
+pub async fn read_import(source: ImportSource, path: &Path) -> Result<ParsedImport, String> {
+    match source {
+        ImportSource::Chromium => chromium::read_chromium_bookmarks(path).await,
+        ImportSource::Firefox => firefox::read_firefox_bookmarks(path).await,
+        ImportSource::Pocket => pocket::read_pocket_export(path).await,
+        ImportSource::Pinboard => pinboard::read_pinboard_export(path).await,
+        ImportSource::Raindrop => raindrop::read_raindrop_export(path).await,
+    }
+}
+
+/// Reads an export file as text, shared by the file based sources
+pub(crate) async fn read_export_file(path: &Path) -> Result<String, String> {
+    tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Reads the rows of an app's CSV export with their line numbers. A row that can't be read
+/// is reported and left out, only a file without the `url` column fails as a whole.
+pub(crate) fn read_csv_rows<T: DeserializeOwned>(
+    content: &str,
+    app: &str,
+    errors: &mut Vec<ImportRowError>,
+) -> Result<Vec<(u32, T)>, String> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Not a {} export: {}", app, e))?
+        .clone();
+    if !headers.iter().any(|header| header.trim() == "url") {
+        return Err(format!("Not a {} export: the url column is missing", app));
+    }
+
+    let line = |position: Option<&csv::Position>| position.map_or(0, |p| p.line() as u32);
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let row = record.and_then(|record| {
+            let row = line(record.position());
+            record.deserialize(Some(&headers)).map(|value| (row, value))
+        });
+        match row {
+            Ok(row) => rows.push(row),
+            Err(e) => errors.push(ImportRowError {
+                row: line(e.position()),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Favorite columns hold `true`, `1` or `yes` depending on the app
+pub(crate) fn is_checked(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "true" | "1" | "yes"
+    )
+}
+
+/// Joins tags already split by the source into rayzen's comma separated form
+pub(crate) fn join_tags<'a>(tags: impl IntoIterator<Item = &'a str>) -> String {
+    tags.into_iter()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Group a bookmark lands in: top-level entries keep the name of their root folder,
+/// anything nested is named after its folder path below the root
+pub fn folder_group_name(root: &str, folders: &[String]) -> String {
+    if folders.is_empty() {
+        root.to_string()
+    } else {
+        folders.join(FOLDER_SEPARATOR)
+    }
+}
+
+pub(crate) fn is_web_url(url: &str) -> bool {
+    url::Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+/// Form urls are compared in: host lowercased by the parser, no fragment and no trailing slash
+pub fn normalize_url(url: &str) -> String {
+    match url::Url::parse(url.trim()) {
+        Ok(mut url) => {
+            url.set_fragment(None);
+            url.to_string().trim_end_matches('/').to_string()
+        }
+        Err(_) => url.trim().to_string(),
+    }
+}
+
+/// Why a bookmark from the file is left out of the commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConflictReason {
+    /// The workspace already has a bookmark with this url
+    AlreadySaved { bookmark_id: i32, name: String },
+    /// The url appeared earlier in the same file, the first occurrence is imported
+    DuplicateInImport,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct ImportConflict {
+    pub bookmark: ImportedBookmark,
+    pub reason: ConflictReason,
+}
+
+/// Bookmarks already in the workspace, keyed by normalized url and by UUID, with their id and
+/// name
+#[derive(Debug, Clone, Default)]
+pub struct SavedBookmarks {
+    pub by_url: HashMap<String, (i32, String)>,
+    pub by_uuid: HashMap<Uuid, (i32, String)>,
+}
+
+/// Splits imported bookmarks into the ones to create and the ones that conflict. A bookmark
+/// with a known UUID is the same bookmark even if its url changed since the export.
+pub fn dedupe(
+    bookmarks: Vec<ImportedBookmark>,
+    saved: &SavedBookmarks,
+) -> (Vec<ImportedBookmark>, Vec<ImportConflict>) {
+    let mut seen = HashSet::new();
+    let mut seen_uuids = HashSet::new();
+    let mut kept = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for bookmark in bookmarks {
+        let url = normalize_url(&bookmark.url);
+        let existing = bookmark
+            .uuid
+            .and_then(|uuid| saved.by_uuid.get(&uuid))
+            .or_else(|| saved.by_url.get(&url));
+        let reason = if let Some((bookmark_id, name)) = existing {
+            Some(ConflictReason::AlreadySaved {
+                bookmark_id: *bookmark_id,
+                name: name.clone(),
+            })
+        } else if !seen.insert(url) || bookmark.uuid.is_some_and(|uuid| !seen_uuids.insert(uuid)) {
+            Some(ConflictReason::DuplicateInImport)
+        } else {
+            None
+        };
+
+        match reason {
+            Some(reason) => conflicts.push(ImportConflict { bookmark, reason }),
+            None => kept.push(bookmark),
+        }
+    }
+
+    (kept, conflicts)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportDto {
+    pub source: ImportSource,
+    /// Export file, or for browsers the bookmarks file, places database or profile directory
+    pub path: String,
+    pub workspace_id: i32,
+}
+
+/// Bookmarks that will land in one group
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportGroupPreview {
+    pub name: String,
+    /// Set when a group with this name already exists in the workspace and will be reused
+    pub existing_group_id: Option<i32>,
+    pub bookmarks: Vec<ImportedBookmark>,
+}
+
+/// What an import would do, nothing has been written yet
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportPreview {
+    pub groups: Vec<ImportGroupPreview>,
+    /// Bookmarks that would be created, conflicts not included
+    pub total: u32,
+    pub skipped: Vec<String>,
+    pub conflicts: Vec<ImportConflict>,
+    pub errors: Vec<ImportRowError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportResult {
+    pub groups_created: u32,
+    pub bookmarks_created: u32,
+    pub skipped: u32,
+    pub conflicts: Vec<ImportConflict>,
+    pub errors: Vec<ImportRowError>,
+}
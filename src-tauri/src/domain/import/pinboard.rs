@@ -0,0 +1,59 @@
+//! Pinboard exports every post as a JSON array, tags are space separated and `toread` marks
+//! the unread ones
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{join_tags, read_export_file, ImportedBookmark, ParsedImport};
+use crate::domain::types::read_later::ReadState;
+
+/// Pinboard has no folders, everything lands in one group
+pub const PINBOARD_GROUP: &str = "Pinboard";
+
+#[derive(Deserialize)]
+struct Post {
+    href: String,
+    #[serde(default)]
+    description: String,
+    time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    toread: String,
+    #[serde(default)]
+    tags: String,
+}
+
+pub async fn read_pinboard_export(path: &Path) -> Result<ParsedImport, String> {
+    parse_pinboard_json(&read_export_file(path).await?)
+}
+
+pub fn parse_pinboard_json(json: &str) -> Result<ParsedImport, String> {
+    let posts: Vec<Post> =
+        serde_json::from_str(json).map_err(|e| format!("Not a Pinboard export: {}", e))?;
+
+    let mut parsed = ParsedImport::default();
+    for post in posts {
+        parsed.push(ImportedBookmark {
+            name: if post.description.trim().is_empty() {
+                post.href.clone()
+            } else {
+                post.description.trim().to_string()
+            },
+            tags: join_tags(post.tags.split_whitespace()),
+            group: PINBOARD_GROUP.to_string(),
+            is_favorite: false,
+            created_at: post.time,
+            uuid: None,
+            row: parsed.next_row(),
+            read_state: Some(if post.toread == "yes" {
+                ReadState::Unread
+            } else {
+                ReadState::Read
+            }),
+            url: post.href,
+        });
+    }
+
+    Ok(parsed)
+}
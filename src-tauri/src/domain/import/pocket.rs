@@ -0,0 +1,118 @@
+//! Pocket exports a CSV (`title,url,time_added,tags,status`) with `|` separated tags, older
+//! exports are a `ril_export.html` page with an "Unread" and a "Read Archive" list
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use scraper::{Html, Selector};
+use serde::Deserialize;
+
+use super::{
+    is_checked, join_tags, read_csv_rows, read_export_file, ImportedBookmark, ParsedImport,
+};
+use crate::domain::types::read_later::ReadState;
+
+/// Pocket has no folders, everything lands in one group
+pub const POCKET_GROUP: &str = "Pocket";
+
+const ARCHIVE_HEADING: &str = "Read Archive";
+
+#[derive(Deserialize)]
+struct Row {
+    #[serde(default)]
+    title: String,
+    url: String,
+    #[serde(default)]
+    time_added: Option<i64>,
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    status: String,
+    /// Only in exports that kept Pocket's favorites
+    #[serde(default)]
+    favorite: String,
+}
+
+pub async fn read_pocket_export(path: &Path) -> Result<ParsedImport, String> {
+    let content = read_export_file(path).await?;
+    if content.trim_start().starts_with('<') {
+        Ok(parse_pocket_html(&content))
+    } else {
+        parse_pocket_csv(&content)
+    }
+}
+
+pub fn parse_pocket_csv(content: &str) -> Result<ParsedImport, String> {
+    let mut parsed = ParsedImport::default();
+
+    for (line, row) in read_csv_rows::<Row>(content, "Pocket", &mut parsed.errors)? {
+        parsed.push(ImportedBookmark {
+            name: title_or_url(row.title, &row.url),
+            tags: join_tags(row.tags.split('|')),
+            group: POCKET_GROUP.to_string(),
+            is_favorite: is_checked(&row.favorite),
+            created_at: row
+                .time_added
+                .and_then(|secs| DateTime::from_timestamp(secs, 0)),
+            uuid: None,
+            row: line,
+            read_state: Some(read_state(row.status == "archive")),
+            url: row.url,
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Links are read in document order, each one belongs to the list under the last heading
+pub fn parse_pocket_html(content: &str) -> ParsedImport {
+    let document = Html::parse_document(content);
+    let selector = Selector::parse("h1, a").expect("Static selector is valid");
+    let mut parsed = ParsedImport::default();
+    let mut archived = false;
+
+    for element in document.select(&selector) {
+        if element.value().name() == "h1" {
+            archived = element.text().collect::<String>().trim() == ARCHIVE_HEADING;
+            continue;
+        }
+        let Some(url) = element.value().attr("href") else {
+            continue;
+        };
+
+        let attr = |name: &str| element.value().attr(name).unwrap_or_default();
+        parsed.push(ImportedBookmark {
+            name: title_or_url(element.text().collect(), url),
+            url: url.to_string(),
+            group: POCKET_GROUP.to_string(),
+            tags: join_tags(attr("tags").split(',')),
+            is_favorite: false,
+            created_at: attr("time_added")
+                .parse()
+                .ok()
+                .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0)),
+            uuid: None,
+            row: parsed.next_row(),
+            read_state: Some(read_state(archived)),
+        });
+    }
+
+    parsed
+}
+
+/// Archiving in Pocket means it was read, the rest is still in the queue
+fn read_state(archived: bool) -> ReadState {
+    if archived {
+        ReadState::Read
+    } else {
+        ReadState::Unread
+    }
+}
+
+fn title_or_url(title: String, url: &str) -> String {
+    if title.trim().is_empty() {
+        url.to_string()
+    } else {
+        title.trim().to_string()
+    }
+}
@@ -0,0 +1,74 @@
+//! Raindrop exports a CSV with one row per bookmark, collections are `/` separated paths in
+//! the `folder` column and `favorite` is `true` or `false`. Rows that can't be read are
+//! reported by line.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{
+    is_checked, join_tags, read_csv_rows, read_export_file, ImportedBookmark, ParsedImport,
+    FOLDER_SEPARATOR,
+};
+
+/// Where bookmarks outside of any collection land, named like Raindrop's own inbox
+pub const UNSORTED_GROUP: &str = "Unsorted";
+
+#[derive(Deserialize)]
+struct Row {
+    #[serde(default)]
+    title: String,
+    url: String,
+    #[serde(default)]
+    folder: String,
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    created: Option<DateTime<Utc>>,
+    #[serde(default)]
+    favorite: String,
+}
+
+pub async fn read_raindrop_export(path: &Path) -> Result<ParsedImport, String> {
+    parse_raindrop_csv(&read_export_file(path).await?)
+}
+
+pub fn parse_raindrop_csv(content: &str) -> Result<ParsedImport, String> {
+    let mut parsed = ParsedImport::default();
+
+    for (line, row) in read_csv_rows::<Row>(content, "Raindrop", &mut parsed.errors)? {
+        parsed.push(ImportedBookmark {
+            name: if row.title.trim().is_empty() {
+                row.url.clone()
+            } else {
+                row.title.trim().to_string()
+            },
+            group: collection_group_name(&row.folder),
+            tags: join_tags(row.tags.split(',')),
+            is_favorite: is_checked(&row.favorite),
+            created_at: row.created,
+            uuid: None,
+            row: line,
+            read_state: None,
+            url: row.url,
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// `Dev/Rust` becomes `Dev / Rust`, matching how nested browser folders are named
+fn collection_group_name(folder: &str) -> String {
+    let path: Vec<&str> = folder
+        .split('/')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    if path.is_empty() {
+        UNSORTED_GROUP.to_string()
+    } else {
+        path.join(FOLDER_SEPARATOR)
+    }
+}
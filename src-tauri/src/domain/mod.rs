@@ -1,6 +1,18 @@
+pub mod archiver;
+pub mod context;
+pub mod deep_link;
 pub mod dto;
+pub mod events;
+pub mod export;
+pub mod extractor;
+pub mod http_api;
+pub mod import;
+pub mod permissions;
 pub mod repository;
 pub mod router;
 pub mod service;
+pub mod session;
+pub mod sync;
+pub mod tray_menu;
 pub mod types;
 pub mod util;
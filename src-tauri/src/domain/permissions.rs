@@ -0,0 +1,174 @@
+//! Role checks for the signed in user. Services call these before changing anything, the
+//! signed in user must be a member of the organization that owns the record with a role that
+//! allows the access. Without a session only organizations with a single member can be changed,
+//! that is the app before anyone was invited, see `Session`. Reading needs no session.
+//!
+//! These checks are advisory, the session they read is chosen without a credential.
+
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        organization_member_repository::OrganizationMemberRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    types::membership::{Access, MemberRole},
+};
+
+/// Prefix of every permission error, routers report these as forbidden instead of failures
+pub const FORBIDDEN: &str = "Forbidden: ";
+
+pub fn forbidden(message: &str) -> String {
+    format!("{}{}", FORBIDDEN, message)
+}
+
+pub fn is_forbidden(error: &str) -> bool {
+    error.starts_with(FORBIDDEN)
+}
+
+#[derive(Clone)]
+pub struct Permissions {
+    pub member_repository: Arc<dyn OrganizationMemberRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+}
+
+impl Permissions {
+    pub fn new(
+        member_repository: Arc<dyn OrganizationMemberRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+    ) -> Self {
+        Permissions {
+            member_repository,
+            workspace_repository,
+            groups_repository,
+            bookmark_repository,
+        }
+    }
+
+    /// Role of the signed in user, `None` when nobody is signed in and the organization isn't
+    /// shared with anyone
+    pub async fn role_in(
+        &self,
+        ctx: &ContextRouter,
+        organization_id: i32,
+    ) -> Result<Option<MemberRole>, String> {
+        let Some(user_id) = ctx.services.session.user_id() else {
+            let members = self
+                .member_repository
+                .list_members(&ctx.db, organization_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            if members.len() > 1 {
+                return Err(forbidden("Sign in to change a shared organization"));
+            }
+            return Ok(None);
+        };
+
+        let member = self
+            .member_repository
+            .find_member(&ctx.db, organization_id, user_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| forbidden("You are not a member of this organization"))?;
+
+        Ok(Some(MemberRole::from_db(&member.role)))
+    }
+
+    /// Organizations list reads may return records of, `None` when nobody is signed in and
+    /// every organization can be read. Lists span organizations, so they are narrowed to these
+    /// instead of checked record by record.
+    pub async fn readable_organizations(
+        &self,
+        ctx: &ContextRouter,
+    ) -> Result<Option<Vec<i32>>, String> {
+        let Some(user_id) = ctx.services.session.user_id() else {
+            return Ok(None);
+        };
+
+        let memberships = self
+            .member_repository
+            .list_memberships(&ctx.db, user_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Some(
+            memberships
+                .into_iter()
+                .map(|member| member.organization_id)
+                .collect(),
+        ))
+    }
+
+    pub async fn require_organization(
+        &self,
+        ctx: &ContextRouter,
+        organization_id: i32,
+        access: Access,
+    ) -> Result<(), String> {
+        if access == Access::Read && ctx.services.session.user_id().is_none() {
+            return Ok(());
+        }
+
+        match self.role_in(ctx, organization_id).await? {
+            Some(role) if !role.allows(access) => Err(forbidden(match access {
+                Access::Manage => "Only owners can manage this organization",
+                _ => "Viewers can't change this organization",
+            })),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn require_workspace(
+        &self,
+        ctx: &ContextRouter,
+        workspace_id: i32,
+        access: Access,
+    ) -> Result<(), String> {
+        let workspace = self
+            .workspace_repository
+            .get_workspace_by_id(&ctx.db, workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.require_organization(ctx, workspace.organization_id, access)
+            .await
+    }
+
+    pub async fn require_group(
+        &self,
+        ctx: &ContextRouter,
+        group_id: i32,
+        access: Access,
+    ) -> Result<(), String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.require_workspace(ctx, group.workspace_id, access)
+            .await
+    }
+
+    pub async fn require_bookmark(
+        &self,
+        ctx: &ContextRouter,
+        bookmark_id: i32,
+        access: Access,
+    ) -> Result<(), String> {
+        let bookmark = self
+            .bookmark_repository
+            .find_by_id(&ctx.db, bookmark_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Bookmark with id {} not found", bookmark_id))?;
+
+        self.require_group(ctx, bookmark.group_id, access).await
+    }
+}
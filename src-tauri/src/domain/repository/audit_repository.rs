@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use entity::audit_event::{self, Column, Entity as AuditEvent};
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+
+use crate::domain::{
+    repository::pagination::{fetch_page, ListColumns},
+    types::pagination::{ListParams, Page},
+};
+
+/// Audit rows are written by the triggers of the audited tables, so this side only reads
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    /// Events of rows that belonged to `organization_id` when they changed, users belong to none
+    async fn list_events(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+        entity_type: Option<&str>,
+        entity_id: Option<i32>,
+        actor_user_id: Option<i32>,
+        params: &ListParams,
+    ) -> Result<Page<audit_event::Model>, DbErr>;
+}
+
+pub fn list_columns() -> ListColumns<Column> {
+    ListColumns {
+        id: Column::Id,
+        name: Column::EntityType,
+        created_at: Column::CreatedAt,
+        sortable: vec![
+            ("id", Column::Id),
+            ("entity_type", Column::EntityType),
+            ("action", Column::Action),
+            ("created_at", Column::CreatedAt),
+        ],
+        default_sort: Column::Id,
+    }
+}
+
+pub struct AuditRepositoryImpl {}
+
+impl AuditRepositoryImpl {
+    pub fn new() -> Self {
+        AuditRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl AuditRepository for AuditRepositoryImpl {
+    async fn list_events(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+        entity_type: Option<&str>,
+        entity_id: Option<i32>,
+        actor_user_id: Option<i32>,
+        params: &ListParams,
+    ) -> Result<Page<audit_event::Model>, DbErr> {
+        let mut select = AuditEvent::find().filter(Column::OrganizationId.eq(organization_id));
+        if let Some(entity_type) = entity_type {
+            select = select.filter(Column::EntityType.eq(entity_type));
+        }
+        if let Some(entity_id) = entity_id {
+            select = select.filter(Column::EntityId.eq(entity_id));
+        }
+        if let Some(actor_user_id) = actor_user_id {
+            select = select.filter(Column::ActorUserId.eq(actor_user_id));
+        }
+
+        fetch_page(db, select, params, &list_columns()).await
+    }
+}
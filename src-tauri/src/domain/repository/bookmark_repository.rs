@@ -1,13 +1,66 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use entity::bookmark::{
-    self, ActiveModel as BookmarkActiveModel, Entity as Bookmark, Model as BookmarkModel,
+use entity::{
+    bookmark::{
+        self, ActiveModel as BookmarkActiveModel, Entity as Bookmark, Model as BookmarkModel,
+    },
+    bookmark_content,
+    groups::{self, ActiveModel as GroupsActiveModel},
+};
+
+use crate::domain::{
+    import::ImportRowError,
+    repository::{
+        groups_repository::in_organizations,
+        pagination::{contains_pattern, escape_like, fetch_page, ListColumns, LIKE_ESCAPE},
+    },
+    types::{
+        groups::SmartGroupFilter,
+        pagination::{ListParams, Page},
+        read_later::ReadState,
+    },
+    util::new_uuid,
 };
 
 // Import SeaORM entities and DTOs
 use sea_orm::{
-    prelude::Expr, ActiveValue::Set, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    prelude::Expr,
+    sea_query::{Query, SimpleExpr},
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+    TransactionTrait,
 };
 
+/// Number of bookmarks in a group sharing the same read state
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct ReadStateCount {
+    pub group_id: i32,
+    pub read_state: String,
+    pub count: i64,
+}
+
+/// Group imported bookmarks land in, created along with them unless it already exists
+pub enum ImportGroup {
+    Existing(i32),
+    New(GroupsActiveModel),
+}
+
+/// Imported bookmarks headed for one group, each with the row it was read from
+pub struct ImportBatch {
+    pub group: ImportGroup,
+    pub bookmarks: Vec<(u32, BookmarkActiveModel)>,
+}
+
+/// Rows an import wrote, and the ones that failed and were left out
+#[derive(Debug, Default)]
+pub struct ImportedRows {
+    pub groups: Vec<groups::Model>,
+    pub bookmarks: Vec<BookmarkModel>,
+    pub errors: Vec<ImportRowError>,
+}
+
 #[async_trait]
 pub trait BookmarkRepository: Send + Sync {
     async fn create(
@@ -20,17 +73,37 @@ pub trait BookmarkRepository: Send + Sync {
         db: &DatabaseConnection,
         id: i32,
     ) -> Result<Option<BookmarkModel>, DbErr>;
-    async fn find_all(&self, db: &DatabaseConnection) -> Result<Vec<BookmarkModel>, DbErr>;
+    async fn find_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<Option<BookmarkModel>, DbErr>;
+    /// Bookmarks of every organization, or only of `organization_ids` when given
+    async fn find_page(
+        &self,
+        db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr>;
+    /// Matches name, tags and page text, narrowed to `organization_ids` like `find_page`
     async fn search(
         &self,
         db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
         query: &str,
-    ) -> Result<Vec<bookmark::Model>, DbErr>;
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr>;
     async fn get_by_group(
         &self,
         db: &DatabaseConnection,
         group_id: i32,
     ) -> Result<Vec<BookmarkModel>, DbErr>;
+    async fn find_page_in_group(
+        &self,
+        db: &DatabaseConnection,
+        group_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr>;
     async fn update(
         &self,
         db: &DatabaseConnection,
@@ -38,6 +111,247 @@ pub trait BookmarkRepository: Send + Sync {
         bookmark: BookmarkActiveModel,
     ) -> Result<bookmark::Model, DbErr>;
     async fn delete(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
+    /// Copies a bookmark into `group_id` under a new UUID
+    async fn copy_to(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        group_id: i32,
+    ) -> Result<BookmarkModel, DbErr>;
+    async fn list_read_later(
+        &self,
+        db: &DatabaseConnection,
+        group_id: Option<i32>,
+        states: Vec<String>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr>;
+    async fn update_read_state(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        bookmark: BookmarkActiveModel,
+    ) -> Result<BookmarkModel, DbErr>;
+    async fn count_read_states(
+        &self,
+        db: &DatabaseConnection,
+    ) -> Result<Vec<ReadStateCount>, DbErr>;
+    async fn find_word_counts(
+        &self,
+        db: &DatabaseConnection,
+        ids: Vec<i32>,
+    ) -> Result<HashMap<i32, i32>, DbErr>;
+    async fn find_matching(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+    ) -> Result<Vec<BookmarkModel>, DbErr>;
+    async fn find_matching_page(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr>;
+    async fn search_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<Vec<BookmarkModel>, DbErr>;
+    async fn count_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<u64, DbErr>;
+    async fn mark_opened(&self, db: &DatabaseConnection, id: i32) -> Result<BookmarkModel, DbErr>;
+    /// Flags the bookmark's link as broken, or clears the flag once the page loads again
+    async fn mark_broken(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        broken: bool,
+    ) -> Result<BookmarkModel, DbErr>;
+    async fn find_favorites(&self, db: &DatabaseConnection) -> Result<Vec<BookmarkModel>, DbErr>;
+    async fn find_recently_opened(
+        &self,
+        db: &DatabaseConnection,
+        limit: u64,
+    ) -> Result<Vec<BookmarkModel>, DbErr>;
+    /// Writes a whole import in one transaction. A group or bookmark that fails to insert is
+    /// rolled back on its own and reported by row, anything else undoes the import.
+    async fn import(
+        &self,
+        db: &DatabaseConnection,
+        batches: Vec<ImportBatch>,
+    ) -> Result<ImportedRows, DbErr>;
+}
+
+/// Matches any keyword against name, tags or extracted page text
+fn search_condition(query: &str) -> Condition {
+    let keywords: Vec<&str> = query.split_whitespace().collect();
+
+    let mut condition = Condition::any();
+
+    for keyword in keywords {
+        let pattern = contains_pattern(keyword);
+
+        // Extracted page text is searched alongside the bookmark's own fields
+        let content_match = Query::select()
+            .column(bookmark_content::Column::BookmarkId)
+            .from(bookmark_content::Entity)
+            .and_where(Expr::col(bookmark_content::Column::Text).like(pattern.clone()))
+            .to_owned();
+
+        // Qualified, saved searches join the groups table which has a name too
+        let keyword_condition = Condition::any()
+            .add(Expr::col((bookmark::Entity, bookmark::Column::Name)).like(pattern.clone()))
+            .add(Expr::col((bookmark::Entity, bookmark::Column::Tags)).like(pattern))
+            .add(bookmark::Column::Id.in_subquery(content_match));
+
+        condition = condition.add(keyword_condition);
+    }
+
+    condition
+}
+
+/// Bookmarks whose group is in a workspace of `organization_ids`, `None` matches them all
+fn organizations_condition(organization_ids: Option<&[i32]>) -> Condition {
+    let Some(organization_ids) = organization_ids else {
+        return Condition::all();
+    };
+
+    let groups_in_organizations = Query::select()
+        .column(groups::Column::Id)
+        .from(groups::Entity)
+        .cond_where(in_organizations(organization_ids))
+        .to_owned();
+
+    Condition::all().add(bookmark::Column::GroupId.in_subquery(groups_in_organizations))
+}
+
+/// Archived bookmarks stay searchable but are hidden from group listings
+fn in_group_condition(group_id: i32) -> Condition {
+    Condition::all()
+        .add(Expr::col(bookmark::Column::GroupId).is(group_id))
+        .add(bookmark::Column::ReadState.ne("archived"))
+}
+
+/// Bookmarks of the workspace that match a smart group filter
+fn matching_select(workspace_id: i32, filter: &SmartGroupFilter) -> Select<Bookmark> {
+    let mut condition = Condition::all().add(groups::Column::WorkspaceId.eq(workspace_id));
+
+    for tag in filter.tags.iter().flatten() {
+        condition = condition.add(tag_condition(tag));
+    }
+    if let Some(host) = &filter.host {
+        condition = condition.add(host_condition(host));
+    }
+    if let Some(is_favorite) = filter.is_favorite {
+        condition = condition.add(bookmark::Column::IsFavorite.eq(is_favorite));
+    }
+    if let Some(created_after) = filter.created_after {
+        condition = condition.add(bookmark::Column::CreatedAt.gte(created_after));
+    }
+    if let Some(created_before) = filter.created_before {
+        condition = condition.add(bookmark::Column::CreatedAt.lt(created_before));
+    }
+
+    // Like static groups, smart groups hide archived items unless asked for them
+    condition = match &filter.read_states {
+        Some(states) => condition
+            .add(bookmark::Column::ReadState.is_in(states.iter().map(|state| state.as_str()))),
+        None => condition.add(bookmark::Column::ReadState.ne(ReadState::Archived.as_str())),
+    };
+
+    Bookmark::find()
+        .inner_join(groups::Entity)
+        .filter(condition)
+}
+
+/// Matches whole entries of the comma separated tags, so `go` does not match `golang`. The
+/// escape character is bound as a value, sea-query would read a `'\'` literal as an escaped quote.
+fn tag_condition(tag: &str) -> SimpleExpr {
+    Expr::cust_with_values(
+        r#"(',' || replace(replace("bookmark"."tags", ', ', ','), ' ,', ',') || ',') LIKE ? ESCAPE ?"#,
+        [
+            format!("%,{},%", escape_like(tag.trim())),
+            LIKE_ESCAPE.to_string(),
+        ],
+    )
+}
+
+/// Matches the host right after the scheme, up to the path or the end of the URL, so
+/// `github.com` matches neither `github.com.evil.org` nor a URL that only mentions it in its
+/// query string
+fn host_condition(host: &str) -> SimpleExpr {
+    let host = escape_like(host.trim());
+
+    Expr::cust_with_values(
+        r#"instr("bookmark"."url", '://') > 0
+           AND (substr("bookmark"."url", instr("bookmark"."url", '://') + 3) LIKE ? ESCAPE ?
+             OR substr("bookmark"."url", instr("bookmark"."url", '://') + 3) LIKE ? ESCAPE ?)"#,
+        [
+            format!("{}/%", host),
+            LIKE_ESCAPE.to_string(),
+            host,
+            LIKE_ESCAPE.to_string(),
+        ],
+    )
+}
+
+/// Inserts a copy of `bookmark` into `group_id`. Extracted page text comes along so the copy
+/// is searchable and shows a reading time right away, snapshots stay with the original.
+pub(crate) async fn insert_copy<C: ConnectionTrait>(
+    db: &C,
+    bookmark: &BookmarkModel,
+    group_id: i32,
+) -> Result<BookmarkModel, DbErr> {
+    let now = chrono::Utc::now();
+    let copy = BookmarkActiveModel {
+        id: NotSet,
+        uuid: Set(new_uuid()),
+        group_id: Set(group_id),
+        opened_at: Set(None),
+        open_count: Set(0),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..bookmark.clone().into()
+    };
+    let copy = Bookmark::insert(copy).exec_with_returning(db).await?;
+
+    let content = bookmark_content::Entity::find()
+        .filter(bookmark_content::Column::BookmarkId.eq(bookmark.id))
+        .one(db)
+        .await?;
+    if let Some(content) = content {
+        let content = bookmark_content::ActiveModel {
+            id: NotSet,
+            bookmark_id: Set(copy.id),
+            ..content.into()
+        };
+        bookmark_content::Entity::insert(content).exec(db).await?;
+    }
+
+    Ok(copy)
+}
+
+/// Columns the bookmarks list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<bookmark::Column> {
+    ListColumns {
+        id: bookmark::Column::Id,
+        name: bookmark::Column::Name,
+        created_at: bookmark::Column::CreatedAt,
+        sortable: vec![
+            ("id", bookmark::Column::Id),
+            ("name", bookmark::Column::Name),
+            ("url", bookmark::Column::Url),
+            ("created_at", bookmark::Column::CreatedAt),
+            ("updated_at", bookmark::Column::UpdatedAt),
+        ],
+        default_sort: bookmark::Column::Id,
+    }
 }
 
 pub struct BookmarkRepositoryImpl;
@@ -69,36 +383,40 @@ impl BookmarkRepository for BookmarkRepositoryImpl {
         Ok(bookmark)
     }
 
-    async fn find_all(&self, db: &DatabaseConnection) -> Result<Vec<BookmarkModel>, DbErr> {
-        let bookmarks = Bookmark::find().all(db).await?;
-        let list_of_bookmarks = bookmarks
-            .into_iter()
-            .map(|b| b.into())
-            .collect::<Vec<BookmarkModel>>();
+    async fn find_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<Option<BookmarkModel>, DbErr> {
+        Bookmark::find()
+            .filter(bookmark::Column::Uuid.eq(uuid))
+            .one(db)
+            .await
+    }
+
+    async fn find_page(
+        &self,
+        db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let select = Bookmark::find().filter(organizations_condition(organization_ids));
 
-        Ok(list_of_bookmarks)
+        fetch_page(db, select, params, &list_columns()).await
     }
 
     async fn search(
         &self,
         db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
         query: &str,
-    ) -> Result<Vec<bookmark::Model>, DbErr> {
-        let keywords: Vec<&str> = query.split_whitespace().collect();
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let select = bookmark::Entity::find()
+            .filter(search_condition(query))
+            .filter(organizations_condition(organization_ids));
 
-        let mut condition = Condition::any();
-
-        for keyword in keywords {
-            let pattern = format!("%{}%", keyword);
-
-            let keyword_condition = Condition::any()
-                .add(Expr::col(bookmark::Column::Name).like(&pattern))
-                .add(Expr::col(bookmark::Column::Tags).like(&pattern));
-
-            condition = condition.add(keyword_condition);
-        }
-
-        bookmark::Entity::find().filter(condition).all(db).await
+        fetch_page(db, select, params, &list_columns()).await
     }
 
     async fn get_by_group(
@@ -106,9 +424,21 @@ impl BookmarkRepository for BookmarkRepositoryImpl {
         db: &DatabaseConnection,
         group_id: i32,
     ) -> Result<Vec<BookmarkModel>, DbErr> {
-        let condition = Condition::all().add(Expr::col(bookmark::Column::GroupId).is(group_id));
+        bookmark::Entity::find()
+            .filter(in_group_condition(group_id))
+            .all(db)
+            .await
+    }
 
-        bookmark::Entity::find().filter(condition).all(db).await
+    async fn find_page_in_group(
+        &self,
+        db: &DatabaseConnection,
+        group_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let select = bookmark::Entity::find().filter(in_group_condition(group_id));
+
+        fetch_page(db, select, params, &list_columns()).await
     }
 
     async fn update(
@@ -117,9 +447,12 @@ impl BookmarkRepository for BookmarkRepositoryImpl {
         id: i32,
         bookmark: BookmarkActiveModel,
     ) -> Result<bookmark::Model, DbErr> {
-        let found_bookmark = Bookmark::find_by_id(id).one(db).await?;
+        let found_bookmark = Bookmark::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
 
-        let mut found_bookmark_active_model: BookmarkActiveModel = found_bookmark.unwrap().into();
+        let mut found_bookmark_active_model: BookmarkActiveModel = found_bookmark.into();
 
         if let Set(name) = bookmark.name {
             if !name.is_empty() {
@@ -140,22 +473,309 @@ impl BookmarkRepository for BookmarkRepositoryImpl {
                 found_bookmark_active_model.url = Set(url);
             }
         }
+        if let Set(group_id) = bookmark.group_id {
+            found_bookmark_active_model.group_id = Set(group_id);
+        }
 
-        println!(
-            "@BookmarkRepository::update-data: {:?}",
-            found_bookmark_active_model.clone()
-        );
-
-        let updated_bookmark: BookmarkModel = Bookmark::update(found_bookmark_active_model)
-            .exec(db)
-            .await?;
-
-        println!("Bookmarkupdated: {:?}", updated_bookmark);
-        Ok(updated_bookmark)
+        Bookmark::update(found_bookmark_active_model).exec(db).await
     }
 
     async fn delete(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
         let _ = Bookmark::delete_by_id(id).exec(db).await;
         Ok(())
     }
+
+    async fn copy_to(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        group_id: i32,
+    ) -> Result<BookmarkModel, DbErr> {
+        let bookmark = Bookmark::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        // The bookmark and its page text are written together or not at all
+        let txn = db.begin().await?;
+        let copy = insert_copy(&txn, &bookmark, group_id).await?;
+        txn.commit().await?;
+
+        Ok(copy)
+    }
+
+    async fn import(
+        &self,
+        db: &DatabaseConnection,
+        batches: Vec<ImportBatch>,
+    ) -> Result<ImportedRows, DbErr> {
+        let txn = db.begin().await?;
+        let mut imported = ImportedRows::default();
+
+        for ImportBatch { group, bookmarks } in batches {
+            let group_id = match group {
+                ImportGroup::Existing(id) => id,
+                ImportGroup::New(group) => {
+                    let name = group.name.clone().take().unwrap_or_default();
+                    let savepoint = txn.begin().await?;
+                    match groups::Entity::insert(group)
+                        .exec_with_returning(&savepoint)
+                        .await
+                    {
+                        Ok(created) => {
+                            savepoint.commit().await?;
+                            imported.groups.push(created.clone());
+                            created.id
+                        }
+                        Err(e) => {
+                            savepoint.rollback().await?;
+                            // Nothing of the group can be saved, each of its rows is reported
+                            imported.errors.extend(bookmarks.iter().map(|(row, _)| {
+                                ImportRowError {
+                                    row: *row,
+                                    message: format!(
+                                        "The group \"{}\" could not be created: {}",
+                                        name, e
+                                    ),
+                                }
+                            }));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            for (row, mut bookmark) in bookmarks {
+                bookmark.group_id = Set(group_id);
+                // A copy of a bookmark another workspace still has gets a UUID of its own
+                if let Set(uuid) = &bookmark.uuid {
+                    let taken = Bookmark::find()
+                        .filter(bookmark::Column::Uuid.eq(uuid.as_str()))
+                        .one(&txn)
+                        .await?
+                        .is_some();
+                    if taken {
+                        bookmark.uuid = Set(new_uuid());
+                    }
+                }
+
+                let savepoint = txn.begin().await?;
+                match Bookmark::insert(bookmark)
+                    .exec_with_returning(&savepoint)
+                    .await
+                {
+                    Ok(created) => {
+                        savepoint.commit().await?;
+                        imported.bookmarks.push(created);
+                    }
+                    Err(e) => {
+                        savepoint.rollback().await?;
+                        imported.errors.push(ImportRowError {
+                            row,
+                            message: e.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        txn.commit().await?;
+
+        Ok(imported)
+    }
+
+    async fn list_read_later(
+        &self,
+        db: &DatabaseConnection,
+        group_id: Option<i32>,
+        states: Vec<String>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let mut condition = Condition::all().add(bookmark::Column::ReadState.is_in(states));
+
+        if let Some(group_id) = group_id {
+            condition = condition.add(bookmark::Column::GroupId.eq(group_id));
+        }
+
+        // Oldest first unless the caller sorts, the queue is read in the order it was filled
+        let mut columns = list_columns();
+        columns.default_sort = bookmark::Column::CreatedAt;
+
+        fetch_page(db, Bookmark::find().filter(condition), params, &columns).await
+    }
+
+    async fn update_read_state(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        bookmark: BookmarkActiveModel,
+    ) -> Result<BookmarkModel, DbErr> {
+        let found_bookmark = Bookmark::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        let updated_bookmark = BookmarkActiveModel {
+            read_state: bookmark.read_state,
+            reading_at: bookmark.reading_at,
+            read_at: bookmark.read_at,
+            archived_at: bookmark.archived_at,
+            updated_at: Set(chrono::Utc::now()),
+            ..found_bookmark.into()
+        };
+
+        Bookmark::update(updated_bookmark).exec(db).await
+    }
+
+    async fn count_read_states(
+        &self,
+        db: &DatabaseConnection,
+    ) -> Result<Vec<ReadStateCount>, DbErr> {
+        Bookmark::find()
+            .select_only()
+            .column(bookmark::Column::GroupId)
+            .column(bookmark::Column::ReadState)
+            .column_as(bookmark::Column::Id.count(), "count")
+            .group_by(bookmark::Column::GroupId)
+            .group_by(bookmark::Column::ReadState)
+            .into_model::<ReadStateCount>()
+            .all(db)
+            .await
+    }
+
+    async fn find_word_counts(
+        &self,
+        db: &DatabaseConnection,
+        ids: Vec<i32>,
+    ) -> Result<HashMap<i32, i32>, DbErr> {
+        let rows: Vec<(i32, i32)> = bookmark_content::Entity::find()
+            .select_only()
+            .column(bookmark_content::Column::BookmarkId)
+            .column(bookmark_content::Column::WordCount)
+            .filter(bookmark_content::Column::BookmarkId.is_in(ids))
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn find_matching(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        matching_select(workspace_id, filter)
+            .order_by_desc(bookmark::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    async fn find_matching_page(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        fetch_page(
+            db,
+            matching_select(workspace_id, filter),
+            params,
+            &list_columns(),
+        )
+        .await
+    }
+
+    async fn search_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        Bookmark::find()
+            .inner_join(groups::Entity)
+            .filter(groups::Column::WorkspaceId.eq(workspace_id))
+            .filter(search_condition(query))
+            .order_by_desc(bookmark::Column::CreatedAt)
+            .all(db)
+            .await
+    }
+
+    async fn count_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<u64, DbErr> {
+        Bookmark::find()
+            .inner_join(groups::Entity)
+            .filter(groups::Column::WorkspaceId.eq(workspace_id))
+            .filter(search_condition(query))
+            .count(db)
+            .await
+    }
+
+    async fn mark_opened(&self, db: &DatabaseConnection, id: i32) -> Result<BookmarkModel, DbErr> {
+        let found_bookmark = Bookmark::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        // Opening is not an edit, so `updated_at` is left alone
+        let opened_bookmark = BookmarkActiveModel {
+            opened_at: Set(Some(chrono::Utc::now())),
+            open_count: Set(found_bookmark.open_count + 1),
+            ..found_bookmark.into()
+        };
+
+        Bookmark::update(opened_bookmark).exec(db).await
+    }
+
+    async fn mark_broken(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        broken: bool,
+    ) -> Result<BookmarkModel, DbErr> {
+        let found_bookmark = Bookmark::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        // Keep the time the link first broke while it stays broken
+        let broken_at = match (broken, found_bookmark.broken_at) {
+            (true, Some(broken_at)) => Some(broken_at),
+            (true, None) => Some(chrono::Utc::now()),
+            (false, _) => None,
+        };
+        let checked_bookmark = BookmarkActiveModel {
+            broken_at: Set(broken_at),
+            ..found_bookmark.into()
+        };
+
+        Bookmark::update(checked_bookmark).exec(db).await
+    }
+
+    async fn find_favorites(&self, db: &DatabaseConnection) -> Result<Vec<BookmarkModel>, DbErr> {
+        Bookmark::find()
+            .filter(bookmark::Column::IsFavorite.eq(true))
+            .order_by_asc(bookmark::Column::Name)
+            .all(db)
+            .await
+    }
+
+    async fn find_recently_opened(
+        &self,
+        db: &DatabaseConnection,
+        limit: u64,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        Bookmark::find()
+            .filter(bookmark::Column::OpenedAt.is_not_null())
+            .order_by_desc(bookmark::Column::OpenedAt)
+            .limit(limit)
+            .all(db)
+            .await
+    }
 }
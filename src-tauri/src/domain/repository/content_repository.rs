@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use entity::bookmark_content::{
+    self, ActiveModel as ContentActiveModel, Entity as BookmarkContent, Model as ContentModel,
+};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+};
+
+#[async_trait]
+pub trait ContentRepository: Send + Sync {
+    async fn upsert_content(
+        &self,
+        db: &DatabaseConnection,
+        input: ContentActiveModel,
+    ) -> Result<ContentModel, DbErr>;
+    async fn get_by_bookmark(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+    ) -> Result<Option<ContentModel>, DbErr>;
+}
+
+pub struct ContentRepositoryImpl {}
+
+impl ContentRepositoryImpl {
+    pub fn new() -> Self {
+        ContentRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl ContentRepository for ContentRepositoryImpl {
+    async fn upsert_content(
+        &self,
+        db: &DatabaseConnection,
+        input: ContentActiveModel,
+    ) -> Result<ContentModel, DbErr> {
+        // Re-extracting a page replaces the previous text for that bookmark
+        BookmarkContent::insert(input)
+            .on_conflict(
+                OnConflict::column(bookmark_content::Column::BookmarkId)
+                    .update_columns([
+                        bookmark_content::Column::Title,
+                        bookmark_content::Column::Text,
+                        bookmark_content::Column::WordCount,
+                        bookmark_content::Column::ExtractedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(db)
+            .await
+    }
+
+    async fn get_by_bookmark(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+    ) -> Result<Option<ContentModel>, DbErr> {
+        BookmarkContent::find()
+            .filter(bookmark_content::Column::BookmarkId.eq(bookmark_id))
+            .one(db)
+            .await
+    }
+}
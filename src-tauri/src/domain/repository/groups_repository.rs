@@ -1,12 +1,25 @@
 use entity::{
+    bookmark,
     groups::{self, ActiveModel as GroupsActiveModel, Entity as Groups},
     workspace,
 };
 
 use async_trait::async_trait;
 
+use crate::domain::{
+    repository::{
+        bookmark_repository::insert_copy,
+        pagination::{fetch_page, ListColumns},
+    },
+    types::pagination::{ListParams, Page},
+    util::new_uuid,
+};
+
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    sea_query::{Query, SimpleExpr},
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
 };
 
 #[async_trait]
@@ -21,6 +34,12 @@ pub trait GroupRepository: Send + Sync {
         db: &DatabaseConnection,
         id: i32,
     ) -> Result<groups::Model, DbErr>;
+    async fn get_group_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<groups::Model, DbErr>;
+    /// Writes every field that is set, a new workspace included, in one transaction
     async fn update_group(
         &self,
         db: &DatabaseConnection,
@@ -28,7 +47,29 @@ pub trait GroupRepository: Send + Sync {
         input: GroupsActiveModel,
     ) -> Result<groups::Model, DbErr>;
     async fn delete_group(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
-    async fn list_groups(&self, db: &DatabaseConnection) -> Result<Vec<groups::Model>, DbErr>;
+    /// Moves a group to another workspace, its bookmarks go with it
+    async fn move_group(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        workspace_id: i32,
+    ) -> Result<groups::Model, DbErr>;
+    /// Copies a group and every bookmark in it, archived ones included, all at once or not at
+    /// all
+    async fn duplicate_group(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        workspace_id: i32,
+        name: String,
+    ) -> Result<groups::Model, DbErr>;
+    /// Groups of every organization, or only of `organization_ids` when given
+    async fn list_groups(
+        &self,
+        db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr>;
     async fn find_by_workspace_id(
         &self,
         db: &DatabaseConnection,
@@ -39,7 +80,41 @@ pub trait GroupRepository: Send + Sync {
         db: &DatabaseConnection,
         workspace_id: i32,
         organization_id: i32,
-    ) -> Result<Vec<groups::Model>, DbErr>;
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr>;
+    async fn update_group_filter(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        filter: String,
+    ) -> Result<groups::Model, DbErr>;
+}
+
+/// Columns the groups list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<groups::Column> {
+    ListColumns {
+        id: groups::Column::Id,
+        name: groups::Column::Name,
+        created_at: groups::Column::CreatedAt,
+        sortable: vec![
+            ("id", groups::Column::Id),
+            ("name", groups::Column::Name),
+            ("created_at", groups::Column::CreatedAt),
+            ("updated_at", groups::Column::UpdatedAt),
+        ],
+        default_sort: groups::Column::Id,
+    }
+}
+
+/// Groups in a workspace of one of `organization_ids`
+pub(crate) fn in_organizations(organization_ids: &[i32]) -> SimpleExpr {
+    let workspaces_in_organizations = Query::select()
+        .column(workspace::Column::Id)
+        .from(workspace::Entity)
+        .and_where(workspace::Column::OrganizationId.is_in(organization_ids.iter().copied()))
+        .to_owned();
+
+    groups::Column::WorkspaceId.in_subquery(workspaces_in_organizations)
 }
 
 pub struct GroupRepositoryImpl {}
@@ -86,38 +161,59 @@ impl GroupRepository for GroupRepositoryImpl {
         group.ok_or(DbErr::RecordNotFound("Group not found".to_string()))
     }
 
+    async fn get_group_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<groups::Model, DbErr> {
+        Groups::find()
+            .filter(groups::Column::Uuid.eq(uuid))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))
+    }
+
     async fn update_group(
         &self,
         db: &DatabaseConnection,
         id: i32,
         input: GroupsActiveModel,
     ) -> Result<groups::Model, DbErr> {
+        // A new workspace and the other fields are written together, never one without the other
+        let txn = db.begin().await?;
+
         // Fetch the existing group to update
         let existing_group = Groups::find_by_id(id)
-            .one(db)
+            .one(&txn)
             .await?
             .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
 
-        let groups_active_model: GroupsActiveModel = existing_group.into();
+        let mut updated_groups: GroupsActiveModel = existing_group.into();
 
-        let updated_groups = GroupsActiveModel {
-            id: Set(input.id.unwrap()),
-            name: Set(input.name.unwrap()),
-            workspace_id: Set(input.workspace_id.unwrap()),
-            ..groups_active_model
-        };
+        // Only fields present in the update are written, the rest keep their stored values
+        if let Set(name) = input.name {
+            updated_groups.name = Set(name);
+        }
+        if let Set(workspace_id) = input.workspace_id {
+            updated_groups.workspace_id = Set(workspace_id);
+        }
+        if let Set(updated_at) = input.updated_at {
+            updated_groups.updated_at = Set(updated_at);
+        }
 
         let updated_groups = Groups::update(updated_groups)
             .filter(groups::Column::Id.eq(id))
-            .exec(db)
+            .exec(&txn)
             .await
             .map_err(|e| DbErr::Custom(e.to_string()))?;
 
         let groups_model = Groups::find_by_id(updated_groups.id)
-            .one(db)
+            .one(&txn)
             .await?
             .ok_or(DbErr::RecordNotFound("Updated group not found".to_string()))?;
 
+        txn.commit().await?;
+
         // Convert the model to DTO
         Ok(groups_model)
     }
@@ -130,49 +226,91 @@ impl GroupRepository for GroupRepositoryImpl {
         Ok(())
     }
 
-    async fn list_groups(&self, db: &DatabaseConnection) -> Result<Vec<groups::Model>, DbErr> {
-        let groups: Vec<groups::Model> = Groups::find()
-            .all(db)
-            .await
-            .map_err(|e| DbErr::Custom(e.to_string()))?;
+    async fn move_group(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        workspace_id: i32,
+    ) -> Result<groups::Model, DbErr> {
+        let existing_group = Groups::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+
+        // Bookmarks only point at the group, so updating this one row moves all of them
+        let moved_group = GroupsActiveModel {
+            workspace_id: Set(workspace_id),
+            updated_at: Set(chrono::Utc::now()),
+            ..existing_group.into()
+        };
 
-        Ok(groups)
+        Groups::update(moved_group).exec(db).await
     }
 
-    async fn find_by_workspace_id(
+    async fn duplicate_group(
         &self,
         db: &DatabaseConnection,
+        id: i32,
         workspace_id: i32,
-    ) -> Result<Vec<groups::Model>, DbErr> {
-        println!(
-            "🔍 Searching active groups with workspace_id: {}",
-            workspace_id
-        );
+        name: String,
+    ) -> Result<groups::Model, DbErr> {
+        let txn = db.begin().await?;
 
-        // Debug: Check all groups in workspace (including deleted)
-        let all_groups_in_workspace = Groups::find()
-            .filter(groups::Column::WorkspaceId.eq(workspace_id))
-            .all(db)
+        let original = Groups::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+        let now = chrono::Utc::now();
+        let copy = GroupsActiveModel {
+            id: NotSet,
+            uuid: Set(new_uuid()),
+            name: Set(name),
+            workspace_id: Set(workspace_id),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..original.into()
+        };
+        let copy = Groups::insert(copy).exec_with_returning(&txn).await?;
+
+        let bookmarks = bookmark::Entity::find()
+            .filter(bookmark::Column::GroupId.eq(id))
+            .order_by_asc(bookmark::Column::Id)
+            .all(&txn)
             .await?;
+        for bookmark in &bookmarks {
+            insert_copy(&txn, bookmark, copy.id).await?;
+        }
 
-        println!(
-            "📊 All groups in workspace {}: {}",
-            workspace_id,
-            all_groups_in_workspace.len()
-        );
+        txn.commit().await?;
+
+        Ok(copy)
+    }
+
+    async fn list_groups(
+        &self,
+        db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr> {
+        let mut select = Groups::find();
+        if let Some(organization_ids) = organization_ids {
+            select = select.filter(in_organizations(organization_ids));
+        }
 
-        let active_groups = Groups::find()
+        fetch_page(db, select, params, &list_columns()).await
+    }
+
+    async fn find_by_workspace_id(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Vec<groups::Model>, DbErr> {
+        // No logging here, the CLI writes exports that use this to stdout
+        Groups::find()
             .filter(groups::Column::WorkspaceId.eq(workspace_id))
+            .order_by_asc(groups::Column::Id)
             .all(db)
             .await
-            .map_err(|e| {
-                println!("❌ Query error: {}", e);
-                DbErr::Custom(e.to_string())
-            })?;
-
-        println!("✅ Active groups found: {}", active_groups.len());
-
-        Ok(active_groups)
     }
 
     async fn find_belonged_groups(
@@ -180,29 +318,34 @@ impl GroupRepository for GroupRepositoryImpl {
         db: &DatabaseConnection,
         workspace_id: i32,
         organization_id: i32,
-    ) -> Result<Vec<groups::Model>, DbErr> {
-        println!(
-            "🔍 Searching active groups with workspace_id: {} and organization_id: {}",
-            workspace_id, organization_id,
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr> {
+        // Query dengan join ke tabel workspaces untuk memfilter berdasarkan organization_id
+        let select = Groups::find().inner_join(workspace::Entity).filter(
+            Condition::all()
+                .add(groups::Column::WorkspaceId.eq(workspace_id))
+                .add(workspace::Column::OrganizationId.eq(organization_id)),
         );
+        fetch_page(db, select, params, &list_columns()).await
+    }
 
-        // Query dengan join ke tabel workspaces untuk memfilter berdasarkan organization_id
-        let active_groups = Groups::find()
-            .inner_join(workspace::Entity)
-            .filter(
-                Condition::all()
-                    .add(groups::Column::WorkspaceId.eq(workspace_id))
-                    .add(workspace::Column::OrganizationId.eq(organization_id)),
-            )
-            .all(db)
-            .await
-            .map_err(|e| {
-                println!("❌ Query error: {}", e);
-                DbErr::Custom(e.to_string())
-            })?;
+    async fn update_group_filter(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        filter: String,
+    ) -> Result<groups::Model, DbErr> {
+        let existing_group = Groups::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
 
-        println!("✅ Active groups found: {}", active_groups.len());
+        let updated_group = GroupsActiveModel {
+            filter: Set(Some(filter)),
+            updated_at: Set(chrono::Utc::now()),
+            ..existing_group.into()
+        };
 
-        Ok(active_groups)
+        Groups::update(updated_group).exec(db).await
     }
 }
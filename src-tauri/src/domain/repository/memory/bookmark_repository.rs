@@ -0,0 +1,518 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use async_trait::async_trait;
+use entity::{
+    bookmark::{self, ActiveModel as BookmarkActiveModel, Model as BookmarkModel},
+    groups,
+};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::{
+    import::ImportRowError,
+    repository::{
+        bookmark_repository::{
+            list_columns, BookmarkRepository, ImportBatch, ImportGroup, ImportedRows,
+            ReadStateCount,
+        },
+        memory::{
+            group_in_organizations, like, new_row, next_id, paginate, MemoryStore, MemoryTables,
+        },
+    },
+    types::{
+        groups::SmartGroupFilter,
+        pagination::{ListParams, Page},
+        read_later::ReadState,
+    },
+    util::new_uuid,
+};
+
+/// Extracted page text is not kept in memory, so searches only look at name and tags
+pub struct InMemoryBookmarkRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryBookmarkRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryBookmarkRepository { store }
+    }
+}
+
+fn in_workspace(tables: &MemoryTables, bookmark: &BookmarkModel, workspace_id: i32) -> bool {
+    tables
+        .groups
+        .get(&bookmark.group_id)
+        .is_some_and(|group| group.workspace_id == workspace_id)
+}
+
+fn matches_query(bookmark: &BookmarkModel, query: &str) -> bool {
+    let keywords: Vec<&str> = query.split_whitespace().collect();
+
+    keywords.is_empty()
+        || keywords
+            .iter()
+            .any(|keyword| like(&bookmark.name, keyword) || like(&bookmark.tags, keyword))
+}
+
+fn matches_filter(bookmark: &BookmarkModel, filter: &SmartGroupFilter) -> bool {
+    let tags_match = filter.tags.iter().flatten().all(|tag| {
+        bookmark
+            .tags
+            .split(',')
+            .any(|entry| entry.trim().eq_ignore_ascii_case(tag.trim()))
+    });
+    let host_match = filter.host.as_ref().is_none_or(|host| {
+        let url = bookmark.url.to_ascii_lowercase();
+        let host = host.trim().to_ascii_lowercase();
+        url.split_once("://")
+            .is_some_and(|(_, rest)| rest == host || rest.starts_with(&format!("{}/", host)))
+    });
+    let favorite_match = filter
+        .is_favorite
+        .is_none_or(|is_favorite| bookmark.is_favorite == is_favorite);
+    let after_match = filter
+        .created_after
+        .is_none_or(|created_after| bookmark.created_at >= created_after);
+    let before_match = filter
+        .created_before
+        .is_none_or(|created_before| bookmark.created_at < created_before);
+    let state_match = match &filter.read_states {
+        Some(states) => states
+            .iter()
+            .any(|state| state.as_str() == bookmark.read_state),
+        None => bookmark.read_state != ReadState::Archived.as_str(),
+    };
+
+    tags_match && host_match && favorite_match && after_match && before_match && state_match
+}
+
+/// Inserts a copy of `bookmark` into `group_id` under a new UUID, shared with the group repository
+pub(crate) fn insert_copy(
+    tables: &mut MemoryTables,
+    bookmark: &BookmarkModel,
+    group_id: i32,
+) -> BookmarkModel {
+    let now = chrono::Utc::now();
+    let copy = BookmarkModel {
+        id: next_id(&tables.bookmarks),
+        uuid: new_uuid(),
+        group_id,
+        opened_at: None,
+        open_count: 0,
+        created_at: now,
+        updated_at: now,
+        ..bookmark.clone()
+    };
+    tables.bookmarks.insert(copy.id, copy.clone());
+
+    copy
+}
+
+#[async_trait]
+impl BookmarkRepository for InMemoryBookmarkRepository {
+    async fn create(
+        &self,
+        _db: &DatabaseConnection,
+        mut bookmark: BookmarkActiveModel,
+    ) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+
+        bookmark.id = Set(next_id(&tables.bookmarks));
+        let created_bookmark: BookmarkModel = new_row(&bookmark)?;
+        tables
+            .bookmarks
+            .insert(created_bookmark.id, created_bookmark.clone());
+
+        Ok(created_bookmark)
+    }
+
+    async fn find_by_id(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<Option<BookmarkModel>, DbErr> {
+        Ok(self.store.tables().bookmarks.get(&id).cloned())
+    }
+
+    async fn find_by_uuid(
+        &self,
+        _db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<Option<BookmarkModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .bookmarks
+            .values()
+            .find(|bookmark| bookmark.uuid == uuid)
+            .cloned())
+    }
+
+    async fn find_page(
+        &self,
+        _db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let tables = self.store.tables();
+        let bookmarks = tables
+            .bookmarks
+            .values()
+            .filter(|bookmark| group_in_organizations(&tables, bookmark.group_id, organization_ids))
+            .cloned()
+            .collect();
+        paginate(bookmarks, params, &list_columns())
+    }
+
+    async fn search(
+        &self,
+        _db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        query: &str,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let tables = self.store.tables();
+        let bookmarks = tables
+            .bookmarks
+            .values()
+            .filter(|bookmark| matches_query(bookmark, query))
+            .filter(|bookmark| group_in_organizations(&tables, bookmark.group_id, organization_ids))
+            .cloned()
+            .collect();
+        paginate(bookmarks, params, &list_columns())
+    }
+
+    async fn get_by_group(
+        &self,
+        _db: &DatabaseConnection,
+        group_id: i32,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .bookmarks
+            .values()
+            .filter(|bookmark| {
+                bookmark.group_id == group_id && bookmark.read_state != ReadState::Archived.as_str()
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_page_in_group(
+        &self,
+        db: &DatabaseConnection,
+        group_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let bookmarks = self.get_by_group(db, group_id).await?;
+        paginate(bookmarks, params, &list_columns())
+    }
+
+    async fn update(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        bookmark: BookmarkActiveModel,
+    ) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+        let found_bookmark = tables
+            .bookmarks
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        // Same rules as the SQL repository, empty strings leave the stored value alone
+        if let Set(name) = bookmark.name {
+            if !name.is_empty() {
+                found_bookmark.name = name;
+            }
+        }
+        if let Set(is_favorite) = bookmark.is_favorite {
+            found_bookmark.is_favorite = is_favorite;
+        }
+        if let Set(tags) = bookmark.tags {
+            if !tags.is_empty() {
+                found_bookmark.tags = tags;
+            }
+        }
+        if let Set(url) = bookmark.url {
+            if !url.is_empty() {
+                found_bookmark.url = url;
+            }
+        }
+        if let Set(group_id) = bookmark.group_id {
+            found_bookmark.group_id = group_id;
+        }
+
+        Ok(found_bookmark.clone())
+    }
+
+    async fn delete(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().bookmarks.remove(&id);
+        Ok(())
+    }
+
+    async fn copy_to(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        group_id: i32,
+    ) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+        let bookmark = tables
+            .bookmarks
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        Ok(insert_copy(&mut tables, &bookmark, group_id))
+    }
+
+    async fn import(
+        &self,
+        _db: &DatabaseConnection,
+        batches: Vec<ImportBatch>,
+    ) -> Result<ImportedRows, DbErr> {
+        let mut tables = self.store.tables();
+        let mut imported = ImportedRows::default();
+
+        for ImportBatch { group, bookmarks } in batches {
+            let group_id = match group {
+                ImportGroup::Existing(id) => id,
+                ImportGroup::New(mut group) => {
+                    group.id = Set(next_id(&tables.groups));
+                    let group: groups::Model = new_row(&group)?;
+                    tables.groups.insert(group.id, group.clone());
+                    imported.groups.push(group.clone());
+                    group.id
+                }
+            };
+
+            for (row, mut bookmark) in bookmarks {
+                bookmark.id = Set(next_id(&tables.bookmarks));
+                bookmark.group_id = Set(group_id);
+                if let Set(uuid) = &bookmark.uuid {
+                    if tables.bookmarks.values().any(|saved| &saved.uuid == uuid) {
+                        bookmark.uuid = Set(new_uuid());
+                    }
+                }
+
+                match new_row::<_, BookmarkModel>(&bookmark) {
+                    Ok(created) => {
+                        tables.bookmarks.insert(created.id, created.clone());
+                        imported.bookmarks.push(created);
+                    }
+                    Err(e) => imported.errors.push(ImportRowError {
+                        row,
+                        message: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        Ok(imported)
+    }
+
+    async fn list_read_later(
+        &self,
+        _db: &DatabaseConnection,
+        group_id: Option<i32>,
+        states: Vec<String>,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let bookmarks = self
+            .store
+            .tables()
+            .bookmarks
+            .values()
+            .filter(|bookmark| states.contains(&bookmark.read_state))
+            .filter(|bookmark| group_id.is_none_or(|group_id| bookmark.group_id == group_id))
+            .cloned()
+            .collect();
+
+        let mut columns = list_columns();
+        columns.default_sort = bookmark::Column::CreatedAt;
+        paginate(bookmarks, params, &columns)
+    }
+
+    async fn update_read_state(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        bookmark: BookmarkActiveModel,
+    ) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+        let found_bookmark = tables
+            .bookmarks
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        if let Set(read_state) = bookmark.read_state {
+            found_bookmark.read_state = read_state;
+        }
+        if let Set(reading_at) = bookmark.reading_at {
+            found_bookmark.reading_at = reading_at;
+        }
+        if let Set(read_at) = bookmark.read_at {
+            found_bookmark.read_at = read_at;
+        }
+        if let Set(archived_at) = bookmark.archived_at {
+            found_bookmark.archived_at = archived_at;
+        }
+        found_bookmark.updated_at = chrono::Utc::now();
+
+        Ok(found_bookmark.clone())
+    }
+
+    async fn count_read_states(
+        &self,
+        _db: &DatabaseConnection,
+    ) -> Result<Vec<ReadStateCount>, DbErr> {
+        let mut counts: HashMap<(i32, String), i64> = HashMap::new();
+        for bookmark in self.store.tables().bookmarks.values() {
+            *counts
+                .entry((bookmark.group_id, bookmark.read_state.clone()))
+                .or_default() += 1;
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|((group_id, read_state), count)| ReadStateCount {
+                group_id,
+                read_state,
+                count,
+            })
+            .collect())
+    }
+
+    async fn find_word_counts(
+        &self,
+        _db: &DatabaseConnection,
+        _ids: Vec<i32>,
+    ) -> Result<HashMap<i32, i32>, DbErr> {
+        Ok(HashMap::new())
+    }
+
+    async fn find_matching(
+        &self,
+        _db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        let tables = self.store.tables();
+        let mut bookmarks: Vec<BookmarkModel> = tables
+            .bookmarks
+            .values()
+            .filter(|bookmark| in_workspace(&tables, bookmark, workspace_id))
+            .filter(|bookmark| matches_filter(bookmark, filter))
+            .cloned()
+            .collect();
+
+        bookmarks.sort_by_key(|bookmark| Reverse(bookmark.created_at));
+        Ok(bookmarks)
+    }
+
+    async fn find_matching_page(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        filter: &SmartGroupFilter,
+        params: &ListParams,
+    ) -> Result<Page<BookmarkModel>, DbErr> {
+        let bookmarks = self.find_matching(db, workspace_id, filter).await?;
+        paginate(bookmarks, params, &list_columns())
+    }
+
+    async fn search_in_workspace(
+        &self,
+        _db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        let tables = self.store.tables();
+        let mut bookmarks: Vec<BookmarkModel> = tables
+            .bookmarks
+            .values()
+            .filter(|bookmark| in_workspace(&tables, bookmark, workspace_id))
+            .filter(|bookmark| matches_query(bookmark, query))
+            .cloned()
+            .collect();
+
+        bookmarks.sort_by_key(|bookmark| Reverse(bookmark.created_at));
+        Ok(bookmarks)
+    }
+
+    async fn count_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        query: &str,
+    ) -> Result<u64, DbErr> {
+        let bookmarks = self.search_in_workspace(db, workspace_id, query).await?;
+        Ok(bookmarks.len() as u64)
+    }
+
+    async fn mark_opened(&self, _db: &DatabaseConnection, id: i32) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+        let found_bookmark = tables
+            .bookmarks
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        found_bookmark.opened_at = Some(chrono::Utc::now());
+        found_bookmark.open_count += 1;
+        Ok(found_bookmark.clone())
+    }
+
+    async fn mark_broken(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        broken: bool,
+    ) -> Result<BookmarkModel, DbErr> {
+        let mut tables = self.store.tables();
+        let found_bookmark = tables
+            .bookmarks
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_string()))?;
+
+        found_bookmark.broken_at = match (broken, found_bookmark.broken_at) {
+            (true, Some(broken_at)) => Some(broken_at),
+            (true, None) => Some(chrono::Utc::now()),
+            (false, _) => None,
+        };
+        Ok(found_bookmark.clone())
+    }
+
+    async fn find_favorites(&self, _db: &DatabaseConnection) -> Result<Vec<BookmarkModel>, DbErr> {
+        let mut bookmarks: Vec<BookmarkModel> = self
+            .store
+            .tables()
+            .bookmarks
+            .values()
+            .filter(|bookmark| bookmark.is_favorite)
+            .cloned()
+            .collect();
+
+        bookmarks.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(bookmarks)
+    }
+
+    async fn find_recently_opened(
+        &self,
+        _db: &DatabaseConnection,
+        limit: u64,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        let mut bookmarks: Vec<BookmarkModel> = self
+            .store
+            .tables()
+            .bookmarks
+            .values()
+            .filter(|bookmark| bookmark.opened_at.is_some())
+            .cloned()
+            .collect();
+
+        bookmarks.sort_by_key(|bookmark| Reverse(bookmark.opened_at));
+        bookmarks.truncate(limit as usize);
+        Ok(bookmarks)
+    }
+}
@@ -0,0 +1,230 @@
+use async_trait::async_trait;
+use entity::groups::{self, ActiveModel as GroupsActiveModel};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::{
+    repository::{
+        groups_repository::{list_columns, GroupRepository},
+        memory::{
+            bookmark_repository::insert_copy, group_in_organizations, new_row, next_id, paginate,
+            MemoryStore,
+        },
+    },
+    types::pagination::{ListParams, Page},
+    util::new_uuid,
+};
+
+pub struct InMemoryGroupRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryGroupRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryGroupRepository { store }
+    }
+}
+
+#[async_trait]
+impl GroupRepository for InMemoryGroupRepository {
+    async fn create_group(
+        &self,
+        _db: &DatabaseConnection,
+        mut input: GroupsActiveModel,
+    ) -> Result<groups::Model, DbErr> {
+        let mut tables = self.store.tables();
+
+        input.id = Set(next_id(&tables.groups));
+        let group: groups::Model = new_row(&input)?;
+        tables.groups.insert(group.id, group.clone());
+
+        Ok(group)
+    }
+
+    async fn get_group_by_id(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<groups::Model, DbErr> {
+        self.store
+            .tables()
+            .groups
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))
+    }
+
+    async fn get_group_by_uuid(
+        &self,
+        _db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<groups::Model, DbErr> {
+        self.store
+            .tables()
+            .groups
+            .values()
+            .find(|group| group.uuid == uuid)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))
+    }
+
+    async fn update_group(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        input: GroupsActiveModel,
+    ) -> Result<groups::Model, DbErr> {
+        let mut tables = self.store.tables();
+        let group = tables
+            .groups
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+
+        if let Set(name) = input.name {
+            group.name = name;
+        }
+        if let Set(workspace_id) = input.workspace_id {
+            group.workspace_id = workspace_id;
+        }
+        if let Set(updated_at) = input.updated_at {
+            group.updated_at = updated_at;
+        }
+
+        Ok(group.clone())
+    }
+
+    async fn delete_group(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().groups.remove(&id);
+        Ok(())
+    }
+
+    async fn move_group(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        workspace_id: i32,
+    ) -> Result<groups::Model, DbErr> {
+        let mut tables = self.store.tables();
+        let group = tables
+            .groups
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+
+        group.workspace_id = workspace_id;
+        group.updated_at = chrono::Utc::now();
+
+        Ok(group.clone())
+    }
+
+    async fn duplicate_group(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        workspace_id: i32,
+        name: String,
+    ) -> Result<groups::Model, DbErr> {
+        // One lock for the whole copy, nobody sees it half done
+        let mut tables = self.store.tables();
+        let original = tables
+            .groups
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+
+        let now = chrono::Utc::now();
+        let copy = groups::Model {
+            id: next_id(&tables.groups),
+            uuid: new_uuid(),
+            name,
+            workspace_id,
+            created_at: now,
+            updated_at: now,
+            ..original
+        };
+        tables.groups.insert(copy.id, copy.clone());
+
+        let bookmarks: Vec<_> = tables
+            .bookmarks
+            .values()
+            .filter(|bookmark| bookmark.group_id == id)
+            .cloned()
+            .collect();
+        for bookmark in &bookmarks {
+            insert_copy(&mut tables, bookmark, copy.id);
+        }
+
+        Ok(copy)
+    }
+
+    async fn list_groups(
+        &self,
+        _db: &DatabaseConnection,
+        organization_ids: Option<&[i32]>,
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr> {
+        let tables = self.store.tables();
+        let groups = tables
+            .groups
+            .values()
+            .filter(|group| group_in_organizations(&tables, group.id, organization_ids))
+            .cloned()
+            .collect();
+        paginate(groups, params, &list_columns())
+    }
+
+    async fn find_by_workspace_id(
+        &self,
+        _db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Vec<groups::Model>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .groups
+            .values()
+            .filter(|group| group.workspace_id == workspace_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_belonged_groups(
+        &self,
+        _db: &DatabaseConnection,
+        workspace_id: i32,
+        organization_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<groups::Model>, DbErr> {
+        let tables = self.store.tables();
+
+        let groups = tables
+            .groups
+            .values()
+            .filter(|group| group.workspace_id == workspace_id)
+            .filter(|group| {
+                tables
+                    .workspaces
+                    .get(&group.workspace_id)
+                    .is_some_and(|workspace| workspace.organization_id == organization_id)
+            })
+            .cloned()
+            .collect();
+        paginate(groups, params, &list_columns())
+    }
+
+    async fn update_group_filter(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        filter: String,
+    ) -> Result<groups::Model, DbErr> {
+        let mut tables = self.store.tables();
+        let group = tables
+            .groups
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Group not found".to_string()))?;
+
+        group.filter = Some(filter);
+        group.updated_at = chrono::Utc::now();
+
+        Ok(group.clone())
+    }
+}
@@ -0,0 +1,162 @@
+//! In-memory repositories for tests and as a starting point for other storage backends.
+//! They ignore the `db` argument of the repository traits and keep rows in a shared `MemoryStore`.
+
+pub mod bookmark_repository;
+pub mod groups_repository;
+pub mod organization_member_repository;
+pub mod organization_repository;
+pub mod user_repository;
+pub mod workspace_repository;
+
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use entity::{bookmark, groups, organization, organization_member, user, workspace};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, DbErr, EntityTrait, Iterable, ModelTrait, TryIntoModel, Value,
+};
+
+use crate::domain::{
+    repository::pagination::ListColumns,
+    types::pagination::{ListParams, Page, SortDirection},
+};
+
+/// Rows of every in-memory table, keyed by primary key
+#[derive(Debug, Default)]
+pub struct MemoryTables {
+    pub users: BTreeMap<i32, user::Model>,
+    pub organizations: BTreeMap<i32, organization::Model>,
+    pub members: BTreeMap<i32, organization_member::Model>,
+    pub workspaces: BTreeMap<i32, workspace::Model>,
+    pub groups: BTreeMap<i32, groups::Model>,
+    pub bookmarks: BTreeMap<i32, bookmark::Model>,
+}
+
+/// Shared between the in-memory repositories so lookups across tables (bookmark -> group -> workspace) still work
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    tables: Arc<Mutex<MemoryTables>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tables(&self) -> MutexGuard<'_, MemoryTables> {
+        self.tables
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Next primary key, ids are never reused while the highest row exists
+pub(crate) fn next_id<M>(rows: &BTreeMap<i32, M>) -> i32 {
+    rows.keys().next_back().map_or(1, |id| id + 1)
+}
+
+/// Copies every `Set` field of `patch` onto `base`
+pub(crate) fn apply<A: ActiveModelTrait>(mut base: A, patch: &A) -> A {
+    for column in <A::Entity as EntityTrait>::Column::iter() {
+        if let ActiveValue::Set(value) = patch.get(column) {
+            base.set(column, value);
+        }
+    }
+    base
+}
+
+/// Builds a row from an insert, fields that are not set fall back to their default like a column default would
+pub(crate) fn new_row<A, M>(input: &A) -> Result<M, DbErr>
+where
+    A: ActiveModelTrait + TryIntoModel<M>,
+    M: ModelTrait,
+{
+    apply(A::default_values(), input).try_into_model()
+}
+
+/// Whether `group_id` is in a workspace of `organization_ids`, `None` allows every group
+pub(crate) fn group_in_organizations(
+    tables: &MemoryTables,
+    group_id: i32,
+    organization_ids: Option<&[i32]>,
+) -> bool {
+    let Some(organization_ids) = organization_ids else {
+        return true;
+    };
+
+    tables
+        .groups
+        .get(&group_id)
+        .and_then(|group| tables.workspaces.get(&group.workspace_id))
+        .is_some_and(|workspace| organization_ids.contains(&workspace.organization_id))
+}
+
+/// In-memory counterpart of `fetch_page`, applying the same filters and sort to a list of rows
+pub(crate) fn paginate<M>(
+    mut rows: Vec<M>,
+    params: &ListParams,
+    columns: &ListColumns<<M::Entity as EntityTrait>::Column>,
+) -> Result<Page<M>, DbErr>
+where
+    M: ModelTrait,
+{
+    if let Some(filter) = &params.filter {
+        if let Some(search) = filter.search.as_deref().filter(|s| !s.trim().is_empty()) {
+            let search = search.trim().to_lowercase();
+            rows.retain(|row| match row.get(columns.name) {
+                Value::String(Some(name)) => name.to_lowercase().contains(&search),
+                _ => false,
+            });
+        }
+        if let Some(created_after) = filter.created_after {
+            let bound = Value::from(created_after);
+            rows.retain(|row| compare_values(&row.get(columns.created_at), &bound).is_ge());
+        }
+        if let Some(created_before) = filter.created_before {
+            let bound = Value::from(created_before);
+            rows.retain(|row| compare_values(&row.get(columns.created_at), &bound).is_lt());
+        }
+    }
+
+    let (sort_column, direction) = columns.resolve_sort(params)?;
+    rows.sort_by(|a, b| {
+        let ordering = compare_values(&a.get(sort_column), &b.get(sort_column))
+            .then_with(|| compare_values(&a.get(columns.id), &b.get(columns.id)));
+        match direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    });
+
+    let per_page = params.per_page();
+    let page = params.page_index();
+    let total = rows.len() as u64;
+    let items = rows
+        .into_iter()
+        .skip((page * per_page) as usize)
+        .take(per_page as usize)
+        .collect();
+
+    Ok(Page::new(items, total, page, per_page))
+}
+
+/// Orders the column types used by the entities, mismatched types compare equal
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::ChronoDateTimeUtc(a), Value::ChronoDateTimeUtc(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+/// SQLite `LIKE '%pattern%'`, which ignores ASCII case
+pub(crate) fn like(haystack: &str, pattern: &str) -> bool {
+    haystack
+        .to_ascii_lowercase()
+        .contains(&pattern.to_ascii_lowercase())
+}
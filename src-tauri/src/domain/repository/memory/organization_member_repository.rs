@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use entity::organization_member::{
+    ActiveModel as OrganizationMemberActiveModel, Model as OrganizationMemberModel,
+};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::repository::{
+    memory::{new_row, next_id, MemoryStore},
+    organization_member_repository::OrganizationMemberRepository,
+};
+
+pub struct InMemoryOrganizationMemberRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryOrganizationMemberRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryOrganizationMemberRepository { store }
+    }
+}
+
+#[async_trait]
+impl OrganizationMemberRepository for InMemoryOrganizationMemberRepository {
+    async fn add_member(
+        &self,
+        _db: &DatabaseConnection,
+        mut input: OrganizationMemberActiveModel,
+    ) -> Result<OrganizationMemberModel, DbErr> {
+        let mut tables = self.store.tables();
+
+        input.id = Set(next_id(&tables.members));
+        let member: OrganizationMemberModel = new_row(&input)?;
+        tables.members.insert(member.id, member.clone());
+
+        Ok(member)
+    }
+
+    async fn find_by_id(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr> {
+        Ok(self.store.tables().members.get(&id).cloned())
+    }
+
+    async fn find_member(
+        &self,
+        _db: &DatabaseConnection,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .members
+            .values()
+            .find(|member| member.organization_id == organization_id && member.user_id == user_id)
+            .cloned())
+    }
+
+    async fn list_members(
+        &self,
+        _db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .members
+            .values()
+            .filter(|member| member.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_memberships(
+        &self,
+        _db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .members
+            .values()
+            .filter(|member| member.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_role(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        role: &str,
+    ) -> Result<OrganizationMemberModel, DbErr> {
+        let mut tables = self.store.tables();
+        let member = tables
+            .members
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Member not found".to_string()))?;
+
+        member.role = role.to_string();
+        member.updated_at = chrono::Utc::now();
+
+        Ok(member.clone())
+    }
+
+    async fn remove_member(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().members.remove(&id);
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use entity::organization::{self, ActiveModel as OrganizationActiveModel};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::{
+    repository::{
+        memory::{new_row, next_id, paginate, MemoryStore},
+        organization_repository::{list_columns, OrganizationRepository},
+    },
+    types::pagination::{ListParams, Page},
+};
+
+pub struct InMemoryOrganizationRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryOrganizationRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryOrganizationRepository { store }
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn create_organization(
+        &self,
+        _db: &DatabaseConnection,
+        mut input: OrganizationActiveModel,
+    ) -> Result<organization::Model, DbErr> {
+        let mut tables = self.store.tables();
+
+        input.id = Set(next_id(&tables.organizations));
+        let organization: organization::Model = new_row(&input)?;
+        tables
+            .organizations
+            .insert(organization.id, organization.clone());
+
+        Ok(organization)
+    }
+
+    async fn get_organization_by_id(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<organization::Model, DbErr> {
+        self.store
+            .tables()
+            .organizations
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Organization not found".to_string()))
+    }
+
+    async fn get_organization_by_uuid(
+        &self,
+        _db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<organization::Model, DbErr> {
+        self.store
+            .tables()
+            .organizations
+            .values()
+            .find(|organization| organization.uuid == uuid)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Organization not found".to_string()))
+    }
+
+    async fn get_organization_by_user_id(
+        &self,
+        _db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Vec<organization::Model>, DbErr> {
+        let tables = self.store.tables();
+
+        Ok(tables
+            .organizations
+            .values()
+            .filter(|organization| {
+                organization.user_id == user_id
+                    || tables.members.values().any(|member| {
+                        member.organization_id == organization.id && member.user_id == user_id
+                    })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update_organization(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        input: OrganizationActiveModel,
+    ) -> Result<organization::Model, DbErr> {
+        let mut tables = self.store.tables();
+        let organization = tables
+            .organizations
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Organization not found".to_string()))?;
+
+        if let Set(name) = input.name {
+            organization.name = name;
+        }
+
+        Ok(organization.clone())
+    }
+
+    async fn delete_organization(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().organizations.remove(&id);
+        Ok(())
+    }
+
+    async fn list_organizations(
+        &self,
+        _db: &DatabaseConnection,
+        params: &ListParams,
+    ) -> Result<Page<organization::Model>, DbErr> {
+        let organizations = self
+            .store
+            .tables()
+            .organizations
+            .values()
+            .cloned()
+            .collect();
+        paginate(organizations, params, &list_columns())
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use entity::user::{ActiveModel as UserActiveModel, Model as UserModel};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::{
+    repository::{
+        memory::{new_row, next_id, paginate, MemoryStore},
+        user_repository::{list_columns, UserRepository},
+    },
+    types::pagination::{ListParams, Page},
+};
+
+pub struct InMemoryUserRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryUserRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryUserRepository { store }
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn create_user(
+        &self,
+        _db: &DatabaseConnection,
+        mut input: UserActiveModel,
+    ) -> Result<UserModel, DbErr> {
+        let mut tables = self.store.tables();
+
+        input.id = Set(next_id(&tables.users));
+        let user: UserModel = new_row(&input)?;
+        tables.users.insert(user.id, user.clone());
+
+        Ok(user)
+    }
+
+    async fn get_user_by_id(&self, _db: &DatabaseConnection, id: i32) -> Result<UserModel, DbErr> {
+        self.store
+            .tables()
+            .users
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("User not found".to_string()))
+    }
+
+    async fn get_user_by_uuid(
+        &self,
+        _db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<UserModel, DbErr> {
+        self.store
+            .tables()
+            .users
+            .values()
+            .find(|user| user.uuid == uuid)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("User not found".to_string()))
+    }
+
+    async fn find_user_by_email(
+        &self,
+        _db: &DatabaseConnection,
+        email: &str,
+    ) -> Result<Option<UserModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .users
+            .values()
+            .find(|user| user.email.eq_ignore_ascii_case(email.trim()))
+            .cloned())
+    }
+
+    async fn update_user(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        input: UserActiveModel,
+    ) -> Result<UserModel, DbErr> {
+        let mut tables = self.store.tables();
+        let user = tables
+            .users
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("User not found".to_string()))?;
+
+        if let Set(name) = input.name {
+            user.name = name;
+        }
+        if let Set(email) = input.email {
+            user.email = email;
+        }
+        if let Set(updated_at) = input.updated_at {
+            user.updated_at = updated_at;
+        }
+
+        Ok(user.clone())
+    }
+
+    async fn delete_user(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().users.remove(&id);
+        Ok(())
+    }
+
+    async fn list_users(
+        &self,
+        _db: &DatabaseConnection,
+        params: &ListParams,
+    ) -> Result<Page<UserModel>, DbErr> {
+        let users = self.store.tables().users.values().cloned().collect();
+        paginate(users, params, &list_columns())
+    }
+}
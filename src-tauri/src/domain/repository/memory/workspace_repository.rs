@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use entity::workspace::{ActiveModel as WorkspaceActiveModel, Model as WorkspaceModel};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr};
+
+use crate::domain::{
+    repository::{
+        memory::{new_row, next_id, paginate, MemoryStore},
+        workspace_repository::{list_columns, WorkspaceRepository},
+    },
+    types::pagination::{ListParams, Page},
+};
+
+pub struct InMemoryWorkspaceRepository {
+    store: MemoryStore,
+}
+
+impl InMemoryWorkspaceRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        InMemoryWorkspaceRepository { store }
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for InMemoryWorkspaceRepository {
+    async fn create_workspace(
+        &self,
+        _db: &DatabaseConnection,
+        mut input: WorkspaceActiveModel,
+    ) -> Result<WorkspaceModel, DbErr> {
+        let mut tables = self.store.tables();
+
+        input.id = Set(next_id(&tables.workspaces));
+        let workspace: WorkspaceModel = new_row(&input)?;
+        tables.workspaces.insert(workspace.id, workspace.clone());
+
+        Ok(workspace)
+    }
+
+    async fn get_workspace_by_id(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<WorkspaceModel, DbErr> {
+        self.store
+            .tables()
+            .workspaces
+            .get(&id)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))
+    }
+
+    async fn get_workspace_by_uuid(
+        &self,
+        _db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<WorkspaceModel, DbErr> {
+        self.store
+            .tables()
+            .workspaces
+            .values()
+            .find(|workspace| workspace.uuid == uuid)
+            .cloned()
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))
+    }
+
+    async fn update_workspace(
+        &self,
+        _db: &DatabaseConnection,
+        id: i32,
+        input: WorkspaceActiveModel,
+    ) -> Result<WorkspaceModel, DbErr> {
+        let mut tables = self.store.tables();
+        let workspace = tables
+            .workspaces
+            .get_mut(&id)
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))?;
+
+        if let Set(name) = input.name {
+            workspace.name = name;
+        }
+
+        Ok(workspace.clone())
+    }
+
+    async fn delete_workspace(&self, _db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        self.store.tables().workspaces.remove(&id);
+        Ok(())
+    }
+
+    async fn list_workspace(
+        &self,
+        _db: &DatabaseConnection,
+        organization_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<WorkspaceModel>, DbErr> {
+        let workspaces = self
+            .store
+            .tables()
+            .workspaces
+            .values()
+            .filter(|workspace| workspace.organization_id == organization_id)
+            .cloned()
+            .collect();
+
+        paginate(workspaces, params, &list_columns())
+    }
+
+    async fn find_by_organization_id(
+        &self,
+        _db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceModel>, DbErr> {
+        Ok(self
+            .store
+            .tables()
+            .workspaces
+            .values()
+            .filter(|workspace| workspace.organization_id == organization_id)
+            .cloned()
+            .collect())
+    }
+}
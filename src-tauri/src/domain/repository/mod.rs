@@ -1,5 +1,16 @@
+pub mod audit_repository;
 pub mod bookmark_repository;
+pub mod content_repository;
 pub mod groups_repository;
+pub mod memory;
+pub mod organization_member_repository;
 pub mod organization_repository;
+pub mod pagination;
+pub mod saved_search_repository;
+pub mod settings_repository;
+pub mod snapshot_repository;
+pub mod stats_repository;
+pub mod sync_repository;
 pub mod user_repository;
 pub mod workspace_repository;
+pub mod workspace_template_repository;
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use entity::organization_member::{
+    self, ActiveModel as OrganizationMemberActiveModel, Entity as OrganizationMember,
+    Model as OrganizationMemberModel,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+#[async_trait]
+pub trait OrganizationMemberRepository: Send + Sync {
+    async fn add_member(
+        &self,
+        db: &DatabaseConnection,
+        input: OrganizationMemberActiveModel,
+    ) -> Result<OrganizationMemberModel, DbErr>;
+    async fn find_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr>;
+    async fn find_member(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr>;
+    async fn list_members(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr>;
+    /// Every organization `user_id` belongs to, one row per membership
+    async fn list_memberships(
+        &self,
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr>;
+    async fn update_role(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        role: &str,
+    ) -> Result<OrganizationMemberModel, DbErr>;
+    async fn remove_member(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
+}
+
+pub struct OrganizationMemberRepositoryImpl {}
+
+impl OrganizationMemberRepositoryImpl {
+    pub fn new() -> Self {
+        OrganizationMemberRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl OrganizationMemberRepository for OrganizationMemberRepositoryImpl {
+    async fn add_member(
+        &self,
+        db: &DatabaseConnection,
+        input: OrganizationMemberActiveModel,
+    ) -> Result<OrganizationMemberModel, DbErr> {
+        OrganizationMember::insert(input)
+            .exec_with_returning(db)
+            .await
+    }
+
+    async fn find_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr> {
+        OrganizationMember::find_by_id(id).one(db).await
+    }
+
+    async fn find_member(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<Option<OrganizationMemberModel>, DbErr> {
+        OrganizationMember::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .one(db)
+            .await
+    }
+
+    async fn list_members(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr> {
+        OrganizationMember::find()
+            .filter(organization_member::Column::OrganizationId.eq(organization_id))
+            .order_by_asc(organization_member::Column::Id)
+            .all(db)
+            .await
+    }
+
+    async fn list_memberships(
+        &self,
+        db: &DatabaseConnection,
+        user_id: i32,
+    ) -> Result<Vec<OrganizationMemberModel>, DbErr> {
+        OrganizationMember::find()
+            .filter(organization_member::Column::UserId.eq(user_id))
+            .order_by_asc(organization_member::Column::Id)
+            .all(db)
+            .await
+    }
+
+    async fn update_role(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        role: &str,
+    ) -> Result<OrganizationMemberModel, DbErr> {
+        let existing = OrganizationMember::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Member not found".to_string()))?;
+
+        let updated = OrganizationMemberActiveModel {
+            role: Set(role.to_string()),
+            updated_at: Set(chrono::Utc::now()),
+            ..existing.into()
+        };
+
+        OrganizationMember::update(updated).exec(db).await
+    }
+
+    async fn remove_member(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        OrganizationMember::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+}
@@ -1,9 +1,18 @@
 // Organization Repository Implementation
 use async_trait::async_trait;
-use entity::organization::{
-    self, ActiveModel as OrganizationActiveModel, Column, Entity as Organization,
+use entity::{
+    organization::{self, ActiveModel as OrganizationActiveModel, Column, Entity as Organization},
+    organization_member,
+};
+use sea_orm::{
+    prelude::Expr, sea_query::Query, ColumnTrait, Condition, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter,
+};
+
+use crate::domain::{
+    repository::pagination::{fetch_page, ListColumns},
+    types::pagination::{ListParams, Page},
 };
-use sea_orm::{prelude::Expr, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
 
 #[async_trait]
 pub trait OrganizationRepository: Send + Sync {
@@ -17,6 +26,11 @@ pub trait OrganizationRepository: Send + Sync {
         db: &DatabaseConnection,
         id: i32,
     ) -> Result<organization::Model, DbErr>;
+    async fn get_organization_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<organization::Model, DbErr>;
     async fn get_organization_by_user_id(
         &self,
         db: &DatabaseConnection,
@@ -32,7 +46,24 @@ pub trait OrganizationRepository: Send + Sync {
     async fn list_organizations(
         &self,
         db: &DatabaseConnection,
-    ) -> Result<Vec<organization::Model>, DbErr>;
+        params: &ListParams,
+    ) -> Result<Page<organization::Model>, DbErr>;
+}
+
+/// Columns the organizations list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<Column> {
+    ListColumns {
+        id: Column::Id,
+        name: Column::Name,
+        created_at: Column::CreatedAt,
+        sortable: vec![
+            ("id", Column::Id),
+            ("name", Column::Name),
+            ("created_at", Column::CreatedAt),
+            ("updated_at", Column::UpdatedAt),
+        ],
+        default_sort: Column::Id,
+    }
 }
 
 pub struct OrganizationRepositoryImpl {}
@@ -73,12 +104,32 @@ impl OrganizationRepository for OrganizationRepositoryImpl {
         organization.ok_or(DbErr::RecordNotFound("Organization not found".to_string()))
     }
 
+    async fn get_organization_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<organization::Model, DbErr> {
+        Organization::find()
+            .filter(Column::Uuid.eq(uuid))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Organization not found".to_string()))
+    }
+
     async fn get_organization_by_user_id(
         &self,
         db: &DatabaseConnection,
         user_id: i32,
     ) -> Result<Vec<organization::Model>, DbErr> {
-        let condition = Condition::all().add(Expr::col(Column::UserId).eq(user_id));
+        // Organizations shared with the user through a membership count as theirs too
+        let memberships = Query::select()
+            .column(organization_member::Column::OrganizationId)
+            .from(organization_member::Entity)
+            .and_where(Expr::col(organization_member::Column::UserId).eq(user_id))
+            .to_owned();
+        let condition = Condition::any()
+            .add(Expr::col(Column::UserId).eq(user_id))
+            .add(Column::Id.in_subquery(memberships));
 
         let organizations = Organization::find().filter(condition).all(db).await?;
 
@@ -128,11 +179,8 @@ impl OrganizationRepository for OrganizationRepositoryImpl {
     async fn list_organizations(
         &self,
         db: &DatabaseConnection,
-    ) -> Result<Vec<organization::Model>, DbErr> {
-        let organizations: Vec<organization::Model> = Organization::find()
-            .all(db)
-            .await
-            .map_err(|e| DbErr::Custom(e.to_string()))?;
-        Ok(organizations)
+        params: &ListParams,
+    ) -> Result<Page<organization::Model>, DbErr> {
+        fetch_page(db, Organization::find(), params, &list_columns()).await
     }
 }
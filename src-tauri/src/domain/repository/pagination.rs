@@ -0,0 +1,94 @@
+use sea_orm::{
+    sea_query::{LikeExpr, Order},
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, IdenStatic, PaginatorTrait, QueryFilter,
+    QueryOrder, Select,
+};
+
+use crate::domain::types::pagination::{ListParams, Page, SortDirection};
+
+/// Columns a list procedure can filter and sort on
+pub struct ListColumns<C: ColumnTrait> {
+    /// Primary key, the last sort key so rows with equal sort values keep a stable order
+    pub id: C,
+    pub name: C,
+    pub created_at: C,
+    pub sortable: Vec<(&'static str, C)>,
+    pub default_sort: C,
+}
+
+impl<C: ColumnTrait> ListColumns<C> {
+    /// Column and direction to order by, unknown fields are rejected rather than ignored
+    pub fn resolve_sort(&self, params: &ListParams) -> Result<(C, SortDirection), DbErr> {
+        match &params.sort {
+            Some(sort) => {
+                let column = self
+                    .sortable
+                    .iter()
+                    .find(|(field, _)| *field == sort.field)
+                    .map(|(_, column)| *column)
+                    .ok_or_else(|| DbErr::Custom(format!("Cannot sort by '{}'", sort.field)))?;
+                Ok((column, sort.direction))
+            }
+            None => Ok((self.default_sort, SortDirection::Asc)),
+        }
+    }
+}
+
+/// Applies the shared filters and sort of `ListParams`, then fetches one page with a `Paginator`
+pub async fn fetch_page<E>(
+    db: &DatabaseConnection,
+    mut select: Select<E>,
+    params: &ListParams,
+    columns: &ListColumns<E::Column>,
+) -> Result<Page<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+{
+    if let Some(filter) = &params.filter {
+        if let Some(search) = filter.search.as_deref().filter(|s| !s.trim().is_empty()) {
+            select = select.filter(columns.name.like(contains_pattern(search.trim())));
+        }
+        if let Some(created_after) = filter.created_after {
+            select = select.filter(columns.created_at.gte(created_after));
+        }
+        if let Some(created_before) = filter.created_before {
+            select = select.filter(columns.created_at.lt(created_before));
+        }
+    }
+
+    let (sort_column, direction) = columns.resolve_sort(params)?;
+    let order = match direction {
+        SortDirection::Asc => Order::Asc,
+        SortDirection::Desc => Order::Desc,
+    };
+    select = select.order_by(sort_column, order.clone());
+    if sort_column.as_str() != columns.id.as_str() {
+        select = select.order_by(columns.id, order);
+    }
+
+    let per_page = params.per_page();
+    let page = params.page_index();
+
+    let paginator = select.paginate(db, per_page);
+    let total = paginator.num_items().await?;
+    let items = paginator.fetch_page(page).await?;
+
+    Ok(Page::new(items, total, page, per_page))
+}
+
+/// Escape character of the `LIKE` patterns built by the repositories
+pub(crate) const LIKE_ESCAPE: char = '\\';
+
+/// Escapes the `LIKE` wildcards in user input so it only matches itself
+pub(crate) fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// `LIKE '%value%'` that matches `value` literally
+pub(crate) fn contains_pattern(value: &str) -> LikeExpr {
+    LikeExpr::new(format!("%{}%", escape_like(value))).escape(LIKE_ESCAPE)
+}
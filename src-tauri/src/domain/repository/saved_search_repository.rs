@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use entity::saved_search::{
+    self, ActiveModel as SavedSearchActiveModel, Entity as SavedSearch, Model as SavedSearchModel,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::domain::{
+    repository::pagination::{fetch_page, ListColumns},
+    types::pagination::{ListParams, Page},
+};
+
+#[async_trait]
+pub trait SavedSearchRepository: Send + Sync {
+    async fn create_saved_search(
+        &self,
+        db: &DatabaseConnection,
+        input: SavedSearchActiveModel,
+    ) -> Result<SavedSearchModel, DbErr>;
+    async fn get_saved_search_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<SavedSearchModel, DbErr>;
+    async fn get_saved_search_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<SavedSearchModel, DbErr>;
+    async fn update_saved_search(
+        &self,
+        db: &DatabaseConnection,
+        input: SavedSearchActiveModel,
+    ) -> Result<SavedSearchModel, DbErr>;
+    async fn delete_saved_search(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
+    async fn list_by_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Vec<SavedSearchModel>, DbErr>;
+    async fn find_page_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<SavedSearchModel>, DbErr>;
+    async fn update_cached_count(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        count: i32,
+    ) -> Result<SavedSearchModel, DbErr>;
+}
+
+/// Columns the saved searches list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<saved_search::Column> {
+    ListColumns {
+        id: saved_search::Column::Id,
+        name: saved_search::Column::Name,
+        created_at: saved_search::Column::CreatedAt,
+        sortable: vec![
+            ("id", saved_search::Column::Id),
+            ("name", saved_search::Column::Name),
+            ("cached_count", saved_search::Column::CachedCount),
+            ("created_at", saved_search::Column::CreatedAt),
+            ("updated_at", saved_search::Column::UpdatedAt),
+        ],
+        default_sort: saved_search::Column::Name,
+    }
+}
+
+pub struct SavedSearchRepositoryImpl {}
+
+impl SavedSearchRepositoryImpl {
+    pub fn new() -> Self {
+        SavedSearchRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl SavedSearchRepository for SavedSearchRepositoryImpl {
+    async fn create_saved_search(
+        &self,
+        db: &DatabaseConnection,
+        input: SavedSearchActiveModel,
+    ) -> Result<SavedSearchModel, DbErr> {
+        SavedSearch::insert(input).exec_with_returning(db).await
+    }
+
+    async fn get_saved_search_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<SavedSearchModel, DbErr> {
+        SavedSearch::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Saved search not found".to_string()))
+    }
+
+    async fn get_saved_search_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<SavedSearchModel, DbErr> {
+        SavedSearch::find()
+            .filter(saved_search::Column::Uuid.eq(uuid))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Saved search not found".to_string()))
+    }
+
+    async fn update_saved_search(
+        &self,
+        db: &DatabaseConnection,
+        input: SavedSearchActiveModel,
+    ) -> Result<SavedSearchModel, DbErr> {
+        SavedSearch::update(input).exec(db).await
+    }
+
+    async fn delete_saved_search(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        SavedSearch::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    async fn list_by_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Vec<SavedSearchModel>, DbErr> {
+        // Pinned searches come first so the sidebar can render them as-is
+        SavedSearch::find()
+            .filter(saved_search::Column::WorkspaceId.eq(workspace_id))
+            .order_by_desc(saved_search::Column::IsPinned)
+            .order_by_asc(saved_search::Column::Name)
+            .all(db)
+            .await
+    }
+
+    async fn find_page_in_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<SavedSearchModel>, DbErr> {
+        // Pinned searches stay on top, the requested sort applies within each half
+        let select = SavedSearch::find()
+            .filter(saved_search::Column::WorkspaceId.eq(workspace_id))
+            .order_by_desc(saved_search::Column::IsPinned);
+
+        fetch_page(db, select, params, &list_columns()).await
+    }
+
+    async fn update_cached_count(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+        count: i32,
+    ) -> Result<SavedSearchModel, DbErr> {
+        let existing = self.get_saved_search_by_id(db, id).await?;
+
+        let updated = SavedSearchActiveModel {
+            cached_count: Set(count),
+            counted_at: Set(Some(chrono::Utc::now())),
+            ..existing.into()
+        };
+
+        SavedSearch::update(updated).exec(db).await
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use entity::app_setting::{
+    self, ActiveModel as AppSettingActiveModel, Entity as AppSetting, Model as AppSettingModel,
+};
+use sea_orm::{sea_query::OnConflict, ActiveValue::Set, DatabaseConnection, DbErr, EntityTrait};
+
+#[async_trait]
+pub trait SettingsRepository: Send + Sync {
+    async fn get_setting(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+    ) -> Result<Option<AppSettingModel>, DbErr>;
+    async fn set_setting(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+        value: String,
+    ) -> Result<AppSettingModel, DbErr>;
+}
+
+pub struct SettingsRepositoryImpl {}
+
+impl SettingsRepositoryImpl {
+    pub fn new() -> Self {
+        SettingsRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for SettingsRepositoryImpl {
+    async fn get_setting(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+    ) -> Result<Option<AppSettingModel>, DbErr> {
+        AppSetting::find_by_id(key.to_string()).one(db).await
+    }
+
+    async fn set_setting(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+        value: String,
+    ) -> Result<AppSettingModel, DbErr> {
+        let setting = AppSettingActiveModel {
+            key: Set(key.to_string()),
+            value: Set(value),
+            updated_at: Set(chrono::Utc::now()),
+        };
+
+        // Settings are keyed, writing an existing key replaces its value
+        AppSetting::insert(setting)
+            .on_conflict(
+                OnConflict::column(app_setting::Column::Key)
+                    .update_columns([app_setting::Column::Value, app_setting::Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec_with_returning(db)
+            .await
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use entity::bookmark_snapshot::{
+    self, ActiveModel as SnapshotActiveModel, Entity as BookmarkSnapshot, Model as SnapshotModel,
+};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+#[async_trait]
+pub trait SnapshotRepository: Send + Sync {
+    async fn create_snapshot(
+        &self,
+        db: &DatabaseConnection,
+        input: SnapshotActiveModel,
+    ) -> Result<SnapshotModel, DbErr>;
+    async fn get_snapshot_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<SnapshotModel, DbErr>;
+    async fn list_by_bookmark(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+    ) -> Result<Vec<SnapshotModel>, DbErr>;
+    async fn latest_version(&self, db: &DatabaseConnection, bookmark_id: i32)
+        -> Result<i32, DbErr>;
+    async fn delete_snapshot(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
+}
+
+pub struct SnapshotRepositoryImpl {}
+
+impl SnapshotRepositoryImpl {
+    pub fn new() -> Self {
+        SnapshotRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl SnapshotRepository for SnapshotRepositoryImpl {
+    async fn create_snapshot(
+        &self,
+        db: &DatabaseConnection,
+        input: SnapshotActiveModel,
+    ) -> Result<SnapshotModel, DbErr> {
+        BookmarkSnapshot::insert(input)
+            .exec_with_returning(db)
+            .await
+    }
+
+    async fn get_snapshot_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<SnapshotModel, DbErr> {
+        BookmarkSnapshot::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Snapshot not found".to_string()))
+    }
+
+    async fn list_by_bookmark(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+    ) -> Result<Vec<SnapshotModel>, DbErr> {
+        BookmarkSnapshot::find()
+            .filter(bookmark_snapshot::Column::BookmarkId.eq(bookmark_id))
+            .order_by_desc(bookmark_snapshot::Column::Version)
+            .all(db)
+            .await
+    }
+
+    async fn latest_version(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+    ) -> Result<i32, DbErr> {
+        let latest: Option<Option<i32>> = BookmarkSnapshot::find()
+            .select_only()
+            .column_as(bookmark_snapshot::Column::Version.max(), "version")
+            .filter(bookmark_snapshot::Column::BookmarkId.eq(bookmark_id))
+            .into_tuple()
+            .one(db)
+            .await?;
+
+        Ok(latest.flatten().unwrap_or(0))
+    }
+
+    async fn delete_snapshot(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        BookmarkSnapshot::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use entity::{
+    bookmark::{self, Entity as Bookmark, Model as BookmarkModel},
+    groups,
+    workspace::{self, Entity as Workspace},
+};
+use sea_orm::{
+    prelude::Expr,
+    sea_query::{Func, SimpleExpr},
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, FromQueryResult,
+    JoinType, QueryFilter, QueryOrder, QuerySelect, QueryTrait, RelationTrait, Select, Statement,
+};
+
+use crate::domain::dto::stats::stats_dto::StatsScope;
+
+/// Counts of one workspace, every bookmark of its groups included
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct WorkspaceCounts {
+    pub workspace_id: i32,
+    pub name: String,
+    pub groups: i64,
+    pub bookmarks: i64,
+    pub favorites: i64,
+    pub broken_links: i64,
+    pub untagged: i64,
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct HostCount {
+    pub host: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct WeekCount {
+    pub week_start: NaiveDate,
+    pub count: i64,
+}
+
+/// Aggregates for dashboards, everything is counted by the database
+#[async_trait]
+pub trait StatsRepository: Send + Sync {
+    /// One row per workspace in scope, ordered by name
+    async fn count_by_workspace(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+    ) -> Result<Vec<WorkspaceCounts>, DbErr>;
+    /// Tags are split on commas, most used first
+    async fn top_tags(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<TagCount>, DbErr>;
+    async fn top_hosts(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<HostCount>, DbErr>;
+    /// Weeks start on Monday, weeks without bookmarks have no row
+    async fn added_per_week(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<WeekCount>, DbErr>;
+    async fn most_visited(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<BookmarkModel>, DbErr>;
+}
+
+/// Splits the comma separated tags of every bookmark in `scoped` into one row per tag
+const TOP_TAGS_SQL: &str = r#"
+WITH RECURSIVE scoped(tags) AS ({scoped}),
+split(tag, rest) AS (
+    SELECT '', tags || ',' FROM scoped
+    UNION ALL
+    SELECT trim(substr(rest, 1, instr(rest, ',') - 1)), substr(rest, instr(rest, ',') + 1)
+    FROM split WHERE rest <> ''
+)
+SELECT tag, COUNT(*) AS count FROM split
+WHERE tag <> ''
+GROUP BY tag
+ORDER BY count DESC, tag ASC
+LIMIT ?
+"#;
+
+/// Host is what sits between `://` and the first `/` of the URL
+const TOP_HOSTS_SQL: &str = r#"
+WITH scoped(url) AS ({scoped}),
+after_scheme(rest) AS (
+    SELECT substr(url, instr(url, '://') + 3) FROM scoped WHERE instr(url, '://') > 0
+)
+SELECT lower(substr(rest, 1, instr(rest || '/', '/') - 1)) AS host, COUNT(*) AS count
+FROM after_scheme
+GROUP BY host
+HAVING host <> ''
+ORDER BY count DESC, host ASC
+LIMIT ?
+"#;
+
+/// Monday of the week a bookmark was created in
+const WEEK_START_SQL: &str =
+    r#"date(substr("bookmark"."created_at", 1, 10), 'weekday 0', '-6 days')"#;
+
+fn scoped_workspaces(scope: &StatsScope) -> Select<Workspace> {
+    match scope {
+        StatsScope::Organization { organization_id } => {
+            Workspace::find().filter(workspace::Column::OrganizationId.eq(*organization_id))
+        }
+        StatsScope::Workspace { workspace_id } => {
+            Workspace::find().filter(workspace::Column::Id.eq(*workspace_id))
+        }
+    }
+}
+
+fn scoped_bookmarks(scope: &StatsScope) -> Select<Bookmark> {
+    let select = Bookmark::find().inner_join(groups::Entity);
+    match scope {
+        StatsScope::Organization { organization_id } => select
+            .join(JoinType::InnerJoin, groups::Relation::Workspace.def())
+            .filter(workspace::Column::OrganizationId.eq(*organization_id)),
+        StatsScope::Workspace { workspace_id } => {
+            select.filter(groups::Column::WorkspaceId.eq(*workspace_id))
+        }
+    }
+}
+
+/// Number of rows `condition` holds for, rows without a bookmark count as zero
+fn count_where(condition: SimpleExpr) -> SimpleExpr {
+    Func::coalesce([Func::sum(condition).into(), Expr::val(0).into()]).into()
+}
+
+/// Runs `sql` with the single column `column` of the bookmarks in scope as its `{scoped}` table
+fn over_scoped_column(
+    db: &DatabaseConnection,
+    sql: &str,
+    scope: &StatsScope,
+    column: bookmark::Column,
+    limit: u64,
+) -> Statement {
+    let backend = db.get_database_backend();
+    let scoped = scoped_bookmarks(scope)
+        .select_only()
+        .column(column)
+        .build(backend);
+
+    let mut values = scoped.values.map(|values| values.0).unwrap_or_default();
+    values.push(limit.into());
+
+    Statement::from_sql_and_values(backend, sql.replace("{scoped}", &scoped.sql), values)
+}
+
+#[derive(Default)]
+pub struct StatsRepositoryImpl {}
+
+impl StatsRepositoryImpl {
+    pub fn new() -> Self {
+        StatsRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl StatsRepository for StatsRepositoryImpl {
+    async fn count_by_workspace(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+    ) -> Result<Vec<WorkspaceCounts>, DbErr> {
+        let bookmark_column = |column| Expr::col((bookmark::Entity, column));
+
+        scoped_workspaces(scope)
+            .select_only()
+            .column_as(workspace::Column::Id, "workspace_id")
+            .column(workspace::Column::Name)
+            .column_as(
+                Expr::col((groups::Entity, groups::Column::Id)).count_distinct(),
+                "groups",
+            )
+            .column_as(bookmark_column(bookmark::Column::Id).count(), "bookmarks")
+            .column_as(
+                count_where(bookmark_column(bookmark::Column::IsFavorite).eq(true)),
+                "favorites",
+            )
+            .column_as(
+                count_where(bookmark_column(bookmark::Column::BrokenAt).is_not_null()),
+                "broken_links",
+            )
+            .column_as(
+                count_where(bookmark_column(bookmark::Column::Tags).eq("")),
+                "untagged",
+            )
+            .join(JoinType::LeftJoin, workspace::Relation::Groups.def())
+            .join(JoinType::LeftJoin, groups::Relation::Bookmark.def())
+            .group_by(workspace::Column::Id)
+            .order_by_asc(workspace::Column::Name)
+            .into_model::<WorkspaceCounts>()
+            .all(db)
+            .await
+    }
+
+    async fn top_tags(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<TagCount>, DbErr> {
+        let statement = over_scoped_column(db, TOP_TAGS_SQL, scope, bookmark::Column::Tags, limit);
+
+        TagCount::find_by_statement(statement).all(db).await
+    }
+
+    async fn top_hosts(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<HostCount>, DbErr> {
+        let statement = over_scoped_column(db, TOP_HOSTS_SQL, scope, bookmark::Column::Url, limit);
+
+        HostCount::find_by_statement(statement).all(db).await
+    }
+
+    async fn added_per_week(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<WeekCount>, DbErr> {
+        scoped_bookmarks(scope)
+            .select_only()
+            .column_as(Expr::cust(WEEK_START_SQL), "week_start")
+            .column_as(bookmark::Column::Id.count(), "count")
+            .filter(bookmark::Column::CreatedAt.gte(since))
+            .group_by(Expr::cust(WEEK_START_SQL))
+            .order_by_asc(Expr::cust(WEEK_START_SQL))
+            .into_model::<WeekCount>()
+            .all(db)
+            .await
+    }
+
+    async fn most_visited(
+        &self,
+        db: &DatabaseConnection,
+        scope: &StatsScope,
+        limit: u64,
+    ) -> Result<Vec<BookmarkModel>, DbErr> {
+        scoped_bookmarks(scope)
+            .filter(bookmark::Column::OpenCount.gt(0))
+            .order_by_desc(bookmark::Column::OpenCount)
+            .order_by_desc(bookmark::Column::OpenedAt)
+            .limit(limit)
+            .all(db)
+            .await
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use entity::sync_change::{self, Entity as SyncChange};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+use crate::domain::sync::{
+    instance_id,
+    log::{find_identity, seen, skip_merged, ChangeLog},
+    merge::merge_bundle,
+    ChangeBundle, MergedRows, SyncReport,
+};
+
+/// The change log of a workspace, what a bundle is written from
+#[derive(Debug, Clone)]
+pub struct WorkspaceLog {
+    pub instance_id: String,
+    pub workspace_uuid: String,
+    pub changes: Vec<sync_change::Model>,
+    pub seen: BTreeMap<String, i32>,
+}
+
+/// Local edits are added to the change log before it is read or merged into, so both see them
+#[async_trait]
+pub trait SyncRepository: Send + Sync {
+    async fn instance_id(&self, db: &DatabaseConnection) -> Result<String, DbErr>;
+    async fn count_changes(&self, db: &DatabaseConnection) -> Result<u64, DbErr>;
+    /// `None` when the workspace doesn't exist
+    async fn workspace_log(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Option<WorkspaceLog>, DbErr>;
+    /// Merges the bundles of other instances, all of them or nothing is written
+    async fn merge_bundles(
+        &self,
+        db: &DatabaseConnection,
+        bundles: &[ChangeBundle],
+        organization_id: i32,
+    ) -> Result<(SyncReport, MergedRows), DbErr>;
+}
+
+#[derive(Default)]
+pub struct SyncRepositoryImpl {}
+
+impl SyncRepositoryImpl {
+    pub fn new() -> Self {
+        SyncRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl SyncRepository for SyncRepositoryImpl {
+    async fn instance_id(&self, db: &DatabaseConnection) -> Result<String, DbErr> {
+        instance_id(db).await
+    }
+
+    async fn count_changes(&self, db: &DatabaseConnection) -> Result<u64, DbErr> {
+        SyncChange::find().count(db).await
+    }
+
+    async fn workspace_log(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Option<WorkspaceLog>, DbErr> {
+        let txn = db.begin().await?;
+        let instance_id = instance_id(&txn).await?;
+        ChangeLog::open(&txn, &instance_id).await?.record().await?;
+
+        let Some(workspace) = find_identity(&txn, "workspace", workspace_id).await? else {
+            return Ok(None);
+        };
+        let changes = SyncChange::find()
+            .filter(sync_change::Column::WorkspaceUuid.eq(&workspace.uuid))
+            .order_by_asc(sync_change::Column::Id)
+            .all(&txn)
+            .await?;
+        let seen = seen(&txn).await?;
+        txn.commit().await?;
+
+        Ok(Some(WorkspaceLog {
+            instance_id,
+            workspace_uuid: workspace.uuid,
+            changes,
+            seen,
+        }))
+    }
+
+    async fn merge_bundles(
+        &self,
+        db: &DatabaseConnection,
+        bundles: &[ChangeBundle],
+        organization_id: i32,
+    ) -> Result<(SyncReport, MergedRows), DbErr> {
+        let txn = db.begin().await?;
+        let instance_id = instance_id(&txn).await?;
+        ChangeLog::open(&txn, &instance_id).await?.record().await?;
+
+        let mut report = SyncReport::default();
+        let mut merged = MergedRows::default();
+        for bundle in bundles
+            .iter()
+            .filter(|bundle| bundle.instance_id != instance_id)
+        {
+            merge_bundle(
+                &txn,
+                &instance_id,
+                bundle,
+                organization_id,
+                &mut report,
+                &mut merged,
+            )
+            .await?;
+        }
+
+        skip_merged(&txn).await?;
+        txn.commit().await?;
+
+        Ok((report, merged))
+    }
+}
@@ -1,7 +1,16 @@
 // Organization Repository Implementation
 use async_trait::async_trait;
-use entity::user::{ActiveModel as UserActiveModel, Entity as User, Model as UserModel};
-use sea_orm::{DatabaseConnection, DbErr, EntityTrait};
+use entity::user::{ActiveModel as UserActiveModel, Column, Entity as User, Model as UserModel};
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveValue::Set,
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+};
+
+use crate::domain::{
+    repository::pagination::{fetch_page, ListColumns},
+    types::pagination::{ListParams, Page},
+};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -11,6 +20,17 @@ pub trait UserRepository: Send + Sync {
         input: UserActiveModel,
     ) -> Result<UserModel, DbErr>;
     async fn get_user_by_id(&self, db: &DatabaseConnection, id: i32) -> Result<UserModel, DbErr>;
+    async fn get_user_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<UserModel, DbErr>;
+    /// Emails are compared case-insensitively
+    async fn find_user_by_email(
+        &self,
+        db: &DatabaseConnection,
+        email: &str,
+    ) -> Result<Option<UserModel>, DbErr>;
     async fn update_user(
         &self,
         db: &DatabaseConnection,
@@ -18,7 +38,27 @@ pub trait UserRepository: Send + Sync {
         input: UserActiveModel,
     ) -> Result<UserModel, DbErr>;
     async fn delete_user(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
-    async fn list_users(&self, db: &DatabaseConnection) -> Result<Vec<UserModel>, DbErr>;
+    async fn list_users(
+        &self,
+        db: &DatabaseConnection,
+        params: &ListParams,
+    ) -> Result<Page<UserModel>, DbErr>;
+}
+
+/// Columns the users list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<Column> {
+    ListColumns {
+        id: Column::Id,
+        name: Column::Name,
+        created_at: Column::CreatedAt,
+        sortable: vec![
+            ("id", Column::Id),
+            ("name", Column::Name),
+            ("email", Column::Email),
+            ("created_at", Column::CreatedAt),
+        ],
+        default_sort: Column::Id,
+    }
 }
 
 pub struct UserRepositoryImpl {}
@@ -55,6 +95,31 @@ impl UserRepository for UserRepositoryImpl {
         user.ok_or(DbErr::RecordNotFound("User not found".to_string()))
     }
 
+    async fn get_user_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<UserModel, DbErr> {
+        User::find()
+            .filter(Column::Uuid.eq(uuid))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("User not found".to_string()))
+    }
+
+    async fn find_user_by_email(
+        &self,
+        db: &DatabaseConnection,
+        email: &str,
+    ) -> Result<Option<UserModel>, DbErr> {
+        User::find()
+            .filter(
+                Expr::expr(Func::lower(Expr::col(Column::Email))).eq(email.trim().to_lowercase()),
+            )
+            .one(db)
+            .await
+    }
+
     async fn update_user(
         &self,
         db: &DatabaseConnection,
@@ -66,7 +131,16 @@ impl UserRepository for UserRepositoryImpl {
             .one(db)
             .await?
             .ok_or(DbErr::RecordNotFound("User not found".to_string()))?;
-        let user_active_model: UserActiveModel = existing_user.into();
+        let mut user_active_model: UserActiveModel = existing_user.into();
+        if let Set(name) = input.name {
+            user_active_model.name = Set(name);
+        }
+        if let Set(email) = input.email {
+            user_active_model.email = Set(email);
+        }
+        if let Set(updated_at) = input.updated_at {
+            user_active_model.updated_at = Set(updated_at);
+        }
         let updated_user = User::update(user_active_model)
             .exec(db)
             .await
@@ -86,11 +160,11 @@ impl UserRepository for UserRepositoryImpl {
         Ok(())
     }
 
-    async fn list_users(&self, db: &DatabaseConnection) -> Result<Vec<UserModel>, DbErr> {
-        let users: Vec<UserModel> = User::find()
-            .all(db)
-            .await
-            .map_err(|e| DbErr::Custom(e.to_string()))?;
-        Ok(users)
+    async fn list_users(
+        &self,
+        db: &DatabaseConnection,
+        params: &ListParams,
+    ) -> Result<Page<UserModel>, DbErr> {
+        fetch_page(db, User::find(), params, &list_columns()).await
     }
 }
@@ -2,7 +2,15 @@ use async_trait::async_trait;
 use entity::workspace::{
     ActiveModel as WorkspaceActiveModel, Column, Entity as Workspace, Model as WorkspaceModel,
 };
-use sea_orm::{prelude::Expr, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use sea_orm::{
+    prelude::Expr, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::domain::{
+    repository::pagination::{fetch_page, ListColumns},
+    types::pagination::{ListParams, Page},
+};
 
 #[async_trait]
 pub trait WorkspaceRepository: Send + Sync {
@@ -16,6 +24,11 @@ pub trait WorkspaceRepository: Send + Sync {
         db: &DatabaseConnection,
         id: i32,
     ) -> Result<WorkspaceModel, DbErr>;
+    async fn get_workspace_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<WorkspaceModel, DbErr>;
     async fn update_workspace(
         &self,
         db: &DatabaseConnection,
@@ -27,9 +40,31 @@ pub trait WorkspaceRepository: Send + Sync {
         &self,
         db: &DatabaseConnection,
         organization_id: i32,
+        params: &ListParams,
+    ) -> Result<Page<WorkspaceModel>, DbErr>;
+    async fn find_by_organization_id(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
     ) -> Result<Vec<WorkspaceModel>, DbErr>;
 }
 
+/// Columns the workspaces list can be filtered and sorted on
+pub fn list_columns() -> ListColumns<Column> {
+    ListColumns {
+        id: Column::Id,
+        name: Column::Name,
+        created_at: Column::CreatedAt,
+        sortable: vec![
+            ("id", Column::Id),
+            ("name", Column::Name),
+            ("created_at", Column::CreatedAt),
+            ("updated_at", Column::UpdatedAt),
+        ],
+        default_sort: Column::Id,
+    }
+}
+
 pub struct WorkspaceRepositoryImpl {}
 
 impl WorkspaceRepositoryImpl {
@@ -69,27 +104,37 @@ impl WorkspaceRepository for WorkspaceRepositoryImpl {
         result.ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))
     }
 
+    async fn get_workspace_by_uuid(
+        &self,
+        db: &DatabaseConnection,
+        uuid: &str,
+    ) -> Result<WorkspaceModel, DbErr> {
+        Workspace::find()
+            .filter(Column::Uuid.eq(uuid))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))
+    }
+
     async fn update_workspace(
         &self,
         db: &DatabaseConnection,
         id: i32,
         input: WorkspaceActiveModel,
     ) -> Result<WorkspaceModel, DbErr> {
-        let existing_workspace = Workspace::find_by_id(id).one(db).await?;
+        let existing_workspace = Workspace::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Workspace not found".to_string()))?;
 
-        let existing_workspace_active_model: WorkspaceActiveModel =
-            existing_workspace.unwrap().into();
+        let existing_workspace_active_model: WorkspaceActiveModel = existing_workspace.into();
 
         let updated_workspace = WorkspaceActiveModel {
             name: input.name,
             ..existing_workspace_active_model
         };
 
-        let updated_workspace: WorkspaceModel =
-            Workspace::update(updated_workspace).exec(db).await?;
-
-        println!("Bookmarkupdated: {:?}", updated_workspace);
-        Ok(updated_workspace)
+        Workspace::update(updated_workspace).exec(db).await
     }
 
     async fn delete_workspace(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
@@ -104,15 +149,28 @@ impl WorkspaceRepository for WorkspaceRepositoryImpl {
         &self,
         db: &DatabaseConnection,
         organization_id: i32,
-    ) -> Result<Vec<WorkspaceModel>, DbErr> {
+        params: &ListParams,
+    ) -> Result<Page<WorkspaceModel>, DbErr> {
         let condition = Condition::all().add(Expr::col(Column::OrganizationId).eq(organization_id));
 
-        let workspaces: Vec<WorkspaceModel> = Workspace::find()
-            .filter(condition)
+        fetch_page(
+            db,
+            Workspace::find().filter(condition),
+            params,
+            &list_columns(),
+        )
+        .await
+    }
+
+    async fn find_by_organization_id(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceModel>, DbErr> {
+        Workspace::find()
+            .filter(Column::OrganizationId.eq(organization_id))
+            .order_by_asc(Column::Id)
             .all(db)
             .await
-            .map_err(|e| DbErr::Custom(e.to_string()))?;
-
-        Ok(workspaces)
     }
 }
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use entity::{
+    bookmark::{self, ActiveModel as BookmarkActiveModel},
+    groups::{self, ActiveModel as GroupsActiveModel},
+    workspace::{self, ActiveModel as WorkspaceActiveModel},
+    workspace_template::{
+        self, ActiveModel as WorkspaceTemplateActiveModel, Entity as WorkspaceTemplate,
+        Model as WorkspaceTemplateModel,
+    },
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+/// A group to create with the bookmarks filed into it, `workspace_id` and `group_id` are
+/// filled in once the rows they point at exist
+pub struct GroupWithBookmarks {
+    pub group: GroupsActiveModel,
+    pub bookmarks: Vec<BookmarkActiveModel>,
+}
+
+#[async_trait]
+pub trait WorkspaceTemplateRepository: Send + Sync {
+    async fn create_template(
+        &self,
+        db: &DatabaseConnection,
+        input: WorkspaceTemplateActiveModel,
+    ) -> Result<WorkspaceTemplateModel, DbErr>;
+    async fn get_template_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<WorkspaceTemplateModel, DbErr>;
+    async fn delete_template(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr>;
+    async fn list_by_organization(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceTemplateModel>, DbErr>;
+    /// Creates a workspace with its groups and bookmarks, all of it or nothing
+    async fn create_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace: WorkspaceActiveModel,
+        groups: Vec<GroupWithBookmarks>,
+    ) -> Result<workspace::Model, DbErr>;
+}
+
+#[derive(Default)]
+pub struct WorkspaceTemplateRepositoryImpl {}
+
+impl WorkspaceTemplateRepositoryImpl {
+    pub fn new() -> Self {
+        WorkspaceTemplateRepositoryImpl {}
+    }
+}
+
+#[async_trait]
+impl WorkspaceTemplateRepository for WorkspaceTemplateRepositoryImpl {
+    async fn create_template(
+        &self,
+        db: &DatabaseConnection,
+        input: WorkspaceTemplateActiveModel,
+    ) -> Result<WorkspaceTemplateModel, DbErr> {
+        WorkspaceTemplate::insert(input)
+            .exec_with_returning(db)
+            .await
+    }
+
+    async fn get_template_by_id(
+        &self,
+        db: &DatabaseConnection,
+        id: i32,
+    ) -> Result<WorkspaceTemplateModel, DbErr> {
+        WorkspaceTemplate::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Template not found".to_string()))
+    }
+
+    async fn delete_template(&self, db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+        WorkspaceTemplate::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    async fn list_by_organization(
+        &self,
+        db: &DatabaseConnection,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceTemplateModel>, DbErr> {
+        WorkspaceTemplate::find()
+            .filter(workspace_template::Column::OrganizationId.eq(organization_id))
+            .order_by_asc(workspace_template::Column::Name)
+            .all(db)
+            .await
+    }
+
+    async fn create_workspace(
+        &self,
+        db: &DatabaseConnection,
+        workspace: WorkspaceActiveModel,
+        groups: Vec<GroupWithBookmarks>,
+    ) -> Result<workspace::Model, DbErr> {
+        let txn = db.begin().await?;
+
+        let workspace = workspace::Entity::insert(workspace)
+            .exec_with_returning(&txn)
+            .await?;
+        for GroupWithBookmarks {
+            mut group,
+            bookmarks,
+        } in groups
+        {
+            group.workspace_id = Set(workspace.id);
+            let group = groups::Entity::insert(group)
+                .exec_with_returning(&txn)
+                .await?;
+
+            for mut bookmark in bookmarks {
+                bookmark.group_id = Set(group.id);
+                bookmark::Entity::insert(bookmark).exec(&txn).await?;
+            }
+        }
+
+        txn.commit().await?;
+
+        Ok(workspace)
+    }
+}
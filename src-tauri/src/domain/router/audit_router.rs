@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::dto::audit::audit_dto::AuditQueryDto,
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_audit_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new().query("list", |t| {
+        t.resolver(|ctx: ContextRouter, input: AuditQueryDto| async move {
+            let service = Arc::clone(&ctx.services.audit);
+            service.list_events(ctx, input).await.map_err(service_error)
+        })
+    })
+}
@@ -4,90 +4,146 @@ use rspc::*;
 
 // Import DTOs with Specta support
 use crate::domain::{
-    dto::bookmark::bookmark_dto::{CreateBookmarkDto, UpdateBookmarkDto},
-    repository::bookmark_repository::*,
-    router::ContextRouter,
-    service::bookmark_service::{BookmarkService, BookmarkServiceImpl},
+    dto::{
+        bookmark::bookmark_dto::{CopyBookmarkDto, CreateBookmarkDto, UpdateBookmarkDto},
+        snapshot::snapshot_dto::CreateSnapshotDto,
+    },
+    router::{service_error, ContextRouter},
+    types::{identity::Lookup, pagination::ListParams},
 };
 
 /// Create bookmark router with type-safe procedures
 pub fn create_bookmark_router() -> RouterBuilder<ContextRouter> {
     Router::new()
         .query("list", |t| {
-            t.resolver(|ctx: ContextRouter, _input: ()| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: ListParams| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
-                    .list_bookmark(ctx)
+                    .list_bookmark(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .query("getById", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
-
-                service
-                    .get_bookmark_by_id(ctx, input)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
+                match input {
+                    Lookup::Id(id) => service.get_bookmark_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_bookmark_by_uuid(ctx, uuid).await,
+                }
+                .map_err(service_error)
             })
         })
         .query("search", |t| {
-            t.resolver(|ctx: ContextRouter, input: String| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: (String, ListParams)| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
-                    .search_bookmarks(ctx, &input)
+                    .search_bookmarks(ctx, &input.0, input.1)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .query("getByGroup", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: (i32, ListParams)| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
-                    .get_by_group(ctx, input)
+                    .get_by_group(ctx, input.0, input.1)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("create", |t| {
             t.resolver(|ctx: ContextRouter, input: CreateBookmarkDto| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
                     .create_bookmark(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("update", |t| {
             t.resolver(|ctx: ContextRouter, input: UpdateBookmarkDto| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
                     .update_bookmark(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("delete", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(BookmarkRepositoryImpl::new());
-                let service = BookmarkServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.bookmarks);
 
                 service
                     .delete_bookmark(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
+            })
+        })
+        .mutation("copyTo", |t| {
+            t.resolver(|ctx: ContextRouter, input: CopyBookmarkDto| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
+
+                service
+                    .copy_bookmark(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("markOpened", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.bookmarks);
+
+                service.mark_opened(ctx, input).await.map_err(service_error)
+            })
+        })
+        .mutation("snapshot", |t| {
+            t.resolver(|ctx: ContextRouter, input: CreateSnapshotDto| async move {
+                let service = Arc::clone(&ctx.services.snapshots);
+
+                service
+                    .snapshot_bookmark(ctx, input)
+                    .await
+                    .map_err(service_error)
             })
         })
+        .query("listSnapshots", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.snapshots);
+
+                service
+                    .list_snapshots(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("openSnapshot", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.snapshots);
+
+                service
+                    .open_snapshot(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("getContent", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.snapshots);
+
+                service
+                    .get_reader_content(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        // Streams bookmark changes of one workspace, the input is the workspace id
+        .subscription("onChanged", |t| {
+            t(|ctx: ContextRouter, input: i32| ctx.services.events.bookmarks(input))
+        })
 }
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use crate::router::ContextRouter;
+use rspc::{ErrorCode, Router, RouterBuilder};
+
+pub fn create_deep_link_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new().query("resolve", |t| {
+        t.resolver(|ctx: ContextRouter, input: String| async move {
+            let service = Arc::clone(&ctx.services.deep_links);
+            service
+                .resolve(ctx, input)
+                .await
+                .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+        })
+    })
+}
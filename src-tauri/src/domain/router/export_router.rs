@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{domain::export::ExportDto, router::ContextRouter};
+use rspc::{ErrorCode, Router, RouterBuilder};
+
+pub fn create_export_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("formats", |t| {
+            t.resolver(|ctx: ContextRouter, _: ()| async move {
+                let service = Arc::clone(&ctx.services.exports);
+                service
+                    .list_formats(ctx)
+                    .await
+                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            })
+        })
+        .query("run", |t| {
+            t.resolver(|ctx: ContextRouter, input: ExportDto| async move {
+                let service = Arc::clone(&ctx.services.exports);
+                service
+                    .export(ctx, input)
+                    .await
+                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            })
+        })
+}
@@ -2,74 +2,119 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        dto::groups::groups_dto::{CreateGroupsDto, UpdateGroupsDto},
-        repository::groups_repository::GroupRepositoryImpl,
-        service::group_service::{GroupService, GroupsServiceImpl},
+        dto::groups::groups_dto::{
+            CreateGroupsDto, CreateSmartGroupDto, DuplicateGroupDto, MoveGroupDto, UpdateGroupsDto,
+            UpdateSmartGroupFilterDto,
+        },
+        types::{identity::Lookup, pagination::ListParams},
     },
-    router::ContextRouter,
+    router::{service_error, ContextRouter},
 };
-use rspc::{ErrorCode, Router, RouterBuilder};
+use rspc::{Router, RouterBuilder};
 
 pub fn create_groups_router() -> RouterBuilder<ContextRouter> {
     Router::<ContextRouter>::new()
         .query("getGroups", |t| {
-            t.resolver(|ctx: ContextRouter, _input: ()| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
-                service
-                    .list_groups(ctx)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: ListParams| async move {
+                let service = Arc::clone(&ctx.services.groups);
+                service.list_groups(ctx, input).await.map_err(service_error)
             })
         })
         .query("getGroupById", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
-                service
-                    .get_group_by_id(ctx, input)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.groups);
+                match input {
+                    Lookup::Id(id) => service.get_group_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_group_by_uuid(ctx, uuid).await,
+                }
+                .map_err(service_error)
             })
         })
         .query("getBelongedGroups", |t| {
-            t.resolver(|ctx: ContextRouter, input: (i32, i32)| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: (i32, i32, ListParams)| async move {
+                let service = Arc::clone(&ctx.services.groups);
                 service
-                    .list_belonged_groups(ctx, input.0, input.1)
+                    .list_belonged_groups(ctx, input.0, input.1, input.2)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("createGroups", |t| {
             t.resolver(|ctx: ContextRouter, input: CreateGroupsDto| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.groups);
                 service
                     .create_group(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("updateGroup", |t| {
             t.resolver(|ctx: ContextRouter, input: UpdateGroupsDto| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.groups);
                 service
                     .update_group(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("deleteGroup", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(GroupRepositoryImpl::new());
-                let service = GroupsServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.groups);
                 service
                     .delete_group(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
+        // Moves a group with its bookmarks, also into another organization's workspace
+        .mutation("move", |t| {
+            t.resolver(|ctx: ContextRouter, input: MoveGroupDto| async move {
+                let service = Arc::clone(&ctx.services.groups);
+                service.move_group(ctx, input).await.map_err(service_error)
+            })
+        })
+        .mutation("duplicate", |t| {
+            t.resolver(|ctx: ContextRouter, input: DuplicateGroupDto| async move {
+                let service = Arc::clone(&ctx.services.groups);
+                service
+                    .duplicate_group(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("evaluateSmartGroup", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.groups);
+                service
+                    .evaluate_smart_group(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("createSmartGroup", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: CreateSmartGroupDto| async move {
+                    let service = Arc::clone(&ctx.services.groups);
+                    service
+                        .create_smart_group(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        .mutation("updateSmartGroupFilter", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: UpdateSmartGroupFilterDto| async move {
+                    let service = Arc::clone(&ctx.services.groups);
+                    service
+                        .update_smart_group_filter(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        // Streams group changes of one workspace, the input is the workspace id
+        .subscription("onChanged", |t| {
+            t(|ctx: ContextRouter, input: i32| ctx.services.events.groups(input))
+        })
 }
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::import::{csv_file::CsvImportDto, ImportDto},
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_import_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("preview", |t| {
+            t.resolver(|ctx: ContextRouter, input: ImportDto| async move {
+                let service = Arc::clone(&ctx.services.imports);
+                service
+                    .preview_import(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("commit", |t| {
+            t.resolver(|ctx: ContextRouter, input: ImportDto| async move {
+                let service = Arc::clone(&ctx.services.imports);
+                service
+                    .commit_import(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("csvColumns", |t| {
+            t.resolver(|ctx: ContextRouter, path: String| async move {
+                let service = Arc::clone(&ctx.services.imports);
+                service.csv_columns(ctx, path).await.map_err(service_error)
+            })
+        })
+        .query("previewCsv", |t| {
+            t.resolver(|ctx: ContextRouter, input: CsvImportDto| async move {
+                let service = Arc::clone(&ctx.services.imports);
+                service
+                    .preview_csv_import(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("commitCsv", |t| {
+            t.resolver(|ctx: ContextRouter, input: CsvImportDto| async move {
+                let service = Arc::clone(&ctx.services.imports);
+                service
+                    .commit_csv_import(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+}
@@ -1,34 +1,36 @@
+pub mod audit_router;
 pub mod bookmark_router;
+pub mod deep_link_router;
+pub mod export_router;
 pub mod groups_router;
+pub mod import_router;
 pub mod organization_router;
+pub mod read_later_router;
+pub mod saved_search_router;
+pub mod session_router;
+pub mod stats_router;
+pub mod sync_router;
 pub mod user;
 pub mod workspace_router;
 
-use crate::domain::service::{bookmark_service, group_service, workspace_service};
+// Procedures keep importing the context from here, it lives in the domain so it doesn't need rspc
+pub use crate::domain::context::ContextRouter;
 
-use std::{
-    env,
-    path::PathBuf,
-    sync::{Arc, Mutex},
-};
+use std::{env, path::PathBuf};
 
-use rspc::{Config, Router};
-use sea_orm::DatabaseConnection;
+use rspc::{Config, ErrorCode, Router};
 
-pub struct ContextRouter {
-    pub db: Arc<DatabaseConnection>,
-    pub session_id: Option<String>,
-    pub message: Arc<Mutex<String>>,
-}
+use crate::domain::permissions::is_forbidden;
+
+/// Service errors are failures, except permission errors which the frontend reports as forbidden
+pub fn service_error(message: String) -> rspc::Error {
+    let code = if is_forbidden(&message) {
+        ErrorCode::Forbidden
+    } else {
+        ErrorCode::InternalServerError
+    };
 
-impl ContextRouter {
-    pub fn new(db: Arc<DatabaseConnection>) -> Self {
-        Self {
-            db: db.clone(),
-            session_id: None,
-            message: Arc::new(Mutex::new("Hello World".to_string())),
-        }
-    }
+    rspc::Error::new(code, message)
 }
 
 pub fn create_router() -> Router<ContextRouter> {
@@ -37,6 +39,15 @@ pub fn create_router() -> Router<ContextRouter> {
     let workspace_router = workspace_router::create_workspace_router();
     let bookmark_router = bookmark_router::create_bookmark_router();
     let groups_router = groups_router::create_groups_router();
+    let read_later_router = read_later_router::create_read_later_router();
+    let saved_search_router = saved_search_router::create_saved_search_router();
+    let deep_link_router = deep_link_router::create_deep_link_router();
+    let import_router = import_router::create_import_router();
+    let export_router = export_router::create_export_router();
+    let session_router = session_router::create_session_router();
+    let audit_router = audit_router::create_audit_router();
+    let sync_router = sync_router::create_sync_router();
+    let stats_router = stats_router::create_stats_router();
 
     let binding_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../src/types/binding.ts");
     println!(
@@ -61,5 +72,14 @@ pub fn create_router() -> Router<ContextRouter> {
         .merge("workspace.", workspace_router)
         .merge("bookmark.", bookmark_router)
         .merge("groups.", groups_router)
+        .merge("readLater.", read_later_router)
+        .merge("savedSearch.", saved_search_router)
+        .merge("deepLink.", deep_link_router)
+        .merge("import.", import_router)
+        .merge("export.", export_router)
+        .merge("session.", session_router)
+        .merge("audit.", audit_router)
+        .merge("sync.", sync_router)
+        .merge("stats.", stats_router)
         .build()
 }
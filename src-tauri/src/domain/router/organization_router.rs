@@ -2,78 +2,112 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        dto::organization::organization_dto::{CreateOrganizationDto, UpdateOrganizationDto},
-        repository::organization_repository::OrganizationRepositoryImpl,
-        service::organization_service::{OrganizationService, OrganizationServiceImpl},
+        dto::organization::organization_dto::{
+            CreateOrganizationDto, InviteMemberDto, UpdateMemberRoleDto, UpdateOrganizationDto,
+        },
+        types::{identity::Lookup, pagination::ListParams},
     },
-    router::ContextRouter,
+    router::{service_error, ContextRouter},
 };
-use rspc::{ErrorCode, Router, RouterBuilder};
+use rspc::{Router, RouterBuilder};
 
 pub fn create_organization_router() -> RouterBuilder<ContextRouter> {
     Router::<ContextRouter>::new()
         .query("getOrganizations", |t| {
-            t.resolver(|ctx: ContextRouter, _input: ()| async move {
-                let repo = Arc::new(OrganizationRepositoryImpl::new());
-                let service = OrganizationServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: ListParams| async move {
+                let service = Arc::clone(&ctx.services.organizations);
                 service
-                    .list_organizations(ctx)
+                    .list_organizations(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .query("getOrganizationById", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(OrganizationRepositoryImpl::new());
-                let service = OrganizationServiceImpl::new(repo);
-                service
-                    .get_organization_by_id(ctx, input)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.organizations);
+                match input {
+                    Lookup::Id(id) => service.get_organization_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_organization_by_uuid(ctx, uuid).await,
+                }
+                .map_err(service_error)
             })
         })
         .query("getOrganizationByUserId", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(OrganizationRepositoryImpl::new());
-                let service = OrganizationServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.organizations);
                 service
                     .get_organization_by_user_id(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("createOrganization", |t| {
             t.resolver(
                 |ctx: ContextRouter, input: CreateOrganizationDto| async move {
-                    let repo = Arc::new(OrganizationRepositoryImpl::new());
-                    let service = OrganizationServiceImpl::new(repo);
+                    let service = Arc::clone(&ctx.services.organizations);
                     service
                         .create_organization(ctx, input)
                         .await
-                        .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                        .map_err(service_error)
                 },
             )
         })
         .mutation("updateOrganization", |t| {
             t.resolver(
                 |ctx: ContextRouter, input: UpdateOrganizationDto| async move {
-                    let repo = Arc::new(OrganizationRepositoryImpl::new());
-                    let service = OrganizationServiceImpl::new(repo);
+                    let service = Arc::clone(&ctx.services.organizations);
                     service
                         .update_organization(ctx, input)
                         .await
-                        .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                        .map_err(service_error)
                 },
             )
         })
         .mutation("deleteOrganization", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(OrganizationRepositoryImpl::new());
-                let service = OrganizationServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.organizations);
                 service
                     .delete_organization(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
+            })
+        })
+        .query("listMembers", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.organizations);
+                service
+                    .list_members(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("inviteMember", |t| {
+            t.resolver(|ctx: ContextRouter, input: InviteMemberDto| async move {
+                let service = Arc::clone(&ctx.services.organizations);
+                service
+                    .invite_member(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("updateMemberRole", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: UpdateMemberRoleDto| async move {
+                    let service = Arc::clone(&ctx.services.organizations);
+                    service
+                        .update_member_role(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        .mutation("removeMember", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.organizations);
+                service
+                    .remove_member(ctx, input)
+                    .await
+                    .map_err(service_error)
             })
         })
 }
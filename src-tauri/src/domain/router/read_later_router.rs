@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        dto::read_later::read_later_dto::{ReadLaterQueueDto, SetReadStateDto},
+        types::read_later::ReadState,
+    },
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_read_later_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("queue", |t| {
+            t.resolver(|ctx: ContextRouter, input: ReadLaterQueueDto| async move {
+                let service = Arc::clone(&ctx.services.read_later);
+                service.list_queue(ctx, input).await.map_err(service_error)
+            })
+        })
+        .query("countsByGroup", |t| {
+            t.resolver(|ctx: ContextRouter, _input: ()| async move {
+                let service = Arc::clone(&ctx.services.read_later);
+                service.count_by_group(ctx).await.map_err(service_error)
+            })
+        })
+        .mutation("setState", |t| {
+            t.resolver(|ctx: ContextRouter, input: SetReadStateDto| async move {
+                let service = Arc::clone(&ctx.services.read_later);
+                service
+                    .set_read_state(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("markRead", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.read_later);
+                service
+                    .set_read_state(
+                        ctx,
+                        SetReadStateDto {
+                            id: input,
+                            state: ReadState::Read,
+                        },
+                    )
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("archive", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.read_later);
+                service
+                    .set_read_state(
+                        ctx,
+                        SetReadStateDto {
+                            id: input,
+                            state: ReadState::Archived,
+                        },
+                    )
+                    .await
+                    .map_err(service_error)
+            })
+        })
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        dto::saved_search::saved_search_dto::{CreateSavedSearchDto, UpdateSavedSearchDto},
+        types::{identity::Lookup, pagination::ListParams},
+    },
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_saved_search_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("list", |t| {
+            t.resolver(|ctx: ContextRouter, input: (i32, ListParams)| async move {
+                let service = Arc::clone(&ctx.services.saved_searches);
+                service
+                    .list_saved_searches(ctx, input.0, input.1)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("getById", |t| {
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.saved_searches);
+                match input {
+                    Lookup::Id(id) => service.get_saved_search_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_saved_search_by_uuid(ctx, uuid).await,
+                }
+                .map_err(service_error)
+            })
+        })
+        .query("run", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.saved_searches);
+                service
+                    .run_saved_search(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("create", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: CreateSavedSearchDto| async move {
+                    let service = Arc::clone(&ctx.services.saved_searches);
+                    service
+                        .create_saved_search(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        .mutation("update", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: UpdateSavedSearchDto| async move {
+                    let service = Arc::clone(&ctx.services.saved_searches);
+                    service
+                        .update_saved_search(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        .mutation("delete", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.saved_searches);
+                service
+                    .delete_saved_search(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+}
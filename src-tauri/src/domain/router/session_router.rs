@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::router::{service_error, ContextRouter};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_session_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("current", |t| {
+            t.resolver(|ctx: ContextRouter, _: ()| async move {
+                let service = Arc::clone(&ctx.services.sessions);
+                service.current_user(ctx).await.map_err(service_error)
+            })
+        })
+        .mutation("signIn", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.sessions);
+                service.sign_in(ctx, input).await.map_err(service_error)
+            })
+        })
+        .mutation("signOut", |t| {
+            t.resolver(|ctx: ContextRouter, _: ()| async move {
+                let service = Arc::clone(&ctx.services.sessions);
+                service.sign_out(ctx).await.map_err(service_error)
+            })
+        })
+}
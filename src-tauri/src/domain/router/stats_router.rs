@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::dto::stats::stats_dto::{StatsQueryDto, WeeklyStatsQueryDto},
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_stats_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("organization", |t| {
+            t.resolver(|ctx: ContextRouter, organization_id: i32| async move {
+                let service = Arc::clone(&ctx.services.stats);
+                service
+                    .organization_stats(ctx, organization_id)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("workspace", |t| {
+            t.resolver(|ctx: ContextRouter, workspace_id: i32| async move {
+                let service = Arc::clone(&ctx.services.stats);
+                service
+                    .workspace_stats(ctx, workspace_id)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("topTags", |t| {
+            t.resolver(|ctx: ContextRouter, input: StatsQueryDto| async move {
+                let service = Arc::clone(&ctx.services.stats);
+                service.top_tags(ctx, input).await.map_err(service_error)
+            })
+        })
+        .query("topHosts", |t| {
+            t.resolver(|ctx: ContextRouter, input: StatsQueryDto| async move {
+                let service = Arc::clone(&ctx.services.stats);
+                service.top_hosts(ctx, input).await.map_err(service_error)
+            })
+        })
+        .query("addedPerWeek", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: WeeklyStatsQueryDto| async move {
+                    let service = Arc::clone(&ctx.services.stats);
+                    service
+                        .added_per_week(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        .query("mostVisited", |t| {
+            t.resolver(|ctx: ContextRouter, input: StatsQueryDto| async move {
+                let service = Arc::clone(&ctx.services.stats);
+                service
+                    .most_visited(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+}
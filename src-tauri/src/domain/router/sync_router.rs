@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::sync::{SyncExportDto, SyncImportDto},
+    router::{service_error, ContextRouter},
+};
+use rspc::{Router, RouterBuilder};
+
+pub fn create_sync_router() -> RouterBuilder<ContextRouter> {
+    Router::<ContextRouter>::new()
+        .query("status", |t| {
+            t.resolver(|ctx: ContextRouter, _: ()| async move {
+                let service = Arc::clone(&ctx.services.sync);
+                service.status(ctx).await.map_err(service_error)
+            })
+        })
+        .mutation("export", |t| {
+            t.resolver(|ctx: ContextRouter, input: SyncExportDto| async move {
+                let service = Arc::clone(&ctx.services.sync);
+                service.export(ctx, input).await.map_err(service_error)
+            })
+        })
+        .mutation("import", |t| {
+            t.resolver(|ctx: ContextRouter, input: SyncImportDto| async move {
+                let service = Arc::clone(&ctx.services.sync);
+                service.import(ctx, input).await.map_err(service_error)
+            })
+        })
+}
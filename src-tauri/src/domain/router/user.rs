@@ -3,8 +3,7 @@ use std::sync::Arc;
 use crate::{
     domain::{
         dto::user::user_dto::{CreateUserDto, UpdateUserDto},
-        repository::user_repository::UserRepositoryImpl,
-        service::user_service::{UserService, UserServiceImpl},
+        types::{identity::Lookup, pagination::ListParams},
     },
     router::ContextRouter,
 };
@@ -13,29 +12,27 @@ use rspc::{ErrorCode, Router, RouterBuilder};
 pub fn create_users_router() -> RouterBuilder<ContextRouter> {
     Router::<ContextRouter>::new()
         .query("getUsers", |t| {
-            t.resolver(|ctx: ContextRouter, _input: ()| async move {
-                let repo = Arc::new(UserRepositoryImpl::new());
-                let service = UserServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: ListParams| async move {
+                let service = Arc::clone(&ctx.services.users);
                 service
-                    .list_users(ctx)
+                    .list_users(ctx, input)
                     .await
                     .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
             })
         })
         .query("getUserById", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(UserRepositoryImpl::new());
-                let service = UserServiceImpl::new(repo);
-                service
-                    .get_user_by_id(ctx, input)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.users);
+                match input {
+                    Lookup::Id(id) => service.get_user_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_user_by_uuid(ctx, uuid).await,
+                }
+                .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
             })
         })
         .mutation("createUser", |t| {
             t.resolver(|ctx: ContextRouter, input: CreateUserDto| async move {
-                let repo = Arc::new(UserRepositoryImpl::new());
-                let service = UserServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.users);
                 service
                     .create_user(ctx, input)
                     .await
@@ -44,8 +41,7 @@ pub fn create_users_router() -> RouterBuilder<ContextRouter> {
         })
         .mutation("updateUser", |t| {
             t.resolver(|ctx: ContextRouter, input: UpdateUserDto| async move {
-                let repo = Arc::new(UserRepositoryImpl::new());
-                let service = UserServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.users);
                 service
                     .update_user(ctx, input)
                     .await
@@ -54,8 +50,7 @@ pub fn create_users_router() -> RouterBuilder<ContextRouter> {
         })
         .mutation("deleteUser", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(UserRepositoryImpl::new());
-                let service = UserServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.users);
                 service
                     .delete_user(ctx, input)
                     .await
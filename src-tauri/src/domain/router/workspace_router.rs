@@ -2,64 +2,118 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        dto::workspace::workspace_dto::{CreateWorkspaceDto, UpdateWorkspaceDto},
-        repository::workspace_repository::WorkspaceRepositoryImpl,
-        service::workspace_service::{WorkspaceService, WorkspaceServiceImpl},
+        dto::{
+            workspace::workspace_dto::{CreateWorkspaceDto, UpdateWorkspaceDto},
+            workspace_template::workspace_template_dto::{
+                CreateFromTemplateDto, SaveWorkspaceTemplateDto,
+            },
+        },
+        types::{identity::Lookup, pagination::ListParams},
     },
-    router::ContextRouter,
+    router::{service_error, ContextRouter},
 };
-use rspc::{ErrorCode, Router, RouterBuilder};
+use rspc::{Router, RouterBuilder};
 
 pub fn create_workspace_router() -> RouterBuilder<ContextRouter> {
     Router::<ContextRouter>::new()
         .query("getWorkspaces", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(WorkspaceRepositoryImpl::new());
-                let service = WorkspaceServiceImpl::new(repo);
+            t.resolver(|ctx: ContextRouter, input: (i32, ListParams)| async move {
+                let service = Arc::clone(&ctx.services.workspaces);
                 service
-                    .list_workspace(ctx, input)
+                    .list_workspace(ctx, input.0, input.1)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .query("getWorkspaceById", |t| {
-            t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(WorkspaceRepositoryImpl::new());
-                let service = WorkspaceServiceImpl::new(repo);
-                service
-                    .get_workspace_by_id(ctx, input)
-                    .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+            t.resolver(|ctx: ContextRouter, input: Lookup| async move {
+                let service = Arc::clone(&ctx.services.workspaces);
+                match input {
+                    Lookup::Id(id) => service.get_workspace_by_id(ctx, id).await,
+                    Lookup::Uuid(uuid) => service.get_workspace_by_uuid(ctx, uuid).await,
+                }
+                .map_err(service_error)
             })
         })
         .mutation("createWorkspace", |t| {
             t.resolver(|ctx: ContextRouter, input: CreateWorkspaceDto| async move {
-                let repo = Arc::new(WorkspaceRepositoryImpl::new());
-                let service = WorkspaceServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.workspaces);
                 service
                     .create_workspace(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("updateWorkspace", |t| {
             t.resolver(|ctx: ContextRouter, input: UpdateWorkspaceDto| async move {
-                let repo = Arc::new(WorkspaceRepositoryImpl::new());
-                let service = WorkspaceServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.workspaces);
                 service
                     .update_workspace(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
             })
         })
         .mutation("deleteWorkspace", |t| {
             t.resolver(|ctx: ContextRouter, input: i32| async move {
-                let repo = Arc::new(WorkspaceRepositoryImpl::new());
-                let service = WorkspaceServiceImpl::new(repo);
+                let service = Arc::clone(&ctx.services.workspaces);
                 service
                     .delete_workspace(ctx, input)
                     .await
-                    .map_err(|e| rspc::Error::new(ErrorCode::InternalServerError, e))
+                    .map_err(service_error)
+            })
+        })
+        .mutation("saveAsTemplate", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: SaveWorkspaceTemplateDto| async move {
+                    let service = Arc::clone(&ctx.services.templates);
+                    service
+                        .save_template(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        // Templates of one organization, the input is the organization id
+        .query("listTemplates", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.templates);
+                service
+                    .list_templates(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .query("exportTemplate", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.templates);
+                service
+                    .export_template(ctx, input)
+                    .await
+                    .map_err(service_error)
             })
         })
+        .mutation("deleteTemplate", |t| {
+            t.resolver(|ctx: ContextRouter, input: i32| async move {
+                let service = Arc::clone(&ctx.services.templates);
+                service
+                    .delete_template(ctx, input)
+                    .await
+                    .map_err(service_error)
+            })
+        })
+        .mutation("createFromTemplate", |t| {
+            t.resolver(
+                |ctx: ContextRouter, input: CreateFromTemplateDto| async move {
+                    let service = Arc::clone(&ctx.services.templates);
+                    service
+                        .create_workspace(ctx, input)
+                        .await
+                        .map_err(service_error)
+                },
+            )
+        })
+        // New workspaces have no id to subscribe to yet, so these stream per organization id
+        .subscription("onChanged", |t| {
+            t(|ctx: ContextRouter, input: i32| ctx.services.events.workspaces(input))
+        })
 }
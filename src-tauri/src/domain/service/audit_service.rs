@@ -0,0 +1,102 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::audit::audit_dto::{AuditEventDto, AuditQueryDto},
+    permissions::Permissions,
+    repository::{audit_repository::AuditRepository, user_repository::UserRepository},
+    types::{
+        membership::Access,
+        pagination::{Page, SortDirection, SortInput},
+    },
+};
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditService: Send + Sync {
+    async fn list_events(
+        &self,
+        ctx: ContextRouter,
+        dto: AuditQueryDto,
+    ) -> Result<Page<AuditEventDto>, String>;
+}
+
+pub struct AuditServiceImpl {
+    pub audit_repository: Arc<dyn AuditRepository>,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub permissions: Permissions,
+}
+
+impl AuditServiceImpl {
+    pub fn new(
+        audit_repository: Arc<dyn AuditRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        permissions: Permissions,
+    ) -> Self {
+        AuditServiceImpl {
+            audit_repository,
+            user_repository,
+            permissions,
+        }
+    }
+}
+
+#[async_trait]
+impl AuditService for AuditServiceImpl {
+    async fn list_events(
+        &self,
+        ctx: ContextRouter,
+        dto: AuditQueryDto,
+    ) -> Result<Page<AuditEventDto>, String> {
+        self.permissions
+            .require_organization(&ctx, dto.organization_id, Access::Read)
+            .await?;
+
+        let mut params = dto.params;
+        if params.sort.is_none() {
+            params.sort = Some(SortInput {
+                field: "id".to_string(),
+                direction: SortDirection::Desc,
+            });
+        }
+
+        let events = self
+            .audit_repository
+            .list_events(
+                &ctx.db,
+                dto.organization_id,
+                dto.entity_type.as_deref(),
+                dto.entity_id,
+                dto.user_id,
+                &params,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Deleted users keep their events, they are just shown without a name
+        let mut names: HashMap<i32, Option<String>> = HashMap::new();
+        for user_id in events.items.iter().filter_map(|event| event.actor_user_id) {
+            if let Entry::Vacant(entry) = names.entry(user_id) {
+                let name = self
+                    .user_repository
+                    .get_user_by_id(&ctx.db, user_id)
+                    .await
+                    .ok()
+                    .map(|user| user.name);
+                entry.insert(name);
+            }
+        }
+
+        Ok(events.map(|event| {
+            let mut dto: AuditEventDto = event.into();
+            dto.actor_name = dto
+                .actor_user_id
+                .and_then(|user_id| names.get(&user_id).cloned().flatten());
+            dto
+        }))
+    }
+}
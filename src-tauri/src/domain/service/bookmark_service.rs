@@ -1,22 +1,154 @@
 use std::sync::Arc;
 
 use crate::domain::{
-    dto::bookmark::bookmark_dto::{BookmarkDto, CreateBookmarkDto, UpdateBookmarkDto},
-    repository::bookmark_repository::BookmarkRepository,
-    router::ContextRouter,
+    context::ContextRouter,
+    dto::bookmark::bookmark_dto::{
+        BookmarkDto, CopyBookmarkDto, CreateBookmarkDto, UpdateBookmarkDto,
+    },
+    events::{BookmarkChangedEvent, EventBus},
+    extractor::reading_time_minutes,
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        saved_search_repository::SavedSearchRepository,
+    },
+    service::saved_search_service::refresh_saved_search_counts,
+    types::{
+        groups::{GroupKind, SmartGroupFilter},
+        membership::Access,
+        pagination::{ListParams, Page},
+    },
 };
 
 use async_trait::async_trait;
+use entity::{bookmark::Model as BookmarkModel, groups::Model as GroupModel};
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Convert bookmark models to DTOs, filling in reading time from extracted page text
+pub async fn to_bookmark_dtos(
+    bookmark_repository: &dyn BookmarkRepository,
+    db: &DatabaseConnection,
+    bookmarks: Vec<BookmarkModel>,
+) -> Result<Vec<BookmarkDto>, String> {
+    let ids = bookmarks.iter().map(|bookmark| bookmark.id).collect();
+    let word_counts = bookmark_repository
+        .find_word_counts(db, ids)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(bookmarks
+        .into_iter()
+        .map(|bookmark| {
+            let word_count = word_counts.get(&bookmark.id).copied();
+            let mut dto: BookmarkDto = bookmark.into();
+            dto.reading_time_minutes = word_count.map(reading_time_minutes);
+            dto
+        })
+        .collect())
+}
+
+/// `to_bookmark_dtos` for one page of a list
+pub async fn to_bookmark_page(
+    bookmark_repository: &dyn BookmarkRepository,
+    db: &DatabaseConnection,
+    page: Page<BookmarkModel>,
+) -> Result<Page<BookmarkDto>, String> {
+    let items = to_bookmark_dtos(bookmark_repository, db, page.items).await?;
+
+    Ok(Page {
+        items,
+        total: page.total,
+        page: page.page,
+        per_page: page.per_page,
+        next_cursor: page.next_cursor,
+    })
+}
+
+/// Every bookmark shown in a group, smart groups are evaluated from their filter
+pub async fn group_bookmarks(
+    bookmark_repository: &dyn BookmarkRepository,
+    db: &DatabaseConnection,
+    group: &GroupModel,
+) -> Result<Vec<BookmarkModel>, String> {
+    match GroupKind::from_db(&group.kind) {
+        GroupKind::Smart => {
+            let filter = SmartGroupFilter::from_json(group.filter.as_deref().unwrap_or("{}"))?;
+            bookmark_repository
+                .find_matching(db, group.workspace_id, &filter)
+                .await
+        }
+        GroupKind::Static => bookmark_repository.get_by_group(db, group.id).await,
+    }
+    .map_err(|e| e.to_string())
+}
 
 pub struct BookmarkServiceImpl {
     pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub saved_search_repository: Arc<dyn SavedSearchRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
 }
 
 impl BookmarkServiceImpl {
-    pub fn new(bookmark_repository: Arc<dyn BookmarkRepository>) -> Self {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        saved_search_repository: Arc<dyn SavedSearchRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
         BookmarkServiceImpl {
             bookmark_repository,
+            groups_repository,
+            saved_search_repository,
+            permissions,
+            events,
+        }
+    }
+
+    /// Saved search badges are recounted for the workspace the bookmark lives in,
+    /// which is returned so the change can be published to it
+    async fn refresh_saved_searches(
+        &self,
+        db: &DatabaseConnection,
+        group_id: i32,
+    ) -> Result<i32, String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(db, group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        refresh_saved_search_counts(
+            self.saved_search_repository.as_ref(),
+            self.bookmark_repository.as_ref(),
+            db,
+            group.workspace_id,
+        )
+        .await?;
+
+        Ok(group.workspace_id)
+    }
+
+    /// Smart group membership is computed, so bookmarks cannot be filed into them
+    async fn ensure_static_group(
+        &self,
+        db: &DatabaseConnection,
+        group_id: i32,
+    ) -> Result<(), String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(db, group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if GroupKind::from_db(&group.kind) == GroupKind::Smart {
+            return Err("Bookmarks cannot be added to a smart group".to_string());
         }
+
+        Ok(())
     }
 }
 
@@ -28,23 +160,40 @@ pub trait BookmarkService: Send + Sync {
         dto: CreateBookmarkDto,
     ) -> Result<BookmarkDto, String>;
     async fn get_bookmark_by_id(&self, ctx: ContextRouter, id: i32) -> Result<BookmarkDto, String>;
+    async fn get_bookmark_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<BookmarkDto, String>;
     async fn search_bookmarks(
         &self,
         ctx: ContextRouter,
         query: &str,
-    ) -> Result<Vec<BookmarkDto>, String>;
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String>;
     async fn get_by_group(
         &self,
         ctx: ContextRouter,
         groupId: i32,
-    ) -> Result<Vec<BookmarkDto>, String>;
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String>;
     async fn update_bookmark(
         &self,
         ctx: ContextRouter,
         dto: UpdateBookmarkDto,
     ) -> Result<BookmarkDto, String>;
     async fn delete_bookmark(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
-    async fn list_bookmark(&self, ctx: ContextRouter) -> Result<Vec<BookmarkDto>, String>;
+    async fn copy_bookmark(
+        &self,
+        ctx: ContextRouter,
+        dto: CopyBookmarkDto,
+    ) -> Result<BookmarkDto, String>;
+    async fn list_bookmark(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String>;
+    async fn mark_opened(&self, ctx: ContextRouter, id: i32) -> Result<BookmarkDto, String>;
 }
 
 #[async_trait]
@@ -54,13 +203,28 @@ impl BookmarkService for BookmarkServiceImpl {
         ctx: ContextRouter,
         dto: CreateBookmarkDto,
     ) -> Result<BookmarkDto, String> {
+        self.permissions
+            .require_group(&ctx, dto.group_id, Access::Write)
+            .await?;
+        self.ensure_static_group(&ctx.db, dto.group_id).await?;
+
         let created_bookmark = self
             .bookmark_repository
             .create(&ctx.db, dto.into())
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(created_bookmark.into())
+        let workspace_id = self
+            .refresh_saved_searches(&ctx.db, created_bookmark.group_id)
+            .await?;
+
+        let created_bookmark: BookmarkDto = created_bookmark.into();
+        self.events.publish(BookmarkChangedEvent::created(
+            workspace_id,
+            created_bookmark.clone(),
+        ));
+
+        Ok(created_bookmark)
     }
 
     async fn get_bookmark_by_id(&self, ctx: ContextRouter, id: i32) -> Result<BookmarkDto, String> {
@@ -71,35 +235,75 @@ impl BookmarkService for BookmarkServiceImpl {
             .map_err(|e| e.to_string())?
             .ok_or_else(|| "Bookmark not found".to_string())?;
 
-        Ok(bookmark.into())
+        let mut dtos =
+            to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, vec![bookmark]).await?;
+        Ok(dtos.remove(0))
+    }
+
+    async fn get_bookmark_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<BookmarkDto, String> {
+        let bookmark = self
+            .bookmark_repository
+            .find_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Bookmark not found".to_string())?;
+
+        let mut dtos =
+            to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, vec![bookmark]).await?;
+        Ok(dtos.remove(0))
     }
 
     async fn search_bookmarks(
         &self,
         ctx: ContextRouter,
         query: &str,
-    ) -> Result<Vec<BookmarkDto>, String> {
-        let bookmarks = self
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String> {
+        let organization_ids = self.permissions.readable_organizations(&ctx).await?;
+        let page = self
             .bookmark_repository
-            .search(&ctx.db, query)
+            .search(&ctx.db, organization_ids.as_deref(), query, &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(bookmarks.into_iter().map(Into::into).collect())
+        to_bookmark_page(self.bookmark_repository.as_ref(), &ctx.db, page).await
     }
 
     async fn get_by_group(
         &self,
         ctx: ContextRouter,
         group_id: i32,
-    ) -> Result<Vec<BookmarkDto>, String> {
-        let bookmarks_by_group = self
-            .bookmark_repository
-            .get_by_group(&ctx.db, group_id)
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, group_id)
             .await
             .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_workspace(&ctx, group.workspace_id, Access::Read)
+            .await?;
+
+        let page = match GroupKind::from_db(&group.kind) {
+            GroupKind::Smart => {
+                let filter = SmartGroupFilter::from_json(group.filter.as_deref().unwrap_or("{}"))?;
+                self.bookmark_repository
+                    .find_matching_page(&ctx.db, group.workspace_id, &filter, &params)
+                    .await
+            }
+            GroupKind::Static => {
+                self.bookmark_repository
+                    .find_page_in_group(&ctx.db, group_id, &params)
+                    .await
+            }
+        }
+        .map_err(|e| e.to_string())?;
 
-        Ok(bookmarks_by_group.into_iter().map(Into::into).collect())
+        to_bookmark_page(self.bookmark_repository.as_ref(), &ctx.db, page).await
     }
 
     async fn update_bookmark(
@@ -113,39 +317,160 @@ impl BookmarkService for BookmarkServiceImpl {
         }
 
         let id = dto.id.unwrap();
+        self.permissions
+            .require_bookmark(&ctx, id, Access::Write)
+            .await?;
 
-        let _found_bookmark = self
+        let found_bookmark = self
             .bookmark_repository
             .find_by_id(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?
             .ok_or_else(|| "Bookmark not found".to_string())?;
 
+        if let Some(group_id) = dto.group_id {
+            // Moving needs write access on both sides
+            self.permissions
+                .require_group(&ctx, group_id, Access::Write)
+                .await?;
+            self.ensure_static_group(&ctx.db, group_id).await?;
+        }
+
         let updated_bookmark = self
             .bookmark_repository
             .update(&ctx.db, id, dto.into())
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(updated_bookmark.into())
+        let previous_workspace_id = self
+            .refresh_saved_searches(&ctx.db, found_bookmark.group_id)
+            .await?;
+        let workspace_id = if updated_bookmark.group_id != found_bookmark.group_id {
+            self.refresh_saved_searches(&ctx.db, updated_bookmark.group_id)
+                .await?
+        } else {
+            previous_workspace_id
+        };
+
+        // Moving to another workspace looks like a delete to the one it left
+        if workspace_id != previous_workspace_id {
+            self.events
+                .publish(BookmarkChangedEvent::deleted(previous_workspace_id, id));
+        }
+
+        let updated_bookmark: BookmarkDto = updated_bookmark.into();
+        self.events.publish(BookmarkChangedEvent::updated(
+            workspace_id,
+            updated_bookmark.clone(),
+        ));
+
+        Ok(updated_bookmark)
     }
 
     async fn delete_bookmark(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
+        self.permissions
+            .require_bookmark(&ctx, id, Access::Write)
+            .await?;
+
+        let found_bookmark = self
+            .bookmark_repository
+            .find_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
         self.bookmark_repository
             .delete(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?;
 
+        if let Some(bookmark) = found_bookmark {
+            let workspace_id = self
+                .refresh_saved_searches(&ctx.db, bookmark.group_id)
+                .await?;
+            self.events
+                .publish(BookmarkChangedEvent::deleted(workspace_id, id));
+        }
+
         Ok(())
     }
 
-    async fn list_bookmark(&self, ctx: ContextRouter) -> Result<Vec<BookmarkDto>, String> {
-        let bookmarks = self
+    async fn copy_bookmark(
+        &self,
+        ctx: ContextRouter,
+        dto: CopyBookmarkDto,
+    ) -> Result<BookmarkDto, String> {
+        self.permissions
+            .require_bookmark(&ctx, dto.id, Access::Read)
+            .await?;
+        self.permissions
+            .require_group(&ctx, dto.group_id, Access::Write)
+            .await?;
+        self.ensure_static_group(&ctx.db, dto.group_id).await?;
+
+        let copied_bookmark = self
+            .bookmark_repository
+            .copy_to(&ctx.db, dto.id, dto.group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let workspace_id = self
+            .refresh_saved_searches(&ctx.db, copied_bookmark.group_id)
+            .await?;
+
+        let copied_bookmark = to_bookmark_dtos(
+            self.bookmark_repository.as_ref(),
+            &ctx.db,
+            vec![copied_bookmark],
+        )
+        .await?
+        .remove(0);
+        self.events.publish(BookmarkChangedEvent::created(
+            workspace_id,
+            copied_bookmark.clone(),
+        ));
+
+        Ok(copied_bookmark)
+    }
+
+    async fn list_bookmark(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<BookmarkDto>, String> {
+        let organization_ids = self.permissions.readable_organizations(&ctx).await?;
+        let page = self
             .bookmark_repository
-            .find_all(&ctx.db)
+            .find_page(&ctx.db, organization_ids.as_deref(), &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(bookmarks.into_iter().map(Into::into).collect())
+        to_bookmark_page(self.bookmark_repository.as_ref(), &ctx.db, page).await
+    }
+
+    /// Remembers when a bookmark was last opened so the tray can list recent ones
+    async fn mark_opened(&self, ctx: ContextRouter, id: i32) -> Result<BookmarkDto, String> {
+        self.permissions
+            .require_bookmark(&ctx, id, Access::Write)
+            .await?;
+
+        let opened_bookmark = self
+            .bookmark_repository
+            .mark_opened(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, opened_bookmark.group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let opened_bookmark: BookmarkDto = opened_bookmark.into();
+        self.events.publish(BookmarkChangedEvent::updated(
+            group.workspace_id,
+            opened_bookmark.clone(),
+        ));
+
+        Ok(opened_bookmark)
     }
 }
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    archiver::PageArchiver,
+    events::EventBus,
+    export::Exporters,
+    permissions::Permissions,
+    repository::{
+        audit_repository::{AuditRepository, AuditRepositoryImpl},
+        bookmark_repository::{BookmarkRepository, BookmarkRepositoryImpl},
+        content_repository::{ContentRepository, ContentRepositoryImpl},
+        groups_repository::{GroupRepository, GroupRepositoryImpl},
+        organization_member_repository::{
+            OrganizationMemberRepository, OrganizationMemberRepositoryImpl,
+        },
+        organization_repository::{OrganizationRepository, OrganizationRepositoryImpl},
+        saved_search_repository::{SavedSearchRepository, SavedSearchRepositoryImpl},
+        settings_repository::{SettingsRepository, SettingsRepositoryImpl},
+        snapshot_repository::{SnapshotRepository, SnapshotRepositoryImpl},
+        stats_repository::{StatsRepository, StatsRepositoryImpl},
+        sync_repository::{SyncRepository, SyncRepositoryImpl},
+        user_repository::{UserRepository, UserRepositoryImpl},
+        workspace_repository::{WorkspaceRepository, WorkspaceRepositoryImpl},
+        workspace_template_repository::{
+            WorkspaceTemplateRepository, WorkspaceTemplateRepositoryImpl,
+        },
+    },
+    service::{
+        audit_service::{AuditService, AuditServiceImpl},
+        bookmark_service::{BookmarkService, BookmarkServiceImpl},
+        deep_link_service::{DeepLinkService, DeepLinkServiceImpl},
+        export_service::{ExportService, ExportServiceImpl},
+        group_service::{GroupService, GroupsServiceImpl},
+        import_service::{ImportService, ImportServiceImpl},
+        organization_service::{OrganizationService, OrganizationServiceImpl},
+        read_later_service::{ReadLaterService, ReadLaterServiceImpl},
+        saved_search_service::{SavedSearchService, SavedSearchServiceImpl},
+        session_service::{SessionService, SessionServiceImpl},
+        settings_service::{SettingsService, SettingsServiceImpl},
+        snapshot_service::{SnapshotService, SnapshotServiceImpl},
+        stats_service::{StatsService, StatsServiceImpl},
+        sync_service::{SyncService, SyncServiceImpl},
+        tray_service::{TrayService, TrayServiceImpl},
+        user_service::{UserService, UserServiceImpl},
+        workspace_service::{WorkspaceService, WorkspaceServiceImpl},
+        workspace_template_service::{WorkspaceTemplateService, WorkspaceTemplateServiceImpl},
+    },
+    session::Session,
+};
+
+/// Storage backend used by the services, swap any of these to change where data lives
+#[derive(Clone)]
+pub struct Repositories {
+    pub users: Arc<dyn UserRepository>,
+    pub organizations: Arc<dyn OrganizationRepository>,
+    pub members: Arc<dyn OrganizationMemberRepository>,
+    pub workspaces: Arc<dyn WorkspaceRepository>,
+    pub groups: Arc<dyn GroupRepository>,
+    pub bookmarks: Arc<dyn BookmarkRepository>,
+    pub snapshots: Arc<dyn SnapshotRepository>,
+    pub contents: Arc<dyn ContentRepository>,
+    pub saved_searches: Arc<dyn SavedSearchRepository>,
+    pub settings: Arc<dyn SettingsRepository>,
+    pub audit_events: Arc<dyn AuditRepository>,
+    pub templates: Arc<dyn WorkspaceTemplateRepository>,
+    pub stats: Arc<dyn StatsRepository>,
+    pub sync: Arc<dyn SyncRepository>,
+}
+
+impl Repositories {
+    /// SeaORM backed repositories
+    pub fn sea_orm() -> Self {
+        Repositories {
+            users: Arc::new(UserRepositoryImpl::new()),
+            organizations: Arc::new(OrganizationRepositoryImpl::new()),
+            members: Arc::new(OrganizationMemberRepositoryImpl::new()),
+            workspaces: Arc::new(WorkspaceRepositoryImpl::new()),
+            groups: Arc::new(GroupRepositoryImpl::new()),
+            bookmarks: Arc::new(BookmarkRepositoryImpl::new()),
+            snapshots: Arc::new(SnapshotRepositoryImpl::new()),
+            contents: Arc::new(ContentRepositoryImpl::new()),
+            saved_searches: Arc::new(SavedSearchRepositoryImpl::new()),
+            settings: Arc::new(SettingsRepositoryImpl::new()),
+            audit_events: Arc::new(AuditRepositoryImpl::new()),
+            templates: Arc::new(WorkspaceTemplateRepositoryImpl::new()),
+            stats: Arc::new(StatsRepositoryImpl::new()),
+            sync: Arc::new(SyncRepositoryImpl::new()),
+        }
+    }
+}
+
+/// Services shared by every resolver, built once at startup and held by `ContextRouter`
+pub struct Services {
+    pub users: Arc<dyn UserService>,
+    pub organizations: Arc<dyn OrganizationService>,
+    pub workspaces: Arc<dyn WorkspaceService>,
+    pub templates: Arc<dyn WorkspaceTemplateService>,
+    pub groups: Arc<dyn GroupService>,
+    pub bookmarks: Arc<dyn BookmarkService>,
+    pub read_later: Arc<dyn ReadLaterService>,
+    pub snapshots: Arc<dyn SnapshotService>,
+    pub saved_searches: Arc<dyn SavedSearchService>,
+    pub settings: Arc<dyn SettingsService>,
+    pub sessions: Arc<dyn SessionService>,
+    pub tray: Arc<dyn TrayService>,
+    pub deep_links: Arc<dyn DeepLinkService>,
+    pub imports: Arc<dyn ImportService>,
+    pub exports: Arc<dyn ExportService>,
+    pub audit: Arc<dyn AuditService>,
+    pub sync: Arc<dyn SyncService>,
+    pub stats: Arc<dyn StatsService>,
+    /// Every mutation publishes here, subscriptions and background jobs share it
+    pub events: EventBus,
+    /// The signed in user, shared by every context so signing in applies everywhere
+    pub session: Session,
+}
+
+impl Services {
+    pub fn new(repositories: Repositories, archiver: Arc<PageArchiver>) -> Self {
+        let Repositories {
+            users,
+            organizations,
+            members,
+            workspaces,
+            groups,
+            bookmarks,
+            snapshots,
+            contents,
+            saved_searches,
+            settings,
+            audit_events,
+            templates,
+            stats,
+            sync,
+        } = repositories;
+        let events = EventBus::new();
+        let permissions = Permissions::new(
+            Arc::clone(&members),
+            Arc::clone(&workspaces),
+            Arc::clone(&groups),
+            Arc::clone(&bookmarks),
+        );
+
+        Services {
+            users: Arc::new(UserServiceImpl::new(Arc::clone(&users))),
+            templates: Arc::new(WorkspaceTemplateServiceImpl::new(
+                templates,
+                Arc::clone(&organizations),
+                Arc::clone(&workspaces),
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                permissions.clone(),
+                events.clone(),
+            )),
+            organizations: Arc::new(OrganizationServiceImpl::new(
+                organizations,
+                members,
+                Arc::clone(&users),
+                Arc::clone(&workspaces),
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                permissions.clone(),
+                events.clone(),
+            )),
+            workspaces: Arc::new(WorkspaceServiceImpl::new(
+                Arc::clone(&workspaces),
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                permissions.clone(),
+                events.clone(),
+            )),
+            groups: Arc::new(GroupsServiceImpl::new(
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                Arc::clone(&saved_searches),
+                permissions.clone(),
+                events.clone(),
+            )),
+            bookmarks: Arc::new(BookmarkServiceImpl::new(
+                Arc::clone(&bookmarks),
+                Arc::clone(&groups),
+                Arc::clone(&saved_searches),
+                permissions.clone(),
+                events.clone(),
+            )),
+            stats: Arc::new(StatsServiceImpl::new(
+                stats,
+                Arc::clone(&bookmarks),
+                permissions.clone(),
+            )),
+            sync: Arc::new(SyncServiceImpl::new(
+                sync,
+                Arc::clone(&workspaces),
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                Arc::clone(&saved_searches),
+                permissions.clone(),
+                events.clone(),
+            )),
+            read_later: Arc::new(ReadLaterServiceImpl::new(
+                Arc::clone(&bookmarks),
+                Arc::clone(&groups),
+                permissions.clone(),
+                events.clone(),
+            )),
+            tray: Arc::new(TrayServiceImpl::new(
+                Arc::clone(&bookmarks),
+                Arc::clone(&groups),
+                Arc::clone(&workspaces),
+            )),
+            exports: Arc::new(ExportServiceImpl::new(
+                workspaces,
+                Arc::clone(&groups),
+                Arc::clone(&bookmarks),
+                Exporters::default(),
+            )),
+            snapshots: Arc::new(SnapshotServiceImpl::new(
+                snapshots,
+                Arc::clone(&bookmarks),
+                Arc::clone(&groups),
+                contents,
+                archiver,
+                permissions.clone(),
+                events.clone(),
+            )),
+            imports: Arc::new(ImportServiceImpl::new(
+                Arc::clone(&bookmarks),
+                groups,
+                Arc::clone(&saved_searches),
+                permissions.clone(),
+                events.clone(),
+            )),
+            saved_searches: Arc::new(SavedSearchServiceImpl::new(
+                saved_searches,
+                bookmarks,
+                permissions.clone(),
+            )),
+            audit: Arc::new(AuditServiceImpl::new(
+                audit_events,
+                Arc::clone(&users),
+                permissions,
+            )),
+            sessions: Arc::new(SessionServiceImpl::new(users, Arc::clone(&settings))),
+            settings: Arc::new(SettingsServiceImpl::new(settings)),
+            deep_links: Arc::new(DeepLinkServiceImpl::new()),
+            events,
+            session: Session::new(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    deep_link::{parse_deep_link, DeepLink, DeepLinkAction},
+    dto::bookmark::bookmark_dto::CreateBookmarkDto,
+};
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait DeepLinkService: Send + Sync {
+    /// Parses a `rayzen://` link and checks that whatever it points at exists.
+    /// Nothing is written, adding a bookmark still needs the user's confirmation.
+    async fn resolve(&self, ctx: ContextRouter, link: String) -> Result<DeepLinkAction, String>;
+}
+
+/// Routes links onto the other services, so it holds no repositories of its own
+#[derive(Default)]
+pub struct DeepLinkServiceImpl;
+
+impl DeepLinkServiceImpl {
+    pub fn new() -> Self {
+        DeepLinkServiceImpl
+    }
+}
+
+#[async_trait]
+impl DeepLinkService for DeepLinkServiceImpl {
+    async fn resolve(&self, ctx: ContextRouter, link: String) -> Result<DeepLinkAction, String> {
+        match parse_deep_link(&link)? {
+            DeepLink::Add {
+                url,
+                group,
+                name,
+                tags,
+            } => {
+                let groups = Arc::clone(&ctx.services.groups);
+                let group = groups.find_group(ctx, &group).await?;
+
+                Ok(DeepLinkAction::ConfirmAdd {
+                    bookmark: CreateBookmarkDto {
+                        name: name.unwrap_or_else(|| url.clone()),
+                        url,
+                        tags,
+                        is_favorite: false,
+                        group_id: group.base.id,
+                    },
+                    group,
+                })
+            }
+            DeepLink::OpenWorkspace(id) => {
+                let workspaces = Arc::clone(&ctx.services.workspaces);
+                let workspace = workspaces.get_workspace_by_id(ctx, id).await?;
+
+                Ok(DeepLinkAction::OpenWorkspace { workspace })
+            }
+            DeepLink::OpenGroup(id) => {
+                let groups = Arc::clone(&ctx.services.groups);
+                let group = groups.get_group_by_id(ctx, id).await?;
+
+                Ok(DeepLinkAction::OpenGroup { group })
+            }
+            DeepLink::OpenBookmark(id) => {
+                let bookmarks = Arc::clone(&ctx.services.bookmarks);
+                let bookmark = bookmarks.get_bookmark_by_id(ctx, id).await?;
+
+                Ok(DeepLinkAction::OpenBookmark { bookmark })
+            }
+        }
+    }
+}
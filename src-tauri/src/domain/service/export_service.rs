@@ -0,0 +1,178 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::bookmark::bookmark_dto::BookmarkDto,
+    export::{
+        file_name, ExportDocument, ExportDto, ExportFile, ExportFormat, ExportGroup, ExportScope,
+        Exporters,
+    },
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    service::bookmark_service::{group_bookmarks, to_bookmark_dtos},
+    types::groups::{GroupKind, SmartGroupFilter},
+};
+
+use async_trait::async_trait;
+use entity::bookmark::Model as BookmarkModel;
+use sea_orm::DatabaseConnection;
+
+#[async_trait]
+pub trait ExportService: Send + Sync {
+    async fn list_formats(&self, ctx: ContextRouter) -> Result<Vec<ExportFormat>, String>;
+    async fn export(&self, ctx: ContextRouter, dto: ExportDto) -> Result<ExportFile, String>;
+}
+
+pub struct ExportServiceImpl {
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub exporters: Exporters,
+}
+
+impl ExportServiceImpl {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        exporters: Exporters,
+    ) -> Self {
+        ExportServiceImpl {
+            workspace_repository,
+            groups_repository,
+            bookmark_repository,
+            exporters,
+        }
+    }
+
+    async fn document(
+        &self,
+        ctx: &ContextRouter,
+        scope: ExportScope,
+    ) -> Result<ExportDocument, String> {
+        match scope {
+            ExportScope::Group { group_id } => {
+                let group = self
+                    .groups_repository
+                    .get_group_by_id(&ctx.db, group_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let workspace = self.workspace_name(&ctx.db, group.workspace_id).await?;
+                // Every bookmark goes into the file, not just the first page
+                let bookmarks =
+                    group_bookmarks(self.bookmark_repository.as_ref(), &ctx.db, &group).await?;
+                let bookmarks =
+                    to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, bookmarks).await?;
+
+                Ok(ExportDocument {
+                    title: group.name.clone(),
+                    groups: vec![ExportGroup {
+                        workspace,
+                        name: group.name,
+                        bookmarks,
+                    }],
+                })
+            }
+            ExportScope::Workspace { workspace_id } => {
+                let bookmarks = self
+                    .bookmark_repository
+                    .find_matching(&ctx.db, workspace_id, &SmartGroupFilter::everything())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let workspace = self.workspace_name(&ctx.db, workspace_id).await?;
+
+                Ok(ExportDocument {
+                    groups: self
+                        .by_group(&ctx.db, workspace_id, &workspace, bookmarks)
+                        .await?,
+                    title: workspace,
+                })
+            }
+            ExportScope::Search {
+                workspace_id,
+                query,
+            } => {
+                let bookmarks = self
+                    .bookmark_repository
+                    .search_in_workspace(&ctx.db, workspace_id, &query)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let workspace = self.workspace_name(&ctx.db, workspace_id).await?;
+
+                Ok(ExportDocument {
+                    title: format!("Search \"{}\"", query),
+                    groups: self
+                        .by_group(&ctx.db, workspace_id, &workspace, bookmarks)
+                        .await?,
+                })
+            }
+        }
+    }
+
+    async fn workspace_name(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<String, String> {
+        self.workspace_repository
+            .get_workspace_by_id(db, workspace_id)
+            .await
+            .map(|workspace| workspace.name)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Sorts bookmarks into the static groups of the workspace, oldest first. Smart groups
+    /// are left out since they only repeat bookmarks of static ones.
+    async fn by_group(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        workspace: &str,
+        mut bookmarks: Vec<BookmarkModel>,
+    ) -> Result<Vec<ExportGroup>, String> {
+        let groups = self
+            .groups_repository
+            .find_by_workspace_id(db, workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        bookmarks.sort_by_key(|bookmark| (bookmark.created_at, bookmark.id));
+        let mut by_group: HashMap<i32, Vec<BookmarkDto>> = HashMap::new();
+        for bookmark in bookmarks {
+            by_group
+                .entry(bookmark.group_id)
+                .or_default()
+                .push(bookmark.into());
+        }
+
+        Ok(groups
+            .into_iter()
+            .filter(|group| GroupKind::from_db(&group.kind) == GroupKind::Static)
+            .map(|group| ExportGroup {
+                workspace: workspace.to_string(),
+                bookmarks: by_group.remove(&group.id).unwrap_or_default(),
+                name: group.name,
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ExportService for ExportServiceImpl {
+    async fn list_formats(&self, _ctx: ContextRouter) -> Result<Vec<ExportFormat>, String> {
+        Ok(self.exporters.formats())
+    }
+
+    async fn export(&self, ctx: ContextRouter, dto: ExportDto) -> Result<ExportFile, String> {
+        let exporter = self.exporters.get(&dto.format)?;
+        let document = self.document(&ctx, dto.scope).await?;
+
+        Ok(ExportFile {
+            file_name: file_name(&document.title, exporter.extension()),
+            mime_type: exporter.mime_type().to_string(),
+            content: exporter.export(&document)?,
+        })
+    }
+}
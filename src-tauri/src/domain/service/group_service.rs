@@ -1,23 +1,93 @@
 use std::sync::Arc;
 
 use crate::domain::{
-    dto::groups::groups_dto::{CreateGroupsDto, GroupsDto, UpdateGroupsDto},
-    repository::groups_repository::GroupRepository,
-    router::ContextRouter,
+    context::ContextRouter,
+    dto::{
+        bookmark::bookmark_dto::BookmarkDto,
+        groups::groups_dto::{
+            CreateGroupsDto, CreateSmartGroupDto, DuplicateGroupDto, GroupsDto, MoveGroupDto,
+            UpdateGroupsDto, UpdateSmartGroupFilterDto,
+        },
+    },
+    events::{BookmarkChangedEvent, EventBus, GroupChangedEvent},
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        saved_search_repository::SavedSearchRepository,
+    },
+    service::{
+        bookmark_service::to_bookmark_dtos, saved_search_service::refresh_saved_search_counts,
+    },
+    types::{
+        groups::{GroupKind, SmartGroupFilter},
+        membership::Access,
+        pagination::{ListFilter, ListParams, Page, MAX_PER_PAGE},
+    },
 };
 
 use async_trait::async_trait;
+use uuid::Uuid;
 
 pub struct GroupsServiceImpl {
     pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub saved_search_repository: Arc<dyn SavedSearchRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
 }
 
 impl GroupsServiceImpl {
-    pub fn new(groups_repository: Arc<dyn GroupRepository>) -> Self {
+    pub fn new(
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        saved_search_repository: Arc<dyn SavedSearchRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
         GroupsServiceImpl {
             groups_repository: groups_repository,
+            bookmark_repository,
+            saved_search_repository,
+            permissions,
+            events,
         }
     }
+
+    /// Bookmarks arriving in or leaving a workspace change its saved search badges
+    async fn refresh_saved_searches(
+        &self,
+        ctx: &ContextRouter,
+        workspace_id: i32,
+    ) -> Result<(), String> {
+        refresh_saved_search_counts(
+            self.saved_search_repository.as_ref(),
+            self.bookmark_repository.as_ref(),
+            &ctx.db,
+            workspace_id,
+        )
+        .await
+    }
+
+    /// Recounts saved searches on both sides of a move, the workspace the group left sees a
+    /// delete and the one it joined a new group
+    async fn finish_move(
+        &self,
+        ctx: &ContextRouter,
+        from_workspace_id: i32,
+        moved_group: &GroupsDto,
+    ) -> Result<(), String> {
+        self.refresh_saved_searches(ctx, from_workspace_id).await?;
+        self.refresh_saved_searches(ctx, moved_group.workspace_id)
+            .await?;
+
+        self.events.publish(GroupChangedEvent::deleted(
+            from_workspace_id,
+            moved_group.base.id,
+        ));
+        self.events
+            .publish(GroupChangedEvent::created(moved_group.clone()));
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -28,31 +98,64 @@ pub trait GroupService: Send + Sync {
         dto: CreateGroupsDto,
     ) -> Result<GroupsDto, String>;
     async fn get_group_by_id(&self, ctx: ContextRouter, id: i32) -> Result<GroupsDto, String>;
+    async fn get_group_by_uuid(&self, ctx: ContextRouter, uuid: Uuid) -> Result<GroupsDto, String>;
+    async fn find_group(&self, ctx: ContextRouter, reference: &str) -> Result<GroupsDto, String>;
     async fn update_group(
         &self,
         ctx: ContextRouter,
         group: UpdateGroupsDto,
     ) -> Result<GroupsDto, String>;
     async fn delete_group(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
-    async fn list_groups(&self, ctx: ContextRouter) -> Result<Vec<GroupsDto>, String>;
+    async fn move_group(&self, ctx: ContextRouter, dto: MoveGroupDto) -> Result<GroupsDto, String>;
+    async fn duplicate_group(
+        &self,
+        ctx: ContextRouter,
+        dto: DuplicateGroupDto,
+    ) -> Result<GroupsDto, String>;
+    async fn list_groups(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<GroupsDto>, String>;
     async fn list_belonged_groups(
         &self,
         ctx: ContextRouter,
         workspace_id: i32,
         organization_id: i32,
-    ) -> Result<Vec<GroupsDto>, String>;
+        params: ListParams,
+    ) -> Result<Page<GroupsDto>, String>;
+    async fn create_smart_group(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSmartGroupDto,
+    ) -> Result<GroupsDto, String>;
+    async fn update_smart_group_filter(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateSmartGroupFilterDto,
+    ) -> Result<GroupsDto, String>;
+    async fn evaluate_smart_group(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<Vec<BookmarkDto>, String>;
 }
 
 #[async_trait]
 impl GroupService for GroupsServiceImpl {
-    async fn list_groups(&self, ctx: ContextRouter) -> Result<Vec<GroupsDto>, String> {
+    async fn list_groups(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<GroupsDto>, String> {
+        let organization_ids = self.permissions.readable_organizations(&ctx).await?;
         let list_of_groups = self
             .groups_repository
-            .list_groups(&ctx.db)
+            .list_groups(&ctx.db, organization_ids.as_deref(), &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(list_of_groups.into_iter().map(Into::into).collect())
+        Ok(list_of_groups.map(Into::into))
     }
 
     async fn list_belonged_groups(
@@ -60,14 +163,19 @@ impl GroupService for GroupsServiceImpl {
         ctx: ContextRouter,
         workspace_id: i32,
         organization_id: i32,
-    ) -> Result<Vec<GroupsDto>, String> {
+        params: ListParams,
+    ) -> Result<Page<GroupsDto>, String> {
+        self.permissions
+            .require_workspace(&ctx, workspace_id, Access::Read)
+            .await?;
+
         let belonged_groups = self
             .groups_repository
-            .find_belonged_groups(&ctx.db, workspace_id, organization_id)
+            .find_belonged_groups(&ctx.db, workspace_id, organization_id, &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(belonged_groups.into_iter().map(Into::into).collect())
+        Ok(belonged_groups.map(Into::into))
     }
 
     async fn get_group_by_id(&self, ctx: ContextRouter, id: i32) -> Result<GroupsDto, String> {
@@ -80,18 +188,89 @@ impl GroupService for GroupsServiceImpl {
         Ok(group.into())
     }
 
+    async fn get_group_by_uuid(&self, ctx: ContextRouter, uuid: Uuid) -> Result<GroupsDto, String> {
+        let group = self
+            .groups_repository
+            .get_group_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(group.into())
+    }
+
+    /// Accepts a group id, a UUID or a case-insensitive name, which has to be unambiguous
+    async fn find_group(&self, ctx: ContextRouter, reference: &str) -> Result<GroupsDto, String> {
+        let reference = reference.trim();
+        if let Ok(id) = reference.parse::<i32>() {
+            return self.get_group_by_id(ctx, id).await;
+        }
+        if let Ok(uuid) = Uuid::parse_str(reference) {
+            return self.get_group_by_uuid(ctx, uuid).await;
+        }
+
+        let mut params = ListParams {
+            per_page: Some(MAX_PER_PAGE as u32),
+            filter: Some(ListFilter {
+                search: Some(reference.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let organization_ids = self.permissions.readable_organizations(&ctx).await?;
+        let mut matches: Vec<GroupsDto> = Vec::new();
+        loop {
+            let page = self
+                .groups_repository
+                .list_groups(&ctx.db, organization_ids.as_deref(), &params)
+                .await
+                .map_err(|e| e.to_string())?;
+            matches.extend(
+                page.items
+                    .into_iter()
+                    .filter(|group| group.name.eq_ignore_ascii_case(reference))
+                    .map(Into::into),
+            );
+            match page.next_cursor {
+                Some(cursor) => params.cursor = Some(cursor),
+                None => break,
+            }
+        }
+
+        match matches.len() {
+            0 => Err(format!("No group named \"{}\"", reference)),
+            1 => Ok(matches.remove(0)),
+            _ => Err(format!(
+                "Several groups are named \"{}\", use one of these ids instead: {}",
+                reference,
+                matches
+                    .iter()
+                    .map(|group| group.base.id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
     async fn create_group(
         &self,
         ctx: ContextRouter,
         dto: CreateGroupsDto,
     ) -> Result<GroupsDto, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+
         let created_group = self
             .groups_repository
             .create_group(&ctx.db, dto.into())
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(created_group.into())
+        let created_group: GroupsDto = created_group.into();
+        self.events
+            .publish(GroupChangedEvent::created(created_group.clone()));
+
+        Ok(created_group)
     }
 
     async fn update_group(
@@ -103,37 +282,230 @@ impl GroupService for GroupsServiceImpl {
             return Err("Group ID is required for update".to_string());
         }
 
-        // log all received data
-        println!("Received update group data: {:?}", dto);
-
         let id = dto.id.unwrap();
+        self.permissions
+            .require_group(&ctx, id, Access::Write)
+            .await?;
 
         // Verify group exists (optional, but good practice)
-        let _found_group = self
+        let found_group = self
             .groups_repository
             .get_group_by_id(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?;
 
-        // Use DTO data directly for update
+        // Another workspace needs the same write access as `move_group`
+        let moved_from = match dto.workspace_id {
+            Some(workspace_id) if workspace_id != found_group.workspace_id => {
+                self.permissions
+                    .require_workspace(&ctx, workspace_id, Access::Write)
+                    .await?;
+                Some(found_group.workspace_id)
+            }
+            _ => None,
+        };
+
+        // The move and the other fields are saved together or not at all
         let updated_group = self
             .groups_repository
             .update_group(&ctx.db, id, dto.into())
             .await
             .map_err(|e| e.to_string())?;
 
-        println!("Updated group: {:?}", updated_group);
+        let updated_group: GroupsDto = updated_group.into();
+        match moved_from {
+            Some(workspace_id) => self.finish_move(&ctx, workspace_id, &updated_group).await?,
+            None => self
+                .events
+                .publish(GroupChangedEvent::updated(updated_group.clone())),
+        }
 
-        Ok(updated_group.into())
+        Ok(updated_group)
     }
 
     async fn delete_group(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
-        let deleted_group = self
+        self.permissions
+            .require_group(&ctx, id, Access::Write)
+            .await?;
+
+        let found_group = self
             .groups_repository
+            .get_group_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // The database drops the group's bookmarks with it, collect them first to announce them
+        let bookmarks = self
+            .bookmark_repository
+            .get_by_group(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.groups_repository
             .delete_group(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?;
 
+        for bookmark in bookmarks {
+            self.events.publish(BookmarkChangedEvent::deleted(
+                found_group.workspace_id,
+                bookmark.id,
+            ));
+        }
+        self.events
+            .publish(GroupChangedEvent::deleted(found_group.workspace_id, id));
+
         Ok(())
     }
+
+    async fn move_group(&self, ctx: ContextRouter, dto: MoveGroupDto) -> Result<GroupsDto, String> {
+        // Moving needs write access on both sides
+        self.permissions
+            .require_group(&ctx, dto.id, Access::Write)
+            .await?;
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, dto.id)
+            .await
+            .map_err(|e| e.to_string())?;
+        if group.workspace_id == dto.workspace_id {
+            return Ok(group.into());
+        }
+
+        let moved_group = self
+            .groups_repository
+            .move_group(&ctx.db, dto.id, dto.workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let moved_group: GroupsDto = moved_group.into();
+        self.finish_move(&ctx, group.workspace_id, &moved_group)
+            .await?;
+
+        Ok(moved_group)
+    }
+
+    async fn duplicate_group(
+        &self,
+        ctx: ContextRouter,
+        dto: DuplicateGroupDto,
+    ) -> Result<GroupsDto, String> {
+        self.permissions
+            .require_group(&ctx, dto.id, Access::Read)
+            .await?;
+
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, dto.id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let workspace_id = dto.workspace_id.unwrap_or(group.workspace_id);
+        self.permissions
+            .require_workspace(&ctx, workspace_id, Access::Write)
+            .await?;
+
+        let name = match dto.name.as_deref().map(str::trim) {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ if workspace_id == group.workspace_id => format!("{} (copy)", group.name),
+            _ => group.name.clone(),
+        };
+
+        let copied_group = self
+            .groups_repository
+            .duplicate_group(&ctx.db, dto.id, workspace_id, name)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.refresh_saved_searches(&ctx, workspace_id).await?;
+
+        let copied_group: GroupsDto = copied_group.into();
+        self.events
+            .publish(GroupChangedEvent::created(copied_group.clone()));
+
+        Ok(copied_group)
+    }
+
+    async fn create_smart_group(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSmartGroupDto,
+    ) -> Result<GroupsDto, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+
+        let created_group = self
+            .groups_repository
+            .create_group(&ctx.db, dto.into())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let created_group: GroupsDto = created_group.into();
+        self.events
+            .publish(GroupChangedEvent::created(created_group.clone()));
+
+        Ok(created_group)
+    }
+
+    async fn update_smart_group_filter(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateSmartGroupFilterDto,
+    ) -> Result<GroupsDto, String> {
+        self.permissions
+            .require_group(&ctx, dto.id, Access::Write)
+            .await?;
+
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, dto.id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if GroupKind::from_db(&group.kind) != GroupKind::Smart {
+            return Err("Only smart groups have a filter".to_string());
+        }
+
+        let updated_group = self
+            .groups_repository
+            .update_group_filter(&ctx.db, dto.id, dto.filter.to_json())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let updated_group: GroupsDto = updated_group.into();
+        self.events
+            .publish(GroupChangedEvent::updated(updated_group.clone()));
+
+        Ok(updated_group)
+    }
+
+    async fn evaluate_smart_group(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<Vec<BookmarkDto>, String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if GroupKind::from_db(&group.kind) != GroupKind::Smart {
+            return Err("Group is not a smart group".to_string());
+        }
+
+        let filter = SmartGroupFilter::from_json(group.filter.as_deref().unwrap_or("{}"))?;
+
+        let bookmarks = self
+            .bookmark_repository
+            .find_matching(&ctx.db, group.workspace_id, &filter)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, bookmarks).await
+    }
 }
@@ -0,0 +1,339 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::{bookmark::bookmark_dto::CreateBookmarkDto, groups::groups_dto::CreateGroupsDto},
+    events::{BookmarkChangedEvent, EventBus, GroupChangedEvent},
+    import::{
+        csv_file::{read_csv_columns, read_csv_import, CsvImportDto},
+        dedupe, normalize_url, read_import, ImportConflict, ImportDto, ImportGroupPreview,
+        ImportPreview, ImportResult, ImportRowError, ImportedBookmark, ParsedImport,
+        SavedBookmarks,
+    },
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::{BookmarkRepository, ImportBatch, ImportGroup},
+        groups_repository::GroupRepository,
+        saved_search_repository::SavedSearchRepository,
+    },
+    service::saved_search_service::refresh_saved_search_counts,
+    types::{
+        groups::{GroupKind, SmartGroupFilter},
+        membership::Access,
+        read_later::ReadState,
+    },
+    util::parse_uuid,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::{bookmark::ActiveModel as BookmarkActiveModel, groups::Model as GroupModel};
+use sea_orm::{ActiveValue::Set, DatabaseConnection};
+
+#[async_trait]
+pub trait ImportService: Send + Sync {
+    async fn preview_import(
+        &self,
+        ctx: ContextRouter,
+        dto: ImportDto,
+    ) -> Result<ImportPreview, String>;
+    async fn commit_import(
+        &self,
+        ctx: ContextRouter,
+        dto: ImportDto,
+    ) -> Result<ImportResult, String>;
+    /// Header row of a CSV file, so columns can be mapped before previewing
+    async fn csv_columns(&self, ctx: ContextRouter, path: String) -> Result<Vec<String>, String>;
+    async fn preview_csv_import(
+        &self,
+        ctx: ContextRouter,
+        dto: CsvImportDto,
+    ) -> Result<ImportPreview, String>;
+    async fn commit_csv_import(
+        &self,
+        ctx: ContextRouter,
+        dto: CsvImportDto,
+    ) -> Result<ImportResult, String>;
+}
+
+pub struct ImportServiceImpl {
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub saved_search_repository: Arc<dyn SavedSearchRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
+}
+
+impl ImportServiceImpl {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        saved_search_repository: Arc<dyn SavedSearchRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
+        ImportServiceImpl {
+            bookmark_repository,
+            groups_repository,
+            saved_search_repository,
+            permissions,
+            events,
+        }
+    }
+
+    /// Drops what the workspace already has, every source goes through here before preview
+    /// and commit
+    async fn dedupe_saved(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        parsed: ParsedImport,
+    ) -> Result<ReadImport, String> {
+        // Archived bookmarks count as saved too
+        let mut saved = SavedBookmarks::default();
+        for bookmark in self
+            .bookmark_repository
+            .find_matching(db, workspace_id, &SmartGroupFilter::everything())
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            saved.by_uuid.insert(
+                parse_uuid(&bookmark.uuid),
+                (bookmark.id, bookmark.name.clone()),
+            );
+            saved
+                .by_url
+                .insert(normalize_url(&bookmark.url), (bookmark.id, bookmark.name));
+        }
+
+        let (bookmarks, conflicts) = dedupe(parsed.bookmarks, &saved);
+        Ok(ReadImport {
+            bookmarks,
+            skipped: parsed.skipped,
+            conflicts,
+            errors: parsed.errors,
+        })
+    }
+
+    /// Static groups of the workspace keyed by lowercased name, imports reuse them
+    async fn existing_groups(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<HashMap<String, GroupModel>, String> {
+        let groups = self
+            .groups_repository
+            .find_by_workspace_id(db, workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(groups
+            .into_iter()
+            .filter(|group| GroupKind::from_db(&group.kind) == GroupKind::Static)
+            .map(|group| (group.name.to_lowercase(), group))
+            .collect())
+    }
+
+    async fn preview(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        parsed: ParsedImport,
+    ) -> Result<ImportPreview, String> {
+        let parsed = self.dedupe_saved(db, workspace_id, parsed).await?;
+        let existing = self.existing_groups(db, workspace_id).await?;
+
+        let total = parsed.bookmarks.len() as u32;
+        let groups = by_group(parsed.bookmarks)
+            .into_iter()
+            .map(|(name, bookmarks)| ImportGroupPreview {
+                existing_group_id: existing.get(&name.to_lowercase()).map(|group| group.id),
+                name,
+                bookmarks,
+            })
+            .collect();
+
+        Ok(ImportPreview {
+            groups,
+            total,
+            skipped: parsed.skipped,
+            conflicts: parsed.conflicts,
+            errors: parsed.errors,
+        })
+    }
+
+    async fn commit(
+        &self,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+        parsed: ParsedImport,
+    ) -> Result<ImportResult, String> {
+        let parsed = self.dedupe_saved(db, workspace_id, parsed).await?;
+        let existing = self.existing_groups(db, workspace_id).await?;
+
+        let batches = by_group(parsed.bookmarks)
+            .into_iter()
+            .map(|(name, bookmarks)| ImportBatch {
+                group: match existing.get(&name.to_lowercase()) {
+                    Some(group) => ImportGroup::Existing(group.id),
+                    None => ImportGroup::New(CreateGroupsDto { name, workspace_id }.into()),
+                },
+                bookmarks: bookmarks
+                    .into_iter()
+                    .map(|bookmark| (bookmark.row, new_bookmark(bookmark)))
+                    .collect(),
+            })
+            .collect();
+
+        // All of the file or nothing, only rows that fail on their own are left out
+        let imported = self
+            .bookmark_repository
+            .import(db, batches)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for group in &imported.groups {
+            self.events
+                .publish(GroupChangedEvent::created(group.clone().into()));
+        }
+        for bookmark in &imported.bookmarks {
+            self.events.publish(BookmarkChangedEvent::created(
+                workspace_id,
+                bookmark.clone().into(),
+            ));
+        }
+
+        let mut errors = parsed.errors;
+        errors.extend(imported.errors);
+        errors.sort_by_key(|error| error.row);
+
+        refresh_saved_search_counts(
+            self.saved_search_repository.as_ref(),
+            self.bookmark_repository.as_ref(),
+            db,
+            workspace_id,
+        )
+        .await?;
+
+        Ok(ImportResult {
+            groups_created: imported.groups.len() as u32,
+            bookmarks_created: imported.bookmarks.len() as u32,
+            skipped: parsed.skipped.len() as u32,
+            conflicts: parsed.conflicts,
+            errors,
+        })
+    }
+}
+
+/// Row for an imported bookmark, `group_id` is filled in once its group exists
+fn new_bookmark(bookmark: ImportedBookmark) -> BookmarkActiveModel {
+    let mut model: BookmarkActiveModel = CreateBookmarkDto {
+        name: bookmark.name,
+        url: bookmark.url,
+        tags: bookmark.tags,
+        is_favorite: bookmark.is_favorite,
+        group_id: 0,
+    }
+    .into();
+    // Keep the date it was bookmarked in the other app
+    if let Some(created_at) = bookmark.created_at {
+        model.created_at = Set(created_at);
+    }
+    // Taken UUIDs are replaced by the repository
+    if let Some(uuid) = bookmark.uuid {
+        model.uuid = Set(uuid.to_string());
+    }
+    match bookmark.read_state {
+        Some(ReadState::Read) => {
+            model.read_state = Set(ReadState::Read.as_str().to_string());
+            model.read_at = Set(Some(Utc::now()));
+        }
+        Some(ReadState::Archived) => {
+            model.read_state = Set(ReadState::Archived.as_str().to_string());
+            model.archived_at = Set(Some(Utc::now()));
+        }
+        Some(state) => model.read_state = Set(state.as_str().to_string()),
+        None => {}
+    }
+
+    model
+}
+
+struct ReadImport {
+    bookmarks: Vec<ImportedBookmark>,
+    skipped: Vec<String>,
+    conflicts: Vec<ImportConflict>,
+    errors: Vec<ImportRowError>,
+}
+
+/// Bookmarks per target group, groups keep the order they were first seen in
+fn by_group(bookmarks: Vec<ImportedBookmark>) -> Vec<(String, Vec<ImportedBookmark>)> {
+    let mut groups: Vec<(String, Vec<ImportedBookmark>)> = Vec::new();
+    for bookmark in bookmarks {
+        match groups
+            .iter_mut()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&bookmark.group))
+        {
+            Some((_, bookmarks)) => bookmarks.push(bookmark),
+            None => groups.push((bookmark.group.clone(), vec![bookmark])),
+        }
+    }
+    groups
+}
+
+#[async_trait]
+impl ImportService for ImportServiceImpl {
+    async fn preview_import(
+        &self,
+        ctx: ContextRouter,
+        dto: ImportDto,
+    ) -> Result<ImportPreview, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Read)
+            .await?;
+        let parsed = read_import(dto.source, Path::new(dto.path.trim())).await?;
+        self.preview(&ctx.db, dto.workspace_id, parsed).await
+    }
+
+    async fn commit_import(
+        &self,
+        ctx: ContextRouter,
+        dto: ImportDto,
+    ) -> Result<ImportResult, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+        let parsed = read_import(dto.source, Path::new(dto.path.trim())).await?;
+        self.commit(&ctx.db, dto.workspace_id, parsed).await
+    }
+
+    async fn csv_columns(&self, _ctx: ContextRouter, path: String) -> Result<Vec<String>, String> {
+        read_csv_columns(Path::new(path.trim())).await
+    }
+
+    async fn preview_csv_import(
+        &self,
+        ctx: ContextRouter,
+        dto: CsvImportDto,
+    ) -> Result<ImportPreview, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Read)
+            .await?;
+        let parsed =
+            read_csv_import(Path::new(dto.path.trim()), &dto.mapping, &dto.default_group).await?;
+        self.preview(&ctx.db, dto.workspace_id, parsed).await
+    }
+
+    async fn commit_csv_import(
+        &self,
+        ctx: ContextRouter,
+        dto: CsvImportDto,
+    ) -> Result<ImportResult, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+        let parsed =
+            read_csv_import(Path::new(dto.path.trim()), &dto.mapping, &dto.default_group).await?;
+        self.commit(&ctx.db, dto.workspace_id, parsed).await
+    }
+}
@@ -1,5 +1,19 @@
+pub mod audit_service;
 pub mod bookmark_service;
+pub mod container;
+pub mod deep_link_service;
+pub mod export_service;
 pub mod group_service;
+pub mod import_service;
 pub mod organization_service;
+pub mod read_later_service;
+pub mod saved_search_service;
+pub mod session_service;
+pub mod settings_service;
+pub mod snapshot_service;
+pub mod stats_service;
+pub mod sync_service;
+pub mod tray_service;
 pub mod user_service;
 pub mod workspace_service;
+pub mod workspace_template_service;
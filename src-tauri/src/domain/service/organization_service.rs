@@ -1,23 +1,127 @@
 use crate::domain::{
-    dto::organization::organization_dto::{
-        CreateOrganizationDto, OrganizationDto, UpdateOrganizationDto,
+    context::ContextRouter,
+    dto::organization::{
+        organization_converter::new_member,
+        organization_dto::{
+            CreateOrganizationDto, InviteMemberDto, OrganizationDto, OrganizationMemberDto,
+            UpdateMemberRoleDto, UpdateOrganizationDto,
+        },
+    },
+    events::{EventBus, WorkspaceChangedEvent},
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        organization_member_repository::OrganizationMemberRepository,
+        organization_repository::OrganizationRepository, user_repository::UserRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    service::workspace_service::WorkspaceContents,
+    types::{
+        membership::{Access, MemberRole},
+        pagination::{ListParams, Page},
     },
-    repository::organization_repository::OrganizationRepository,
-    router::ContextRouter,
 };
 use async_trait::async_trait;
+use entity::organization_member::Model as OrganizationMemberModel;
+use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct OrganizationServiceImpl {
     pub organization_repository: Arc<dyn OrganizationRepository>,
+    pub member_repository: Arc<dyn OrganizationMemberRepository>,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
 }
 
 impl OrganizationServiceImpl {
-    pub fn new(organization_repository: Arc<dyn OrganizationRepository>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        organization_repository: Arc<dyn OrganizationRepository>,
+        member_repository: Arc<dyn OrganizationMemberRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
         OrganizationServiceImpl {
             organization_repository,
+            member_repository,
+            user_repository,
+            workspace_repository,
+            groups_repository,
+            bookmark_repository,
+            permissions,
+            events,
         }
     }
+
+    async fn to_member_dto(
+        &self,
+        db: &DatabaseConnection,
+        member: OrganizationMemberModel,
+    ) -> Result<OrganizationMemberDto, String> {
+        let user = self
+            .user_repository
+            .get_user_by_id(db, member.user_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok((member, user).into())
+    }
+
+    /// Finds the membership and checks the signed in user may manage its organization
+    async fn managed_member(
+        &self,
+        ctx: &ContextRouter,
+        id: i32,
+    ) -> Result<OrganizationMemberModel, String> {
+        let member = self
+            .member_repository
+            .find_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Member with id {} not found", id))?;
+
+        self.permissions
+            .require_organization(ctx, member.organization_id, Access::Manage)
+            .await?;
+
+        Ok(member)
+    }
+
+    /// Demoting or removing `member` must leave someone who can manage the organization
+    async fn ensure_other_owner(
+        &self,
+        db: &DatabaseConnection,
+        member: &OrganizationMemberModel,
+    ) -> Result<(), String> {
+        if MemberRole::from_db(&member.role) != MemberRole::Owner {
+            return Ok(());
+        }
+
+        let members = self
+            .member_repository
+            .list_members(db, member.organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let owners = members
+            .iter()
+            .filter(|other| MemberRole::from_db(&other.role) == MemberRole::Owner)
+            .count();
+
+        if owners <= 1 {
+            return Err("An organization needs at least one owner".to_string());
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -32,6 +136,11 @@ pub trait OrganizationService: Send + Sync {
         ctx: ContextRouter,
         id: i32,
     ) -> Result<OrganizationDto, String>;
+    async fn get_organization_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<OrganizationDto, String>;
     async fn get_organization_by_user_id(
         &self,
         ctx: ContextRouter,
@@ -43,18 +152,42 @@ pub trait OrganizationService: Send + Sync {
         organization: UpdateOrganizationDto,
     ) -> Result<OrganizationDto, String>;
     async fn delete_organization(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
-    async fn list_organizations(&self, ctx: ContextRouter) -> Result<Vec<OrganizationDto>, String>;
+    async fn list_organizations(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<OrganizationDto>, String>;
+    async fn list_members(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationMemberDto>, String>;
+    async fn invite_member(
+        &self,
+        ctx: ContextRouter,
+        dto: InviteMemberDto,
+    ) -> Result<OrganizationMemberDto, String>;
+    async fn update_member_role(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateMemberRoleDto,
+    ) -> Result<OrganizationMemberDto, String>;
+    async fn remove_member(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
 }
 
 #[async_trait]
 impl OrganizationService for OrganizationServiceImpl {
-    async fn list_organizations(&self, ctx: ContextRouter) -> Result<Vec<OrganizationDto>, String> {
+    async fn list_organizations(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<OrganizationDto>, String> {
         let list_of_organizations = self
             .organization_repository
-            .list_organizations(&ctx.db)
+            .list_organizations(&ctx.db, &params)
             .await
             .map_err(|e| e.to_string())?;
-        Ok(list_of_organizations.into_iter().map(Into::into).collect())
+        Ok(list_of_organizations.map(Into::into))
     }
 
     async fn get_organization_by_id(
@@ -70,6 +203,19 @@ impl OrganizationService for OrganizationServiceImpl {
         Ok(organization.into())
     }
 
+    async fn get_organization_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<OrganizationDto, String> {
+        let organization = self
+            .organization_repository
+            .get_organization_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(organization.into())
+    }
+
     async fn get_organization_by_user_id(
         &self,
         ctx: ContextRouter,
@@ -94,6 +240,20 @@ impl OrganizationService for OrganizationServiceImpl {
             .create_organization(&ctx.db, dto.into())
             .await
             .map_err(|e| e.to_string())?;
+
+        // The creator owns the organization and can invite others into it
+        self.member_repository
+            .add_member(
+                &ctx.db,
+                new_member(
+                    created_organization.id,
+                    created_organization.user_id,
+                    MemberRole::Owner,
+                ),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
         Ok(created_organization.into())
     }
 
@@ -106,6 +266,9 @@ impl OrganizationService for OrganizationServiceImpl {
             return Err("Organization ID is required for update".to_string());
         }
         let id = dto.id.unwrap();
+        self.permissions
+            .require_organization(&ctx, id, Access::Manage)
+            .await?;
 
         let updated_organization = self
             .organization_repository
@@ -116,11 +279,128 @@ impl OrganizationService for OrganizationServiceImpl {
     }
 
     async fn delete_organization(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
+        self.permissions
+            .require_organization(&ctx, id, Access::Manage)
+            .await?;
+
+        // Workspaces, their groups and bookmarks go with the organization, collect them first to
+        // announce them
+        let workspaces = self
+            .workspace_repository
+            .find_by_organization_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut contents = Vec::with_capacity(workspaces.len());
+        for workspace in &workspaces {
+            contents.push(
+                WorkspaceContents::collect(
+                    self.groups_repository.as_ref(),
+                    self.bookmark_repository.as_ref(),
+                    &ctx.db,
+                    workspace.id,
+                )
+                .await?,
+            );
+        }
+
         let _deleted_organization = self
             .organization_repository
             .delete_organization(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?;
+
+        for (workspace, contents) in workspaces.into_iter().zip(contents) {
+            contents.publish_deleted(&self.events);
+            self.events
+                .publish(WorkspaceChangedEvent::deleted(id, workspace.id));
+        }
+        Ok(())
+    }
+
+    async fn list_members(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationMemberDto>, String> {
+        self.permissions
+            .require_organization(&ctx, organization_id, Access::Read)
+            .await?;
+
+        let members = self
+            .member_repository
+            .list_members(&ctx.db, organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut dtos = Vec::with_capacity(members.len());
+        for member in members {
+            dtos.push(self.to_member_dto(&ctx.db, member).await?);
+        }
+        Ok(dtos)
+    }
+
+    async fn invite_member(
+        &self,
+        ctx: ContextRouter,
+        dto: InviteMemberDto,
+    ) -> Result<OrganizationMemberDto, String> {
+        self.permissions
+            .require_organization(&ctx, dto.organization_id, Access::Manage)
+            .await?;
+
+        let user = self
+            .user_repository
+            .find_user_by_email(&ctx.db, dto.email.trim())
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("No user with the email {}", dto.email.trim()))?;
+
+        let existing = self
+            .member_repository
+            .find_member(&ctx.db, dto.organization_id, user.id)
+            .await
+            .map_err(|e| e.to_string())?;
+        if existing.is_some() {
+            return Err(format!("{} is already a member", user.email));
+        }
+
+        let member = self
+            .member_repository
+            .add_member(&ctx.db, new_member(dto.organization_id, user.id, dto.role))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok((member, user).into())
+    }
+
+    async fn update_member_role(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateMemberRoleDto,
+    ) -> Result<OrganizationMemberDto, String> {
+        let member = self.managed_member(&ctx, dto.id).await?;
+        if dto.role != MemberRole::Owner {
+            self.ensure_other_owner(&ctx.db, &member).await?;
+        }
+
+        let member = self
+            .member_repository
+            .update_role(&ctx.db, dto.id, dto.role.as_str())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.to_member_dto(&ctx.db, member).await
+    }
+
+    async fn remove_member(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
+        let member = self.managed_member(&ctx, id).await?;
+        self.ensure_other_owner(&ctx.db, &member).await?;
+
+        self.member_repository
+            .remove_member(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
         Ok(())
     }
 }
@@ -0,0 +1,177 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::{
+        bookmark::bookmark_dto::BookmarkDto,
+        read_later::read_later_dto::{ReadLaterCountDto, ReadLaterQueueDto, SetReadStateDto},
+    },
+    events::{BookmarkChangedEvent, EventBus},
+    permissions::Permissions,
+    repository::{bookmark_repository::BookmarkRepository, groups_repository::GroupRepository},
+    service::bookmark_service::to_bookmark_page,
+    types::{membership::Access, pagination::Page, read_later::ReadState},
+};
+
+use async_trait::async_trait;
+use entity::bookmark::ActiveModel as BookmarkActiveModel;
+use sea_orm::ActiveValue::Set;
+
+#[async_trait]
+pub trait ReadLaterService: Send + Sync {
+    async fn list_queue(
+        &self,
+        ctx: ContextRouter,
+        dto: ReadLaterQueueDto,
+    ) -> Result<Page<BookmarkDto>, String>;
+    async fn set_read_state(
+        &self,
+        ctx: ContextRouter,
+        dto: SetReadStateDto,
+    ) -> Result<BookmarkDto, String>;
+    async fn count_by_group(&self, ctx: ContextRouter) -> Result<Vec<ReadLaterCountDto>, String>;
+}
+
+pub struct ReadLaterServiceImpl {
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
+}
+
+impl ReadLaterServiceImpl {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
+        ReadLaterServiceImpl {
+            bookmark_repository,
+            groups_repository,
+            permissions,
+            events,
+        }
+    }
+}
+
+#[async_trait]
+impl ReadLaterService for ReadLaterServiceImpl {
+    async fn list_queue(
+        &self,
+        ctx: ContextRouter,
+        dto: ReadLaterQueueDto,
+    ) -> Result<Page<BookmarkDto>, String> {
+        // The queue only shows what is still left to read unless asked otherwise
+        let states = dto
+            .states
+            .unwrap_or_else(|| vec![ReadState::Unread, ReadState::Reading])
+            .into_iter()
+            .map(|state| state.as_str().to_string())
+            .collect();
+
+        let page = self
+            .bookmark_repository
+            .list_read_later(&ctx.db, dto.group_id, states, &dto.params)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        to_bookmark_page(self.bookmark_repository.as_ref(), &ctx.db, page).await
+    }
+
+    async fn set_read_state(
+        &self,
+        ctx: ContextRouter,
+        dto: SetReadStateDto,
+    ) -> Result<BookmarkDto, String> {
+        self.permissions
+            .require_bookmark(&ctx, dto.id, Access::Write)
+            .await?;
+
+        let found_bookmark = self
+            .bookmark_repository
+            .find_by_id(&ctx.db, dto.id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Bookmark not found".to_string())?;
+
+        let now = chrono::Utc::now();
+        let mut model = BookmarkActiveModel {
+            read_state: Set(dto.state.as_str().to_string()),
+            reading_at: Set(found_bookmark.reading_at),
+            read_at: Set(found_bookmark.read_at),
+            archived_at: Set(found_bookmark.archived_at),
+            ..Default::default()
+        };
+
+        // Keep the timestamps of earlier states so the reading history survives
+        match dto.state {
+            ReadState::Unread => {
+                model.reading_at = Set(None);
+                model.read_at = Set(None);
+                model.archived_at = Set(None);
+            }
+            ReadState::Reading => {
+                model.reading_at = Set(Some(now));
+                model.read_at = Set(None);
+                model.archived_at = Set(None);
+            }
+            ReadState::Read => {
+                model.read_at = Set(Some(now));
+                model.archived_at = Set(None);
+            }
+            ReadState::Archived => {
+                model.archived_at = Set(Some(now));
+            }
+        }
+
+        let updated_bookmark = self
+            .bookmark_repository
+            .update_read_state(&ctx.db, dto.id, model)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let group = self
+            .groups_repository
+            .get_group_by_id(&ctx.db, updated_bookmark.group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let updated_bookmark: BookmarkDto = updated_bookmark.into();
+        self.events.publish(BookmarkChangedEvent::updated(
+            group.workspace_id,
+            updated_bookmark.clone(),
+        ));
+
+        Ok(updated_bookmark)
+    }
+
+    async fn count_by_group(&self, ctx: ContextRouter) -> Result<Vec<ReadLaterCountDto>, String> {
+        let counts = self
+            .bookmark_repository
+            .count_read_states(&ctx.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut by_group: BTreeMap<i32, ReadLaterCountDto> = BTreeMap::new();
+
+        for row in counts {
+            let entry = by_group
+                .entry(row.group_id)
+                .or_insert_with(|| ReadLaterCountDto {
+                    group_id: row.group_id,
+                    ..Default::default()
+                });
+            let count = row.count as i32;
+
+            match ReadState::from_db(&row.read_state) {
+                ReadState::Unread => entry.unread += count,
+                ReadState::Reading => entry.reading += count,
+                ReadState::Read => entry.read += count,
+                ReadState::Archived => entry.archived += count,
+            }
+        }
+
+        Ok(by_group.into_values().collect())
+    }
+}
@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::saved_search::saved_search_dto::{
+        CreateSavedSearchDto, SavedSearchDto, SavedSearchResultDto, UpdateSavedSearchDto,
+    },
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, saved_search_repository::SavedSearchRepository,
+    },
+    service::bookmark_service::to_bookmark_dtos,
+    types::{
+        membership::Access,
+        pagination::{ListParams, Page},
+    },
+};
+
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Recount every saved search of a workspace so pinned badges stay accurate
+pub async fn refresh_saved_search_counts(
+    saved_search_repository: &dyn SavedSearchRepository,
+    bookmark_repository: &dyn BookmarkRepository,
+    db: &DatabaseConnection,
+    workspace_id: i32,
+) -> Result<(), String> {
+    let saved_searches = saved_search_repository
+        .list_by_workspace(db, workspace_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    for saved_search in saved_searches {
+        let count = bookmark_repository
+            .count_in_workspace(db, workspace_id, &saved_search.query)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        saved_search_repository
+            .update_cached_count(db, saved_search.id, count as i32)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+pub trait SavedSearchService: Send + Sync {
+    async fn create_saved_search(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSavedSearchDto,
+    ) -> Result<SavedSearchDto, String>;
+    async fn get_saved_search_by_id(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<SavedSearchDto, String>;
+    async fn get_saved_search_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<SavedSearchDto, String>;
+    async fn update_saved_search(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateSavedSearchDto,
+    ) -> Result<SavedSearchDto, String>;
+    async fn delete_saved_search(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
+    async fn list_saved_searches(
+        &self,
+        ctx: ContextRouter,
+        workspace_id: i32,
+        params: ListParams,
+    ) -> Result<Page<SavedSearchDto>, String>;
+    async fn run_saved_search(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<SavedSearchResultDto, String>;
+}
+
+pub struct SavedSearchServiceImpl {
+    pub saved_search_repository: Arc<dyn SavedSearchRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub permissions: Permissions,
+}
+
+fn require_query(query: &str) -> Result<(), String> {
+    if query.trim().is_empty() {
+        return Err("Saved search query cannot be empty".to_string());
+    }
+
+    Ok(())
+}
+
+impl SavedSearchServiceImpl {
+    pub fn new(
+        saved_search_repository: Arc<dyn SavedSearchRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        permissions: Permissions,
+    ) -> Self {
+        SavedSearchServiceImpl {
+            saved_search_repository,
+            bookmark_repository,
+            permissions,
+        }
+    }
+
+    async fn recount(&self, db: &DatabaseConnection, id: i32) -> Result<SavedSearchDto, String> {
+        let saved_search = self
+            .saved_search_repository
+            .get_saved_search_by_id(db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let count = self
+            .bookmark_repository
+            .count_in_workspace(db, saved_search.workspace_id, &saved_search.query)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let updated = self
+            .saved_search_repository
+            .update_cached_count(db, id, count as i32)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(updated.into())
+    }
+}
+
+#[async_trait]
+impl SavedSearchService for SavedSearchServiceImpl {
+    async fn create_saved_search(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSavedSearchDto,
+    ) -> Result<SavedSearchDto, String> {
+        require_query(&dto.query)?;
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Write)
+            .await?;
+
+        let created = self
+            .saved_search_repository
+            .create_saved_search(&ctx.db, dto.into())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.recount(&ctx.db, created.id).await
+    }
+
+    async fn get_saved_search_by_id(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<SavedSearchDto, String> {
+        let saved_search = self
+            .saved_search_repository
+            .get_saved_search_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(saved_search.into())
+    }
+
+    async fn get_saved_search_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<SavedSearchDto, String> {
+        let saved_search = self
+            .saved_search_repository
+            .get_saved_search_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(saved_search.into())
+    }
+
+    async fn update_saved_search(
+        &self,
+        ctx: ContextRouter,
+        dto: UpdateSavedSearchDto,
+    ) -> Result<SavedSearchDto, String> {
+        let Some(id) = dto.id else {
+            return Err("Saved search ID is required for update".to_string());
+        };
+        if let Some(query) = &dto.query {
+            require_query(query)?;
+        }
+
+        let existing = self
+            .saved_search_repository
+            .get_saved_search_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_workspace(&ctx, existing.workspace_id, Access::Write)
+            .await?;
+
+        self.saved_search_repository
+            .update_saved_search(&ctx.db, dto.apply_to_model(existing.into()))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.recount(&ctx.db, id).await
+    }
+
+    async fn delete_saved_search(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
+        let existing = self
+            .saved_search_repository
+            .get_saved_search_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_workspace(&ctx, existing.workspace_id, Access::Write)
+            .await?;
+
+        self.saved_search_repository
+            .delete_saved_search(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_saved_searches(
+        &self,
+        ctx: ContextRouter,
+        workspace_id: i32,
+        params: ListParams,
+    ) -> Result<Page<SavedSearchDto>, String> {
+        let saved_searches = self
+            .saved_search_repository
+            .find_page_in_workspace(&ctx.db, workspace_id, &params)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(saved_searches.map(Into::into))
+    }
+
+    async fn run_saved_search(
+        &self,
+        ctx: ContextRouter,
+        id: i32,
+    ) -> Result<SavedSearchResultDto, String> {
+        let saved_search = self
+            .saved_search_repository
+            .get_saved_search_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let bookmarks = self
+            .bookmark_repository
+            .search_in_workspace(&ctx.db, saved_search.workspace_id, &saved_search.query)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Running a search is a free recount, keep the cached badge in step
+        let saved_search = self
+            .saved_search_repository
+            .update_cached_count(&ctx.db, id, bookmarks.len() as i32)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(SavedSearchResultDto {
+            saved_search: saved_search.into(),
+            results: to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, bookmarks)
+                .await?,
+        })
+    }
+}
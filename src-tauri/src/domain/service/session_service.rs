@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::user::user_dto::UserDto,
+    repository::{settings_repository::SettingsRepository, user_repository::UserRepository},
+    types::settings::SESSION_USER_ID,
+};
+
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait SessionService: Send + Sync {
+    /// Signed in user, `None` in single user mode
+    async fn current_user(&self, ctx: ContextRouter) -> Result<Option<UserDto>, String>;
+    /// Acts as `user_id` from now on. There is no credential, see `Session` for why roles are
+    /// advisory
+    async fn sign_in(&self, ctx: ContextRouter, user_id: i32) -> Result<UserDto, String>;
+    async fn sign_out(&self, ctx: ContextRouter) -> Result<(), String>;
+    /// Signs the user of the last run back in, called once at startup
+    async fn restore(&self, ctx: ContextRouter) -> Result<(), String>;
+}
+
+pub struct SessionServiceImpl {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub settings_repository: Arc<dyn SettingsRepository>,
+}
+
+impl SessionServiceImpl {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        settings_repository: Arc<dyn SettingsRepository>,
+    ) -> Self {
+        SessionServiceImpl {
+            user_repository,
+            settings_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl SessionService for SessionServiceImpl {
+    async fn current_user(&self, ctx: ContextRouter) -> Result<Option<UserDto>, String> {
+        let Some(user_id) = ctx.services.session.user_id() else {
+            return Ok(None);
+        };
+
+        let user = self
+            .user_repository
+            .get_user_by_id(&ctx.db, user_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Some(user.into()))
+    }
+
+    async fn sign_in(&self, ctx: ContextRouter, user_id: i32) -> Result<UserDto, String> {
+        let user = self
+            .user_repository
+            .get_user_by_id(&ctx.db, user_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.settings_repository
+            .set_setting(&ctx.db, SESSION_USER_ID, user.id.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.services.session.set_user_id(Some(user.id));
+
+        Ok(user.into())
+    }
+
+    async fn sign_out(&self, ctx: ContextRouter) -> Result<(), String> {
+        self.settings_repository
+            .set_setting(&ctx.db, SESSION_USER_ID, String::new())
+            .await
+            .map_err(|e| e.to_string())?;
+        ctx.services.session.set_user_id(None);
+
+        Ok(())
+    }
+
+    async fn restore(&self, ctx: ContextRouter) -> Result<(), String> {
+        let user_id = self
+            .settings_repository
+            .get_setting(&ctx.db, SESSION_USER_ID)
+            .await
+            .map_err(|e| e.to_string())?
+            .and_then(|setting| setting.value.parse().ok());
+
+        ctx.services.session.set_user_id(user_id);
+        Ok(())
+    }
+}
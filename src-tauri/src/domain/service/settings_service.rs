@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    repository::settings_repository::SettingsRepository,
+    types::settings::{
+        HttpApiSettings, DEFAULT_HTTP_API_PORT, DEFAULT_LAUNCHER_SHORTCUT, HTTP_API_ENABLED,
+        HTTP_API_PORT, HTTP_API_TOKEN, LAUNCHER_SHORTCUT,
+    },
+};
+
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SettingsService: Send + Sync {
+    async fn get_launcher_shortcut(&self, ctx: ContextRouter) -> Result<String, String>;
+    async fn set_launcher_shortcut(
+        &self,
+        ctx: ContextRouter,
+        accelerator: String,
+    ) -> Result<String, String>;
+    async fn get_http_api_settings(&self, ctx: ContextRouter) -> Result<HttpApiSettings, String>;
+    async fn set_http_api_enabled(
+        &self,
+        ctx: ContextRouter,
+        enabled: bool,
+    ) -> Result<HttpApiSettings, String>;
+    async fn set_http_api_port(
+        &self,
+        ctx: ContextRouter,
+        port: u16,
+    ) -> Result<HttpApiSettings, String>;
+    async fn regenerate_http_api_token(
+        &self,
+        ctx: ContextRouter,
+    ) -> Result<HttpApiSettings, String>;
+}
+
+pub struct SettingsServiceImpl {
+    pub settings_repository: Arc<dyn SettingsRepository>,
+}
+
+impl SettingsServiceImpl {
+    pub fn new(settings_repository: Arc<dyn SettingsRepository>) -> Self {
+        SettingsServiceImpl {
+            settings_repository,
+        }
+    }
+
+    async fn get_value(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+    ) -> Result<Option<String>, String> {
+        let setting = self
+            .settings_repository
+            .get_setting(db, key)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(setting.map(|setting| setting.value))
+    }
+
+    async fn set_value(
+        &self,
+        db: &DatabaseConnection,
+        key: &str,
+        value: String,
+    ) -> Result<String, String> {
+        let setting = self
+            .settings_repository
+            .set_setting(db, key, value)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(setting.value)
+    }
+}
+
+fn generate_token() -> String {
+    Uuid::new_v4().simple().to_string()
+}
+
+#[async_trait]
+impl SettingsService for SettingsServiceImpl {
+    async fn get_launcher_shortcut(&self, ctx: ContextRouter) -> Result<String, String> {
+        let setting = self
+            .settings_repository
+            .get_setting(&ctx.db, LAUNCHER_SHORTCUT)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(setting
+            .map(|setting| setting.value)
+            .unwrap_or_else(|| DEFAULT_LAUNCHER_SHORTCUT.to_string()))
+    }
+
+    async fn set_launcher_shortcut(
+        &self,
+        ctx: ContextRouter,
+        accelerator: String,
+    ) -> Result<String, String> {
+        let accelerator = accelerator.trim();
+        if accelerator.is_empty() {
+            return Err("Launcher shortcut cannot be empty".to_string());
+        }
+
+        let setting = self
+            .settings_repository
+            .set_setting(&ctx.db, LAUNCHER_SHORTCUT, accelerator.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(setting.value)
+    }
+
+    async fn get_http_api_settings(&self, ctx: ContextRouter) -> Result<HttpApiSettings, String> {
+        let enabled = self
+            .get_value(&ctx.db, HTTP_API_ENABLED)
+            .await?
+            .is_some_and(|value| value == "true");
+        let port = self
+            .get_value(&ctx.db, HTTP_API_PORT)
+            .await?
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_HTTP_API_PORT);
+
+        // The token is created the first time anyone asks for it so it is stable afterwards
+        let token = match self.get_value(&ctx.db, HTTP_API_TOKEN).await? {
+            Some(token) => token,
+            None => {
+                self.set_value(&ctx.db, HTTP_API_TOKEN, generate_token())
+                    .await?
+            }
+        };
+
+        Ok(HttpApiSettings {
+            enabled,
+            port,
+            token,
+        })
+    }
+
+    async fn set_http_api_enabled(
+        &self,
+        ctx: ContextRouter,
+        enabled: bool,
+    ) -> Result<HttpApiSettings, String> {
+        self.set_value(&ctx.db, HTTP_API_ENABLED, enabled.to_string())
+            .await?;
+
+        self.get_http_api_settings(ctx).await
+    }
+
+    async fn set_http_api_port(
+        &self,
+        ctx: ContextRouter,
+        port: u16,
+    ) -> Result<HttpApiSettings, String> {
+        if port == 0 {
+            return Err("HTTP API port cannot be 0".to_string());
+        }
+
+        self.set_value(&ctx.db, HTTP_API_PORT, port.to_string())
+            .await?;
+
+        self.get_http_api_settings(ctx).await
+    }
+
+    async fn regenerate_http_api_token(
+        &self,
+        ctx: ContextRouter,
+    ) -> Result<HttpApiSettings, String> {
+        self.set_value(&ctx.db, HTTP_API_TOKEN, generate_token())
+            .await?;
+
+        self.get_http_api_settings(ctx).await
+    }
+}
@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    archiver::{ArchivedPage, PageArchiver},
+    context::ContextRouter,
+    dto::{
+        content::content_dto::ReaderContentDto,
+        snapshot::snapshot_dto::{CreateSnapshotDto, SnapshotContentDto, SnapshotDto},
+    },
+    events::{BookmarkChangedEvent, EventBus},
+    extractor,
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, content_repository::ContentRepository,
+        groups_repository::GroupRepository, snapshot_repository::SnapshotRepository,
+    },
+    service::bookmark_service::to_bookmark_dtos,
+    types::membership::Access,
+};
+
+use async_trait::async_trait;
+use entity::{bookmark::Model as BookmarkModel, bookmark_snapshot::Model as SnapshotModel};
+use sea_orm::{DatabaseConnection, SqlErr};
+
+/// Snapshots taken at the same time can pick the same version, the loser tries the next one
+const SAVE_ATTEMPTS: usize = 5;
+
+#[async_trait]
+pub trait SnapshotService: Send + Sync {
+    async fn snapshot_bookmark(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSnapshotDto,
+    ) -> Result<SnapshotDto, String>;
+    async fn list_snapshots(
+        &self,
+        ctx: ContextRouter,
+        bookmark_id: i32,
+    ) -> Result<Vec<SnapshotDto>, String>;
+    async fn open_snapshot(
+        &self,
+        ctx: ContextRouter,
+        snapshot_id: i32,
+    ) -> Result<SnapshotContentDto, String>;
+    async fn get_reader_content(
+        &self,
+        ctx: ContextRouter,
+        bookmark_id: i32,
+    ) -> Result<ReaderContentDto, String>;
+}
+
+pub struct SnapshotServiceImpl {
+    pub snapshot_repository: Arc<dyn SnapshotRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub content_repository: Arc<dyn ContentRepository>,
+    pub archiver: Arc<PageArchiver>,
+    pub permissions: Permissions,
+    pub events: EventBus,
+}
+
+impl SnapshotServiceImpl {
+    pub fn new(
+        snapshot_repository: Arc<dyn SnapshotRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        content_repository: Arc<dyn ContentRepository>,
+        archiver: Arc<PageArchiver>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
+        SnapshotServiceImpl {
+            snapshot_repository,
+            bookmark_repository,
+            groups_repository,
+            content_repository,
+            archiver,
+            permissions,
+            events,
+        }
+    }
+
+    /// Saves the snapshot under the bookmark's next version, then moves the archived page to
+    /// that version's file. A version taken by another snapshot in the meantime is skipped, the
+    /// unique index on `(bookmark_id, version)` tells the two apart
+    async fn save_snapshot(
+        &self,
+        db: &DatabaseConnection,
+        bookmark_id: i32,
+        archived: &ArchivedPage,
+        single_file: bool,
+    ) -> Result<SnapshotModel, String> {
+        for _ in 0..SAVE_ATTEMPTS {
+            let version = self
+                .snapshot_repository
+                .latest_version(db, bookmark_id)
+                .await
+                .map_err(|e| e.to_string())?
+                + 1;
+            let file_path = self.archiver.path_for(bookmark_id, version);
+
+            let snapshot = match self
+                .snapshot_repository
+                .create_snapshot(
+                    db,
+                    archived.to_active_model(bookmark_id, version, file_path.clone(), single_file),
+                )
+                .await
+            {
+                Ok(snapshot) => snapshot,
+                Err(e) if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                    continue
+                }
+                Err(e) => return Err(e.to_string()),
+            };
+
+            // A row whose file never arrived would fail to open, so it goes too
+            if let Err(e) = self.archiver.keep(archived, &file_path).await {
+                let _ = self
+                    .snapshot_repository
+                    .delete_snapshot(db, snapshot.id)
+                    .await;
+                return Err(e);
+            }
+            return Ok(snapshot);
+        }
+
+        Err("Snapshot version kept changing, try again".to_string())
+    }
+
+    /// Broken links and reading time both show on the bookmark, so its workspace hears about
+    /// every snapshot attempt
+    async fn publish_updated(
+        &self,
+        db: &DatabaseConnection,
+        bookmark: BookmarkModel,
+    ) -> Result<(), String> {
+        let group = self
+            .groups_repository
+            .get_group_by_id(db, bookmark.group_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut dtos =
+            to_bookmark_dtos(self.bookmark_repository.as_ref(), db, vec![bookmark]).await?;
+
+        self.events.publish(BookmarkChangedEvent::updated(
+            group.workspace_id,
+            dtos.remove(0),
+        ));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SnapshotService for SnapshotServiceImpl {
+    async fn snapshot_bookmark(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateSnapshotDto,
+    ) -> Result<SnapshotDto, String> {
+        self.permissions
+            .require_bookmark(&ctx, dto.bookmark_id, Access::Write)
+            .await?;
+
+        let bookmark = self
+            .bookmark_repository
+            .find_by_id(&ctx.db, dto.bookmark_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Bookmark not found".to_string())?;
+
+        // A failed fetch is what marks a link as broken, a successful one clears it again
+        let archived = self
+            .archiver
+            .archive(bookmark.id, &bookmark.url, dto.single_file)
+            .await;
+        let bookmark = self
+            .bookmark_repository
+            .mark_broken(&ctx.db, bookmark.id, archived.is_err())
+            .await
+            .map_err(|e| e.to_string())?;
+        let archived = match archived {
+            Ok(archived) => archived,
+            Err(e) => {
+                self.publish_updated(&ctx.db, bookmark).await?;
+                return Err(e);
+            }
+        };
+
+        // Every fresh fetch refreshes the reader text used for search and offline reading
+        let content = extractor::extract(&archived.page_html);
+        let saved = match self
+            .content_repository
+            .upsert_content(&ctx.db, content.into_active_model(bookmark.id))
+            .await
+        {
+            Ok(_) => {
+                self.save_snapshot(&ctx.db, bookmark.id, &archived, dto.single_file)
+                    .await
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        // Nothing points at the pending page unless it was kept, so it is removed either way
+        let snapshot = match saved {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.archiver.discard(&archived).await;
+                return Err(e);
+            }
+        };
+
+        self.publish_updated(&ctx.db, bookmark).await?;
+
+        Ok(snapshot.into())
+    }
+
+    async fn list_snapshots(
+        &self,
+        ctx: ContextRouter,
+        bookmark_id: i32,
+    ) -> Result<Vec<SnapshotDto>, String> {
+        self.permissions
+            .require_bookmark(&ctx, bookmark_id, Access::Read)
+            .await?;
+
+        let snapshots = self
+            .snapshot_repository
+            .list_by_bookmark(&ctx.db, bookmark_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(snapshots.into_iter().map(Into::into).collect())
+    }
+
+    async fn open_snapshot(
+        &self,
+        ctx: ContextRouter,
+        snapshot_id: i32,
+    ) -> Result<SnapshotContentDto, String> {
+        let snapshot = self
+            .snapshot_repository
+            .get_snapshot_by_id(&ctx.db, snapshot_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_bookmark(&ctx, snapshot.bookmark_id, Access::Read)
+            .await?;
+
+        let html = self.archiver.open(&snapshot.file_path).await?;
+
+        Ok(SnapshotContentDto {
+            snapshot: snapshot.into(),
+            html,
+        })
+    }
+
+    async fn get_reader_content(
+        &self,
+        ctx: ContextRouter,
+        bookmark_id: i32,
+    ) -> Result<ReaderContentDto, String> {
+        self.permissions
+            .require_bookmark(&ctx, bookmark_id, Access::Read)
+            .await?;
+
+        let content = self
+            .content_repository
+            .get_by_bookmark(&ctx.db, bookmark_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "No reader content, snapshot the bookmark first".to_string())?;
+
+        Ok(content.into())
+    }
+}
@@ -0,0 +1,274 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::{
+        bookmark::bookmark_dto::BookmarkDto,
+        stats::stats_dto::{
+            HostCountDto, OrganizationStatsDto, StatsCountsDto, StatsQueryDto, StatsScope,
+            TagCountDto, WeekCountDto, WeeklyStatsQueryDto, WorkspaceStatsDto,
+        },
+    },
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository,
+        stats_repository::{StatsRepository, WorkspaceCounts},
+    },
+    service::bookmark_service::to_bookmark_dtos,
+    types::membership::Access,
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc, Weekday};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+const DEFAULT_WEEKS: u32 = 12;
+const MAX_WEEKS: u32 = 104;
+
+#[async_trait]
+pub trait StatsService: Send + Sync {
+    async fn organization_stats(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<OrganizationStatsDto, String>;
+    async fn workspace_stats(
+        &self,
+        ctx: ContextRouter,
+        workspace_id: i32,
+    ) -> Result<WorkspaceStatsDto, String>;
+    async fn top_tags(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<TagCountDto>, String>;
+    async fn top_hosts(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<HostCountDto>, String>;
+    /// Every week of the range gets a row, oldest first
+    async fn added_per_week(
+        &self,
+        ctx: ContextRouter,
+        query: WeeklyStatsQueryDto,
+    ) -> Result<Vec<WeekCountDto>, String>;
+    async fn most_visited(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<BookmarkDto>, String>;
+}
+
+pub struct StatsServiceImpl {
+    pub stats_repository: Arc<dyn StatsRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub permissions: Permissions,
+}
+
+impl StatsServiceImpl {
+    pub fn new(
+        stats_repository: Arc<dyn StatsRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        permissions: Permissions,
+    ) -> Self {
+        StatsServiceImpl {
+            stats_repository,
+            bookmark_repository,
+            permissions,
+        }
+    }
+
+    async fn require_scope(&self, ctx: &ContextRouter, scope: &StatsScope) -> Result<(), String> {
+        match scope {
+            StatsScope::Organization { organization_id } => {
+                self.permissions
+                    .require_organization(ctx, *organization_id, Access::Read)
+                    .await
+            }
+            StatsScope::Workspace { workspace_id } => {
+                self.permissions
+                    .require_workspace(ctx, *workspace_id, Access::Read)
+                    .await
+            }
+        }
+    }
+}
+
+fn limit_of(limit: Option<u32>) -> u64 {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as u64
+}
+
+fn to_count(count: i64) -> u32 {
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+impl From<WorkspaceCounts> for WorkspaceStatsDto {
+    fn from(counts: WorkspaceCounts) -> Self {
+        WorkspaceStatsDto {
+            workspace_id: counts.workspace_id,
+            name: counts.name,
+            counts: StatsCountsDto {
+                groups: to_count(counts.groups),
+                bookmarks: to_count(counts.bookmarks),
+                favorites: to_count(counts.favorites),
+                broken_links: to_count(counts.broken_links),
+                untagged: to_count(counts.untagged),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl StatsService for StatsServiceImpl {
+    async fn organization_stats(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<OrganizationStatsDto, String> {
+        let scope = StatsScope::Organization { organization_id };
+        self.require_scope(&ctx, &scope).await?;
+
+        let workspaces: Vec<WorkspaceStatsDto> = self
+            .stats_repository
+            .count_by_workspace(&ctx.db, &scope)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        // Workspaces share no groups or bookmarks, so their counts simply add up
+        let totals = workspaces
+            .iter()
+            .fold(StatsCountsDto::default(), |totals, workspace| {
+                StatsCountsDto {
+                    groups: totals.groups + workspace.counts.groups,
+                    bookmarks: totals.bookmarks + workspace.counts.bookmarks,
+                    favorites: totals.favorites + workspace.counts.favorites,
+                    broken_links: totals.broken_links + workspace.counts.broken_links,
+                    untagged: totals.untagged + workspace.counts.untagged,
+                }
+            });
+
+        Ok(OrganizationStatsDto {
+            organization_id,
+            totals,
+            workspaces,
+        })
+    }
+
+    async fn workspace_stats(
+        &self,
+        ctx: ContextRouter,
+        workspace_id: i32,
+    ) -> Result<WorkspaceStatsDto, String> {
+        let scope = StatsScope::Workspace { workspace_id };
+        self.require_scope(&ctx, &scope).await?;
+
+        self.stats_repository
+            .count_by_workspace(&ctx.db, &scope)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .next()
+            .map(Into::into)
+            .ok_or_else(|| "Workspace not found".to_string())
+    }
+
+    async fn top_tags(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<TagCountDto>, String> {
+        self.require_scope(&ctx, &query.scope).await?;
+
+        let tags = self
+            .stats_repository
+            .top_tags(&ctx.db, &query.scope, limit_of(query.limit))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| TagCountDto {
+                tag: tag.tag,
+                count: to_count(tag.count),
+            })
+            .collect())
+    }
+
+    async fn top_hosts(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<HostCountDto>, String> {
+        self.require_scope(&ctx, &query.scope).await?;
+
+        let hosts = self
+            .stats_repository
+            .top_hosts(&ctx.db, &query.scope, limit_of(query.limit))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(hosts
+            .into_iter()
+            .map(|host| HostCountDto {
+                host: host.host,
+                count: to_count(host.count),
+            })
+            .collect())
+    }
+
+    async fn added_per_week(
+        &self,
+        ctx: ContextRouter,
+        query: WeeklyStatsQueryDto,
+    ) -> Result<Vec<WeekCountDto>, String> {
+        self.require_scope(&ctx, &query.scope).await?;
+
+        let weeks = query.weeks.unwrap_or(DEFAULT_WEEKS).clamp(1, MAX_WEEKS);
+        let this_week = Utc::now().date_naive().week(Weekday::Mon).first_day();
+        let first_week = this_week - Duration::weeks(weeks as i64 - 1);
+
+        let counts: HashMap<_, _> = self
+            .stats_repository
+            .added_per_week(
+                &ctx.db,
+                &query.scope,
+                first_week.and_time(Default::default()).and_utc(),
+            )
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|week| (week.week_start, week.count))
+            .collect();
+
+        Ok((0..weeks as i64)
+            .map(|offset| {
+                let week_start = first_week + Duration::weeks(offset);
+                WeekCountDto {
+                    week_start,
+                    count: to_count(counts.get(&week_start).copied().unwrap_or(0)),
+                }
+            })
+            .collect())
+    }
+
+    async fn most_visited(
+        &self,
+        ctx: ContextRouter,
+        query: StatsQueryDto,
+    ) -> Result<Vec<BookmarkDto>, String> {
+        self.require_scope(&ctx, &query.scope).await?;
+
+        let bookmarks = self
+            .stats_repository
+            .most_visited(&ctx.db, &query.scope, limit_of(query.limit))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        to_bookmark_dtos(self.bookmark_repository.as_ref(), &ctx.db, bookmarks).await
+    }
+}
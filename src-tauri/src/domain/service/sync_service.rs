@@ -0,0 +1,238 @@
+use std::{path::Path, sync::Arc};
+
+use crate::domain::{
+    context::ContextRouter,
+    events::{BookmarkChangedEvent, EventBus, GroupChangedEvent, WorkspaceChangedEvent},
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        saved_search_repository::SavedSearchRepository, sync_repository::SyncRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    service::saved_search_service::refresh_saved_search_counts,
+    sync::{
+        BundleChange, ChangeBundle, MergedRows, SyncExportDto, SyncExportResult, SyncImportDto,
+        SyncReport, SyncStatus, BUNDLE_VERSION,
+    },
+    types::membership::Access,
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::DatabaseConnection;
+
+#[async_trait]
+pub trait SyncService: Send + Sync {
+    async fn status(&self, ctx: ContextRouter) -> Result<SyncStatus, String>;
+    /// Writes the change log of a workspace to the shared folder
+    async fn export(
+        &self,
+        ctx: ContextRouter,
+        dto: SyncExportDto,
+    ) -> Result<SyncExportResult, String>;
+    /// Merges every bundle other instances left in the shared folder
+    async fn import(&self, ctx: ContextRouter, dto: SyncImportDto) -> Result<SyncReport, String>;
+}
+
+pub struct SyncServiceImpl {
+    pub sync_repository: Arc<dyn SyncRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub saved_search_repository: Arc<dyn SavedSearchRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
+}
+
+impl SyncServiceImpl {
+    pub fn new(
+        sync_repository: Arc<dyn SyncRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        saved_search_repository: Arc<dyn SavedSearchRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
+        SyncServiceImpl {
+            sync_repository,
+            workspace_repository,
+            groups_repository,
+            bookmark_repository,
+            saved_search_repository,
+            permissions,
+            events,
+        }
+    }
+
+    /// Change events for what a merge wrote. The merge is committed by now, so a row that is
+    /// gone again, deleted by a later bundle, is left out instead of failing the import.
+    async fn publish_merged(&self, db: &DatabaseConnection, merged: &MergedRows) {
+        let updated = merged.updated.iter().filter(|row| {
+            !merged.created.contains(row)
+                && !merged
+                    .deleted
+                    .iter()
+                    .any(|(entity_type, id, _)| (*entity_type, *id) == **row)
+        });
+        let changed = merged
+            .created
+            .iter()
+            .map(|row| (row, true))
+            .chain(updated.map(|row| (row, false)));
+
+        for (&(entity_type, id), created) in changed {
+            match entity_type {
+                "workspace" => {
+                    if let Ok(workspace) =
+                        self.workspace_repository.get_workspace_by_id(db, id).await
+                    {
+                        self.events.publish(if created {
+                            WorkspaceChangedEvent::created(workspace.into())
+                        } else {
+                            WorkspaceChangedEvent::updated(workspace.into())
+                        });
+                    }
+                }
+                "group" => {
+                    if let Ok(group) = self.groups_repository.get_group_by_id(db, id).await {
+                        self.events.publish(if created {
+                            GroupChangedEvent::created(group.into())
+                        } else {
+                            GroupChangedEvent::updated(group.into())
+                        });
+                    }
+                }
+                "bookmark" => {
+                    let Ok(Some(bookmark)) = self.bookmark_repository.find_by_id(db, id).await
+                    else {
+                        continue;
+                    };
+                    let Ok(group) = self
+                        .groups_repository
+                        .get_group_by_id(db, bookmark.group_id)
+                        .await
+                    else {
+                        continue;
+                    };
+                    self.events.publish(if created {
+                        BookmarkChangedEvent::created(group.workspace_id, bookmark.into())
+                    } else {
+                        BookmarkChangedEvent::updated(group.workspace_id, bookmark.into())
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        for &(entity_type, id, scope_id) in &merged.deleted {
+            match entity_type {
+                "workspace" => self
+                    .events
+                    .publish(WorkspaceChangedEvent::deleted(scope_id, id)),
+                "group" => self
+                    .events
+                    .publish(GroupChangedEvent::deleted(scope_id, id)),
+                "bookmark" => self
+                    .events
+                    .publish(BookmarkChangedEvent::deleted(scope_id, id)),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl SyncService for SyncServiceImpl {
+    async fn status(&self, ctx: ContextRouter) -> Result<SyncStatus, String> {
+        let instance_id = self
+            .sync_repository
+            .instance_id(&ctx.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        let changes = self
+            .sync_repository
+            .count_changes(&ctx.db)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(SyncStatus {
+            instance_id,
+            changes: changes as u32,
+        })
+    }
+
+    async fn export(
+        &self,
+        ctx: ContextRouter,
+        dto: SyncExportDto,
+    ) -> Result<SyncExportResult, String> {
+        self.permissions
+            .require_workspace(&ctx, dto.workspace_id, Access::Read)
+            .await?;
+
+        let log = self
+            .sync_repository
+            .workspace_log(&ctx.db, dto.workspace_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("Workspace not found")?;
+
+        let bundle = ChangeBundle {
+            version: BUNDLE_VERSION,
+            instance_id: log.instance_id,
+            workspace_id: log.workspace_uuid,
+            exported_at: Utc::now(),
+            seen: log.seen,
+            changes: log
+                .changes
+                .into_iter()
+                .map(|change| BundleChange {
+                    id: change.change_uuid,
+                    instance_id: change.instance_id,
+                    seq: change.seq,
+                    entity_type: change.entity_type,
+                    entity_id: change.entity_uuid,
+                    action: change.action,
+                    field: change.field,
+                    value: change
+                        .value
+                        .and_then(|value| serde_json::from_str(&value).ok()),
+                    changed_at: change.changed_at,
+                })
+                .collect(),
+        };
+        let path = bundle.write(Path::new(&dto.folder)).await?;
+
+        Ok(SyncExportResult {
+            path: path.to_string_lossy().to_string(),
+            changes: bundle.changes.len() as u32,
+        })
+    }
+
+    async fn import(&self, ctx: ContextRouter, dto: SyncImportDto) -> Result<SyncReport, String> {
+        self.permissions
+            .require_organization(&ctx, dto.organization_id, Access::Write)
+            .await?;
+        let bundles = ChangeBundle::read_folder(Path::new(&dto.folder)).await?;
+
+        let (report, merged) = self
+            .sync_repository
+            .merge_bundles(&ctx.db, &bundles, dto.organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.publish_merged(&ctx.db, &merged).await;
+        for &workspace_id in &merged.workspaces {
+            refresh_saved_search_counts(
+                self.saved_search_repository.as_ref(),
+                self.bookmark_repository.as_ref(),
+                &ctx.db,
+                workspace_id,
+            )
+            .await?;
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+
+use crate::domain::{
+    context::ContextRouter,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    tray_menu::{build_tray_menu, TrayBookmark, TrayEntry},
+};
+
+use async_trait::async_trait;
+use entity::{bookmark::Model as BookmarkModel, workspace::Model as WorkspaceModel};
+use sea_orm::DatabaseConnection;
+
+/// How many recently opened bookmarks the tray lists across all workspaces
+pub const RECENT_LIMIT: u64 = 10;
+
+#[async_trait]
+pub trait TrayService: Send + Sync {
+    async fn build_menu(&self, ctx: ContextRouter) -> Result<Vec<TrayEntry>, String>;
+}
+
+pub struct TrayServiceImpl {
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+}
+
+impl TrayServiceImpl {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+    ) -> Self {
+        TrayServiceImpl {
+            bookmark_repository,
+            groups_repository,
+            workspace_repository,
+        }
+    }
+
+    /// Resolve the workspace of every bookmark, looking each group and workspace up only once
+    async fn to_tray_bookmarks(
+        &self,
+        db: &DatabaseConnection,
+        bookmarks: Vec<BookmarkModel>,
+        workspaces_by_group: &mut HashMap<i32, WorkspaceModel>,
+    ) -> Result<Vec<TrayBookmark>, String> {
+        let mut tray_bookmarks = Vec::with_capacity(bookmarks.len());
+
+        for bookmark in bookmarks {
+            if let Entry::Vacant(entry) = workspaces_by_group.entry(bookmark.group_id) {
+                let group = self
+                    .groups_repository
+                    .get_group_by_id(db, bookmark.group_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let workspace = self
+                    .workspace_repository
+                    .get_workspace_by_id(db, group.workspace_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                entry.insert(workspace);
+            }
+
+            let workspace = &workspaces_by_group[&bookmark.group_id];
+            tray_bookmarks.push(TrayBookmark {
+                id: bookmark.id,
+                name: bookmark.name,
+                workspace_id: workspace.id,
+                workspace_name: workspace.name.clone(),
+            });
+        }
+
+        Ok(tray_bookmarks)
+    }
+}
+
+#[async_trait]
+impl TrayService for TrayServiceImpl {
+    async fn build_menu(&self, ctx: ContextRouter) -> Result<Vec<TrayEntry>, String> {
+        let favorites = self
+            .bookmark_repository
+            .find_favorites(&ctx.db)
+            .await
+            .map_err(|e| e.to_string())?;
+        let recent = self
+            .bookmark_repository
+            .find_recently_opened(&ctx.db, RECENT_LIMIT)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut workspaces_by_group = HashMap::new();
+        let favorites = self
+            .to_tray_bookmarks(&ctx.db, favorites, &mut workspaces_by_group)
+            .await?;
+        let recent = self
+            .to_tray_bookmarks(&ctx.db, recent, &mut workspaces_by_group)
+            .await?;
+
+        Ok(build_tray_menu(&favorites, &recent))
+    }
+}
@@ -1,20 +1,27 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    context::ContextRouter,
     dto::user::user_dto::{CreateUserDto, UpdateUserDto, UserDto},
     repository::user_repository::UserRepository,
-    router::ContextRouter,
+    types::pagination::{ListParams, Page},
 };
 
 use async_trait::async_trait;
+use uuid::Uuid;
 
 #[async_trait]
 pub trait UserService: Send + Sync {
     async fn create_user(&self, ctx: ContextRouter, dto: CreateUserDto) -> Result<UserDto, String>;
     async fn get_user_by_id(&self, ctx: ContextRouter, id: i32) -> Result<UserDto, String>;
+    async fn get_user_by_uuid(&self, ctx: ContextRouter, uuid: Uuid) -> Result<UserDto, String>;
     async fn update_user(&self, ctx: ContextRouter, dto: UpdateUserDto) -> Result<UserDto, String>;
     async fn delete_user(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
-    async fn list_users(&self, ctx: ContextRouter) -> Result<Vec<UserDto>, String>;
+    async fn list_users(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<UserDto>, String>;
 }
 
 pub struct UserServiceImpl {
@@ -49,6 +56,16 @@ impl UserService for UserServiceImpl {
         Ok(user.into())
     }
 
+    async fn get_user_by_uuid(&self, ctx: ContextRouter, uuid: Uuid) -> Result<UserDto, String> {
+        let user = self
+            .user_repository
+            .get_user_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(user.into())
+    }
+
     async fn update_user(&self, ctx: ContextRouter, dto: UpdateUserDto) -> Result<UserDto, String> {
         if dto.id.is_none() {
             return Err("Organization ID is required for update".to_string());
@@ -64,7 +81,7 @@ impl UserService for UserServiceImpl {
 
         let updated_user = self
             .user_repository
-            .update_user(&ctx.db, id, existing_user.into())
+            .update_user(&ctx.db, id, dto.apply_to_model(existing_user.into()))
             .await
             .map_err(|e| e.to_string())?;
 
@@ -81,13 +98,17 @@ impl UserService for UserServiceImpl {
         Ok(())
     }
 
-    async fn list_users(&self, ctx: ContextRouter) -> Result<Vec<UserDto>, String> {
+    async fn list_users(
+        &self,
+        ctx: ContextRouter,
+        params: ListParams,
+    ) -> Result<Page<UserDto>, String> {
         let users = self
             .user_repository
-            .list_users(&ctx.db)
+            .list_users(&ctx.db, &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(users.into_iter().map(Into::into).collect())
+        Ok(users.map(Into::into))
     }
 }
@@ -1,12 +1,74 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    context::ContextRouter,
     dto::workspace::workspace_dto::{CreateWorkspaceDto, UpdateWorkspaceDto, WorkspaceDto},
-    repository::workspace_repository::WorkspaceRepository,
-    router::ContextRouter,
+    events::{BookmarkChangedEvent, EventBus, GroupChangedEvent, WorkspaceChangedEvent},
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository, groups_repository::GroupRepository,
+        workspace_repository::WorkspaceRepository,
+    },
+    types::{
+        membership::Access,
+        pagination::{ListParams, Page},
+    },
 };
 
 use async_trait::async_trait;
+use entity::{bookmark::Model as BookmarkModel, groups::Model as GroupsModel};
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Groups and bookmarks the database drops along with a workspace, collected before the delete so
+/// they can be announced after it
+pub struct WorkspaceContents {
+    workspace_id: i32,
+    groups: Vec<GroupsModel>,
+    bookmarks: Vec<BookmarkModel>,
+}
+
+impl WorkspaceContents {
+    pub async fn collect(
+        groups_repository: &dyn GroupRepository,
+        bookmark_repository: &dyn BookmarkRepository,
+        db: &DatabaseConnection,
+        workspace_id: i32,
+    ) -> Result<Self, String> {
+        let groups = groups_repository
+            .find_by_workspace_id(db, workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut bookmarks = Vec::new();
+        for group in &groups {
+            bookmarks.extend(
+                bookmark_repository
+                    .get_by_group(db, group.id)
+                    .await
+                    .map_err(|e| e.to_string())?,
+            );
+        }
+
+        Ok(WorkspaceContents {
+            workspace_id,
+            groups,
+            bookmarks,
+        })
+    }
+
+    /// Bookmarks first, then the groups they were in
+    pub fn publish_deleted(self, events: &EventBus) {
+        for bookmark in self.bookmarks {
+            events.publish(BookmarkChangedEvent::deleted(
+                self.workspace_id,
+                bookmark.id,
+            ));
+        }
+        for group in self.groups {
+            events.publish(GroupChangedEvent::deleted(self.workspace_id, group.id));
+        }
+    }
+}
 
 #[async_trait]
 pub trait WorkspaceService: Send + Sync {
@@ -20,6 +82,11 @@ pub trait WorkspaceService: Send + Sync {
         ctx: ContextRouter,
         id: i32,
     ) -> Result<WorkspaceDto, String>;
+    async fn get_workspace_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<WorkspaceDto, String>;
     async fn update_workspace(
         &self,
         ctx: ContextRouter,
@@ -30,17 +97,32 @@ pub trait WorkspaceService: Send + Sync {
         &self,
         ctx: ContextRouter,
         organization_id: i32,
-    ) -> Result<Vec<WorkspaceDto>, String>;
+        params: ListParams,
+    ) -> Result<Page<WorkspaceDto>, String>;
 }
 
 pub struct WorkspaceServiceImpl {
     pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
 }
 
 impl WorkspaceServiceImpl {
-    pub fn new(workspace_repository: Arc<dyn WorkspaceRepository>) -> Self {
+    pub fn new(
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
         WorkspaceServiceImpl {
             workspace_repository,
+            groups_repository,
+            bookmark_repository,
+            permissions,
+            events,
         }
     }
 }
@@ -52,13 +134,21 @@ impl WorkspaceService for WorkspaceServiceImpl {
         ctx: ContextRouter,
         dto: CreateWorkspaceDto,
     ) -> Result<WorkspaceDto, String> {
+        self.permissions
+            .require_organization(&ctx, dto.organization_id, Access::Write)
+            .await?;
+
         let workspace = self
             .workspace_repository
             .create_workspace(&ctx.db, dto.into())
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(workspace.into())
+        let workspace: WorkspaceDto = workspace.into();
+        self.events
+            .publish(WorkspaceChangedEvent::created(workspace.clone()));
+
+        Ok(workspace)
     }
 
     async fn get_workspace_by_id(
@@ -75,6 +165,20 @@ impl WorkspaceService for WorkspaceServiceImpl {
         Ok(workspace.into())
     }
 
+    async fn get_workspace_by_uuid(
+        &self,
+        ctx: ContextRouter,
+        uuid: Uuid,
+    ) -> Result<WorkspaceDto, String> {
+        let workspace = self
+            .workspace_repository
+            .get_workspace_by_uuid(&ctx.db, &uuid.to_string())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(workspace.into())
+    }
+
     async fn update_workspace(
         &self,
         ctx: ContextRouter,
@@ -85,6 +189,14 @@ impl WorkspaceService for WorkspaceServiceImpl {
         }
 
         let id = dto.id.unwrap();
+        self.permissions
+            .require_workspace(&ctx, id, Access::Write)
+            .await?;
+        if let Some(organization_id) = dto.organization_id {
+            self.permissions
+                .require_organization(&ctx, organization_id, Access::Write)
+                .await?;
+        }
 
         let updated_workspace = self
             .workspace_repository
@@ -92,16 +204,43 @@ impl WorkspaceService for WorkspaceServiceImpl {
             .await
             .map_err(|e| e.to_string())?;
 
-        Ok(updated_workspace.into())
+        let updated_workspace: WorkspaceDto = updated_workspace.into();
+        self.events
+            .publish(WorkspaceChangedEvent::updated(updated_workspace.clone()));
+
+        Ok(updated_workspace)
     }
 
     async fn delete_workspace(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
-        let _deleted_workspace = self
+        let found_workspace = self
             .workspace_repository
+            .get_workspace_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_organization(&ctx, found_workspace.organization_id, Access::Write)
+            .await?;
+
+        // Groups and their bookmarks go with the workspace, collect them first to announce them
+        let contents = WorkspaceContents::collect(
+            self.groups_repository.as_ref(),
+            self.bookmark_repository.as_ref(),
+            &ctx.db,
+            id,
+        )
+        .await?;
+
+        self.workspace_repository
             .delete_workspace(&ctx.db, id)
             .await
             .map_err(|e| e.to_string())?;
 
+        contents.publish_deleted(&self.events);
+        self.events.publish(WorkspaceChangedEvent::deleted(
+            found_workspace.organization_id,
+            id,
+        ));
+
         Ok(())
     }
 
@@ -109,15 +248,18 @@ impl WorkspaceService for WorkspaceServiceImpl {
         &self,
         ctx: ContextRouter,
         organization_id: i32,
-    ) -> Result<Vec<WorkspaceDto>, String> {
+        params: ListParams,
+    ) -> Result<Page<WorkspaceDto>, String> {
+        self.permissions
+            .require_organization(&ctx, organization_id, Access::Read)
+            .await?;
+
         let workspaces = self
             .workspace_repository
-            .list_workspace(&ctx.db, organization_id)
+            .list_workspace(&ctx.db, organization_id, &params)
             .await
             .map_err(|e| e.to_string())?;
 
-        println!("Workspaces found: {:?}", workspaces);
-
-        Ok(workspaces.into_iter().map(Into::into).collect())
+        Ok(workspaces.map(Into::into))
     }
 }
@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    context::ContextRouter,
+    dto::{
+        bookmark::bookmark_dto::CreateBookmarkDto,
+        groups::groups_dto::{CreateGroupsDto, CreateSmartGroupDto},
+        workspace::workspace_dto::{CreateWorkspaceDto, WorkspaceDto},
+        workspace_template::workspace_template_dto::{
+            CreateFromTemplateDto, SaveWorkspaceTemplateDto, TemplateBookmark, TemplateExport,
+            TemplateGroup, TemplateLayout, WorkspaceTemplateDto,
+        },
+    },
+    events::{EventBus, WorkspaceChangedEvent},
+    export::{file_name, ExportFile},
+    permissions::Permissions,
+    repository::{
+        bookmark_repository::BookmarkRepository,
+        groups_repository::GroupRepository,
+        organization_repository::OrganizationRepository,
+        workspace_repository::WorkspaceRepository,
+        workspace_template_repository::{GroupWithBookmarks, WorkspaceTemplateRepository},
+    },
+    types::{
+        groups::{GroupKind, SmartGroupFilter},
+        membership::Access,
+    },
+    util::{new_uuid, parse_uuid},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::workspace_template;
+use sea_orm::ActiveValue::Set;
+
+/// Bumped when the exported JSON changes shape
+pub const TEMPLATE_EXPORT_VERSION: u32 = 1;
+
+#[async_trait]
+pub trait WorkspaceTemplateService: Send + Sync {
+    /// Saves the groups of a workspace, and optionally their bookmarks, as a template of its
+    /// organization
+    async fn save_template(
+        &self,
+        ctx: ContextRouter,
+        dto: SaveWorkspaceTemplateDto,
+    ) -> Result<WorkspaceTemplateDto, String>;
+    async fn list_templates(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceTemplateDto>, String>;
+    async fn delete_template(&self, ctx: ContextRouter, id: i32) -> Result<(), String>;
+    async fn export_template(&self, ctx: ContextRouter, id: i32) -> Result<ExportFile, String>;
+    async fn create_workspace(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateFromTemplateDto,
+    ) -> Result<WorkspaceDto, String>;
+}
+
+pub struct WorkspaceTemplateServiceImpl {
+    pub template_repository: Arc<dyn WorkspaceTemplateRepository>,
+    pub organization_repository: Arc<dyn OrganizationRepository>,
+    pub workspace_repository: Arc<dyn WorkspaceRepository>,
+    pub groups_repository: Arc<dyn GroupRepository>,
+    pub bookmark_repository: Arc<dyn BookmarkRepository>,
+    pub permissions: Permissions,
+    pub events: EventBus,
+}
+
+impl WorkspaceTemplateServiceImpl {
+    pub fn new(
+        template_repository: Arc<dyn WorkspaceTemplateRepository>,
+        organization_repository: Arc<dyn OrganizationRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        groups_repository: Arc<dyn GroupRepository>,
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        permissions: Permissions,
+        events: EventBus,
+    ) -> Self {
+        WorkspaceTemplateServiceImpl {
+            template_repository,
+            organization_repository,
+            workspace_repository,
+            groups_repository,
+            bookmark_repository,
+            permissions,
+            events,
+        }
+    }
+
+    async fn layout_of(
+        &self,
+        ctx: &ContextRouter,
+        workspace_id: i32,
+        include_bookmarks: bool,
+    ) -> Result<TemplateLayout, String> {
+        let groups = self
+            .groups_repository
+            .find_by_workspace_id(&ctx.db, workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut layout = TemplateLayout::default();
+        for group in groups {
+            let kind = GroupKind::from_db(&group.kind);
+            // Smart groups are saved as their filter, whatever matches it now is not copied
+            let bookmarks = if include_bookmarks && kind == GroupKind::Static {
+                self.bookmark_repository
+                    .get_by_group(&ctx.db, group.id)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .into_iter()
+                    .map(|bookmark| TemplateBookmark {
+                        name: bookmark.name,
+                        url: bookmark.url,
+                        tags: bookmark.tags,
+                        is_favorite: bookmark.is_favorite,
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            layout.groups.push(TemplateGroup {
+                name: group.name,
+                kind,
+                filter: group
+                    .filter
+                    .as_deref()
+                    .and_then(|filter| SmartGroupFilter::from_json(filter).ok()),
+                bookmarks,
+            });
+        }
+
+        Ok(layout)
+    }
+}
+
+/// Rows for the groups and bookmarks of a layout, ids are filled in as they are inserted
+fn layout_rows(layout: TemplateLayout) -> Vec<GroupWithBookmarks> {
+    layout
+        .groups
+        .into_iter()
+        .map(|group| GroupWithBookmarks {
+            group: match group.kind {
+                GroupKind::Static => CreateGroupsDto {
+                    name: group.name,
+                    workspace_id: 0,
+                }
+                .into(),
+                GroupKind::Smart => CreateSmartGroupDto {
+                    name: group.name,
+                    workspace_id: 0,
+                    filter: group.filter.unwrap_or_default(),
+                }
+                .into(),
+            },
+            bookmarks: group
+                .bookmarks
+                .into_iter()
+                .map(|bookmark| {
+                    CreateBookmarkDto {
+                        name: bookmark.name,
+                        url: bookmark.url,
+                        tags: bookmark.tags,
+                        is_favorite: bookmark.is_favorite,
+                        group_id: 0,
+                    }
+                    .into()
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn required_name(name: &str, what: &str) -> Result<String, String> {
+    match name.trim() {
+        "" => Err(format!("A {} name is required", what)),
+        name => Ok(name.to_string()),
+    }
+}
+
+#[async_trait]
+impl WorkspaceTemplateService for WorkspaceTemplateServiceImpl {
+    async fn save_template(
+        &self,
+        ctx: ContextRouter,
+        dto: SaveWorkspaceTemplateDto,
+    ) -> Result<WorkspaceTemplateDto, String> {
+        let name = required_name(&dto.name, "template")?;
+        let workspace = self
+            .workspace_repository
+            .get_workspace_by_id(&ctx.db, dto.workspace_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_organization(&ctx, workspace.organization_id, Access::Write)
+            .await?;
+
+        let layout = self
+            .layout_of(&ctx, workspace.id, dto.include_bookmarks)
+            .await?;
+
+        let now = Utc::now();
+        let template = self
+            .template_repository
+            .create_template(
+                &ctx.db,
+                workspace_template::ActiveModel {
+                    uuid: Set(new_uuid()),
+                    organization_id: Set(workspace.organization_id),
+                    name: Set(name),
+                    layout: Set(layout.to_json()),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(template.into())
+    }
+
+    async fn list_templates(
+        &self,
+        ctx: ContextRouter,
+        organization_id: i32,
+    ) -> Result<Vec<WorkspaceTemplateDto>, String> {
+        self.permissions
+            .require_organization(&ctx, organization_id, Access::Read)
+            .await?;
+
+        let templates = self
+            .template_repository
+            .list_by_organization(&ctx.db, organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(templates.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_template(&self, ctx: ContextRouter, id: i32) -> Result<(), String> {
+        let template = self
+            .template_repository
+            .get_template_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_organization(&ctx, template.organization_id, Access::Write)
+            .await?;
+
+        self.template_repository
+            .delete_template(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn export_template(&self, ctx: ContextRouter, id: i32) -> Result<ExportFile, String> {
+        let template = self
+            .template_repository
+            .get_template_by_id(&ctx.db, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_organization(&ctx, template.organization_id, Access::Read)
+            .await?;
+        let organization = self
+            .organization_repository
+            .get_organization_by_id(&ctx.db, template.organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let export = TemplateExport {
+            version: TEMPLATE_EXPORT_VERSION,
+            uuid: parse_uuid(&template.uuid),
+            organization_uuid: parse_uuid(&organization.uuid),
+            name: template.name.clone(),
+            exported_at: Utc::now(),
+            layout: TemplateLayout::from_json(&template.layout)?,
+        };
+        let content = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+
+        Ok(ExportFile {
+            file_name: file_name(&template.name, "json"),
+            mime_type: "application/json".to_string(),
+            content,
+        })
+    }
+
+    async fn create_workspace(
+        &self,
+        ctx: ContextRouter,
+        dto: CreateFromTemplateDto,
+    ) -> Result<WorkspaceDto, String> {
+        let name = required_name(&dto.name, "workspace")?;
+        let template = self
+            .template_repository
+            .get_template_by_id(&ctx.db, dto.template_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.organization_repository
+            .get_organization_by_id(&ctx.db, dto.organization_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.permissions
+            .require_organization(&ctx, template.organization_id, Access::Read)
+            .await?;
+        self.permissions
+            .require_organization(&ctx, dto.organization_id, Access::Write)
+            .await?;
+
+        let layout = TemplateLayout::from_json(&template.layout)?;
+        let workspace = self
+            .template_repository
+            .create_workspace(
+                &ctx.db,
+                CreateWorkspaceDto {
+                    name,
+                    organization_id: dto.organization_id,
+                }
+                .into(),
+                layout_rows(layout),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let workspace: WorkspaceDto = workspace.into();
+        self.events
+            .publish(WorkspaceChangedEvent::created(workspace.clone()));
+
+        Ok(workspace)
+    }
+}
@@ -0,0 +1,34 @@
+//! The user rayzen acts as. Every `ContextRouter` clone shares it, so signing in from one
+//! window applies to every procedure after it. Nobody being signed in is the single user mode
+//! rayzen started with, permission checks only apply once someone signs in.
+//!
+//! Signing in takes a user id and no credential. Anyone who can run rayzen can also open its
+//! database file, so the session only picks whose view and roles apply. Roles keep members
+//! from changing or seeing what they shouldn't by accident, they are not access control.
+
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    user_id: Arc<RwLock<Option<i32>>>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn user_id(&self) -> Option<i32> {
+        *self
+            .user_id
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn set_user_id(&self, user_id: Option<i32>) {
+        *self
+            .user_id
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = user_id;
+    }
+}
@@ -0,0 +1,353 @@
+//! The change log is built from the audit log: every audit event of a synced table after the
+//! cursor becomes one change per field it touched. Rows that existed before the first sync are
+//! logged as created.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use entity::{
+    audit_event::{self, Entity as AuditEvent},
+    sync_change::{self, ActiveModel as SyncChangeActiveModel, Entity as SyncChange},
+    sync_identity::{self, ActiveModel as SyncIdentityActiveModel, Entity as SyncIdentity},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Statement,
+};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::domain::{
+    sync::{
+        get_setting, set_setting, synced_table, SyncedTable, ACTION_DELETE, ACTION_SET,
+        SYNCED_TABLES,
+    },
+    types::settings::SYNC_AUDIT_CURSOR,
+};
+
+pub async fn find_identity<C: ConnectionTrait>(
+    db: &C,
+    entity_type: &str,
+    local_id: i32,
+) -> Result<Option<sync_identity::Model>, DbErr> {
+    SyncIdentity::find()
+        .filter(sync_identity::Column::EntityType.eq(entity_type))
+        .filter(sync_identity::Column::LocalId.eq(local_id))
+        .one(db)
+        .await
+}
+
+pub async fn find_identity_by_uuid<C: ConnectionTrait>(
+    db: &C,
+    uuid: &str,
+) -> Result<Option<sync_identity::Model>, DbErr> {
+    SyncIdentity::find()
+        .filter(sync_identity::Column::Uuid.eq(uuid))
+        .one(db)
+        .await
+}
+
+pub async fn mark_deleted<C: ConnectionTrait>(
+    db: &C,
+    identity: sync_identity::Model,
+) -> Result<(), DbErr> {
+    let mut identity: SyncIdentityActiveModel = identity.into();
+    identity.deleted = Set(true);
+    identity.update(db).await?;
+    Ok(())
+}
+
+async fn latest_audit_id<C: ConnectionTrait>(db: &C) -> Result<i32, DbErr> {
+    Ok(AuditEvent::find()
+        .order_by_desc(audit_event::Column::Id)
+        .one(db)
+        .await?
+        .map(|event| event.id)
+        .unwrap_or(0))
+}
+
+async fn audit_cursor<C: ConnectionTrait>(db: &C) -> Result<Option<i32>, DbErr> {
+    Ok(get_setting(db, SYNC_AUDIT_CURSOR)
+        .await?
+        .and_then(|cursor| cursor.parse().ok()))
+}
+
+fn parse_row(json: Option<&str>) -> Map<String, Value> {
+    json.and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default()
+}
+
+/// Appends changes signed by this instance
+pub struct ChangeLog<'a, C: ConnectionTrait> {
+    db: &'a C,
+    instance_id: &'a str,
+    next_seq: i32,
+    pub recorded: u32,
+}
+
+impl<'a, C: ConnectionTrait> ChangeLog<'a, C> {
+    pub async fn open(db: &'a C, instance_id: &'a str) -> Result<Self, DbErr> {
+        let last = SyncChange::find()
+            .filter(sync_change::Column::InstanceId.eq(instance_id))
+            .order_by_desc(sync_change::Column::Seq)
+            .one(db)
+            .await?;
+
+        Ok(ChangeLog {
+            db,
+            instance_id,
+            next_seq: last.map(|change| change.seq + 1).unwrap_or(1),
+            recorded: 0,
+        })
+    }
+
+    /// Logs audit events since the last call and moves the cursor past them
+    pub async fn record(&mut self) -> Result<u32, DbErr> {
+        let latest = latest_audit_id(self.db).await?;
+
+        match audit_cursor(self.db).await? {
+            None => {
+                for table in SYNCED_TABLES {
+                    self.record_existing(table).await?;
+                }
+            }
+            Some(cursor) => {
+                let events = AuditEvent::find()
+                    .filter(audit_event::Column::Id.gt(cursor))
+                    .filter(audit_event::Column::Id.lte(latest))
+                    .filter(
+                        audit_event::Column::EntityType
+                            .is_in(SYNCED_TABLES.iter().map(|table| table.entity_type)),
+                    )
+                    .order_by_asc(audit_event::Column::Id)
+                    .all(self.db)
+                    .await?;
+
+                for event in events {
+                    self.record_event(event).await?;
+                }
+            }
+        }
+
+        set_setting(self.db, SYNC_AUDIT_CURSOR, latest.to_string()).await?;
+        Ok(self.recorded)
+    }
+
+    async fn record_existing(&mut self, table: &'static SyncedTable) -> Result<(), DbErr> {
+        let rows = self
+            .db
+            .query_all(Statement::from_string(
+                self.db.get_database_backend(),
+                table.select_rows(),
+            ))
+            .await?;
+
+        let now = Utc::now();
+        for row in rows {
+            let id: i32 = row.try_get("", "id")?;
+            let json: String = row.try_get("", "row")?;
+            self.record_row(table, id, &parse_row(Some(&json)), None, now)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn record_event(&mut self, event: audit_event::Model) -> Result<(), DbErr> {
+        let Some(table) = synced_table(&event.entity_type) else {
+            return Ok(());
+        };
+
+        let after = parse_row(event.after.as_deref());
+        match event.action.as_str() {
+            "create" => {
+                self.record_row(table, event.entity_id, &after, None, event.created_at)
+                    .await
+            }
+            "update" => {
+                let before = parse_row(event.before.as_deref());
+                self.record_row(
+                    table,
+                    event.entity_id,
+                    &after,
+                    Some(&before),
+                    event.created_at,
+                )
+                .await
+            }
+            _ => {
+                let identity = find_identity(self.db, table.entity_type, event.entity_id).await?;
+                if let Some(identity) = identity.filter(|identity| !identity.deleted) {
+                    self.push(&identity, ACTION_DELETE, None, None, event.created_at)
+                        .await?;
+                    mark_deleted(self.db, identity).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Logs the fields that differ from `before`, all of them for a new row
+    async fn record_row(
+        &mut self,
+        table: &'static SyncedTable,
+        local_id: i32,
+        row: &Map<String, Value>,
+        before: Option<&Map<String, Value>>,
+        changed_at: DateTime<Utc>,
+    ) -> Result<(), DbErr> {
+        let parent = match &table.parent {
+            Some(parent) => {
+                let parent_id = row
+                    .get(parent.column)
+                    .and_then(Value::as_i64)
+                    .unwrap_or_default() as i32;
+                match find_identity(self.db, parent.entity_type, parent_id).await? {
+                    Some(identity) => Some(identity),
+                    // The parent was never logged, so neither is the row
+                    None => return Ok(()),
+                }
+            }
+            None => None,
+        };
+
+        let existing = find_identity(self.db, table.entity_type, local_id).await?;
+        // A row logged for the first time needs every field, whatever the event changed
+        let before = before.filter(|_| existing.is_some());
+        let identity = match existing {
+            Some(identity) => identity,
+            None => {
+                // Shared under the row's own UUID, rows from before it existed get a new one
+                let uuid = row
+                    .get("uuid")
+                    .and_then(Value::as_str)
+                    .filter(|uuid| !uuid.is_empty())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| Uuid::new_v4().to_string());
+                SyncIdentityActiveModel {
+                    entity_type: Set(table.entity_type.to_string()),
+                    local_id: Set(local_id),
+                    workspace_uuid: Set(parent
+                        .as_ref()
+                        .map(|parent| parent.workspace_uuid.clone())
+                        .unwrap_or_else(|| uuid.clone())),
+                    uuid: Set(uuid),
+                    deleted: Set(false),
+                    ..Default::default()
+                }
+                .insert(self.db)
+                .await?
+            }
+        };
+        if identity.deleted {
+            return Ok(());
+        }
+
+        // A row moved to another workspace is shared with that workspace from now on
+        let identity = match &parent {
+            Some(parent) if parent.workspace_uuid != identity.workspace_uuid => {
+                let mut moved: SyncIdentityActiveModel = identity.into();
+                moved.workspace_uuid = Set(parent.workspace_uuid.clone());
+                moved.update(self.db).await?
+            }
+            _ => identity,
+        };
+
+        let previous = before.map(|before| table.values(before));
+        for (index, (field, value)) in table.values(row).into_iter().enumerate() {
+            let unchanged = previous
+                .as_ref()
+                .is_some_and(|previous| previous[index].1 == value);
+            if !unchanged {
+                self.push(&identity, ACTION_SET, Some(field), Some(value), changed_at)
+                    .await?;
+            }
+        }
+
+        if let (Some(synced), Some(parent)) = (&table.parent, &parent) {
+            let moved =
+                before.is_none_or(|before| before.get(synced.column) != row.get(synced.column));
+            if moved {
+                self.push(
+                    &identity,
+                    ACTION_SET,
+                    Some(synced.field),
+                    Some(Value::String(parent.uuid.clone())),
+                    changed_at,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn push(
+        &mut self,
+        identity: &sync_identity::Model,
+        action: &str,
+        field: Option<&str>,
+        value: Option<Value>,
+        changed_at: DateTime<Utc>,
+    ) -> Result<(), DbErr> {
+        SyncChangeActiveModel {
+            change_uuid: Set(Uuid::new_v4().to_string()),
+            instance_id: Set(self.instance_id.to_string()),
+            seq: Set(self.next_seq),
+            entity_type: Set(identity.entity_type.clone()),
+            entity_uuid: Set(identity.uuid.clone()),
+            workspace_uuid: Set(identity.workspace_uuid.clone()),
+            action: Set(action.to_string()),
+            field: Set(field.map(str::to_string)),
+            value: Set(value.map(|value| value.to_string())),
+            changed_at: Set(changed_at),
+            ..Default::default()
+        }
+        .insert(self.db)
+        .await?;
+
+        self.next_seq += 1;
+        self.recorded += 1;
+        Ok(())
+    }
+}
+
+/// Moves the cursor past the audit events a merge wrote, they came from another instance.
+/// Rows deleted along with a merged delete are only marked as deleted.
+pub async fn skip_merged<C: ConnectionTrait>(db: &C) -> Result<(), DbErr> {
+    let latest = latest_audit_id(db).await?;
+    let cursor = audit_cursor(db).await?.unwrap_or(latest);
+
+    let deletes = AuditEvent::find()
+        .filter(audit_event::Column::Id.gt(cursor))
+        .filter(audit_event::Column::Id.lte(latest))
+        .filter(audit_event::Column::Action.eq("delete"))
+        .all(db)
+        .await?;
+    for event in deletes {
+        if let Some(identity) = find_identity(db, &event.entity_type, event.entity_id).await? {
+            if !identity.deleted {
+                mark_deleted(db, identity).await?;
+            }
+        }
+    }
+
+    set_setting(db, SYNC_AUDIT_CURSOR, latest.to_string()).await
+}
+
+/// Highest `seq` of every instance in the log
+pub async fn seen<C: ConnectionTrait>(db: &C) -> Result<BTreeMap<String, i32>, DbErr> {
+    let rows: Vec<(String, Option<i32>)> = SyncChange::find()
+        .select_only()
+        .column(sync_change::Column::InstanceId)
+        .column_as(sync_change::Column::Seq.max(), "seq")
+        .group_by(sync_change::Column::InstanceId)
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(instance_id, seq)| (instance_id, seq.unwrap_or_default()))
+        .collect())
+}
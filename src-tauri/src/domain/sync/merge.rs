@@ -0,0 +1,481 @@
+//! Merges the bundle of another instance into the local database. Each field keeps the value
+//! with the newest `changed_at`, the instance id breaks ties so every instance picks the same
+//! winner. A conflict is reported when the other side overwrote, or lost to, a local edit it
+//! hadn't seen yet.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use entity::{
+    sync_change::{self, ActiveModel as SyncChangeActiveModel, Entity as SyncChange},
+    sync_identity::{self, ActiveModel as SyncIdentityActiveModel},
+    workspace::Entity as Workspace,
+};
+use sea_orm::{
+    sea_query::{Alias, Expr, Query},
+    ActiveModelTrait,
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+use serde_json::Value;
+
+use crate::domain::sync::{
+    log::{find_identity, find_identity_by_uuid, mark_deleted},
+    BundleChange, ChangeBundle, MergedRows, SyncConflict, SyncReport, SyncWinner, SyncedTable,
+    ACTION_DELETE, ACTION_SET, SYNCED_TABLES,
+};
+
+/// Merges one bundle, rows shared for the first time are created in `organization_id`. The
+/// local rows it wrote are added to `merged`.
+pub async fn merge_bundle<C: ConnectionTrait>(
+    db: &C,
+    instance_id: &str,
+    bundle: &ChangeBundle,
+    organization_id: i32,
+    report: &mut SyncReport,
+    merged: &mut MergedRows,
+) -> Result<(), DbErr> {
+    let mut merge = Merge {
+        db,
+        instance_id,
+        workspace_uuid: &bundle.workspace_id,
+        workspace_id: None,
+        // Our changes up to here were merged by the other side before it made its own
+        seen: bundle.seen.get(instance_id).copied().unwrap_or_default(),
+        organization_id,
+        report,
+        merged,
+    };
+
+    let mut changes = Vec::new();
+    for change in &bundle.changes {
+        let known = SyncChange::find()
+            .filter(sync_change::Column::ChangeUuid.eq(&change.id))
+            .one(db)
+            .await?
+            .is_some();
+        if !known {
+            changes.push(change);
+        }
+    }
+    changes.sort_by(|a, b| {
+        (a.changed_at, &a.instance_id, a.seq).cmp(&(b.changed_at, &b.instance_id, b.seq))
+    });
+
+    for table in SYNCED_TABLES {
+        // Rows in the order they were first changed, each with its field changes
+        let mut rows: BTreeMap<&str, (usize, Vec<&BundleChange>)> = BTreeMap::new();
+        for (position, change) in changes.iter().enumerate() {
+            if change.entity_type == table.entity_type && change.action == ACTION_SET {
+                rows.entry(&change.entity_id)
+                    .or_insert_with(|| (position, Vec::new()))
+                    .1
+                    .push(change);
+            }
+        }
+        let mut rows: Vec<_> = rows.into_iter().collect();
+        rows.sort_by_key(|(_, (position, _))| *position);
+
+        for (entity_uuid, (_, sets)) in rows {
+            merge.merge_row(table, entity_uuid, &sets).await?;
+        }
+    }
+
+    // The workspace exists here now unless it was never shared completely
+    merge.workspace_id = find_identity_by_uuid(db, &bundle.workspace_id)
+        .await?
+        .filter(|identity| !identity.deleted)
+        .map(|identity| identity.local_id);
+    merge.merged.workspaces.extend(merge.workspace_id);
+
+    for table in SYNCED_TABLES.iter().rev() {
+        for change in changes
+            .iter()
+            .filter(|change| change.entity_type == table.entity_type)
+            .filter(|change| change.action == ACTION_DELETE)
+        {
+            merge.merge_delete(table, change).await?;
+        }
+    }
+
+    merge.report.bundles += 1;
+    Ok(())
+}
+
+struct Merge<'a, C: ConnectionTrait> {
+    db: &'a C,
+    instance_id: &'a str,
+    workspace_uuid: &'a str,
+    /// Local id of the bundle's workspace, known once its rows are merged
+    workspace_id: Option<i32>,
+    seen: i32,
+    organization_id: i32,
+    report: &'a mut SyncReport,
+    merged: &'a mut MergedRows,
+}
+
+impl<C: ConnectionTrait> Merge<'_, C> {
+    async fn merge_row(
+        &mut self,
+        table: &'static SyncedTable,
+        entity_uuid: &str,
+        sets: &[&BundleChange],
+    ) -> Result<(), DbErr> {
+        match find_identity_by_uuid(self.db, entity_uuid).await? {
+            None => {
+                // A copy that got here another way, like a re-imported CSV export, already
+                // has the UUID and is the same row
+                if let Some(identity) = self.attach_local_row(table, entity_uuid).await? {
+                    for change in sets {
+                        self.merge_field(table, &identity, change).await?;
+                        self.store(change).await?;
+                    }
+                    return Ok(());
+                }
+
+                match self.insert_row(table, entity_uuid, sets).await? {
+                    Some(local_id) => {
+                        self.attach(table, entity_uuid, local_id).await?;
+                        self.report.created += 1;
+                        self.merged.created.push((table.entity_type, local_id));
+                        for change in sets {
+                            self.store(change).await?;
+                        }
+                    }
+                    // Left out of the log so the row is created by a later import, once its
+                    // parent or missing fields have arrived
+                    None => self.report.skipped += sets.len() as u32,
+                }
+            }
+            Some(identity) if identity.deleted => {
+                self.report.skipped += sets.len() as u32;
+                for change in sets {
+                    self.store(change).await?;
+                }
+            }
+            Some(identity) => {
+                for change in sets {
+                    self.merge_field(table, &identity, change).await?;
+                    self.store(change).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn merge_field(
+        &mut self,
+        table: &'static SyncedTable,
+        identity: &sync_identity::Model,
+        change: &BundleChange,
+    ) -> Result<(), DbErr> {
+        let Some(field) = change.field.as_deref() else {
+            self.report.skipped += 1;
+            return Ok(());
+        };
+
+        let latest = SyncChange::find()
+            .filter(sync_change::Column::EntityUuid.eq(&identity.uuid))
+            .filter(sync_change::Column::Field.eq(field))
+            .filter(sync_change::Column::Action.eq(ACTION_SET))
+            .order_by_desc(sync_change::Column::ChangedAt)
+            .order_by_desc(sync_change::Column::InstanceId)
+            .one(self.db)
+            .await?;
+
+        let remote_wins = latest.as_ref().is_none_or(|local| {
+            (change.changed_at, change.instance_id.as_str())
+                > (local.changed_at, local.instance_id.as_str())
+        });
+        let remote_value = stored_value(change);
+
+        if let Some(local) = latest.filter(|local| {
+            local.instance_id == self.instance_id
+                && local.seq > self.seen
+                && local.value != remote_value
+        }) {
+            self.report.conflicts.push(SyncConflict {
+                entity_type: table.entity_type.to_string(),
+                entity_id: identity.uuid.clone(),
+                field: Some(field.to_string()),
+                local_value: local.value,
+                remote_value: remote_value.clone(),
+                winner: if remote_wins {
+                    SyncWinner::Remote
+                } else {
+                    SyncWinner::Local
+                },
+            });
+        }
+
+        let value = change.value.clone().unwrap_or(Value::Null);
+        if remote_wins
+            && self
+                .update_field(table, identity.local_id, field, &value)
+                .await?
+        {
+            self.report.applied += 1;
+            self.merged
+                .updated
+                .insert((table.entity_type, identity.local_id));
+        } else {
+            self.report.skipped += 1;
+        }
+
+        Ok(())
+    }
+
+    async fn merge_delete(
+        &mut self,
+        table: &'static SyncedTable,
+        change: &BundleChange,
+    ) -> Result<(), DbErr> {
+        let identity = find_identity_by_uuid(self.db, &change.entity_id).await?;
+
+        match identity.filter(|identity| !identity.deleted) {
+            Some(identity) => {
+                let unseen = SyncChange::find()
+                    .filter(sync_change::Column::EntityUuid.eq(&identity.uuid))
+                    .filter(sync_change::Column::InstanceId.eq(self.instance_id))
+                    .filter(sync_change::Column::Seq.gt(self.seen))
+                    .one(self.db)
+                    .await?;
+                if unseen.is_some() {
+                    self.report.conflicts.push(SyncConflict {
+                        entity_type: table.entity_type.to_string(),
+                        entity_id: identity.uuid.clone(),
+                        field: None,
+                        local_value: None,
+                        remote_value: None,
+                        winner: SyncWinner::Remote,
+                    });
+                }
+
+                let scope_id = match &table.parent {
+                    Some(_) => self.workspace_id,
+                    None => {
+                        self.merged.workspaces.remove(&identity.local_id);
+                        Workspace::find_by_id(identity.local_id)
+                            .one(self.db)
+                            .await?
+                            .map(|workspace| workspace.organization_id)
+                    }
+                };
+                if let Some(scope_id) = scope_id {
+                    self.merged
+                        .deleted
+                        .push((table.entity_type, identity.local_id, scope_id));
+                }
+
+                let delete = Query::delete()
+                    .from_table(Alias::new(table.table))
+                    .and_where(Expr::col(Alias::new("id")).eq(identity.local_id))
+                    .to_owned();
+                self.db
+                    .execute(self.db.get_database_backend().build(&delete))
+                    .await?;
+                mark_deleted(self.db, identity).await?;
+                self.report.deleted += 1;
+            }
+            None => self.report.skipped += 1,
+        }
+
+        self.store(change).await
+    }
+
+    /// Shares a local row under `entity_uuid`
+    async fn attach(
+        &self,
+        table: &'static SyncedTable,
+        entity_uuid: &str,
+        local_id: i32,
+    ) -> Result<sync_identity::Model, DbErr> {
+        SyncIdentityActiveModel {
+            entity_type: Set(table.entity_type.to_string()),
+            local_id: Set(local_id),
+            uuid: Set(entity_uuid.to_string()),
+            workspace_uuid: Set(self.workspace_uuid.to_string()),
+            deleted: Set(false),
+            ..Default::default()
+        }
+        .insert(self.db)
+        .await
+    }
+
+    /// Identity of a local row whose `uuid` column already holds `entity_uuid`, attached now
+    /// if the row wasn't shared yet. Inserting it again would hit the unique index.
+    async fn attach_local_row(
+        &self,
+        table: &'static SyncedTable,
+        entity_uuid: &str,
+    ) -> Result<Option<sync_identity::Model>, DbErr> {
+        let select = Query::select()
+            .column(Alias::new("id"))
+            .from(Alias::new(table.table))
+            .and_where(Expr::col(Alias::new("uuid")).eq(entity_uuid))
+            .to_owned();
+        let Some(row) = self
+            .db
+            .query_one(self.db.get_database_backend().build(&select))
+            .await?
+        else {
+            return Ok(None);
+        };
+        let local_id: i32 = row.try_get("", "id")?;
+
+        match find_identity(self.db, table.entity_type, local_id).await? {
+            // Shared under the UUID it had before, the changes are merged into it all the same
+            Some(identity) => Ok(Some(identity)),
+            None => Ok(Some(self.attach(table, entity_uuid, local_id).await?)),
+        }
+    }
+
+    /// Local id of the parent a change points to, `None` when it isn't here or was deleted
+    async fn parent_id(&self, table: &SyncedTable, value: &Value) -> Result<Option<i32>, DbErr> {
+        let (Some(parent), Some(uuid)) = (&table.parent, value.as_str()) else {
+            return Ok(None);
+        };
+
+        Ok(find_identity_by_uuid(self.db, uuid)
+            .await?
+            .filter(|identity| identity.entity_type == parent.entity_type && !identity.deleted)
+            .map(|identity| identity.local_id))
+    }
+
+    /// Creates a row shared for the first time, it needs a value for every field. The row takes
+    /// the UUID it is shared under.
+    async fn insert_row(
+        &self,
+        table: &'static SyncedTable,
+        entity_uuid: &str,
+        sets: &[&BundleChange],
+    ) -> Result<Option<i32>, DbErr> {
+        let mut values: BTreeMap<&str, &Value> = BTreeMap::new();
+        for change in sets {
+            if let Some(field) = &change.field {
+                values.insert(field, change.value.as_ref().unwrap_or(&Value::Null));
+            }
+        }
+
+        let mut columns = vec![Alias::new("uuid")];
+        let mut row = vec![Expr::val(entity_uuid).into()];
+        for field in table.fields {
+            let Some(value) = values.get(field) else {
+                return Ok(None);
+            };
+            columns.push(Alias::new(*field));
+            row.push(Expr::val(to_sql_value(value)).into());
+        }
+
+        let parent_id = match &table.parent {
+            Some(parent) => {
+                let parent_value = values.get(parent.field).copied().unwrap_or(&Value::Null);
+                let Some(parent_id) = self.parent_id(table, parent_value).await? else {
+                    return Ok(None);
+                };
+                columns.push(Alias::new(parent.column));
+                parent_id
+            }
+            None => {
+                columns.push(Alias::new("organization_id"));
+                self.organization_id
+            }
+        };
+        row.push(Expr::val(parent_id).into());
+
+        let created_at: DateTime<Utc> = sets
+            .iter()
+            .map(|change| change.changed_at)
+            .min()
+            .unwrap_or_else(Utc::now);
+        columns.push(Alias::new("created_at"));
+        row.push(Expr::val(created_at).into());
+        columns.push(Alias::new("updated_at"));
+        row.push(Expr::val(Utc::now()).into());
+
+        let insert = Query::insert()
+            .into_table(Alias::new(table.table))
+            .columns(columns)
+            .values_panic(row)
+            .to_owned();
+        let result = self
+            .db
+            .execute(self.db.get_database_backend().build(&insert))
+            .await?;
+
+        Ok(Some(result.last_insert_id() as i32))
+    }
+
+    /// Writes a field that won, the parent is translated back to a local id
+    async fn update_field(
+        &self,
+        table: &'static SyncedTable,
+        local_id: i32,
+        field: &str,
+        value: &Value,
+    ) -> Result<bool, DbErr> {
+        let (column, value) = match &table.parent {
+            Some(parent) if parent.field == field => match self.parent_id(table, value).await? {
+                Some(parent_id) => (parent.column, sea_orm::Value::from(parent_id)),
+                None => return Ok(false),
+            },
+            _ => match table.fields.iter().find(|synced| **synced == field) {
+                Some(column) => (*column, to_sql_value(value)),
+                None => return Ok(false),
+            },
+        };
+
+        let update = Query::update()
+            .table(Alias::new(table.table))
+            .value(Alias::new(column), value)
+            .value(Alias::new("updated_at"), Utc::now())
+            .and_where(Expr::col(Alias::new("id")).eq(local_id))
+            .to_owned();
+        let result = self
+            .db
+            .execute(self.db.get_database_backend().build(&update))
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Keeps the change in the local log so it's only merged once and can be passed on
+    async fn store(&self, change: &BundleChange) -> Result<(), DbErr> {
+        SyncChangeActiveModel {
+            change_uuid: Set(change.id.clone()),
+            instance_id: Set(change.instance_id.clone()),
+            seq: Set(change.seq),
+            entity_type: Set(change.entity_type.clone()),
+            entity_uuid: Set(change.entity_id.clone()),
+            workspace_uuid: Set(self.workspace_uuid.to_string()),
+            action: Set(change.action.clone()),
+            field: Set(change.field.clone()),
+            value: Set(stored_value(change)),
+            changed_at: Set(change.changed_at),
+            ..Default::default()
+        }
+        .insert(self.db)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Bundles drop `null` values, the log keeps them the way local changes are written
+fn stored_value(change: &BundleChange) -> Option<String> {
+    (change.action == ACTION_SET).then(|| change.value.clone().unwrap_or(Value::Null).to_string())
+}
+
+fn to_sql_value(value: &Value) -> sea_orm::Value {
+    match value {
+        Value::Null => sea_orm::Value::String(None),
+        Value::Bool(value) => (*value).into(),
+        Value::Number(number) => match number.as_i64() {
+            Some(number) => number.into(),
+            None => sea_orm::Value::Double(number.as_f64()),
+        },
+        Value::String(value) => value.clone().into(),
+        other => other.to_string().into(),
+    }
+}
@@ -0,0 +1,334 @@
+//! Shares workspaces between rayzen instances without a server. Changes to synced rows are
+//! kept in an append-only log keyed by stable UUIDs ([`log`]), each instance writes the log of
+//! a workspace to a shared folder as a [`ChangeBundle`] and merges the bundles of the others
+//! field by field, the newest write wins ([`merge`]).
+
+pub mod log;
+pub mod merge;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use entity::app_setting::{self, ActiveModel as AppSettingActiveModel, Entity as AppSetting};
+use sea_orm::{sea_query::OnConflict, ActiveValue::Set, ConnectionTrait, DbErr, EntityTrait};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+use tokio::fs;
+use uuid::Uuid;
+
+use crate::domain::types::settings::SYNC_INSTANCE_ID;
+
+/// Bumped when bundles change shape, newer bundles are refused
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// A field got a new value
+pub const ACTION_SET: &str = "set";
+
+/// The whole row was deleted
+pub const ACTION_DELETE: &str = "delete";
+
+/// Row a synced row belongs to, logged as the parent's UUID since local ids differ
+pub struct SyncedParent {
+    pub column: &'static str,
+    pub field: &'static str,
+    pub entity_type: &'static str,
+}
+
+/// A table shared by sync, `entity_type` matches the audit log
+pub struct SyncedTable {
+    pub entity_type: &'static str,
+    pub table: &'static str,
+    /// Columns merged one by one, all of them are needed to create the row on another instance
+    pub fields: &'static [&'static str],
+    /// Stored as 0/1, logged as true/false
+    pub booleans: &'static [&'static str],
+    /// Workspaces belong to an organization that only exists locally, so they have none
+    pub parent: Option<SyncedParent>,
+}
+
+/// Parents come before their children, rows are created in this order and deleted in reverse
+pub const SYNCED_TABLES: &[SyncedTable] = &[
+    SyncedTable {
+        entity_type: "workspace",
+        table: "workspace",
+        fields: &["name"],
+        booleans: &[],
+        parent: None,
+    },
+    SyncedTable {
+        entity_type: "group",
+        table: "groups",
+        fields: &["name", "kind", "filter"],
+        booleans: &[],
+        parent: Some(SyncedParent {
+            column: "workspace_id",
+            field: "workspace",
+            entity_type: "workspace",
+        }),
+    },
+    SyncedTable {
+        entity_type: "bookmark",
+        table: "bookmark",
+        fields: &[
+            "name",
+            "url",
+            "tags",
+            "is_favorite",
+            "read_state",
+            "reading_at",
+            "read_at",
+            "archived_at",
+            "opened_at",
+        ],
+        booleans: &["is_favorite"],
+        parent: Some(SyncedParent {
+            column: "group_id",
+            field: "group",
+            entity_type: "group",
+        }),
+    },
+];
+
+pub fn synced_table(entity_type: &str) -> Option<&'static SyncedTable> {
+    SYNCED_TABLES
+        .iter()
+        .find(|table| table.entity_type == entity_type)
+}
+
+impl SyncedTable {
+    /// Every row as the same JSON object the audit triggers write, used to log existing rows
+    pub fn select_rows(&self) -> String {
+        let mut columns = vec!["id", "uuid"];
+        columns.extend(self.parent.iter().map(|parent| parent.column));
+        columns.extend(self.fields);
+
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                if self.booleans.contains(column) {
+                    format!(
+                        "'{column}', CASE WHEN \"{column}\" THEN json('true') ELSE json('false') END"
+                    )
+                } else {
+                    format!("'{column}', \"{column}\"")
+                }
+            })
+            .collect();
+
+        format!(
+            "SELECT \"id\", json_object({}) AS \"row\" FROM \"{}\" ORDER BY \"id\"",
+            fields.join(", "),
+            self.table
+        )
+    }
+
+    /// The merged fields of a row, without the parent
+    pub fn values(&self, row: &Map<String, Value>) -> Vec<(&'static str, Value)> {
+        self.fields
+            .iter()
+            .map(|field| (*field, row.get(*field).cloned().unwrap_or(Value::Null)))
+            .collect()
+    }
+}
+
+/// One change of a bundle, `entity_id` is the UUID of the row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleChange {
+    pub id: String,
+    pub instance_id: String,
+    /// Position in the log of the instance that made the change
+    pub seq: i32,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub action: String,
+    pub field: Option<String>,
+    pub value: Option<Value>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// The log of one workspace as seen by one instance, written to the shared folder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeBundle {
+    pub version: u32,
+    pub instance_id: String,
+    pub workspace_id: String,
+    pub exported_at: DateTime<Utc>,
+    /// Highest `seq` of every instance merged so far, edits past it were made concurrently
+    pub seen: BTreeMap<String, i32>,
+    pub changes: Vec<BundleChange>,
+}
+
+const BUNDLE_PREFIX: &str = "rayzen-sync-";
+
+impl ChangeBundle {
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}{}-{}.json",
+            BUNDLE_PREFIX, self.workspace_id, self.instance_id
+        )
+    }
+
+    /// Replaces the previous bundle of this instance, the log only grows so nothing is lost
+    pub async fn write(&self, folder: &Path) -> Result<PathBuf, String> {
+        fs::create_dir_all(folder)
+            .await
+            .map_err(|e| e.to_string())?;
+        let path = folder.join(self.file_name());
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&path, json).await.map_err(|e| e.to_string())?;
+
+        Ok(path)
+    }
+
+    /// Every bundle in the folder, other files are left alone
+    pub async fn read_folder(folder: &Path) -> Result<Vec<ChangeBundle>, String> {
+        let cant_read = |e: std::io::Error| format!("Can't read {}: {}", folder.display(), e);
+        let mut entries = fs::read_dir(folder).await.map_err(cant_read)?;
+        let mut paths = Vec::new();
+        while let Some(entry) = entries.next_entry().await.map_err(cant_read)? {
+            let path = entry.path();
+            let is_bundle = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(BUNDLE_PREFIX) && name.ends_with(".json"));
+            if is_bundle {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut bundles = Vec::new();
+        for path in paths {
+            let json = fs::read_to_string(&path).await.map_err(|e| e.to_string())?;
+            let bundle: ChangeBundle = serde_json::from_str(&json)
+                .map_err(|e| format!("Invalid sync bundle {}: {}", path.display(), e))?;
+            if bundle.version > BUNDLE_VERSION {
+                return Err(format!(
+                    "{} was written by a newer version of rayzen",
+                    path.display()
+                ));
+            }
+            bundles.push(bundle);
+        }
+
+        Ok(bundles)
+    }
+}
+
+/// UUID the changes of this instance are signed with
+pub async fn instance_id<C: ConnectionTrait>(db: &C) -> Result<String, DbErr> {
+    if let Some(instance_id) = get_setting(db, SYNC_INSTANCE_ID).await? {
+        return Ok(instance_id);
+    }
+
+    let instance_id = Uuid::new_v4().to_string();
+    set_setting(db, SYNC_INSTANCE_ID, instance_id.clone()).await?;
+    Ok(instance_id)
+}
+
+/// Sync writes its settings inside the merge transaction, so it can't use the repository
+pub(crate) async fn get_setting<C: ConnectionTrait>(
+    db: &C,
+    key: &str,
+) -> Result<Option<String>, DbErr> {
+    Ok(AppSetting::find_by_id(key.to_string())
+        .one(db)
+        .await?
+        .map(|setting| setting.value)
+        .filter(|value| !value.is_empty()))
+}
+
+pub(crate) async fn set_setting<C: ConnectionTrait>(
+    db: &C,
+    key: &str,
+    value: String,
+) -> Result<(), DbErr> {
+    let setting = AppSettingActiveModel {
+        key: Set(key.to_string()),
+        value: Set(value),
+        updated_at: Set(Utc::now()),
+    };
+
+    AppSetting::insert(setting)
+        .on_conflict(
+            OnConflict::column(app_setting::Column::Key)
+                .update_columns([app_setting::Column::Value, app_setting::Column::UpdatedAt])
+                .to_owned(),
+        )
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncStatus {
+    pub instance_id: String,
+    /// Changes in the local log, including the ones merged from others
+    pub changes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncExportDto {
+    pub workspace_id: i32,
+    /// Folder every instance can read, like a synced drive or a network share
+    pub folder: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncExportResult {
+    pub path: String,
+    pub changes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncImportDto {
+    pub folder: String,
+    /// Workspaces shared by others are created in this organization
+    pub organization_id: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncWinner {
+    Local,
+    Remote,
+}
+
+/// A field both sides changed without seeing the other's edit, `field` is `None` when the
+/// row was deleted on the other side. Values are JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct SyncConflict {
+    pub entity_type: String,
+    pub entity_id: String,
+    pub field: Option<String>,
+    pub local_value: Option<String>,
+    pub remote_value: Option<String>,
+    pub winner: SyncWinner,
+}
+
+/// What an import merged, `skipped` counts changes that lost or had nothing to apply to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct SyncReport {
+    pub bundles: u32,
+    pub applied: u32,
+    pub created: u32,
+    pub deleted: u32,
+    pub skipped: u32,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// Local rows an import wrote, their change events are published once the merge is committed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MergedRows {
+    /// Entity type and local id, parents come before their children
+    pub created: Vec<(&'static str, i32)>,
+    pub updated: BTreeSet<(&'static str, i32)>,
+    /// Entity type, local id and the workspace the row was in, the organization for workspaces
+    pub deleted: Vec<(&'static str, i32, i32)>,
+    /// Local ids of the workspaces bundles were merged into
+    pub workspaces: BTreeSet<i32>,
+}
@@ -0,0 +1,151 @@
+use std::collections::{BTreeMap, HashSet};
+
+pub const SHOW_MAIN_ID: &str = "show_main";
+pub const OPEN_LAUNCHER_ID: &str = "open_launcher";
+pub const QUIT_ID: &str = "quit";
+
+const BOOKMARK_ID_PREFIX: &str = "bookmark:";
+
+/// Longer bookmark names are cut so the menu keeps a sane width
+const MAX_LABEL_CHARS: usize = 48;
+
+/// Platform independent description of the tray menu, turned into native menu items by the app
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrayEntry {
+    Item {
+        id: String,
+        label: String,
+        enabled: bool,
+    },
+    Submenu {
+        label: String,
+        entries: Vec<TrayEntry>,
+    },
+    Separator,
+}
+
+impl TrayEntry {
+    fn item(id: &str, label: &str) -> Self {
+        TrayEntry::Item {
+            id: id.to_string(),
+            label: label.to_string(),
+            enabled: true,
+        }
+    }
+
+    fn heading(label: &str) -> Self {
+        TrayEntry::Item {
+            id: String::new(),
+            label: label.to_string(),
+            enabled: false,
+        }
+    }
+}
+
+/// Bookmark shown in the tray, with the workspace it lives in already resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrayBookmark {
+    pub id: i32,
+    pub name: String,
+    pub workspace_id: i32,
+    pub workspace_name: String,
+}
+
+pub fn bookmark_item_id(bookmark_id: i32) -> String {
+    format!("{}{}", BOOKMARK_ID_PREFIX, bookmark_id)
+}
+
+/// Bookmark id behind a menu item id, `None` for the fixed entries
+pub fn parse_bookmark_item_id(item_id: &str) -> Option<i32> {
+    item_id.strip_prefix(BOOKMARK_ID_PREFIX)?.parse().ok()
+}
+
+fn label(name: &str) -> String {
+    if name.chars().count() <= MAX_LABEL_CHARS {
+        return name.to_string();
+    }
+
+    let mut label: String = name.chars().take(MAX_LABEL_CHARS - 1).collect();
+    label.push('…');
+    label
+}
+
+#[derive(Default)]
+struct WorkspaceSection<'a> {
+    name: &'a str,
+    favorites: Vec<&'a TrayBookmark>,
+    recent: Vec<&'a TrayBookmark>,
+}
+
+/// Builds the tray menu from favorites and recently opened bookmarks, one submenu per workspace.
+/// Input order is kept inside each section and recent bookmarks that are also favorites are
+/// only listed once.
+pub fn build_tray_menu(favorites: &[TrayBookmark], recent: &[TrayBookmark]) -> Vec<TrayEntry> {
+    let favorite_ids: HashSet<i32> = favorites.iter().map(|bookmark| bookmark.id).collect();
+
+    // Keyed by name first so workspaces show up alphabetically
+    let mut sections: BTreeMap<(&str, i32), WorkspaceSection> = BTreeMap::new();
+    for bookmark in favorites {
+        section(&mut sections, bookmark).favorites.push(bookmark);
+    }
+    for bookmark in recent {
+        if !favorite_ids.contains(&bookmark.id) {
+            section(&mut sections, bookmark).recent.push(bookmark);
+        }
+    }
+
+    let mut entries = vec![
+        TrayEntry::item(SHOW_MAIN_ID, "Show rayzen"),
+        TrayEntry::item(OPEN_LAUNCHER_ID, "Open launcher"),
+        TrayEntry::Separator,
+    ];
+
+    if sections.is_empty() {
+        entries.push(TrayEntry::heading("No favorite or recent bookmarks"));
+    }
+
+    for section in sections.into_values() {
+        let mut section_entries = Vec::new();
+
+        if !section.favorites.is_empty() {
+            section_entries.push(TrayEntry::heading("Favorites"));
+            section_entries.extend(section.favorites.into_iter().map(bookmark_entry));
+        }
+        if !section.recent.is_empty() {
+            if !section_entries.is_empty() {
+                section_entries.push(TrayEntry::Separator);
+            }
+            section_entries.push(TrayEntry::heading("Recent"));
+            section_entries.extend(section.recent.into_iter().map(bookmark_entry));
+        }
+
+        entries.push(TrayEntry::Submenu {
+            label: label(section.name),
+            entries: section_entries,
+        });
+    }
+
+    entries.push(TrayEntry::Separator);
+    entries.push(TrayEntry::item(QUIT_ID, "Quit rayzen"));
+    entries
+}
+
+fn section<'a, 'b>(
+    sections: &'b mut BTreeMap<(&'a str, i32), WorkspaceSection<'a>>,
+    bookmark: &'a TrayBookmark,
+) -> &'b mut WorkspaceSection<'a> {
+    sections
+        .entry((bookmark.workspace_name.as_str(), bookmark.workspace_id))
+        .or_insert_with(|| WorkspaceSection {
+            name: &bookmark.workspace_name,
+            ..Default::default()
+        })
+}
+
+fn bookmark_entry(bookmark: &TrayBookmark) -> TrayEntry {
+    TrayEntry::Item {
+        id: bookmark_item_id(bookmark.id),
+        label: label(&bookmark.name),
+        enabled: true,
+    }
+}
@@ -1,8 +1,69 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use super::read_later::ReadState;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct GroupsByWorkspaceOrg {
     pub id: i32,
     pub name: String,
 }
+
+/// Static groups hold filed bookmarks, smart groups are evaluated from a saved filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupKind {
+    Static,
+    Smart,
+}
+
+impl GroupKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupKind::Static => "static",
+            GroupKind::Smart => "smart",
+        }
+    }
+
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "smart" => GroupKind::Smart,
+            _ => GroupKind::Static,
+        }
+    }
+}
+
+/// Saved query of a smart group, every set field must match
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct SmartGroupFilter {
+    pub tags: Option<Vec<String>>,
+    pub host: Option<String>,
+    pub is_favorite: Option<bool>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub read_states: Option<Vec<ReadState>>,
+}
+
+impl SmartGroupFilter {
+    /// Matches every bookmark of the workspace, archived ones included
+    pub fn everything() -> Self {
+        SmartGroupFilter {
+            read_states: Some(vec![
+                ReadState::Unread,
+                ReadState::Reading,
+                ReadState::Read,
+                ReadState::Archived,
+            ]),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_json(value: &str) -> Result<Self, String> {
+        serde_json::from_str(value).map_err(|e| format!("Invalid smart group filter: {}", e))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Smart group filter is always serializable")
+    }
+}
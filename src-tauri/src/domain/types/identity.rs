@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use uuid::Uuid;
+
+/// Finds a row by its local id or by its UUID, the one that survives exports and sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(untagged)]
+pub enum Lookup {
+    Id(i32),
+    Uuid(Uuid),
+}
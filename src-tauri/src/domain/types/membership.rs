@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Role of a user in an organization, stored as text in `organization_member.role`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum MemberRole {
+    /// Everything editors can do, plus renaming or deleting the organization and managing members
+    Owner,
+    /// Creates and changes workspaces, groups and bookmarks
+    Editor,
+    /// Read only
+    Viewer,
+}
+
+impl MemberRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRole::Owner => "owner",
+            MemberRole::Editor => "editor",
+            MemberRole::Viewer => "viewer",
+        }
+    }
+
+    /// Unknown values fall back to `Viewer` so a bad row never grants more than reading
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "owner" => MemberRole::Owner,
+            "editor" => MemberRole::Editor,
+            _ => MemberRole::Viewer,
+        }
+    }
+
+    pub fn allows(&self, access: Access) -> bool {
+        match access {
+            Access::Read => true,
+            Access::Write => matches!(self, MemberRole::Owner | MemberRole::Editor),
+            Access::Manage => *self == MemberRole::Owner,
+        }
+    }
+}
+
+/// What an operation needs from the role of the signed in user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Read,
+    Write,
+    Manage,
+}
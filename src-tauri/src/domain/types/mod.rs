@@ -1 +1,6 @@
 pub mod groups;
+pub mod identity;
+pub mod membership;
+pub mod pagination;
+pub mod read_later;
+pub mod settings;
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+pub const DEFAULT_PER_PAGE: u64 = 50;
+pub const MAX_PER_PAGE: u64 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SortInput {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct ListFilter {
+    /// Case-insensitive match on the entity's name
+    pub search: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+/// Shared paging input for list procedures, `cursor` takes precedence over `page`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct ListParams {
+    pub cursor: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    pub sort: Option<SortInput>,
+    pub filter: Option<ListFilter>,
+}
+
+impl ListParams {
+    pub fn per_page(&self) -> u64 {
+        self.per_page
+            .map(|per_page| (per_page as u64).clamp(1, MAX_PER_PAGE))
+            .unwrap_or(DEFAULT_PER_PAGE)
+    }
+
+    /// Zero-based page index, cursors are opaque page indexes handed out by `Page`
+    pub fn page_index(&self) -> u64 {
+        self.cursor
+            .as_deref()
+            .and_then(|cursor| cursor.parse::<u64>().ok())
+            .or(self.page.map(|page| page as u64))
+            .unwrap_or(0)
+    }
+}
+
+/// Page envelope returned by list procedures
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+    pub page: u32,
+    pub per_page: u32,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: u64, page: u64, per_page: u64) -> Self {
+        let has_more = (page + 1) * per_page < total;
+
+        Page {
+            items,
+            total: total as u32,
+            page: page as u32,
+            per_page: per_page as u32,
+            next_cursor: has_more.then(|| (page + 1).to_string()),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            per_page: self.per_page,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Reading progress of a bookmark, stored as text in `bookmark.read_state`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadState {
+    Unread,
+    Reading,
+    Read,
+    Archived,
+}
+
+impl ReadState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadState::Unread => "unread",
+            ReadState::Reading => "reading",
+            ReadState::Read => "read",
+            ReadState::Archived => "archived",
+        }
+    }
+
+    /// Unknown values fall back to `Unread` so legacy rows stay in the queue
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "reading" => ReadState::Reading,
+            "read" => ReadState::Read,
+            "archived" => ReadState::Archived,
+            _ => ReadState::Unread,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Key of the accelerator that toggles the launcher window
+pub const LAUNCHER_SHORTCUT: &str = "launcher.shortcut";
+
+/// Used until the user picks their own launcher shortcut
+pub const DEFAULT_LAUNCHER_SHORTCUT: &str = "CommandOrControl+Shift+Space";
+
+/// Key of the flag that turns the localhost HTTP API on
+pub const HTTP_API_ENABLED: &str = "http_api.enabled";
+
+/// Key of the port the localhost HTTP API listens on
+pub const HTTP_API_PORT: &str = "http_api.port";
+
+/// Key of the bearer token every HTTP API request has to carry
+pub const HTTP_API_TOKEN: &str = "http_api.token";
+
+/// Used until the user picks another port for the HTTP API
+pub const DEFAULT_HTTP_API_PORT: u16 = 7821;
+
+/// Persisted configuration of the localhost HTTP API, the server itself is off by default
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct HttpApiSettings {
+    pub enabled: bool,
+    pub port: u16,
+    pub token: String,
+}
+
+/// Key of the id of the signed in user, empty when nobody is signed in
+pub const SESSION_USER_ID: &str = "session.user_id";
+
+/// Key of the UUID this instance signs its sync changes with, created on first use
+pub const SYNC_INSTANCE_ID: &str = "sync.instance_id";
+
+/// Key of the last audit event turned into sync changes
+pub const SYNC_AUDIT_CURSOR: &str = "sync.audit_cursor";
@@ -3,10 +3,43 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use uuid::Uuid;
 
+/// Fields every entity DTO starts with. `id` is only meaningful in this database, `uuid` is the
+/// same wherever the row is exported, imported or synced to.
 #[derive(Type, Debug, Clone, Serialize, Deserialize)]
 pub struct BaseEntity {
-    pub id: Uuid,
+    pub id: i32,
+    pub uuid: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
-    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl BaseEntity {
+    /// From the columns of a stored row, `uuid` as it is stored
+    pub fn new(id: i32, uuid: &str, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            uuid: parse_uuid(uuid),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// UUID for a new row, stored as text
+pub fn new_uuid() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// The stored `uuid` column, every row has a valid one since the migration that added it
+pub fn parse_uuid(uuid: &str) -> Uuid {
+    Uuid::parse_str(uuid).unwrap_or_default()
+}
+
+/// Tags are stored comma separated, `a, b,,c` becomes `a,b,c`
+pub fn normalize_tags(tags: &str) -> String {
+    tags.split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
 }
@@ -0,0 +1,153 @@
+//! Frameless quick-launcher window and the system-wide shortcut that toggles it
+
+use std::sync::Arc;
+
+use tauri::{App, AppHandle, Manager, State, WebviewWindow, WindowEvent};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::domain::router::ContextRouter;
+
+/// Label of the launcher window declared in `tauri.conf.json`
+pub const LAUNCHER_WINDOW: &str = "launcher";
+
+fn launcher_window(app: &AppHandle) -> Result<WebviewWindow, String> {
+    app.get_webview_window(LAUNCHER_WINDOW)
+        .ok_or_else(|| "Launcher window not found".to_string())
+}
+
+pub fn show(app: &AppHandle) -> Result<(), String> {
+    let window = launcher_window(app)?;
+    window.center().map_err(|e| e.to_string())?;
+    window.show().map_err(|e| e.to_string())?;
+    window.set_focus().map_err(|e| e.to_string())
+}
+
+pub fn hide(app: &AppHandle) -> Result<(), String> {
+    launcher_window(app)?.hide().map_err(|e| e.to_string())
+}
+
+pub fn toggle(app: &AppHandle) -> Result<(), String> {
+    if launcher_window(app)?
+        .is_visible()
+        .map_err(|e| e.to_string())?
+    {
+        hide(app)
+    } else {
+        show(app)
+    }
+}
+
+fn parse_shortcut(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse()
+        .map_err(|e| format!("Invalid shortcut \"{}\": {}", accelerator, e))
+}
+
+fn register(app: &AppHandle, shortcut: Shortcut) -> Result<(), String> {
+    app.global_shortcut()
+        .on_shortcut(shortcut, |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                if let Err(e) = toggle(app) {
+                    eprintln!("Failed to toggle launcher: {}", e);
+                }
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Swap the launcher shortcut, the previous one is restored if the new one can't be registered
+pub fn replace_shortcut(
+    app: &AppHandle,
+    previous: Option<&str>,
+    accelerator: &str,
+) -> Result<(), String> {
+    let shortcut = parse_shortcut(accelerator)?;
+    // A stored shortcut that no longer parses has nothing to unregister
+    let previous = previous.and_then(|previous| parse_shortcut(previous).ok());
+    let shortcuts = app.global_shortcut();
+
+    if let Some(previous) = previous {
+        if shortcuts.is_registered(previous) {
+            shortcuts.unregister(previous).map_err(|e| e.to_string())?;
+        }
+    }
+
+    register(app, shortcut).or_else(|e| {
+        if let Some(previous) = previous {
+            register(app, previous)?;
+        }
+        Err(e)
+    })
+}
+
+/// Install the shortcut plugin, register the saved shortcut and hide the launcher when it loses focus
+pub fn setup(app: &App, context: ContextRouter) -> Result<(), Box<dyn std::error::Error>> {
+    app.handle()
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
+
+    if let Some(window) = app.get_webview_window(LAUNCHER_WINDOW) {
+        let launcher = window.clone();
+        window.on_window_event(move |event| {
+            if let WindowEvent::Focused(false) = event {
+                let _ = launcher.hide();
+            }
+        });
+    }
+
+    let handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        let service = Arc::clone(&context.services.settings);
+        let registered = match service.get_launcher_shortcut(context).await {
+            Ok(accelerator) => replace_shortcut(&handle, None, &accelerator),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = registered {
+            eprintln!("Failed to register launcher shortcut: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn toggle_launcher(app: AppHandle) -> Result<(), String> {
+    toggle(&app)
+}
+
+#[tauri::command]
+pub fn hide_launcher(app: AppHandle) -> Result<(), String> {
+    hide(&app)
+}
+
+#[tauri::command]
+pub async fn get_launcher_shortcut(ctx: State<'_, ContextRouter>) -> Result<String, String> {
+    let service = Arc::clone(&ctx.services.settings);
+    service.get_launcher_shortcut(ctx.inner().clone()).await
+}
+
+/// Registers the new shortcut first so an accelerator the OS rejects is never saved
+#[tauri::command]
+pub async fn set_launcher_shortcut(
+    app: AppHandle,
+    ctx: State<'_, ContextRouter>,
+    accelerator: String,
+) -> Result<String, String> {
+    let service = Arc::clone(&ctx.services.settings);
+    let previous = service.get_launcher_shortcut(ctx.inner().clone()).await?;
+    let accelerator = accelerator.trim().to_string();
+
+    replace_shortcut(&app, Some(&previous), &accelerator)?;
+
+    match service
+        .set_launcher_shortcut(ctx.inner().clone(), accelerator.clone())
+        .await
+    {
+        Ok(saved) => Ok(saved),
+        Err(e) => {
+            // Keep the registered shortcut in line with what is persisted
+            replace_shortcut(&app, Some(&accelerator), &previous)?;
+            Err(e)
+        }
+    }
+}
@@ -1,9 +1,18 @@
+#[cfg(desktop)]
+mod api_server;
 mod db;
-mod domain;
+#[cfg(desktop)]
+mod deep_link;
+pub mod domain;
+#[cfg(desktop)]
+mod launcher;
+#[cfg(desktop)]
+mod tray;
 
-use domain::router;
-use dotenvy;
-use std::sync::Arc;
+use domain::{
+    context::{database_uri, ContextRouter},
+    router,
+};
 
 use migration;
 use rspc_tauri;
@@ -11,23 +20,58 @@ use rspc_tauri;
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 #[tokio::main]
 pub async fn run() {
-    dotenvy::dotenv().ok();
-
-    // Initialize the database connection
-    let database_uri =
-        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set in the environment");
-    let db = db::establish_connection(&database_uri)
+    let database_uri = database_uri().expect("Failed to resolve the database");
+    let context = ContextRouter::connect(&database_uri)
         .await
-        .expect("Failed to establish database connection");
-    let db = Arc::new(db);
+        .expect("Failed to set up the app context");
 
-    // Create the router context with the database connection
     let router = router::create_router();
 
-    tauri::Builder::default()
+    // rspc procedures and Tauri commands outside of rspc share the same context
+    let rspc_context = context.clone();
+
+    let builder = tauri::Builder::default();
+
+    // Has to come first: a second launch, which is how Windows and Linux deliver a link to a
+    // running app, exits and hands its link to this instance's deep link handler
+    #[cfg(desktop)]
+    let builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+        tray::show_main_window(app);
+    }));
+
+    let builder = builder
+        .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(rspc_tauri::plugin(router.arced(), move |_app_handle| {
-            router::ContextRouter::new(Arc::clone(&db))
+            rspc_context.clone()
         }))
+        .manage(context.clone())
+        .setup(move |app| {
+            #[cfg(desktop)]
+            {
+                launcher::setup(app, context.clone())?;
+                api_server::setup(app, context)?;
+                tray::setup(app)?;
+                deep_link::setup(app)?;
+            }
+            Ok(())
+        });
+
+    // The launcher, its global shortcut, the HTTP API and deep links only exist on desktop
+    #[cfg(desktop)]
+    let builder = builder.invoke_handler(tauri::generate_handler![
+        launcher::toggle_launcher,
+        launcher::hide_launcher,
+        launcher::get_launcher_shortcut,
+        launcher::set_launcher_shortcut,
+        api_server::get_http_api_status,
+        api_server::set_http_api_enabled,
+        api_server::set_http_api_port,
+        api_server::regenerate_http_api_token,
+        deep_link::take_pending_deep_links,
+    ]);
+
+    builder
         .run(tauri::generate_context!())
         .expect("Error while running Tauri App");
 }
@@ -0,0 +1,151 @@
+//! Tray icon whose menu lists favorite and recently opened bookmarks
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use tauri::{
+    menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
+    tray::TrayIconBuilder,
+    App, AppHandle, Manager, WindowEvent, Wry,
+};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::{
+    domain::{
+        router::ContextRouter,
+        tray_menu::{parse_bookmark_item_id, TrayEntry, OPEN_LAUNCHER_ID, QUIT_ID, SHOW_MAIN_ID},
+    },
+    launcher,
+};
+
+const TRAY_ID: &str = "rayzen";
+
+fn to_menu_item(app: &AppHandle, entry: &TrayEntry) -> tauri::Result<Box<dyn IsMenuItem<Wry>>> {
+    Ok(match entry {
+        // Headings have no id of their own, they are never clicked
+        TrayEntry::Item { id, label, enabled } if id.is_empty() => {
+            Box::new(MenuItem::new(app, label, *enabled, None::<&str>)?)
+        }
+        TrayEntry::Item { id, label, enabled } => Box::new(MenuItem::with_id(
+            app,
+            id.as_str(),
+            label,
+            *enabled,
+            None::<&str>,
+        )?),
+        TrayEntry::Submenu { label, entries } => {
+            let submenu = Submenu::new(app, label, true)?;
+            for entry in entries {
+                submenu.append(to_menu_item(app, entry)?.as_ref())?;
+            }
+            Box::new(submenu)
+        }
+        TrayEntry::Separator => Box::new(PredefinedMenuItem::separator(app)?),
+    })
+}
+
+fn to_menu(app: &AppHandle, entries: &[TrayEntry]) -> tauri::Result<Menu<Wry>> {
+    let menu = Menu::new(app)?;
+    for entry in entries {
+        menu.append(to_menu_item(app, entry)?.as_ref())?;
+    }
+    Ok(menu)
+}
+
+async fn build_menu(app: &AppHandle) -> Result<Menu<Wry>, String> {
+    let context = app.state::<ContextRouter>().inner().clone();
+    let service = Arc::clone(&context.services.tray);
+    let entries = service.build_menu(context).await?;
+
+    to_menu(app, &entries).map_err(|e| e.to_string())
+}
+
+async fn rebuild_menu(app: &AppHandle) -> Result<(), String> {
+    let menu = build_menu(app).await?;
+    let tray = app
+        .tray_by_id(TRAY_ID)
+        .ok_or_else(|| "Tray icon not found".to_string())?;
+
+    tray.set_menu(Some(menu)).map_err(|e| e.to_string())
+}
+
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+async fn open_bookmark(app: &AppHandle, id: i32) -> Result<(), String> {
+    let context = app.state::<ContextRouter>().inner().clone();
+    let service = Arc::clone(&context.services.bookmarks);
+    let bookmark = service.mark_opened(context, id).await?;
+
+    app.opener()
+        .open_url(bookmark.url, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        SHOW_MAIN_ID => show_main_window(app),
+        OPEN_LAUNCHER_ID => {
+            if let Err(e) = launcher::show(app) {
+                eprintln!("Failed to open launcher from tray: {}", e);
+            }
+        }
+        QUIT_ID => app.exit(0),
+        _ => {
+            if let Some(bookmark_id) = parse_bookmark_item_id(id) {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = open_bookmark(&app, bookmark_id).await {
+                        eprintln!("Failed to open bookmark from tray: {}", e);
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Create the tray icon and keep its menu in sync with bookmark changes
+pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tray = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("rayzen")
+        .on_menu_event(|app, event| handle_menu_event(app, event.id().as_ref()));
+    if let Some(icon) = app.default_window_icon() {
+        tray = tray.icon(icon.clone());
+    }
+    tray.build(app)?;
+
+    // With the tray around, closing the main window only hides it, quitting goes through the menu
+    if let Some(window) = app.get_webview_window("main") {
+        let main = window.clone();
+        window.on_window_event(move |event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                api.prevent_close();
+                let _ = main.hide();
+            }
+        });
+    }
+
+    let handle = app.handle().clone();
+    let context = app.state::<ContextRouter>().inner().clone();
+    tauri::async_runtime::spawn(async move {
+        // Subscribe before the first build so no change slips in between
+        let mut changes = Box::pin(context.services.events.all());
+
+        if let Err(e) = rebuild_menu(&handle).await {
+            eprintln!("Failed to build tray menu: {}", e);
+        }
+
+        // Group and workspace changes can rename or drop whole sections, not only bookmarks
+        while changes.next().await.is_some() {
+            if let Err(e) = rebuild_menu(&handle).await {
+                eprintln!("Failed to rebuild tray menu: {}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
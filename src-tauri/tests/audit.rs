@@ -0,0 +1,340 @@
+mod common;
+
+use common::{id_of, TestApp};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::ConnectionTrait;
+use serde_json::{json, Value};
+
+async fn audit(app: &TestApp, query: Value) -> Vec<Value> {
+    let page = app.ok_query("audit.list", query).await;
+    page["items"].as_array().unwrap().clone()
+}
+
+fn parse(json: &Value) -> Value {
+    serde_json::from_str(json.as_str().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn every_change_is_recorded_with_its_actor() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.ok_mutation("session.signIn", json!(seed.user_id)).await;
+
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Rust", "https://www.rust-lang.org", "")
+        .await;
+    let id = id_of(&bookmark);
+    app.ok_mutation(
+        "bookmark.update",
+        json!({ "id": id, "name": "Rust lang", "is_favorite": true }),
+    )
+    .await;
+    app.ok_mutation("bookmark.delete", json!(id)).await;
+
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "bookmark", "entity_id": id, "params": {} }),
+    )
+    .await;
+    let actions: Vec<&str> = events
+        .iter()
+        .map(|event| event["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, vec!["delete", "update", "create"]);
+
+    for event in &events {
+        assert_eq!(event["actor_user_id"], seed.user_id);
+        assert_eq!(event["actor_name"], "Ada");
+    }
+
+    let update = &events[1];
+    assert_eq!(parse(&update["before"])["name"], "Rust");
+    assert_eq!(parse(&update["before"])["is_favorite"], false);
+    assert_eq!(parse(&update["after"])["name"], "Rust lang");
+    assert_eq!(parse(&update["after"])["is_favorite"], true);
+    assert_eq!(events[2]["before"], Value::Null);
+    assert_eq!(events[0]["after"], Value::Null);
+
+    // The seed ran before signing in, so only the bookmark changes belong to Ada
+    let by_ada = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "user_id": seed.user_id, "params": { "per_page": 100 } }),
+    )
+    .await;
+    assert_eq!(by_ada.len(), 3);
+}
+
+#[tokio::test]
+async fn changes_without_a_session_have_no_actor_and_dates_filter() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "workspace", "entity_id": seed.workspace_id, "params": {} }),
+    )
+    .await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["action"], "create");
+    assert_eq!(events[0]["actor_user_id"], Value::Null);
+    assert_eq!(events[0]["actor_name"], Value::Null);
+    assert_eq!(parse(&events[0]["after"])["name"], "Reading");
+
+    // Organizations record their owner membership as well
+    let members = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "organization_member", "params": {} }),
+    )
+    .await;
+    assert_eq!(members.len(), 1);
+
+    let recent = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "params": { "filter": { "created_after": "2000-01-01T00:00:00Z" }, "per_page": 100 } }),
+    )
+    .await;
+    // The user isn't part of the organization's history
+    assert_eq!(recent.len(), 4);
+    let future = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "params": { "filter": { "created_after": "2999-01-01T00:00:00Z" } } }),
+    )
+    .await;
+    assert!(future.is_empty());
+}
+
+#[tokio::test]
+async fn failed_changes_leave_no_audit_event() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Docs", "https://docs.rs", "rust")
+        .await;
+
+    let moved = app
+        .db
+        .execute_unprepared(&format!(
+            "UPDATE bookmark SET group_id = 9999 WHERE id = {}",
+            id_of(&bookmark)
+        ))
+        .await;
+    assert!(moved.is_err());
+
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "bookmark", "params": {} }),
+    )
+    .await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["action"], "create");
+}
+
+#[tokio::test]
+async fn events_are_listed_per_organization_for_its_members() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.ok_mutation("session.signIn", json!(seed.user_id)).await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Docs", "https://docs.rs", "rust")
+        .await;
+    // Deleted records keep their place in the history
+    app.ok_mutation("bookmark.delete", json!(id_of(&bookmark)))
+        .await;
+
+    let work = app
+        .ok_mutation(
+            "organization.createOrganization",
+            json!({ "name": "Work", "user_id": seed.user_id }),
+        )
+        .await;
+    app.ok_mutation(
+        "workspace.createWorkspace",
+        json!({ "name": "Team", "organization_id": work["id"] }),
+    )
+    .await;
+
+    let personal = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "params": { "per_page": 100 } }),
+    )
+    .await;
+    let types: Vec<&str> = personal
+        .iter()
+        .map(|event| event["entity_type"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "bookmark",
+            "bookmark",
+            "group",
+            "workspace",
+            "organization_member",
+            "organization"
+        ]
+    );
+    let work_events = audit(
+        &app,
+        json!({ "organization_id": work["id"], "params": { "per_page": 100 } }),
+    )
+    .await;
+    assert_eq!(work_events.len(), 3);
+
+    let eve = app
+        .ok_mutation(
+            "users.createUser",
+            json!({ "name": "Eve", "email": "eve@example.com" }),
+        )
+        .await;
+    app.ok_mutation("session.signIn", json!(id_of(&eve))).await;
+    let error = app
+        .query(
+            "audit.list",
+            json!({ "organization_id": seed.organization_id, "params": {} }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, 403);
+}
+
+#[tokio::test]
+async fn events_follow_the_organization_rows_are_in_when_they_change() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Docs", "https://docs.rs", "rust")
+        .await;
+    let id = id_of(&bookmark);
+    // Like rows saved before there was an audit log
+    app.db
+        .execute_unprepared("DELETE FROM audit_event WHERE entity_type IN ('group', 'bookmark')")
+        .await
+        .unwrap();
+
+    app.ok_mutation("bookmark.update", json!({ "id": id, "name": "docs.rs" }))
+        .await;
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "bookmark", "params": {} }),
+    )
+    .await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["action"], "update");
+
+    // After a move, the old organization doesn't see what happens to the bookmark
+    let work = app
+        .ok_mutation(
+            "organization.createOrganization",
+            json!({ "name": "Work", "user_id": seed.user_id }),
+        )
+        .await;
+    let team = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": work["id"] }),
+        )
+        .await;
+    app.ok_mutation(
+        "groups.move",
+        json!({ "id": seed.group_id, "workspace_id": team["id"] }),
+    )
+    .await;
+    app.ok_mutation("bookmark.update", json!({ "id": id, "name": "Docs" }))
+        .await;
+
+    let personal = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "bookmark", "params": {} }),
+    )
+    .await;
+    assert_eq!(personal.len(), 1);
+    let at_work = audit(
+        &app,
+        json!({ "organization_id": work["id"], "entity_type": "bookmark", "params": {} }),
+    )
+    .await;
+    assert_eq!(at_work.len(), 1);
+    assert_eq!(parse(&at_work[0]["after"])["name"], "Docs");
+}
+
+#[tokio::test]
+async fn events_recorded_before_the_upgrade_get_their_organization() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Docs", "https://docs.rs", "rust")
+        .await;
+    // Deleted rows are found through what their events recorded
+    app.ok_mutation("bookmark.delete", json!(id_of(&bookmark)))
+        .await;
+
+    // Back to before the organization column, whatever came after it
+    let since_organization = Migrator::migrations()
+        .iter()
+        .rev()
+        .position(|migration| migration.name() == "m20250814_090000_add_audit_event_organization")
+        .unwrap()
+        + 1;
+    Migrator::down(app.db.as_ref(), Some(since_organization as u32))
+        .await
+        .unwrap();
+    Migrator::up(app.db.as_ref(), None).await.unwrap();
+
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "params": { "per_page": 100 } }),
+    )
+    .await;
+    let types: Vec<&str> = events
+        .iter()
+        .map(|event| event["entity_type"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "bookmark",
+            "bookmark",
+            "group",
+            "workspace",
+            "organization_member",
+            "organization"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn every_bookmark_column_is_recorded_but_opening_is_not_a_change() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Gone", "https://gone.invalid", "")
+        .await;
+    let id = id_of(&bookmark);
+
+    app.ok_mutation("bookmark.markOpened", json!(id)).await;
+    app.ok_mutation("bookmark.markOpened", json!(id)).await;
+    // The fetch fails, which flags the link as broken
+    assert!(app
+        .mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id, "single_file": false }),
+        )
+        .await
+        .is_err());
+
+    let events = audit(
+        &app,
+        json!({ "organization_id": seed.organization_id, "entity_type": "bookmark", "entity_id": id, "params": {} }),
+    )
+    .await;
+    let actions: Vec<&str> = events
+        .iter()
+        .map(|event| event["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, vec!["update", "create"]);
+
+    let broken = parse(&events[0]["after"]);
+    assert!(broken["broken_at"].is_string());
+    assert_eq!(broken["open_count"], 2);
+    assert!(parse(&events[0]["before"])["broken_at"].is_null());
+}
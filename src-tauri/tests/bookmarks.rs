@@ -0,0 +1,401 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use sea_orm::ConnectionTrait;
+use serde_json::json;
+
+#[tokio::test]
+async fn bookmark_crud_round_trip() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let bookmark = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "rust,docs",
+        )
+        .await;
+    let id = id_of(&bookmark);
+    assert_eq!(bookmark["read_state"], "unread");
+
+    let found = app.ok_query("bookmark.getById", json!(id)).await;
+    assert_eq!(found["url"], "https://doc.rust-lang.org/book");
+    assert!(found["reading_time_minutes"].is_null());
+
+    let updated = app
+        .ok_mutation(
+            "bookmark.update",
+            json!({
+                "id": id,
+                "name": "The Rust Book",
+                "url": null,
+                "tags": "",
+                "is_favorite": true,
+                "group_id": null,
+            }),
+        )
+        .await;
+    assert_eq!(updated["name"], "The Rust Book");
+    assert_eq!(updated["tags"], "rust,docs");
+    assert_eq!(updated["is_favorite"], true);
+
+    let by_group = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(by_group["items"].as_array().unwrap().len(), 1);
+
+    let found = app
+        .ok_query("bookmark.search", json!(["docs", first_page()]))
+        .await;
+    assert_eq!(found["items"].as_array().unwrap().len(), 1);
+
+    app.ok_mutation("bookmark.delete", json!(id)).await;
+    assert!(app.query("bookmark.getById", json!(id)).await.is_err());
+}
+
+#[tokio::test]
+async fn bookmark_list_is_paged() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    for n in 0..5 {
+        app.create_bookmark(
+            seed.group_id,
+            &format!("Bookmark {}", n),
+            &format!("https://example.com/{}", n),
+            "",
+        )
+        .await;
+    }
+
+    let page = app
+        .ok_query(
+            "bookmark.list",
+            json!({ "page": 1, "per_page": 2, "sort": { "field": "id", "direction": "desc" } }),
+        )
+        .await;
+    assert_eq!(page["total"], 5);
+    assert_eq!(page["page"], 1);
+    assert_eq!(page["items"][0]["name"], "Bookmark 2");
+    assert_eq!(page["next_cursor"], "2");
+
+    let everything = app.ok_query("bookmark.list", first_page()).await;
+    assert_eq!(everything["items"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn group_bookmarks_and_search_results_are_paged() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    for n in 0..3 {
+        app.create_bookmark(
+            seed.group_id,
+            &format!("Rust {}", n),
+            &format!("https://example.com/{}", n),
+            "rust",
+        )
+        .await;
+    }
+    let smart = app
+        .ok_mutation(
+            "groups.createSmartGroup",
+            json!({
+                "name": "Rust",
+                "workspace_id": seed.workspace_id,
+                "filter": { "tags": ["rust"] },
+            }),
+        )
+        .await;
+
+    for group_id in [seed.group_id, id_of(&smart)] {
+        let first = app
+            .ok_query(
+                "bookmark.getByGroup",
+                json!([group_id, { "per_page": 2, "sort": { "field": "name", "direction": "asc" } }]),
+            )
+            .await;
+        assert_eq!(first["total"], 3);
+        assert_eq!(first["items"].as_array().unwrap().len(), 2);
+        assert_eq!(first["items"][0]["name"], "Rust 0");
+        assert_eq!(first["next_cursor"], "1");
+
+        let last = app
+            .ok_query(
+                "bookmark.getByGroup",
+                json!([group_id, {
+                    "cursor": first["next_cursor"],
+                    "per_page": 2,
+                    "sort": { "field": "name", "direction": "asc" },
+                }]),
+            )
+            .await;
+        assert_eq!(last["items"].as_array().unwrap().len(), 1);
+        assert_eq!(last["items"][0]["name"], "Rust 2");
+        assert!(last["next_cursor"].is_null());
+    }
+
+    let found = app
+        .ok_query("bookmark.search", json!(["rust", { "per_page": 3 }]))
+        .await;
+    assert_eq!(found["total"], 3);
+    assert_eq!(found["items"].as_array().unwrap().len(), 3);
+    assert!(found["next_cursor"].is_null());
+
+    let past_the_end = app
+        .ok_query(
+            "bookmark.search",
+            json!(["rust", { "page": 1, "per_page": 3 }]),
+        )
+        .await;
+    assert_eq!(past_the_end["total"], 3);
+    assert!(past_the_end["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn search_wildcards_match_themselves() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(seed.group_id, "100% Rust", "https://example.com/1", "")
+        .await;
+    app.create_bookmark(seed.group_id, "snake_case", "https://example.com/2", "")
+        .await;
+    app.create_bookmark(seed.group_id, "Plain", "https://example.com/3", "")
+        .await;
+
+    for (query, name) in [("%", "100% Rust"), ("_", "snake_case")] {
+        let found = app
+            .ok_query("bookmark.search", json!([query, first_page()]))
+            .await;
+        assert_eq!(found["total"], 1, "searching {:?}", query);
+        assert_eq!(found["items"][0]["name"], name);
+
+        let listed = app
+            .ok_query("bookmark.list", json!({ "filter": { "search": query } }))
+            .await;
+        assert_eq!(listed["total"], 1, "listing {:?}", query);
+    }
+}
+
+/// Rows with the same sort value are ordered by id, so no page repeats or skips one of them
+#[tokio::test]
+async fn equal_sort_values_page_in_id_order() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let mut ids = Vec::new();
+    for n in 0..5 {
+        let bookmark = app
+            .create_bookmark(
+                seed.group_id,
+                "Same name",
+                &format!("https://example.com/{}", n),
+                "",
+            )
+            .await;
+        ids.push(id_of(&bookmark));
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor = serde_json::Value::Null;
+    loop {
+        let page = app
+            .ok_query(
+                "bookmark.list",
+                json!({
+                    "cursor": cursor,
+                    "per_page": 2,
+                    "sort": { "field": "name", "direction": "asc" },
+                }),
+            )
+            .await;
+        seen.extend(page["items"].as_array().unwrap().iter().map(id_of));
+        cursor = page["next_cursor"].clone();
+        if cursor.is_null() {
+            break;
+        }
+    }
+    assert_eq!(seen, ids);
+}
+
+/// `BookmarkRepositoryImpl::update` used to unwrap the lookup and panic on a missing row
+#[tokio::test]
+async fn updating_missing_bookmark_is_an_error() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .mutation(
+            "bookmark.update",
+            json!({
+                "id": 42,
+                "name": "Ghost",
+                "url": null,
+                "tags": null,
+                "is_favorite": null,
+                "group_id": null,
+            }),
+        )
+        .await;
+
+    assert!(result.unwrap_err().message.contains("not found"));
+}
+
+#[tokio::test]
+async fn snapshots_are_versioned_and_feed_reader_content() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Offline", "https://example.com/offline", "")
+        .await;
+    let id = id_of(&bookmark);
+
+    let first = app
+        .ok_mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id, "single_file": false }),
+        )
+        .await;
+    let second = app
+        .ok_mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id, "single_file": true }),
+        )
+        .await;
+    assert_eq!(first["version"], 1);
+    assert_eq!(second["version"], 2);
+
+    let snapshots = app.ok_query("bookmark.listSnapshots", json!(id)).await;
+    assert_eq!(snapshots.as_array().unwrap().len(), 2);
+
+    let opened = app
+        .ok_query("bookmark.openSnapshot", json!(id_of(&first)))
+        .await;
+    assert!(opened["html"]
+        .as_str()
+        .unwrap()
+        .contains("Snapshots keep a copy"));
+
+    let content = app.ok_query("bookmark.getContent", json!(id)).await;
+    assert!(content["text"].as_str().unwrap().contains("reader view"));
+    assert!(content["word_count"].as_i64().unwrap() > 0);
+
+    // Extracted text is searchable and gives the bookmark a reading time
+    let found = app
+        .ok_query("bookmark.search", json!(["navigation", first_page()]))
+        .await;
+    assert_eq!(found["items"].as_array().unwrap().len(), 1);
+    let bookmark = app.ok_query("bookmark.getById", json!(id)).await;
+    assert_eq!(bookmark["reading_time_minutes"], 1);
+}
+
+#[tokio::test]
+async fn snapshots_taken_together_get_their_own_versions() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Offline", "https://example.com/offline", "")
+        .await;
+    let input = json!({ "bookmark_id": id_of(&bookmark), "single_file": false });
+
+    let (first, second) = tokio::join!(
+        app.ok_mutation("bookmark.snapshot", input.clone()),
+        app.ok_mutation("bookmark.snapshot", input.clone()),
+    );
+
+    let mut versions = [first["version"].as_i64(), second["version"].as_i64()];
+    versions.sort();
+    assert_eq!(versions, [Some(1), Some(2)]);
+}
+
+/// A snapshot that fails to save leaves no page file behind
+#[tokio::test]
+async fn failed_snapshot_removes_its_file() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Offline", "https://example.com/offline", "")
+        .await;
+    app.db
+        .execute_unprepared(
+            "CREATE TRIGGER reject_snapshot BEFORE INSERT ON bookmark_snapshot
+             BEGIN SELECT RAISE(ABORT, 'rejected'); END",
+        )
+        .await
+        .unwrap();
+
+    let result = app
+        .mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id_of(&bookmark), "single_file": false }),
+        )
+        .await;
+
+    assert!(result.unwrap_err().message.contains("rejected"));
+    let files = std::fs::read_dir(app.snapshot_dir.join(id_of(&bookmark).to_string()))
+        .map(|dir| dir.count())
+        .unwrap_or(0);
+    assert_eq!(files, 0);
+}
+
+#[tokio::test]
+async fn snapshot_of_missing_bookmark_is_an_error() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": 42, "single_file": false }),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn bookmarks_are_copied_with_their_page_text() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Offline", "https://example.com/offline", "")
+        .await;
+    app.ok_mutation(
+        "bookmark.snapshot",
+        json!({ "bookmark_id": id_of(&bookmark), "single_file": false }),
+    )
+    .await;
+    let other = app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Later", "workspace_id": seed.workspace_id }),
+        )
+        .await;
+
+    let copy = app
+        .ok_mutation(
+            "bookmark.copyTo",
+            json!({ "id": id_of(&bookmark), "group_id": id_of(&other) }),
+        )
+        .await;
+    assert_ne!(id_of(&copy), id_of(&bookmark));
+    assert_ne!(copy["uuid"], bookmark["uuid"]);
+    assert_eq!(copy["group_id"], id_of(&other));
+    assert_eq!(copy["url"], "https://example.com/offline");
+    assert_eq!(copy["reading_time_minutes"], 1);
+
+    // The original stays where it was
+    let originals = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(originals["items"].as_array().unwrap().len(), 1);
+    let found = app
+        .ok_query("bookmark.search", json!(["navigation", first_page()]))
+        .await;
+    assert_eq!(found["items"].as_array().unwrap().len(), 2);
+
+    assert!(app
+        .mutation(
+            "bookmark.copyTo",
+            json!({ "id": 999, "group_id": id_of(&other) }),
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,165 @@
+//! Runs the `rayzen` command line binary against a throwaway sqlite file seeded through the same services
+
+use std::{path::PathBuf, process::Command};
+
+use rayzen_lib::domain::{
+    context::ContextRouter,
+    dto::{
+        groups::groups_dto::CreateGroupsDto, organization::organization_dto::CreateOrganizationDto,
+        user::user_dto::CreateUserDto, workspace::workspace_dto::CreateWorkspaceDto,
+    },
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+struct CliDatabase {
+    path: PathBuf,
+    uri: String,
+}
+
+impl CliDatabase {
+    /// Creates the database with a user, organization, workspace and an "Articles" group
+    async fn seeded() -> Self {
+        let path = std::env::temp_dir().join(format!("rayzen-cli-{}.db", Uuid::new_v4()));
+        let uri = format!("sqlite://{}?mode=rwc", path.display());
+        let ctx = ContextRouter::connect(&uri).await.unwrap();
+        let services = &ctx.services;
+
+        let user = services
+            .users
+            .create_user(
+                ctx.clone(),
+                CreateUserDto {
+                    name: "Ada".to_string(),
+                    email: "ada@example.com".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        let organization = services
+            .organizations
+            .create_organization(
+                ctx.clone(),
+                CreateOrganizationDto {
+                    name: "Personal".to_string(),
+                    user_id: user.base.id,
+                },
+            )
+            .await
+            .unwrap();
+        let workspace = services
+            .workspaces
+            .create_workspace(
+                ctx.clone(),
+                CreateWorkspaceDto {
+                    name: "Reading".to_string(),
+                    organization_id: organization.base.id,
+                },
+            )
+            .await
+            .unwrap();
+        services
+            .groups
+            .create_group(
+                ctx.clone(),
+                CreateGroupsDto {
+                    name: "Articles".to_string(),
+                    workspace_id: workspace.base.id,
+                },
+            )
+            .await
+            .unwrap();
+
+        CliDatabase { path, uri }
+    }
+
+    fn run(&self, args: &[&str]) -> Result<String, String> {
+        let output = Command::new(env!("CARGO_BIN_EXE_rayzen"))
+            .args(args)
+            .env("DATABASE_URL", &self.uri)
+            .output()
+            .expect("Failed to run the rayzen CLI");
+
+        if output.status.success() {
+            Ok(String::from_utf8(output.stdout).unwrap())
+        } else {
+            Err(String::from_utf8(output.stderr).unwrap())
+        }
+    }
+
+    fn json(&self, args: &[&str]) -> Value {
+        let mut args = args.to_vec();
+        args.push("--json");
+        serde_json::from_str(&self.run(&args).unwrap()).unwrap()
+    }
+}
+
+impl Drop for CliDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[tokio::test]
+async fn bookmarks_can_be_added_searched_and_exported() {
+    let db = CliDatabase::seeded().await;
+
+    let added = db.json(&[
+        "add",
+        "https://doc.rust-lang.org/book",
+        "--group",
+        "articles",
+        "--tags",
+        "rust, books,",
+        "--name",
+        "Rust book",
+    ]);
+    assert_eq!(added["name"], "Rust book");
+    assert_eq!(added["tags"], "rust,books");
+
+    let group_id = added["group_id"].as_i64().unwrap().to_string();
+    db.json(&["add", "https://go.dev/tour", "--group", &group_id]);
+
+    let results = db.json(&["search", "rust"]);
+    assert_eq!(results.as_array().unwrap().len(), 1);
+    assert_eq!(results[0]["url"], "https://doc.rust-lang.org/book");
+
+    let text = db.run(&["search", "rust"]).unwrap();
+    assert!(text.contains("Rust book"));
+
+    let exported: Value = serde_json::from_str(&db.run(&["export"]).unwrap()).unwrap();
+    let urls: Vec<&str> = exported
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|bookmark| bookmark["url"].as_str().unwrap())
+        .collect();
+    assert_eq!(urls.len(), 2);
+    assert!(urls.contains(&"https://go.dev/tour"));
+
+    // The seeded workspace is the first one, stdout holds nothing but the CSV
+    let csv = db
+        .run(&["export", "--format", "csv", "--workspace", "1"])
+        .unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("workspace,group,name,url"));
+    assert!(lines[1].starts_with("Reading,Articles,Rust book,https://doc.rust-lang.org/book,"));
+
+    let markdown = db
+        .run(&["export", "--format", "markdown", "--group", "Articles"])
+        .unwrap();
+    assert!(markdown.starts_with("# Articles\n\n## Articles\n\n- [Rust book]"));
+    assert!(db.run(&["export", "--format", "opml"]).is_err());
+}
+
+#[tokio::test]
+async fn unknown_groups_fail_with_a_message() {
+    let db = CliDatabase::seeded().await;
+
+    let error = db
+        .run(&["add", "https://example.com", "--group", "Nope"])
+        .unwrap_err();
+
+    assert!(error.contains("No group named \"Nope\""));
+}
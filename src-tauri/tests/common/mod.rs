@@ -0,0 +1,239 @@
+//! Shared harness for the integration tests: a migrated `sqlite::memory:` database behind the
+//! real rspc router, with a network-free page fetcher for snapshot procedures.
+#![allow(dead_code)]
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+};
+
+use async_trait::async_trait;
+use migration::{Migrator, MigratorTrait};
+use rayzen_lib::domain::{
+    archiver::{
+        fetcher::{FetchedResource, PageFetcher},
+        storage::SnapshotStorage,
+        PageArchiver,
+    },
+    router::{create_router, ContextRouter},
+    service::container::{Repositories, Services},
+};
+use rspc::{
+    internal::jsonrpc::{handle_json_rpc, Request, Sender, SubscriptionMap},
+    Router,
+};
+use sea_orm::{Database, DatabaseConnection};
+use serde_json::{json, Value};
+
+pub const PAGE_HTML: &str = r#"<html>
+<head><title>Offline reading</title></head>
+<body>
+  <nav>Home | About</nav>
+  <article>
+    <h1>Offline reading</h1>
+    <p>Snapshots keep a copy of the page so it can be read without a connection.</p>
+    <p>The reader view strips navigation and keeps only the article text.</p>
+  </article>
+</body>
+</html>"#;
+
+/// Serves `PAGE_HTML` for every url so snapshot procedures never touch the network,
+/// urls on `.invalid` hosts fail like an unreachable page would
+pub struct StaticPageFetcher;
+
+#[async_trait]
+impl PageFetcher for StaticPageFetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedResource, String> {
+        if url.contains(".invalid") {
+            return Err(format!("Failed to fetch {}", url));
+        }
+
+        Ok(FetchedResource {
+            url: url.to_string(),
+            content_type: Some("text/html".to_string()),
+            body: PAGE_HTML.as_bytes().to_vec(),
+        })
+    }
+}
+
+/// Error returned by a procedure, as the frontend would receive it
+#[derive(Debug)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+pub struct TestApp {
+    pub db: Arc<DatabaseConnection>,
+    pub services: Arc<Services>,
+    /// Where the app's snapshots are written
+    pub snapshot_dir: PathBuf,
+    router: Arc<Router<ContextRouter>>,
+}
+
+/// Building the router exports bindings in debug builds, so it only happens once per test binary
+fn router() -> Arc<Router<ContextRouter>> {
+    static ROUTER: OnceLock<Arc<Router<ContextRouter>>> = OnceLock::new();
+    Arc::clone(ROUTER.get_or_init(|| create_router().arced()))
+}
+
+impl TestApp {
+    pub async fn new() -> Self {
+        Self::with_repositories(Repositories::sea_orm()).await
+    }
+
+    pub async fn with_repositories(repositories: Repositories) -> Self {
+        let db = Database::connect("sqlite::memory:")
+            .await
+            .expect("Failed to open in-memory database");
+        Migrator::up(&db, None)
+            .await
+            .expect("Failed to run migrations");
+
+        let snapshot_dir = std::env::temp_dir()
+            .join("rayzen-tests")
+            .join(uuid::Uuid::new_v4().to_string());
+        let archiver = Arc::new(PageArchiver::new(
+            Arc::new(StaticPageFetcher),
+            SnapshotStorage::new(snapshot_dir.clone()),
+        ));
+
+        TestApp {
+            db: Arc::new(db),
+            services: Arc::new(Services::new(repositories, archiver)),
+            snapshot_dir,
+            router: router(),
+        }
+    }
+
+    pub fn context(&self) -> ContextRouter {
+        ContextRouter::new(Arc::clone(&self.db), Arc::clone(&self.services))
+    }
+
+    pub async fn query(&self, path: &str, input: Value) -> Result<Value, RpcError> {
+        self.exec("query", path, input).await
+    }
+
+    pub async fn mutation(&self, path: &str, input: Value) -> Result<Value, RpcError> {
+        self.exec("mutation", path, input).await
+    }
+
+    /// Runs a procedure through the same JSON-RPC entry point the Tauri plugin uses
+    async fn exec(&self, method: &str, path: &str, input: Value) -> Result<Value, RpcError> {
+        let request: Request = serde_json::from_value(json!({
+            "id": 1,
+            "method": method,
+            "params": { "path": path, "input": input },
+        }))
+        .expect("Invalid request");
+
+        let mut sender = Sender::Response(None);
+        handle_json_rpc(
+            self.context(),
+            request,
+            &self.router,
+            &mut sender,
+            &mut SubscriptionMap::None,
+        )
+        .await;
+
+        let response = match sender {
+            Sender::Response(Some(response)) => serde_json::to_value(response).unwrap(),
+            _ => panic!("No response for {}", path),
+        };
+
+        let result = &response["result"];
+        match result["type"].as_str() {
+            Some("response") => Ok(result["data"].clone()),
+            Some("error") => Err(RpcError {
+                code: result["data"]["code"].as_i64().unwrap_or_default(),
+                message: result["data"]["message"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            }),
+            _ => panic!("Unexpected response for {}: {}", path, response),
+        }
+    }
+
+    /// Like `query`/`mutation` but fails the test on a procedure error
+    pub async fn ok_query(&self, path: &str, input: Value) -> Value {
+        self.query(path, input)
+            .await
+            .unwrap_or_else(|e| panic!("{} failed: {:?}", path, e))
+    }
+
+    pub async fn ok_mutation(&self, path: &str, input: Value) -> Value {
+        self.mutation(path, input)
+            .await
+            .unwrap_or_else(|e| panic!("{} failed: {:?}", path, e))
+    }
+
+    /// Creates a user, organization, workspace and static group, the minimum a bookmark needs
+    pub async fn seed(&self) -> Seed {
+        let user = self
+            .ok_mutation(
+                "users.createUser",
+                json!({ "name": "Ada", "email": "ada@example.com" }),
+            )
+            .await;
+        let organization = self
+            .ok_mutation(
+                "organization.createOrganization",
+                json!({ "name": "Personal", "user_id": user["id"] }),
+            )
+            .await;
+        let workspace = self
+            .ok_mutation(
+                "workspace.createWorkspace",
+                json!({ "name": "Reading", "organization_id": organization["id"] }),
+            )
+            .await;
+        let group = self
+            .ok_mutation(
+                "groups.createGroups",
+                json!({ "name": "Articles", "workspace_id": workspace["id"] }),
+            )
+            .await;
+
+        Seed {
+            user_id: id_of(&user),
+            organization_id: id_of(&organization),
+            workspace_id: id_of(&workspace),
+            group_id: id_of(&group),
+        }
+    }
+
+    pub async fn create_bookmark(&self, group_id: i64, name: &str, url: &str, tags: &str) -> Value {
+        self.ok_mutation(
+            "bookmark.create",
+            json!({
+                "name": name,
+                "url": url,
+                "tags": tags,
+                "is_favorite": false,
+                "group_id": group_id,
+            }),
+        )
+        .await
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Seed {
+    pub user_id: i64,
+    pub organization_id: i64,
+    pub workspace_id: i64,
+    pub group_id: i64,
+}
+
+pub fn id_of(value: &Value) -> i64 {
+    value["id"]
+        .as_i64()
+        .unwrap_or_else(|| panic!("No id in {}", value))
+}
+
+/// Default paging input, every field is optional
+pub fn first_page() -> Value {
+    json!({})
+}
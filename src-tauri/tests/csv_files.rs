@@ -0,0 +1,206 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::TestApp;
+use rayzen_lib::domain::import::{
+    csv_file::{parse_csv_import, read_csv_import, CsvColumnMapping},
+    ImportRowError,
+};
+use sea_orm::ConnectionTrait;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+fn inventory_mapping() -> CsvColumnMapping {
+    CsvColumnMapping {
+        url: "Link".to_string(),
+        name: Some("Title".to_string()),
+        tags: Some("Labels".to_string()),
+        group: Some("team".to_string()),
+        uuid: None,
+    }
+}
+
+#[tokio::test]
+async fn mapped_columns_are_read_and_bad_rows_collected() {
+    let parsed = read_csv_import(&fixture("csv/inventory.csv"), &inventory_mapping(), "Inbox")
+        .await
+        .unwrap();
+
+    let placed: Vec<(&str, &str, &str)> = parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| {
+            (
+                bookmark.name.as_str(),
+                bookmark.group.as_str(),
+                bookmark.tags.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("API latency", "Dashboards", "monitoring,api"),
+            ("DB failover", "Runbooks", "runbook"),
+            // No title and no team fall back to the url and the default group
+            ("https://wiki.example.com/onboarding", "Inbox", "docs"),
+        ]
+    );
+    let rows: Vec<u32> = parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| bookmark.row)
+        .collect();
+    assert_eq!(rows, [2, 3, 6]);
+    assert_eq!(
+        parsed.errors,
+        [
+            ImportRowError {
+                row: 4,
+                message: "The url is empty".to_string(),
+            },
+            ImportRowError {
+                row: 5,
+                message: "\"ftp://files.example.com/dump\" is not a web link".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn unknown_columns_fail_the_whole_file() {
+    let mapping = CsvColumnMapping {
+        url: "href".to_string(),
+        name: None,
+        tags: None,
+        group: None,
+        uuid: None,
+    };
+
+    let error = parse_csv_import("url,name\nhttps://a.example,A\n", &mapping, "Inbox").unwrap_err();
+    assert_eq!(error, "Column \"href\" not found, the file has url, name");
+    assert!(parse_csv_import("url\n", &inventory_mapping(), " ").is_err());
+}
+
+#[tokio::test]
+async fn csv_import_creates_groups_and_export_round_trips() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let path = fixture("csv/inventory.csv");
+
+    let columns = app
+        .ok_query("import.csvColumns", json!(path.display().to_string()))
+        .await;
+    assert_eq!(columns, json!(["Link", "Title", "Labels", "Team", "Owner"]));
+
+    let input = json!({
+        "path": path,
+        "workspace_id": seed.workspace_id,
+        "mapping": { "url": "Link", "name": "Title", "tags": "Labels", "group": "Team" },
+        "default_group": "Articles",
+    });
+    let preview = app.ok_query("import.previewCsv", input.clone()).await;
+    assert_eq!(preview["total"], 3);
+    assert_eq!(preview["errors"].as_array().unwrap().len(), 2);
+    // The default group is the seeded one, so it is reused
+    assert_eq!(preview["groups"][2]["existing_group_id"], seed.group_id);
+
+    let result = app.ok_mutation("import.commitCsv", input).await;
+    assert_eq!(result["groups_created"], 2);
+    assert_eq!(result["bookmarks_created"], 3);
+    assert_eq!(result["errors"][0]["row"], 4);
+
+    let export = app
+        .ok_query(
+            "export.run",
+            json!({
+                "format": "csv",
+                "scope": { "type": "workspace", "workspace_id": seed.workspace_id },
+            }),
+        )
+        .await;
+    assert_eq!(export["file_name"], "reading.csv");
+    let csv = export["content"].as_str().unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "workspace,group,name,url,tags,is_favorite,read_state,created_at,uuid"
+    );
+    assert!(lines[1].starts_with(
+        "Reading,Articles,https://wiki.example.com/onboarding,https://wiki.example.com/onboarding,docs,false,unread,"
+    ));
+    assert!(lines[2].starts_with("Reading,Dashboards,API latency,"));
+    assert!(lines.iter().any(|line| line.contains("\"monitoring,api\"")));
+
+    // Importing the export again finds every bookmark already saved
+    let export_path = std::env::temp_dir().join(format!("rayzen-export-{}.csv", Uuid::new_v4()));
+    std::fs::write(&export_path, csv).unwrap();
+    let again = app
+        .ok_query(
+            "import.previewCsv",
+            json!({
+                "path": export_path,
+                "workspace_id": seed.workspace_id,
+                "mapping": { "url": "url", "name": "name", "tags": "tags", "group": "group" },
+                "default_group": "Articles",
+            }),
+        )
+        .await;
+    let _ = std::fs::remove_file(&export_path);
+
+    assert_eq!(again["total"], 0);
+    let reasons: Vec<&Value> = again["conflicts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|conflict| &conflict["reason"]["type"])
+        .collect();
+    assert_eq!(reasons, [&json!("already_saved"); 3]);
+}
+
+#[tokio::test]
+async fn rows_that_fail_to_save_keep_their_line_number() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    // Stands in for any database error on a single insert
+    app.db
+        .execute_unprepared(
+            "CREATE TRIGGER reject_failover BEFORE INSERT ON bookmark WHEN NEW.name = 'DB failover'
+             BEGIN SELECT RAISE(ABORT, 'rejected by test'); END",
+        )
+        .await
+        .unwrap();
+
+    let result = app
+        .ok_mutation(
+            "import.commitCsv",
+            json!({
+                "path": fixture("csv/inventory.csv"),
+                "workspace_id": seed.workspace_id,
+                "mapping": { "url": "Link", "name": "Title", "tags": "Labels", "group": "Team" },
+                "default_group": "Articles",
+            }),
+        )
+        .await;
+
+    // The rows after the failing one are still imported
+    assert_eq!(result["bookmarks_created"], 2);
+    let rows: Vec<&Value> = result["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| &error["row"])
+        .collect();
+    assert_eq!(rows, [&json!(3), &json!(4), &json!(5)]);
+    assert!(result["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("rejected by test"));
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use rayzen_lib::domain::deep_link::{parse_deep_link, DeepLink};
+use serde_json::json;
+
+#[test]
+fn add_links_are_parsed_and_normalized() {
+    let link = parse_deep_link(
+        "rayzen://add?url=https%3A%2F%2Fdoc.rust-lang.org%2Fbook&group=Articles&name=Rust%20book&tags=rust,%20books,",
+    )
+    .unwrap();
+
+    assert_eq!(
+        link,
+        DeepLink::Add {
+            url: "https://doc.rust-lang.org/book".to_string(),
+            group: "Articles".to_string(),
+            name: Some("Rust book".to_string()),
+            tags: "rust,books".to_string(),
+        }
+    );
+}
+
+#[test]
+fn open_links_are_parsed() {
+    assert_eq!(
+        parse_deep_link("rayzen://open/workspace/3"),
+        Ok(DeepLink::OpenWorkspace(3))
+    );
+    assert_eq!(
+        parse_deep_link("rayzen://open/group/12/"),
+        Ok(DeepLink::OpenGroup(12))
+    );
+    assert_eq!(
+        parse_deep_link("rayzen://open/bookmark/42"),
+        Ok(DeepLink::OpenBookmark(42))
+    );
+}
+
+#[test]
+fn invalid_links_are_rejected() {
+    let invalid = [
+        "https://example.com",
+        "rayzen://delete/bookmark/1",
+        "rayzen://add?group=Articles",
+        "rayzen://add?url=https://example.com",
+        "rayzen://add?url=javascript:alert(1)&group=Articles",
+        "rayzen://add?url=not%20a%20url&group=Articles",
+        "rayzen://open/workspace",
+        "rayzen://open/workspace/abc",
+        "rayzen://open/workspace/-1",
+        "rayzen://open/organization/1",
+        "not a link",
+    ];
+
+    for link in invalid {
+        assert!(parse_deep_link(link).is_err(), "{} was accepted", link);
+    }
+}
+
+#[tokio::test]
+async fn add_links_resolve_to_a_confirmation_without_saving() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let action = app
+        .ok_query(
+            "deepLink.resolve",
+            json!("rayzen://add?url=https://go.dev/tour&group=articles"),
+        )
+        .await;
+
+    assert_eq!(action["type"], "confirm_add");
+    assert_eq!(action["group"]["id"], seed.group_id);
+    assert_eq!(action["bookmark"]["name"], "https://go.dev/tour");
+    assert_eq!(action["bookmark"]["group_id"], seed.group_id);
+
+    // Confirming is left to the user, so the group is still empty
+    let bookmarks = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert!(bookmarks["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn open_links_resolve_to_existing_entities() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "")
+        .await;
+
+    let workspace = app
+        .ok_query(
+            "deepLink.resolve",
+            json!(format!("rayzen://open/workspace/{}", seed.workspace_id)),
+        )
+        .await;
+    assert_eq!(workspace["type"], "open_workspace");
+    assert_eq!(workspace["workspace"]["name"], "Reading");
+
+    let opened = app
+        .ok_query(
+            "deepLink.resolve",
+            json!(format!("rayzen://open/bookmark/{}", id_of(&bookmark))),
+        )
+        .await;
+    assert_eq!(opened["type"], "open_bookmark");
+    assert_eq!(opened["bookmark"]["url"], "https://go.dev/tour");
+
+    assert!(app
+        .query("deepLink.resolve", json!("rayzen://open/group/9999"))
+        .await
+        .is_err());
+    assert!(app
+        .query(
+            "deepLink.resolve",
+            json!("rayzen://add?url=https://go.dev&group=Nope")
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,269 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{id_of, TestApp};
+use futures::{Stream, StreamExt};
+use rayzen_lib::domain::events::{BookmarkChangedEvent, ChangeKind};
+use serde_json::json;
+
+async fn next_event<S: Stream + Unpin>(stream: &mut S) -> S::Item {
+    tokio::time::timeout(Duration::from_secs(1), stream.next())
+        .await
+        .expect("No change event was published")
+        .expect("Change stream ended")
+}
+
+#[tokio::test]
+async fn bookmark_mutations_are_published_to_their_workspace() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let workspace_id = seed.workspace_id as i32;
+    let mut changes = Box::pin(app.services.events.bookmarks(workspace_id));
+    let mut elsewhere = Box::pin(app.services.events.bookmarks(workspace_id + 1));
+
+    let bookmark = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "",
+        )
+        .await;
+    let id = id_of(&bookmark) as i32;
+
+    let created = next_event(&mut changes).await;
+    assert_eq!(created.kind, ChangeKind::Created);
+    assert_eq!(created.bookmark_id, id);
+    assert_eq!(created.bookmark.unwrap().name, "Rust book");
+
+    app.ok_mutation("readLater.markRead", json!(id)).await;
+    let read = next_event(&mut changes).await;
+    assert_eq!(read.kind, ChangeKind::Updated);
+
+    app.ok_mutation("bookmark.delete", json!(id)).await;
+    let deleted = next_event(&mut changes).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert!(deleted.bookmark.is_none());
+
+    // Other workspaces never hear about it
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), elsewhere.next())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn group_and_workspace_mutations_are_published() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let mut groups = Box::pin(app.services.events.groups(seed.workspace_id as i32));
+    let mut workspaces = Box::pin(app.services.events.workspaces(seed.organization_id as i32));
+
+    app.ok_mutation(
+        "groups.updateGroup",
+        json!({ "id": seed.group_id, "name": "Essays", "workspace_id": null }),
+    )
+    .await;
+    let renamed = next_event(&mut groups).await;
+    assert_eq!(renamed.kind, ChangeKind::Updated);
+    assert_eq!(renamed.group.unwrap().name, "Essays");
+
+    app.ok_mutation("groups.deleteGroup", json!(seed.group_id))
+        .await;
+    let deleted = next_event(&mut groups).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.group_id, seed.group_id as i32);
+
+    let workspace = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Later", "organization_id": seed.organization_id }),
+        )
+        .await;
+    let created = next_event(&mut workspaces).await;
+    assert_eq!(created.kind, ChangeKind::Created);
+    assert_eq!(created.workspace_id, id_of(&workspace) as i32);
+}
+
+/// Rows the database drops along with their parent are announced too
+#[tokio::test]
+async fn cascaded_deletes_are_published() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let workspace_id = seed.workspace_id as i32;
+    let bookmark = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "",
+        )
+        .await;
+    let other = app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Later", "workspace_id": seed.workspace_id }),
+        )
+        .await;
+    let kept = app
+        .create_bookmark(id_of(&other), "Go tour", "https://go.dev/tour", "")
+        .await;
+    let mut bookmarks = Box::pin(app.services.events.bookmarks(workspace_id));
+    let mut groups = Box::pin(app.services.events.groups(workspace_id));
+
+    app.ok_mutation("groups.deleteGroup", json!(seed.group_id))
+        .await;
+    let deleted = next_event(&mut bookmarks).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.bookmark_id, id_of(&bookmark) as i32);
+    assert_eq!(next_event(&mut groups).await.group_id, seed.group_id as i32);
+
+    app.ok_mutation("workspace.deleteWorkspace", json!(seed.workspace_id))
+        .await;
+    let deleted = next_event(&mut bookmarks).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.bookmark_id, id_of(&kept) as i32);
+    let deleted = next_event(&mut groups).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.group_id, id_of(&other) as i32);
+}
+
+/// Moving a bookmark to another workspace is a delete there and an update here
+#[tokio::test]
+async fn bookmarks_moved_across_workspaces_are_published_to_both() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "",
+        )
+        .await;
+    let id = id_of(&bookmark);
+    let team = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": seed.organization_id }),
+        )
+        .await;
+    let shared = app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Shared", "workspace_id": team["id"] }),
+        )
+        .await;
+    let mut left = Box::pin(app.services.events.bookmarks(seed.workspace_id as i32));
+    let mut joined = Box::pin(app.services.events.bookmarks(id_of(&team) as i32));
+
+    let moved = app
+        .ok_mutation(
+            "bookmark.update",
+            json!({
+                "id": id,
+                "name": null,
+                "url": null,
+                "tags": null,
+                "is_favorite": null,
+                "group_id": id_of(&shared),
+            }),
+        )
+        .await;
+    assert_eq!(moved["group_id"], id_of(&shared));
+
+    let deleted = next_event(&mut left).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.bookmark_id, id as i32);
+    let updated = next_event(&mut joined).await;
+    assert_eq!(updated.kind, ChangeKind::Updated);
+    assert_eq!(updated.bookmark.unwrap().group_id, id_of(&shared) as i32);
+
+    let found = app.ok_query("bookmark.getById", json!(id)).await;
+    assert_eq!(found["group_id"], id_of(&shared));
+}
+
+/// A subscriber that fell too far behind is told to fetch everything again
+#[tokio::test]
+async fn lagging_subscribers_are_told_to_resync() {
+    let app = TestApp::new().await;
+    let events = &app.services.events;
+    let mut changes = Box::pin(events.bookmarks(1));
+
+    for id in 0..300 {
+        events.publish(BookmarkChangedEvent::deleted(1, id));
+    }
+
+    let resync = next_event(&mut changes).await;
+    assert_eq!(resync.kind, ChangeKind::Resync);
+    assert_eq!(resync.workspace_id, 1);
+    // Whatever is still buffered follows
+    assert_eq!(next_event(&mut changes).await.kind, ChangeKind::Deleted);
+}
+
+#[tokio::test]
+async fn organization_deletes_are_published_for_everything_in_it() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "",
+        )
+        .await;
+    let mut bookmarks = Box::pin(app.services.events.bookmarks(seed.workspace_id as i32));
+    let mut groups = Box::pin(app.services.events.groups(seed.workspace_id as i32));
+    let mut workspaces = Box::pin(app.services.events.workspaces(seed.organization_id as i32));
+
+    app.ok_mutation(
+        "organization.deleteOrganization",
+        json!(seed.organization_id),
+    )
+    .await;
+
+    let deleted = next_event(&mut bookmarks).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.bookmark_id, id_of(&bookmark) as i32);
+    assert_eq!(next_event(&mut groups).await.group_id, seed.group_id as i32);
+    let deleted = next_event(&mut workspaces).await;
+    assert_eq!(deleted.kind, ChangeKind::Deleted);
+    assert_eq!(deleted.workspace_id, seed.workspace_id as i32);
+}
+
+/// Snapshots change a bookmark's broken state and reading time
+#[tokio::test]
+async fn snapshots_are_published_as_bookmark_updates() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Offline", "https://example.com/offline", "")
+        .await;
+    let broken = app
+        .create_bookmark(seed.group_id, "Gone", "https://gone.invalid/", "")
+        .await;
+    let mut changes = Box::pin(app.services.events.bookmarks(seed.workspace_id as i32));
+
+    app.ok_mutation(
+        "bookmark.snapshot",
+        json!({ "bookmark_id": id_of(&bookmark), "single_file": false }),
+    )
+    .await;
+    let updated = next_event(&mut changes).await;
+    assert_eq!(updated.kind, ChangeKind::Updated);
+    assert_eq!(updated.bookmark.unwrap().reading_time_minutes, Some(1));
+
+    assert!(app
+        .mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id_of(&broken), "single_file": false }),
+        )
+        .await
+        .is_err());
+    let updated = next_event(&mut changes).await;
+    assert_eq!(updated.bookmark_id, id_of(&broken) as i32);
+    assert!(updated.bookmark.unwrap().broken_at.is_some());
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use common::{id_of, Seed, TestApp};
+use serde_json::{json, Value};
+
+/// Seeds "Articles" with one bookmark, then "Rust / Databases" before "Rust" and an empty group
+async fn library(app: &TestApp) -> Seed {
+    let seed = app.seed().await;
+    app.create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "")
+        .await;
+
+    let mut groups = Vec::new();
+    for name in ["Rust / Databases", "Rust", "Empty"] {
+        let group = app
+            .ok_mutation(
+                "groups.createGroups",
+                json!({ "name": name, "workspace_id": seed.workspace_id }),
+            )
+            .await;
+        groups.push(id_of(&group));
+    }
+    app.create_bookmark(
+        groups[0],
+        "SeaORM [docs]",
+        "https://www.sea-ql.org/SeaORM/",
+        "db,orm",
+    )
+    .await;
+    app.create_bookmark(groups[1], "Tokio & Axum", "https://tokio.rs", "")
+        .await;
+
+    seed
+}
+
+async fn export(app: &TestApp, format: &str, scope: Value) -> Value {
+    app.ok_query("export.run", json!({ "format": format, "scope": scope }))
+        .await
+}
+
+#[tokio::test]
+async fn formats_are_listed_from_the_registry() {
+    let app = TestApp::new().await;
+
+    let formats = app.ok_query("export.formats", json!(null)).await;
+    let names: Vec<&str> = formats
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|format| format["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["csv", "markdown", "opml"]);
+}
+
+#[tokio::test]
+async fn markdown_nests_groups_under_headings() {
+    let app = TestApp::new().await;
+    let workspace_id = library(&app).await.workspace_id;
+
+    let file = export(
+        &app,
+        "markdown",
+        json!({ "type": "workspace", "workspace_id": workspace_id }),
+    )
+    .await;
+    assert_eq!(file["file_name"], "reading.md");
+    assert_eq!(file["mime_type"], "text/markdown");
+    assert_eq!(
+        file["content"],
+        "# Reading\n\
+         \n\
+         ## Articles\n\
+         \n\
+         - [Go tour](https://go.dev/tour)\n\
+         \n\
+         ## Rust\n\
+         \n\
+         - [Tokio & Axum](https://tokio.rs)\n\
+         \n\
+         ### Databases\n\
+         \n\
+         - [SeaORM \\[docs\\]](https://www.sea-ql.org/SeaORM/) `db` `orm`\n"
+    );
+}
+
+#[tokio::test]
+async fn opml_outlines_escape_attributes() {
+    let app = TestApp::new().await;
+    let workspace_id = library(&app).await.workspace_id;
+
+    let file = export(
+        &app,
+        "opml",
+        json!({ "type": "workspace", "workspace_id": workspace_id }),
+    )
+    .await;
+    let opml = file["content"].as_str().unwrap();
+
+    assert!(opml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">"));
+    assert!(opml.contains("<title>Reading</title>"));
+    assert!(opml.contains(
+        "    <outline text=\"Rust\">\n      <outline text=\"Tokio &amp; Axum\" type=\"link\" url=\"https://tokio.rs\""
+    ));
+    assert!(opml.contains("      <outline text=\"Databases\">\n"));
+    assert!(opml.contains("category=\"db,orm\"/>"));
+    // Groups without bookmarks are left out
+    assert!(!opml.contains("Empty"));
+}
+
+#[tokio::test]
+async fn groups_and_searches_can_be_exported() {
+    let app = TestApp::new().await;
+    let seed = library(&app).await;
+    let workspace_id = seed.workspace_id;
+
+    let search = export(
+        &app,
+        "markdown",
+        json!({ "type": "search", "workspace_id": workspace_id, "query": "tokio" }),
+    )
+    .await;
+    assert_eq!(search["file_name"], "search-tokio.md");
+    assert_eq!(
+        search["content"],
+        "# Search \"tokio\"\n\n## Rust\n\n- [Tokio & Axum](https://tokio.rs)\n"
+    );
+
+    let group = export(
+        &app,
+        "csv",
+        json!({ "type": "group", "group_id": seed.group_id }),
+    )
+    .await;
+    assert_eq!(group["content"].as_str().unwrap().lines().count(), 2);
+}
+
+#[tokio::test]
+async fn unknown_formats_are_rejected() {
+    let app = TestApp::new().await;
+    let workspace_id = library(&app).await.workspace_id;
+
+    let error = app
+        .query(
+            "export.run",
+            json!({
+                "format": "pdf",
+                "scope": { "type": "workspace", "workspace_id": workspace_id },
+            }),
+        )
+        .await
+        .unwrap_err();
+    assert!(error.message.contains("Unknown export format \"pdf\""));
+}
@@ -0,0 +1,91 @@
+//! Reader-mode extraction on hand-written pages.
+
+use rayzen_lib::domain::extractor::{extract, reading_time_minutes, WORDS_PER_MINUTE};
+
+#[test]
+fn boilerplate_is_left_out_of_the_reader_text() {
+    let html = r#"<html>
+<head><title> The  Title </title><script>track("visit");</script><style>p { color: red }</style></head>
+<body>
+  <nav>Home | Archive | About</nav>
+  <div class="sidebar-widget"><p>Subscribe to the newsletter, it is free, weekly and short.</p></div>
+  <div id="main-content">
+    <h2>Heading</h2>
+    <p>The first paragraph is long enough to count, with a comma or two, for scoring.</p>
+    <p>The second paragraph keeps going so this block clearly wins the scoring.</p>
+    <div class="ad-banner">Buy now</div>
+  </div>
+  <footer>Copyright</footer>
+</body>
+</html>"#;
+
+    let content = extract(html);
+
+    assert_eq!(content.title.as_deref(), Some("The Title"));
+    assert!(content.text.starts_with("Heading\n\nThe first paragraph"));
+    for boilerplate in [
+        "track",
+        "color",
+        "Home",
+        "Subscribe",
+        "Buy now",
+        "Copyright",
+    ] {
+        assert!(
+            !content.text.contains(boilerplate),
+            "{boilerplate:?} leaked into {:?}",
+            content.text
+        );
+    }
+    assert_eq!(
+        content.word_count,
+        content.text.split_whitespace().count() as i32
+    );
+}
+
+#[test]
+fn link_lists_lose_to_article_text() {
+    let html = r#"<body>
+  <div><a href="/1">A link list that is long enough, with commas, to score</a><p><a href="/2">Another long linked paragraph, with commas, that scores too</a></p></div>
+  <article><p>Plain article text without any links in it at all, which should win.</p></article>
+</body>"#;
+
+    let content = extract(html);
+
+    assert_eq!(
+        content.text,
+        "Plain article text without any links in it at all, which should win."
+    );
+}
+
+#[test]
+fn empty_pages_have_no_text() {
+    for html in [
+        "",
+        "<html><body></body></html>",
+        "<body><nav>Only navigation</nav></body>",
+    ] {
+        let content = extract(html);
+
+        assert_eq!(content.title, None);
+        assert_eq!(content.text, "");
+        assert_eq!(content.word_count, 0);
+    }
+}
+
+#[test]
+fn title_falls_back_to_the_first_heading() {
+    let content = extract("<body><h1>Only a heading</h1></body>");
+
+    assert_eq!(content.title.as_deref(), Some("Only a heading"));
+}
+
+#[test]
+fn reading_time_rounds_up_to_whole_minutes() {
+    assert_eq!(reading_time_minutes(-5), 0);
+    assert_eq!(reading_time_minutes(0), 0);
+    assert_eq!(reading_time_minutes(1), 1);
+    assert_eq!(reading_time_minutes(WORDS_PER_MINUTE), 1);
+    assert_eq!(reading_time_minutes(WORDS_PER_MINUTE + 1), 2);
+    assert_eq!(reading_time_minutes(10 * WORDS_PER_MINUTE), 10);
+}
@@ -0,0 +1,420 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use sea_orm::ConnectionTrait;
+use serde_json::json;
+
+#[tokio::test]
+async fn group_crud_round_trip() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let found = app
+        .ok_query("groups.getGroupById", json!(seed.group_id))
+        .await;
+    assert_eq!(found["name"], "Articles");
+    assert_eq!(found["kind"], "static");
+
+    let belonged = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([seed.workspace_id, seed.organization_id, first_page()]),
+        )
+        .await;
+    assert_eq!(belonged["items"].as_array().unwrap().len(), 1);
+
+    let updated = app
+        .ok_mutation(
+            "groups.updateGroup",
+            json!({ "id": seed.group_id, "name": "Essays", "workspace_id": null }),
+        )
+        .await;
+    assert_eq!(updated["name"], "Essays");
+    assert_eq!(updated["workspace_id"], seed.workspace_id);
+
+    let page = app.ok_query("groups.getGroups", first_page()).await;
+    assert_eq!(page["total"], 1);
+
+    app.ok_mutation("groups.deleteGroup", json!(seed.group_id))
+        .await;
+    assert!(app
+        .query("groups.getGroupById", json!(seed.group_id))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn belonged_groups_are_paged() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    for name in ["Books", "Videos"] {
+        app.ok_mutation(
+            "groups.createGroups",
+            json!({ "name": name, "workspace_id": seed.workspace_id }),
+        )
+        .await;
+    }
+
+    let first = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([seed.workspace_id, seed.organization_id, { "per_page": 2 }]),
+        )
+        .await;
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["items"].as_array().unwrap().len(), 2);
+    assert_eq!(first["next_cursor"], "1");
+
+    let last = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([seed.workspace_id, seed.organization_id, { "cursor": "1", "per_page": 2 }]),
+        )
+        .await;
+    assert_eq!(last["items"].as_array().unwrap().len(), 1);
+    assert_eq!(last["items"][0]["name"], "Videos");
+    assert!(last["next_cursor"].is_null());
+}
+
+/// `update_group` used to unwrap every field of the update and panic when one was left out
+#[tokio::test]
+async fn partial_group_update_keeps_other_fields() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let updated = app
+        .ok_mutation(
+            "groups.updateGroup",
+            json!({ "id": seed.group_id, "name": null, "workspace_id": null }),
+        )
+        .await;
+
+    assert_eq!(updated["name"], "Articles");
+}
+
+#[tokio::test]
+async fn smart_group_is_evaluated_from_its_filter() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+    app.create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "go")
+        .await;
+    // Neither a whole tag nor the same host
+    app.create_bookmark(
+        seed.group_id,
+        "Rustlings",
+        "https://go.dev.example.org/rustlings",
+        "rustlings, learning",
+    )
+    .await;
+
+    let smart = app
+        .ok_mutation(
+            "groups.createSmartGroup",
+            json!({
+                "name": "Rust",
+                "workspace_id": seed.workspace_id,
+                "filter": { "tags": ["rust"] },
+            }),
+        )
+        .await;
+    assert_eq!(smart["kind"], "smart");
+
+    let matches = app
+        .ok_query("groups.evaluateSmartGroup", json!(id_of(&smart)))
+        .await;
+    assert_eq!(matches.as_array().unwrap().len(), 1);
+    assert_eq!(matches[0]["name"], "Rust book");
+
+    app.ok_mutation(
+        "groups.updateSmartGroupFilter",
+        json!({ "id": id_of(&smart), "filter": { "host": "go.dev" } }),
+    )
+    .await;
+    let matches = app
+        .ok_query("groups.evaluateSmartGroup", json!(id_of(&smart)))
+        .await;
+    assert_eq!(matches.as_array().unwrap().len(), 1);
+    assert_eq!(matches[0]["name"], "Go tour");
+
+    // Smart group membership is computed, so bookmarks can't be filed into one
+    let result = app
+        .mutation(
+            "bookmark.create",
+            json!({
+                "name": "Nope",
+                "url": "https://example.com",
+                "tags": "",
+                "is_favorite": false,
+                "group_id": id_of(&smart),
+            }),
+        )
+        .await;
+    assert!(result.is_err());
+}
+
+/// Filter input is matched literally and the host only right after the scheme
+#[tokio::test]
+async fn smart_group_filters_escape_wildcards_and_anchor_hosts() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Sale",
+        "https://shop.example.com/",
+        "100%, a_b",
+    )
+    .await;
+    app.create_bookmark(
+        seed.group_id,
+        "Lookalike",
+        "https://example.org/?next=https://go.dev/tour",
+        "100x, axb",
+    )
+    .await;
+
+    let smart = app
+        .ok_mutation(
+            "groups.createSmartGroup",
+            json!({
+                "name": "Literal",
+                "workspace_id": seed.workspace_id,
+                "filter": { "tags": ["100%", "a_b"] },
+            }),
+        )
+        .await;
+    let matches = app
+        .ok_query("groups.evaluateSmartGroup", json!(id_of(&smart)))
+        .await;
+    assert_eq!(matches.as_array().unwrap().len(), 1);
+    assert_eq!(matches[0]["name"], "Sale");
+
+    // `_` would match any single character if it wasn't escaped
+    app.ok_mutation(
+        "groups.updateSmartGroupFilter",
+        json!({ "id": id_of(&smart), "filter": { "tags": ["a_b"], "host": "shop_example.com" } }),
+    )
+    .await;
+    let matches = app
+        .ok_query("groups.evaluateSmartGroup", json!(id_of(&smart)))
+        .await;
+    assert_eq!(matches.as_array().unwrap().len(), 0);
+
+    // The redirect target in the query string is not the bookmark's host
+    app.ok_mutation(
+        "groups.updateSmartGroupFilter",
+        json!({ "id": id_of(&smart), "filter": { "host": "go.dev" } }),
+    )
+    .await;
+    let matches = app
+        .ok_query("groups.evaluateSmartGroup", json!(id_of(&smart)))
+        .await;
+    assert_eq!(matches.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn static_group_has_no_filter() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    assert!(app
+        .query("groups.evaluateSmartGroup", json!(seed.group_id))
+        .await
+        .is_err());
+    assert!(app
+        .mutation(
+            "groups.updateSmartGroupFilter",
+            json!({ "id": seed.group_id, "filter": {} }),
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn groups_move_and_duplicate_with_their_bookmarks() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+    app.create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "go")
+        .await;
+
+    let copy = app
+        .ok_mutation(
+            "groups.duplicate",
+            json!({ "id": seed.group_id, "workspace_id": null, "name": null }),
+        )
+        .await;
+    assert_eq!(copy["name"], "Articles (copy)");
+    assert_eq!(copy["workspace_id"], seed.workspace_id);
+    let copied = app
+        .ok_query("bookmark.getByGroup", json!([id_of(&copy), first_page()]))
+        .await;
+    let originals = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(copied["items"].as_array().unwrap().len(), 2);
+    assert_eq!(copied["items"][0]["url"], originals["items"][0]["url"]);
+    assert_ne!(copied["items"][0]["uuid"], originals["items"][0]["uuid"]);
+
+    // Into a workspace of another organization, its saved search counts the new bookmarks
+    let organization = app
+        .ok_mutation(
+            "organization.createOrganization",
+            json!({ "name": "Work", "user_id": seed.user_id }),
+        )
+        .await;
+    let team = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": organization["id"] }),
+        )
+        .await;
+    let saved = app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": team["id"],
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+    assert_eq!(saved["cached_count"], 0);
+
+    let group = app
+        .ok_query("groups.getGroupById", json!(seed.group_id))
+        .await;
+    let moved = app
+        .ok_mutation(
+            "groups.move",
+            json!({ "id": seed.group_id, "workspace_id": team["id"] }),
+        )
+        .await;
+    assert_eq!(moved["workspace_id"], team["id"]);
+    assert_eq!(moved["uuid"], group["uuid"]);
+    let moved_bookmarks = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(moved_bookmarks, originals);
+    let saved = app
+        .ok_query("savedSearch.getById", saved["id"].clone())
+        .await;
+    assert_eq!(saved["cached_count"], 1);
+
+    let in_reading = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([seed.workspace_id, seed.organization_id, first_page()]),
+        )
+        .await;
+    assert_eq!(in_reading["items"].as_array().unwrap().len(), 1);
+
+    assert!(app
+        .mutation(
+            "groups.move",
+            json!({ "id": seed.group_id, "workspace_id": 999 }),
+        )
+        .await
+        .is_err());
+}
+
+/// A new `workspace_id` in an update moves the group the way `groups.move` does
+#[tokio::test]
+async fn updating_the_workspace_moves_the_group() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+    let team = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": seed.organization_id }),
+        )
+        .await;
+    let saved = app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": team["id"],
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+    assert_eq!(saved["cached_count"], 0);
+
+    let updated = app
+        .ok_mutation(
+            "groups.updateGroup",
+            json!({ "id": seed.group_id, "name": "Books", "workspace_id": team["id"] }),
+        )
+        .await;
+    assert_eq!(updated["name"], "Books");
+    assert_eq!(updated["workspace_id"], team["id"]);
+
+    let saved = app
+        .ok_query("savedSearch.getById", saved["id"].clone())
+        .await;
+    assert_eq!(saved["cached_count"], 1);
+    assert!(app
+        .mutation(
+            "groups.updateGroup",
+            json!({ "id": seed.group_id, "name": null, "workspace_id": 999 }),
+        )
+        .await
+        .is_err());
+}
+
+/// A move whose other fields cannot be saved leaves the group where it was
+#[tokio::test]
+async fn failed_update_does_not_move_the_group() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let team = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": seed.organization_id }),
+        )
+        .await;
+    app.db
+        .execute_unprepared(
+            "CREATE TRIGGER reject_rename BEFORE UPDATE OF name ON groups
+             WHEN NEW.name = 'Rejected'
+             BEGIN SELECT RAISE(ABORT, 'rename rejected'); END",
+        )
+        .await
+        .unwrap();
+
+    let result = app
+        .mutation(
+            "groups.updateGroup",
+            json!({ "id": seed.group_id, "name": "Rejected", "workspace_id": team["id"] }),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let group = app
+        .ok_query("groups.getGroupById", json!(seed.group_id))
+        .await;
+    assert_eq!(group["name"], "Articles");
+    assert_eq!(group["workspace_id"], seed.workspace_id);
+}
@@ -0,0 +1,235 @@
+mod common;
+
+use std::net::TcpListener;
+
+use common::{id_of, TestApp};
+use rayzen_lib::domain::http_api::{serve, HttpApiController};
+use reqwest::{Client, StatusCode};
+use serde_json::{json, Value};
+
+const TOKEN: &str = "test-token";
+
+async fn body(response: reqwest::Response) -> Value {
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+/// Asks the OS for a port nobody is listening on
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+#[tokio::test]
+async fn requests_without_the_token_are_rejected() {
+    let app = TestApp::new().await;
+    let server = serve(app.context(), 0, TOKEN.to_string()).await.unwrap();
+    let url = format!("http://{}/bookmarks", server.address());
+    let client = Client::new();
+
+    let missing = client.get(&url).send().await.unwrap();
+    assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+
+    let wrong = client
+        .get(&url)
+        .bearer_auth("not-the-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(wrong.status(), StatusCode::UNAUTHORIZED);
+    assert!(body(wrong).await["error"].is_string());
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn bookmarks_can_be_created_listed_and_searched() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let server = serve(app.context(), 0, TOKEN.to_string()).await.unwrap();
+    assert!(server.address().ip().is_loopback());
+    let base = format!("http://{}", server.address());
+    let client = Client::new();
+
+    let created = client
+        .post(format!("{}/bookmarks", base))
+        .bearer_auth(TOKEN)
+        .header("content-type", "application/json")
+        .body(
+            json!({
+                "name": "Rust book",
+                "url": "https://doc.rust-lang.org/book",
+                "tags": "rust",
+                "is_favorite": false,
+                "group_id": seed.group_id,
+            })
+            .to_string(),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(created.status(), StatusCode::CREATED);
+    let created = body(created).await;
+    assert_eq!(created["name"], "Rust book");
+
+    // Whatever the API creates is visible to the app through rspc
+    let found = app
+        .ok_query("bookmark.getById", json!(id_of(&created)))
+        .await;
+    assert_eq!(found["url"], "https://doc.rust-lang.org/book");
+
+    app.create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "go")
+        .await;
+
+    let page = client
+        .get(format!("{}/bookmarks?per_page=1", base))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(page.status(), StatusCode::OK);
+    let page = body(page).await;
+    assert_eq!(page["total"], 2);
+    assert_eq!(page["items"].as_array().unwrap().len(), 1);
+    assert_eq!(page["next_cursor"], "1");
+
+    let results = client
+        .get(format!("{}/bookmarks/search?q=rust", base))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    let results = body(results).await;
+    assert_eq!(results["total"], 1);
+    assert_eq!(results["items"][0]["name"], "Rust book");
+
+    let in_group = client
+        .get(format!(
+            "{}/groups/{}/bookmarks?per_page=1&page=1",
+            base, seed.group_id
+        ))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    let in_group = body(in_group).await;
+    assert_eq!(in_group["total"], 2);
+    assert_eq!(in_group["items"].as_array().unwrap().len(), 1);
+    assert_eq!(in_group["next_cursor"], json!(null));
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn groups_are_listed_and_service_errors_are_bad_requests() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let server = serve(app.context(), 0, TOKEN.to_string()).await.unwrap();
+    let base = format!("http://{}", server.address());
+    let client = Client::new();
+
+    let groups = client
+        .get(format!("{}/groups?search=Art", base))
+        .bearer_auth(TOKEN)
+        .send()
+        .await
+        .unwrap();
+    let groups = body(groups).await;
+    assert_eq!(groups["items"][0]["id"], seed.group_id);
+
+    let invalid = client
+        .post(format!("{}/bookmarks", base))
+        .bearer_auth(TOKEN)
+        .header("content-type", "application/json")
+        .body(
+            json!({
+                "name": "Nowhere",
+                "url": "https://example.com",
+                "tags": "",
+                "is_favorite": false,
+                "group_id": 9999,
+            })
+            .to_string(),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn controller_follows_the_saved_settings() {
+    let app = TestApp::new().await;
+    let settings = &app.services.settings;
+    let controller = HttpApiController::new();
+
+    // Off until the user turns it on, with a token ready to copy
+    let status = controller.apply(app.context()).await.unwrap();
+    assert!(!status.enabled);
+    assert!(!status.running);
+    assert!(!status.token.is_empty());
+
+    settings
+        .set_http_api_port(app.context(), free_port())
+        .await
+        .unwrap();
+    settings
+        .set_http_api_enabled(app.context(), true)
+        .await
+        .unwrap();
+    let status = controller.apply(app.context()).await.unwrap();
+    assert!(status.running);
+    let address = status.address.unwrap();
+
+    let response = Client::new()
+        .get(format!("{}/groups", address))
+        .bearer_auth(&status.token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A new token takes effect as soon as the server is restarted with it
+    settings
+        .regenerate_http_api_token(app.context())
+        .await
+        .unwrap();
+    let restarted = controller.apply(app.context()).await.unwrap();
+    assert_ne!(restarted.token, status.token);
+    let stale = Client::new()
+        .get(format!("{}/groups", address))
+        .bearer_auth(&status.token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(stale.status(), StatusCode::UNAUTHORIZED);
+
+    settings
+        .set_http_api_enabled(app.context(), false)
+        .await
+        .unwrap();
+    let stopped = controller.apply(app.context()).await.unwrap();
+    assert!(!stopped.running);
+    assert!(Client::new()
+        .get(format!("{}/groups", address))
+        .send()
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn port_zero_is_rejected() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .services
+        .settings
+        .set_http_api_port(app.context(), 0)
+        .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,456 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::{first_page, TestApp};
+use rayzen_lib::domain::{
+    import::{
+        chromium::read_chromium_bookmarks,
+        firefox::read_firefox_bookmarks,
+        pinboard::read_pinboard_export,
+        pocket::{parse_pocket_csv, read_pocket_export},
+        raindrop::{parse_raindrop_csv, read_raindrop_export},
+        ParsedImport,
+    },
+    types::read_later::ReadState,
+};
+use sea_orm::{ConnectionTrait, Database};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(path)
+}
+
+fn group_names(preview: &Value) -> Vec<&str> {
+    preview["groups"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|group| group["name"].as_str().unwrap())
+        .collect()
+}
+
+fn summary(parsed: &ParsedImport) -> Vec<(&str, &str, &str, Option<ReadState>)> {
+    parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| {
+            (
+                bookmark.name.as_str(),
+                bookmark.group.as_str(),
+                bookmark.tags.as_str(),
+                bookmark.read_state,
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn chromium_folders_become_groups() {
+    let parsed = read_chromium_bookmarks(&fixture("chromium/Bookmarks"))
+        .await
+        .unwrap();
+
+    let placed: Vec<(&str, &str)> = parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| (bookmark.name.as_str(), bookmark.group.as_str()))
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("Rust book", "Bookmarks bar"),
+            ("Tokio tutorial", "Rust"),
+            ("SeaORM docs", "Rust / Databases"),
+            ("Go tour", "Other bookmarks"),
+        ]
+    );
+    assert_eq!(parsed.skipped, ["chrome://extensions/"]);
+
+    // Stored as microseconds since 1601, 13350000000000000 is 2024-01-17 21:20 UTC
+    let created_at = parsed.bookmarks[0].created_at.unwrap();
+    assert_eq!(created_at.to_rfc3339(), "2024-01-17T21:20:00+00:00");
+}
+
+#[tokio::test]
+async fn firefox_places_keep_folders_and_tags() {
+    // A profile directory works as well as the database file
+    let parsed = read_firefox_bookmarks(&fixture("firefox")).await.unwrap();
+
+    let placed: Vec<(&str, &str, &str)> = parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| {
+            (
+                bookmark.name.as_str(),
+                bookmark.group.as_str(),
+                bookmark.tags.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("MDN JavaScript", "Bookmarks Toolbar", ""),
+            ("Hacker News", "Other Bookmarks", ""),
+            ("Rust book", "Docs", "rust,reference"),
+            // Untitled bookmarks fall back to the page title
+            ("SQLite Documentation", "Docs", "reference"),
+        ]
+    );
+    assert_eq!(parsed.skipped, ["place:sort=8&maxResults=10"]);
+}
+
+/// A running Firefox keeps its latest bookmarks in `places.sqlite-wal` until it checkpoints
+#[tokio::test]
+async fn firefox_bookmarks_still_in_the_wal_are_read() {
+    let profile = TempDir::new().unwrap();
+    let places = profile.path().join("places.sqlite");
+    std::fs::copy(fixture("firefox/places.sqlite"), &places).unwrap();
+
+    // Stays open like Firefox would, closing it would checkpoint the WAL into the file
+    let firefox = Database::connect(format!("sqlite://{}?mode=rw", places.display()))
+        .await
+        .unwrap();
+    for sql in [
+        "PRAGMA journal_mode = WAL",
+        "INSERT INTO moz_places (id, url, title) VALUES (9, 'https://ziglang.org', 'Zig')",
+        "INSERT INTO moz_bookmarks (id, type, fk, parent, position, title, dateAdded, guid)
+         VALUES (30, 1, 9, 5, 1, 'Zig', 1700000004000000, 'bm000000zig1')",
+    ] {
+        firefox.execute_unprepared(sql).await.unwrap();
+    }
+    assert!(profile.path().join("places.sqlite-wal").exists());
+
+    let parsed = read_firefox_bookmarks(profile.path()).await.unwrap();
+    assert!(parsed
+        .bookmarks
+        .iter()
+        .any(|bookmark| bookmark.name == "Zig" && bookmark.group == "Other Bookmarks"));
+
+    firefox.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn unreadable_files_are_reported() {
+    assert!(read_chromium_bookmarks(&fixture("firefox/places.sqlite"))
+        .await
+        .is_err());
+    assert!(read_firefox_bookmarks(&fixture("missing/places.sqlite"))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn preview_writes_nothing_and_commit_reuses_groups() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let rust = app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "rust", "workspace_id": seed.workspace_id }),
+        )
+        .await;
+    let input = json!({
+        "source": "chromium",
+        "path": fixture("chromium/Bookmarks"),
+        "workspace_id": seed.workspace_id,
+    });
+
+    let preview = app.ok_query("import.preview", input.clone()).await;
+    assert_eq!(preview["total"], 4);
+    assert_eq!(
+        group_names(&preview),
+        [
+            "Bookmarks bar",
+            "Rust",
+            "Rust / Databases",
+            "Other bookmarks"
+        ]
+    );
+    assert_eq!(preview["groups"][1]["existing_group_id"], rust["id"]);
+    assert!(preview["groups"][0]["existing_group_id"].is_null());
+
+    let bookmarks = app.ok_query("bookmark.list", first_page()).await;
+    assert_eq!(bookmarks["total"], 0);
+
+    let result = app.ok_mutation("import.commit", input).await;
+    assert_eq!(result["groups_created"], 3);
+    assert_eq!(result["bookmarks_created"], 4);
+    assert_eq!(result["skipped"], 1);
+
+    let in_rust = app
+        .ok_query("bookmark.getByGroup", json!([rust["id"], first_page()]))
+        .await;
+    assert_eq!(in_rust["items"][0]["name"], "Tokio tutorial");
+
+    let groups = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([seed.workspace_id, seed.organization_id, first_page()]),
+        )
+        .await;
+    assert_eq!(groups["items"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn pocket_csv_and_html_exports_agree() {
+    let csv = read_pocket_export(&fixture("pocket/part_000000.csv"))
+        .await
+        .unwrap();
+    assert_eq!(
+        summary(&csv),
+        [
+            (
+                "Async Rust",
+                "Pocket",
+                "rust,async",
+                Some(ReadState::Unread)
+            ),
+            (
+                "The Twelve-Factor App",
+                "Pocket",
+                "ops",
+                Some(ReadState::Read)
+            ),
+            // Untitled items are named after their url
+            (
+                "https://example.com/untitled",
+                "Pocket",
+                "",
+                Some(ReadState::Unread)
+            ),
+        ]
+    );
+    assert_eq!(
+        csv.bookmarks[0].created_at.unwrap().to_rfc3339(),
+        "2024-01-01T00:00:00+00:00"
+    );
+
+    let html = read_pocket_export(&fixture("pocket/ril_export.html"))
+        .await
+        .unwrap();
+    assert_eq!(summary(&html), summary(&csv)[..2]);
+}
+
+#[tokio::test]
+async fn pinboard_toread_maps_onto_read_state() {
+    let parsed = read_pinboard_export(&fixture("pinboard/pinboard_export.json"))
+        .await
+        .unwrap();
+    assert_eq!(
+        summary(&parsed),
+        [
+            (
+                "SQL As Understood By SQLite",
+                "Pinboard",
+                "sqlite,reference",
+                Some(ReadState::Unread)
+            ),
+            ("Hacker News", "Pinboard", "", Some(ReadState::Read)),
+            (
+                "SQLite language again",
+                "Pinboard",
+                "sqlite",
+                Some(ReadState::Read)
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn raindrop_collections_become_groups_and_keep_favorites() {
+    let parsed = read_raindrop_export(&fixture("raindrop/raindrop_export.csv"))
+        .await
+        .unwrap();
+    assert_eq!(
+        summary(&parsed),
+        [
+            ("Tokio tutorial", "Dev / Rust", "rust,async", None),
+            ("Grafana dashboards", "Ops", "monitoring", None),
+            ("Some article", "Unsorted", "", None),
+        ]
+    );
+    let favorites: Vec<bool> = parsed
+        .bookmarks
+        .iter()
+        .map(|bookmark| bookmark.is_favorite)
+        .collect();
+    assert_eq!(favorites, [true, false, false]);
+}
+
+#[test]
+fn malformed_export_rows_are_reported_and_the_rest_read() {
+    let raindrop = parse_raindrop_csv(
+        "title,url,folder,tags,created,favorite
+Tokio,https://tokio.rs,Dev,,2024-02-01T08:00:00.000Z,true
+Broken,https://example.com/broken,Dev,,yesterday,false
+Grafana,https://grafana.com,Ops,,2024-02-02T08:00:00.000Z,false
+",
+    )
+    .unwrap();
+    let names: Vec<&str> = raindrop
+        .bookmarks
+        .iter()
+        .map(|bookmark| bookmark.name.as_str())
+        .collect();
+    assert_eq!(names, ["Tokio", "Grafana"]);
+    assert_eq!(raindrop.errors.len(), 1);
+    assert_eq!(raindrop.errors[0].row, 3);
+
+    let pocket = parse_pocket_csv(
+        "title,url,time_added,tags,status,favorite
+Async Rust,https://rust-lang.github.io/async-book/,1704067200,rust,unread,1
+Too many,https://example.com,1704153600,,unread,0,extra
+",
+    )
+    .unwrap();
+    assert_eq!(pocket.bookmarks.len(), 1);
+    assert!(pocket.bookmarks[0].is_favorite);
+    assert_eq!(pocket.errors.len(), 1);
+    assert_eq!(pocket.errors[0].row, 3);
+
+    // Without a url column it's not an export at all
+    assert!(parse_pocket_csv("title,link\nRust,https://rust-lang.org\n").is_err());
+}
+
+#[tokio::test]
+async fn raindrop_favorites_are_kept_on_commit() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let result = app
+        .ok_mutation(
+            "import.commit",
+            json!({
+                "source": "raindrop",
+                "path": fixture("raindrop/raindrop_export.csv"),
+                "workspace_id": seed.workspace_id,
+            }),
+        )
+        .await;
+    assert_eq!(result["bookmarks_created"], 3);
+    assert_eq!(result["groups_created"], 3);
+
+    let bookmarks = app.ok_query("bookmark.list", first_page()).await;
+    let favorites: Vec<&str> = bookmarks["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|bookmark| bookmark["is_favorite"] == true)
+        .map(|bookmark| bookmark["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(favorites, ["Tokio tutorial"]);
+}
+
+#[tokio::test]
+async fn conflicts_are_reported_and_left_out_of_the_commit() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let saved = app
+        .create_bookmark(seed.group_id, "HN", "https://news.ycombinator.com", "")
+        .await;
+    let input = json!({
+        "source": "pinboard",
+        "path": fixture("pinboard/pinboard_export.json"),
+        "workspace_id": seed.workspace_id,
+    });
+
+    let preview = app.ok_query("import.preview", input.clone()).await;
+    assert_eq!(preview["total"], 1);
+    assert_eq!(
+        preview["conflicts"],
+        json!([
+            {
+                "bookmark": preview["conflicts"][0]["bookmark"],
+                "reason": {
+                    "type": "already_saved",
+                    "bookmark_id": saved["id"],
+                    "name": "HN",
+                },
+            },
+            {
+                "bookmark": preview["conflicts"][1]["bookmark"],
+                "reason": { "type": "duplicate_in_import" },
+            },
+        ])
+    );
+    assert_eq!(
+        preview["conflicts"][1]["bookmark"]["url"],
+        "https://sqlite.org/lang.html#top"
+    );
+
+    let result = app.ok_mutation("import.commit", input.clone()).await;
+    assert_eq!(result["bookmarks_created"], 1);
+    assert_eq!(result["groups_created"], 1);
+    assert_eq!(result["conflicts"].as_array().unwrap().len(), 2);
+
+    // Importing the same file again only finds conflicts
+    let again = app.ok_query("import.preview", input).await;
+    assert_eq!(again["total"], 0);
+    assert_eq!(again["conflicts"].as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn read_state_is_kept_on_commit() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let result = app
+        .ok_mutation(
+            "import.commit",
+            json!({
+                "source": "pocket",
+                "path": fixture("pocket/part_000000.csv"),
+                "workspace_id": seed.workspace_id,
+            }),
+        )
+        .await;
+    assert_eq!(result["bookmarks_created"], 3);
+
+    let bookmarks = app.ok_query("bookmark.list", first_page()).await;
+    let archived = bookmarks["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bookmark| bookmark["name"] == "The Twelve-Factor App")
+        .unwrap();
+    assert_eq!(archived["read_state"], "read");
+    assert!(!archived["read_at"].is_null());
+}
+
+#[tokio::test]
+async fn rows_that_fail_to_save_are_reported_and_the_rest_imported() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    // Stands in for any database error on a single insert
+    app.db
+        .execute_unprepared(
+            "CREATE TRIGGER reject_hn BEFORE INSERT ON bookmark WHEN NEW.name = 'Hacker News'
+             BEGIN SELECT RAISE(ABORT, 'rejected by test'); END",
+        )
+        .await
+        .unwrap();
+
+    let result = app
+        .ok_mutation(
+            "import.commit",
+            json!({
+                "source": "pinboard",
+                "path": fixture("pinboard/pinboard_export.json"),
+                "workspace_id": seed.workspace_id,
+            }),
+        )
+        .await;
+
+    assert_eq!(result["bookmarks_created"], 1);
+    assert_eq!(result["errors"].as_array().unwrap().len(), 1);
+    assert_eq!(result["errors"][0]["row"], 2);
+    assert!(result["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("rejected by test"));
+}
@@ -0,0 +1,333 @@
+mod common;
+
+use common::{first_page, id_of, Seed, TestApp};
+use serde_json::{json, Value};
+
+/// rspc's code for `ErrorCode::Forbidden`
+const FORBIDDEN: i64 = 403;
+
+async fn create_user(app: &TestApp, name: &str, email: &str) -> i64 {
+    let user = app
+        .ok_mutation("users.createUser", json!({ "name": name, "email": email }))
+        .await;
+    id_of(&user)
+}
+
+async fn sign_in(app: &TestApp, user_id: i64) {
+    app.ok_mutation("session.signIn", json!(user_id)).await;
+}
+
+async fn invite(app: &TestApp, seed: &Seed, email: &str, role: &str) -> Value {
+    app.ok_mutation(
+        "organization.inviteMember",
+        json!({ "organization_id": seed.organization_id, "email": email, "role": role }),
+    )
+    .await
+}
+
+fn bookmark_input(seed: &Seed) -> Value {
+    json!({
+        "name": "Rust",
+        "url": "https://www.rust-lang.org",
+        "tags": "",
+        "is_favorite": false,
+        "group_id": seed.group_id,
+    })
+}
+
+#[tokio::test]
+async fn creator_owns_the_organization_and_invites_by_email() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bob = create_user(&app, "Bob", "bob@example.com").await;
+
+    let members = app
+        .ok_query("organization.listMembers", json!(seed.organization_id))
+        .await;
+    assert_eq!(members[0]["user_id"], seed.user_id);
+    assert_eq!(members[0]["role"], "owner");
+
+    let invited = invite(&app, &seed, "  BOB@example.com ", "viewer").await;
+    assert_eq!(invited["user_id"], bob);
+    assert_eq!(invited["name"], "Bob");
+    assert_eq!(invited["role"], "viewer");
+
+    // Shared organizations are listed for their members too
+    let organizations = app
+        .ok_query("organization.getOrganizationByUserId", json!(bob))
+        .await;
+    assert_eq!(organizations[0]["name"], "Personal");
+
+    // The organization is shared now, changing it takes a session
+    sign_in(&app, seed.user_id).await;
+    let again = app
+        .mutation(
+            "organization.inviteMember",
+            json!({ "organization_id": seed.organization_id, "email": "bob@example.com", "role": "editor" }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(again.message, "bob@example.com is already a member");
+
+    let unknown = app
+        .mutation(
+            "organization.inviteMember",
+            json!({ "organization_id": seed.organization_id, "email": "eve@example.com", "role": "viewer" }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(unknown.message, "No user with the email eve@example.com");
+}
+
+#[tokio::test]
+async fn viewers_read_but_cannot_change_anything() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bob = create_user(&app, "Bob", "bob@example.com").await;
+    let docs = app
+        .create_bookmark(seed.group_id, "Docs", "https://docs.rs", "rust")
+        .await;
+    invite(&app, &seed, "bob@example.com", "viewer").await;
+
+    sign_in(&app, bob).await;
+    let current = app.ok_query("session.current", json!(null)).await;
+    assert_eq!(current["name"], "Bob");
+
+    let bookmarks = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(bookmarks["items"].as_array().unwrap().len(), 1);
+
+    let error = app
+        .mutation("bookmark.create", bookmark_input(&seed))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, FORBIDDEN);
+    assert_eq!(
+        error.message,
+        "Forbidden: Viewers can't change this organization"
+    );
+
+    for (path, input) in [
+        (
+            "groups.createGroups",
+            json!({ "name": "Videos", "workspace_id": seed.workspace_id }),
+        ),
+        ("groups.deleteGroup", json!(seed.group_id)),
+        (
+            "workspace.createWorkspace",
+            json!({ "name": "Work", "organization_id": seed.organization_id }),
+        ),
+        ("workspace.deleteWorkspace", json!(seed.workspace_id)),
+        (
+            "organization.deleteOrganization",
+            json!(seed.organization_id),
+        ),
+        (
+            "import.commit",
+            json!({
+                "source": "chromium",
+                "path": "/nonexistent/Bookmarks",
+                "workspace_id": seed.workspace_id,
+            }),
+        ),
+        (
+            "readLater.setState",
+            json!({ "id": id_of(&docs), "state": "read" }),
+        ),
+        (
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        ),
+        (
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id_of(&docs), "single_file": false }),
+        ),
+        ("bookmark.markOpened", json!(id_of(&docs))),
+    ] {
+        let error = app.mutation(path, input).await.unwrap_err();
+        assert_eq!(error.code, FORBIDDEN, "{} was allowed", path);
+    }
+}
+
+#[tokio::test]
+async fn viewers_only_see_the_organizations_they_belong_to() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bob = create_user(&app, "Bob", "bob@example.com").await;
+    app.create_bookmark(seed.group_id, "Rust docs", "https://docs.rs", "rust")
+        .await;
+    invite(&app, &seed, "bob@example.com", "viewer").await;
+
+    // Cy keeps a library of their own that Bob was never invited to
+    let cy = create_user(&app, "Cy", "cy@example.com").await;
+    let organization = app
+        .ok_mutation(
+            "organization.createOrganization",
+            json!({ "name": "Cy's", "user_id": cy }),
+        )
+        .await;
+    let workspace = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Private", "organization_id": organization["id"] }),
+        )
+        .await;
+    let group = app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Secret", "workspace_id": workspace["id"] }),
+        )
+        .await;
+    app.create_bookmark(id_of(&group), "Rust secrets", "https://example.com", "rust")
+        .await;
+
+    sign_in(&app, bob).await;
+    let found = app
+        .ok_query("bookmark.search", json!(["rust", first_page()]))
+        .await;
+    assert_eq!(found["total"], 1);
+    assert_eq!(found["items"][0]["name"], "Rust docs");
+
+    let listed = app.ok_query("bookmark.list", first_page()).await;
+    assert_eq!(listed["total"], 1);
+
+    let groups = app.ok_query("groups.getGroups", first_page()).await;
+    assert_eq!(groups["total"], 1);
+    assert_eq!(groups["items"][0]["name"], "Articles");
+
+    for (path, input) in [
+        ("bookmark.getByGroup", json!([id_of(&group), first_page()])),
+        (
+            "groups.getBelongedGroups",
+            json!([id_of(&workspace), id_of(&organization), first_page()]),
+        ),
+        (
+            "workspace.getWorkspaces",
+            json!([id_of(&organization), first_page()]),
+        ),
+    ] {
+        let error = app.query(path, input).await.unwrap_err();
+        assert_eq!(error.code, FORBIDDEN, "{} was allowed", path);
+    }
+}
+
+#[tokio::test]
+async fn editors_change_content_but_only_owners_manage() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bob = create_user(&app, "Bob", "bob@example.com").await;
+    create_user(&app, "Cy", "cy@example.com").await;
+    let membership = invite(&app, &seed, "bob@example.com", "viewer").await;
+
+    sign_in(&app, seed.user_id).await;
+    let promoted = app
+        .ok_mutation(
+            "organization.updateMemberRole",
+            json!({ "id": membership["id"], "role": "editor" }),
+        )
+        .await;
+    assert_eq!(promoted["role"], "editor");
+
+    sign_in(&app, bob).await;
+    let bookmark = app
+        .ok_mutation("bookmark.create", bookmark_input(&seed))
+        .await;
+    app.ok_mutation(
+        "groups.createGroups",
+        json!({ "name": "Videos", "workspace_id": seed.workspace_id }),
+    )
+    .await;
+    app.ok_mutation("bookmark.delete", json!(id_of(&bookmark)))
+        .await;
+
+    let error = app
+        .mutation(
+            "organization.inviteMember",
+            json!({ "organization_id": seed.organization_id, "email": "cy@example.com", "role": "viewer" }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, FORBIDDEN);
+    assert_eq!(
+        error.message,
+        "Forbidden: Only owners can manage this organization"
+    );
+}
+
+#[tokio::test]
+async fn outsiders_are_forbidden_and_the_last_owner_stays() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let cy = create_user(&app, "Cy", "cy@example.com").await;
+
+    sign_in(&app, cy).await;
+    let error = app
+        .mutation("bookmark.create", bookmark_input(&seed))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, FORBIDDEN);
+    assert_eq!(
+        error.message,
+        "Forbidden: You are not a member of this organization"
+    );
+
+    sign_in(&app, seed.user_id).await;
+    let members = app
+        .ok_query("organization.listMembers", json!(seed.organization_id))
+        .await;
+    let owner = id_of(&members[0]);
+    let error = app
+        .mutation("organization.removeMember", json!(owner))
+        .await
+        .unwrap_err();
+    assert_eq!(error.message, "An organization needs at least one owner");
+    let error = app
+        .mutation(
+            "organization.updateMemberRole",
+            json!({ "id": owner, "role": "viewer" }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.message, "An organization needs at least one owner");
+
+    // Nobody else joined, so signing out goes back to the single user mode
+    app.ok_mutation("session.signOut", json!(null)).await;
+    assert_eq!(
+        app.ok_query("session.current", json!(null)).await,
+        Value::Null
+    );
+    app.ok_mutation("bookmark.create", bookmark_input(&seed))
+        .await;
+}
+
+#[tokio::test]
+async fn signing_out_does_not_lift_the_role_in_a_shared_organization() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bob = create_user(&app, "Bob", "bob@example.com").await;
+    invite(&app, &seed, "bob@example.com", "viewer").await;
+
+    sign_in(&app, bob).await;
+    app.ok_mutation("session.signOut", json!(null)).await;
+
+    let error = app
+        .mutation("bookmark.create", bookmark_input(&seed))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, FORBIDDEN);
+    assert_eq!(
+        error.message,
+        "Forbidden: Sign in to change a shared organization"
+    );
+    let error = app
+        .mutation("groups.deleteGroup", json!(seed.group_id))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, FORBIDDEN);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::json;
+
+#[tokio::test]
+async fn organization_crud_round_trip() {
+    let app = TestApp::new().await;
+    let user = app
+        .ok_mutation(
+            "users.createUser",
+            json!({ "name": "Ada", "email": "ada@example.com" }),
+        )
+        .await;
+
+    let organization = app
+        .ok_mutation(
+            "organization.createOrganization",
+            json!({ "name": "Personal", "user_id": user["id"] }),
+        )
+        .await;
+    let id = id_of(&organization);
+
+    let found = app
+        .ok_query("organization.getOrganizationById", json!(id))
+        .await;
+    assert_eq!(found["name"], "Personal");
+
+    let owned = app
+        .ok_query("organization.getOrganizationByUserId", user["id"].clone())
+        .await;
+    assert_eq!(owned.as_array().unwrap().len(), 1);
+
+    let updated = app
+        .ok_mutation(
+            "organization.updateOrganization",
+            json!({ "id": id, "name": "Work", "user_id": null }),
+        )
+        .await;
+    assert_eq!(updated["name"], "Work");
+
+    let page = app
+        .ok_query("organization.getOrganizations", first_page())
+        .await;
+    assert_eq!(page["total"], 1);
+
+    app.ok_mutation("organization.deleteOrganization", json!(id))
+        .await;
+    assert!(app
+        .query("organization.getOrganizationById", json!(id))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn missing_organization_is_an_error() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .mutation(
+            "organization.updateOrganization",
+            json!({ "id": 42, "name": "Ghost", "user_id": null }),
+        )
+        .await;
+
+    assert!(result.unwrap_err().message.contains("not found"));
+}
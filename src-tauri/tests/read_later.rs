@@ -0,0 +1,111 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::json;
+
+#[tokio::test]
+async fn read_state_moves_through_the_queue() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let first = app
+        .create_bookmark(seed.group_id, "First", "https://example.com/1", "")
+        .await;
+    let second = app
+        .create_bookmark(seed.group_id, "Second", "https://example.com/2", "")
+        .await;
+
+    let queue = app
+        .ok_query(
+            "readLater.queue",
+            json!({ "group_id": seed.group_id, "states": null, "params": first_page() }),
+        )
+        .await;
+    assert_eq!(queue["items"].as_array().unwrap().len(), 2);
+
+    let reading = app
+        .ok_mutation(
+            "readLater.setState",
+            json!({ "id": id_of(&first), "state": "reading" }),
+        )
+        .await;
+    assert_eq!(reading["read_state"], "reading");
+    assert!(!reading["reading_at"].is_null());
+
+    let read = app
+        .ok_mutation("readLater.markRead", json!(id_of(&first)))
+        .await;
+    assert_eq!(read["read_state"], "read");
+    assert!(!read["reading_at"].is_null());
+    assert!(!read["read_at"].is_null());
+
+    app.ok_mutation("readLater.archive", json!(id_of(&second)))
+        .await;
+
+    let queue = app
+        .ok_query(
+            "readLater.queue",
+            json!({ "group_id": null, "states": null, "params": first_page() }),
+        )
+        .await;
+    assert!(queue["items"].as_array().unwrap().is_empty());
+
+    let archived = app
+        .ok_query(
+            "readLater.queue",
+            json!({ "group_id": null, "states": ["archived"], "params": first_page() }),
+        )
+        .await;
+    assert_eq!(archived["items"][0]["name"], "Second");
+
+    // Archived bookmarks drop out of their group listing
+    let by_group = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(by_group["items"].as_array().unwrap().len(), 1);
+
+    let counts = app.ok_query("readLater.countsByGroup", json!(null)).await;
+    assert_eq!(counts[0]["group_id"], seed.group_id);
+    assert_eq!(counts[0]["read"], 1);
+    assert_eq!(counts[0]["archived"], 1);
+    assert_eq!(counts[0]["unread"], 0);
+}
+
+#[tokio::test]
+async fn queue_is_paged() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    for name in ["First", "Second", "Third"] {
+        app.create_bookmark(seed.group_id, name, "https://example.com", "")
+            .await;
+    }
+
+    let first = app
+        .ok_query(
+            "readLater.queue",
+            json!({ "group_id": seed.group_id, "states": null, "params": { "per_page": 2 } }),
+        )
+        .await;
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["items"].as_array().unwrap().len(), 2);
+    assert_eq!(first["next_cursor"], "1");
+
+    let last = app
+        .ok_query(
+            "readLater.queue",
+            json!({
+                "group_id": seed.group_id,
+                "states": null,
+                "params": { "cursor": first["next_cursor"], "per_page": 2 },
+            }),
+        )
+        .await;
+    assert_eq!(last["items"].as_array().unwrap().len(), 1);
+    assert!(last["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn setting_state_of_missing_bookmark_is_an_error() {
+    let app = TestApp::new().await;
+
+    assert!(app.mutation("readLater.markRead", json!(42)).await.is_err());
+}
@@ -0,0 +1,403 @@
+//! The same repository contract run against the SeaORM and in-memory implementations.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{first_page, TestApp};
+use entity::bookmark;
+use migration::{Migrator, MigratorTrait};
+use rayzen_lib::domain::{
+    dto::{
+        bookmark::bookmark_dto::{CreateBookmarkDto, UpdateBookmarkDto},
+        groups::groups_dto::{CreateGroupsDto, UpdateGroupsDto},
+        organization::organization_dto::CreateOrganizationDto,
+        user::user_dto::CreateUserDto,
+        workspace::workspace_dto::{CreateWorkspaceDto, UpdateWorkspaceDto},
+    },
+    repository::memory::{
+        bookmark_repository::InMemoryBookmarkRepository,
+        groups_repository::InMemoryGroupRepository,
+        organization_member_repository::InMemoryOrganizationMemberRepository,
+        organization_repository::InMemoryOrganizationRepository,
+        user_repository::InMemoryUserRepository, workspace_repository::InMemoryWorkspaceRepository,
+        MemoryStore,
+    },
+    service::container::Repositories,
+    types::{
+        groups::SmartGroupFilter,
+        pagination::{ListParams, SortDirection, SortInput},
+    },
+};
+use sea_orm::{Database, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait};
+use serde_json::json;
+
+fn in_memory(store: &MemoryStore) -> Repositories {
+    Repositories {
+        users: Arc::new(InMemoryUserRepository::new(store.clone())),
+        organizations: Arc::new(InMemoryOrganizationRepository::new(store.clone())),
+        members: Arc::new(InMemoryOrganizationMemberRepository::new(store.clone())),
+        workspaces: Arc::new(InMemoryWorkspaceRepository::new(store.clone())),
+        groups: Arc::new(InMemoryGroupRepository::new(store.clone())),
+        bookmarks: Arc::new(InMemoryBookmarkRepository::new(store.clone())),
+        ..Repositories::sea_orm()
+    }
+}
+
+async fn migrated_database() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    db
+}
+
+fn bookmark(group_id: i32, name: &str, url: &str, tags: &str) -> CreateBookmarkDto {
+    CreateBookmarkDto {
+        name: name.to_string(),
+        url: url.to_string(),
+        tags: tags.to_string(),
+        is_favorite: false,
+        group_id,
+    }
+}
+
+/// Behaviour every repository implementation has to share
+async fn repository_contract(repos: &Repositories, db: &DatabaseConnection) {
+    let user = repos
+        .users
+        .create_user(
+            db,
+            CreateUserDto {
+                name: "Ada".to_string(),
+                email: "ada@example.com".to_string(),
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    let organization = repos
+        .organizations
+        .create_organization(
+            db,
+            CreateOrganizationDto {
+                name: "Personal".to_string(),
+                user_id: user.id,
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    let workspace = repos
+        .workspaces
+        .create_workspace(
+            db,
+            CreateWorkspaceDto {
+                name: "Reading".to_string(),
+                organization_id: organization.id,
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    let group = repos
+        .groups
+        .create_group(
+            db,
+            CreateGroupsDto {
+                name: "Articles".to_string(),
+                workspace_id: workspace.id,
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(group.kind, "static");
+    assert_eq!(group.filter, None);
+
+    let found = repos
+        .groups
+        .get_group_by_uuid(db, &group.uuid)
+        .await
+        .unwrap();
+    assert_eq!(found.id, group.id);
+    assert_ne!(group.uuid, workspace.uuid);
+
+    let rust = repos
+        .bookmarks
+        .create(
+            db,
+            bookmark(
+                group.id,
+                "Rust book",
+                "https://doc.rust-lang.org/book",
+                "rust",
+            )
+            .into(),
+        )
+        .await
+        .unwrap();
+    repos
+        .bookmarks
+        .create(
+            db,
+            bookmark(group.id, "Go tour", "https://go.dev/tour", "go").into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(rust.read_state, "unread");
+    assert_eq!(rust.reading_at, None);
+
+    // Lookups
+    assert_eq!(
+        repos.bookmarks.find_by_id(db, rust.id).await.unwrap(),
+        Some(rust.clone())
+    );
+    assert_eq!(repos.bookmarks.find_by_id(db, 999).await.unwrap(), None);
+    assert_eq!(
+        repos
+            .bookmarks
+            .get_by_group(db, group.id)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        repos
+            .bookmarks
+            .search(db, None, "RUST", &ListParams::default())
+            .await
+            .unwrap()
+            .total,
+        1
+    );
+    assert_eq!(
+        repos
+            .bookmarks
+            .count_in_workspace(db, workspace.id, "rust go")
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        repos
+            .groups
+            .find_belonged_groups(db, workspace.id, organization.id, &ListParams::default())
+            .await
+            .unwrap()
+            .total,
+        1
+    );
+
+    let filter = SmartGroupFilter {
+        host: Some("go.dev".to_string()),
+        ..Default::default()
+    };
+    let matching = repos
+        .bookmarks
+        .find_matching(db, workspace.id, &filter)
+        .await
+        .unwrap();
+    assert_eq!(matching.len(), 1);
+    assert_eq!(matching[0].name, "Go tour");
+
+    // Paging
+    let params = ListParams {
+        per_page: Some(1),
+        sort: Some(SortInput {
+            field: "name".to_string(),
+            direction: SortDirection::Desc,
+        }),
+        ..Default::default()
+    };
+    let page = repos.bookmarks.find_page(db, None, &params).await.unwrap();
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items[0].name, "Rust book");
+    assert_eq!(page.next_cursor.as_deref(), Some("1"));
+
+    let bad_sort = ListParams {
+        sort: Some(SortInput {
+            field: "nope".to_string(),
+            direction: SortDirection::Asc,
+        }),
+        ..Default::default()
+    };
+    assert!(repos.users.list_users(db, &bad_sort).await.is_err());
+
+    // Partial updates keep the fields that were left out
+    let updated = repos
+        .bookmarks
+        .update(
+            db,
+            rust.id,
+            UpdateBookmarkDto {
+                id: Some(rust.id),
+                name: Some("The Rust Book".to_string()),
+                url: None,
+                tags: Some(String::new()),
+                is_favorite: None,
+                group_id: None,
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "The Rust Book");
+    assert_eq!(updated.tags, "rust");
+
+    let renamed = repos
+        .groups
+        .update_group(
+            db,
+            group.id,
+            UpdateGroupsDto {
+                id: Some(group.id),
+                name: None,
+                workspace_id: None,
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "Articles");
+
+    // Missing rows are errors, not panics
+    let missing = repos
+        .bookmarks
+        .update(
+            db,
+            999,
+            UpdateBookmarkDto {
+                id: Some(999),
+                name: Some("Ghost".to_string()),
+                url: None,
+                tags: None,
+                is_favorite: None,
+                group_id: None,
+            }
+            .into(),
+        )
+        .await;
+    assert!(matches!(missing, Err(DbErr::RecordNotFound(_))));
+
+    let missing = repos
+        .workspaces
+        .update_workspace(
+            db,
+            999,
+            UpdateWorkspaceDto {
+                id: Some(999),
+                name: Some("Ghost".to_string()),
+                organization_id: None,
+            }
+            .into(),
+        )
+        .await;
+    assert!(matches!(missing, Err(DbErr::RecordNotFound(_))));
+
+    // Link health and visits
+    let opened = repos.bookmarks.mark_opened(db, rust.id).await.unwrap();
+    assert_eq!(opened.open_count, 1);
+    let broken = repos
+        .bookmarks
+        .mark_broken(db, rust.id, true)
+        .await
+        .unwrap();
+    assert!(broken.broken_at.is_some());
+    let still_broken = repos
+        .bookmarks
+        .mark_broken(db, rust.id, true)
+        .await
+        .unwrap();
+    assert_eq!(still_broken.broken_at, broken.broken_at);
+    let fixed = repos
+        .bookmarks
+        .mark_broken(db, rust.id, false)
+        .await
+        .unwrap();
+    assert_eq!(fixed.broken_at, None);
+
+    // Copies get ids and UUIDs of their own
+    let copy = repos
+        .bookmarks
+        .copy_to(db, rust.id, group.id)
+        .await
+        .unwrap();
+    assert_ne!(copy.id, rust.id);
+    assert_ne!(copy.uuid, rust.uuid);
+    assert_eq!(copy.name, "The Rust Book");
+
+    let duplicate = repos
+        .groups
+        .duplicate_group(db, group.id, workspace.id, "Copies".to_string())
+        .await
+        .unwrap();
+    assert_ne!(duplicate.uuid, group.uuid);
+    assert_eq!(
+        repos
+            .bookmarks
+            .get_by_group(db, duplicate.id)
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+    assert!(matches!(
+        repos
+            .groups
+            .duplicate_group(db, 999, workspace.id, "Ghost".to_string())
+            .await,
+        Err(DbErr::RecordNotFound(_))
+    ));
+
+    // Deletes
+    repos.bookmarks.delete(db, rust.id).await.unwrap();
+    assert_eq!(repos.bookmarks.find_by_id(db, rust.id).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn sea_orm_repositories_follow_the_contract() {
+    let db = migrated_database().await;
+
+    repository_contract(&Repositories::sea_orm(), &db).await;
+}
+
+#[tokio::test]
+async fn in_memory_repositories_follow_the_contract() {
+    let store = MemoryStore::new();
+
+    // In-memory repositories never touch the connection
+    repository_contract(&in_memory(&store), &DatabaseConnection::Disconnected).await;
+    assert_eq!(store.tables().bookmarks.len(), 5);
+}
+
+#[tokio::test]
+async fn procedures_run_on_in_memory_repositories() {
+    let store = MemoryStore::new();
+    let app = TestApp::with_repositories(in_memory(&store)).await;
+    let seed = app.seed().await;
+
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+
+    let page = app.ok_query("bookmark.list", first_page()).await;
+    assert_eq!(page["total"], 1);
+    let by_group = app
+        .ok_query("bookmark.getByGroup", json!([seed.group_id, first_page()]))
+        .await;
+    assert_eq!(by_group["items"][0]["name"], "Rust book");
+
+    // Nothing reached the database
+    assert_eq!(store.tables().bookmarks.len(), 1);
+    assert_eq!(
+        bookmark::Entity::find()
+            .count(app.db.as_ref())
+            .await
+            .unwrap(),
+        0
+    );
+}
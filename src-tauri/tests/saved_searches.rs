@@ -0,0 +1,139 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::json;
+
+#[tokio::test]
+async fn saved_search_counts_follow_bookmark_changes() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+
+    let saved = app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": true,
+            }),
+        )
+        .await;
+    let id = id_of(&saved);
+    assert_eq!(saved["cached_count"], 1);
+
+    app.create_bookmark(
+        seed.group_id,
+        "Rustonomicon",
+        "https://doc.rust-lang.org/nomicon",
+        "rust",
+    )
+    .await;
+    let found = app.ok_query("savedSearch.getById", json!(id)).await;
+    assert_eq!(found["cached_count"], 2);
+
+    let run = app.ok_query("savedSearch.run", json!(id)).await;
+    assert_eq!(run["results"].as_array().unwrap().len(), 2);
+
+    let updated = app
+        .ok_mutation(
+            "savedSearch.update",
+            json!({ "id": id, "name": null, "query": "nomicon", "is_pinned": null }),
+        )
+        .await;
+    assert_eq!(updated["query"], "nomicon");
+    assert_eq!(updated["cached_count"], 1);
+
+    let listed = app
+        .ok_query("savedSearch.list", json!([seed.workspace_id, first_page()]))
+        .await;
+    assert_eq!(listed["items"].as_array().unwrap().len(), 1);
+
+    app.ok_mutation("savedSearch.delete", json!(id)).await;
+    assert!(app.query("savedSearch.getById", json!(id)).await.is_err());
+}
+
+/// Pinned searches come first on every page, the rest follow by name
+#[tokio::test]
+async fn saved_searches_are_paged_with_pinned_first() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    for (name, is_pinned) in [("Go", false), ("Zig", true), ("Rust", false)] {
+        app.ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": name,
+                "query": name.to_lowercase(),
+                "is_pinned": is_pinned,
+            }),
+        )
+        .await;
+    }
+
+    let first = app
+        .ok_query(
+            "savedSearch.list",
+            json!([seed.workspace_id, { "per_page": 2 }]),
+        )
+        .await;
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["items"][0]["name"], "Zig");
+    assert_eq!(first["items"][1]["name"], "Go");
+    assert_eq!(first["next_cursor"], "1");
+
+    let last = app
+        .ok_query(
+            "savedSearch.list",
+            json!([seed.workspace_id, { "cursor": "1", "per_page": 2 }]),
+        )
+        .await;
+    assert_eq!(last["items"].as_array().unwrap().len(), 1);
+    assert_eq!(last["items"][0]["name"], "Rust");
+    assert!(last["next_cursor"].is_null());
+}
+
+#[tokio::test]
+async fn empty_saved_search_query_is_rejected() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let result = app
+        .mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": "Everything",
+                "query": "  ",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let created = app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+    let result = app
+        .mutation(
+            "savedSearch.update",
+            json!({ "id": id_of(&created), "query": "" }),
+        )
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::TestApp;
+use rayzen_lib::domain::types::settings::DEFAULT_LAUNCHER_SHORTCUT;
+
+#[tokio::test]
+async fn launcher_shortcut_is_persisted() {
+    let app = TestApp::new().await;
+    let settings = &app.services.settings;
+
+    assert_eq!(
+        settings.get_launcher_shortcut(app.context()).await.unwrap(),
+        DEFAULT_LAUNCHER_SHORTCUT
+    );
+
+    let saved = settings
+        .set_launcher_shortcut(app.context(), " Alt+Space ".to_string())
+        .await
+        .unwrap();
+    assert_eq!(saved, "Alt+Space");
+
+    // Saving again replaces the stored value instead of adding a second row
+    settings
+        .set_launcher_shortcut(app.context(), "Ctrl+K".to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        settings.get_launcher_shortcut(app.context()).await.unwrap(),
+        "Ctrl+K"
+    );
+}
+
+#[tokio::test]
+async fn empty_launcher_shortcut_is_rejected() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .services
+        .settings
+        .set_launcher_shortcut(app.context(), "  ".to_string())
+        .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,113 @@
+//! The archiver against a scripted fetcher, no network or database involved.
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use rayzen_lib::domain::archiver::{
+    fetcher::{FetchedResource, PageFetcher},
+    storage::SnapshotStorage,
+    PageArchiver,
+};
+use tempfile::TempDir;
+
+const PAGE: &str = r#"<html>
+<head><link rel="stylesheet" href="/style.css"></head>
+<body><img src="logo.png"><img src="missing.png"><p>Hello</p></body>
+</html>"#;
+
+/// Serves a fixed set of urls, anything else fails like an unreachable page
+struct ScriptedFetcher {
+    resources: HashMap<&'static str, (&'static str, &'static [u8])>,
+}
+
+#[async_trait]
+impl PageFetcher for ScriptedFetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedResource, String> {
+        let (content_type, body) = self
+            .resources
+            .get(url)
+            .ok_or_else(|| format!("Not found: {}", url))?;
+
+        Ok(FetchedResource {
+            url: url.to_string(),
+            content_type: Some(content_type.to_string()),
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// The archiver and the directory its snapshots go to, removed when dropped
+fn archiver() -> (PageArchiver, TempDir) {
+    let fetcher = ScriptedFetcher {
+        resources: HashMap::from([
+            (
+                "https://example.com/articles/1",
+                ("text/html; charset=utf-8", PAGE.as_bytes()),
+            ),
+            ("https://example.com/style.css", ("text/css", b"p{}")),
+            (
+                "https://example.com/articles/logo.png",
+                ("image/png", b"png"),
+            ),
+        ]),
+    };
+    let root = TempDir::new().unwrap();
+    let storage = SnapshotStorage::new(root.path().to_path_buf());
+
+    (PageArchiver::new(Arc::new(fetcher), storage), root)
+}
+
+#[tokio::test]
+async fn pages_are_kept_per_version_and_read_back() {
+    let (archiver, root) = archiver();
+
+    let first = archiver
+        .archive(7, "https://example.com/articles/1", false)
+        .await
+        .unwrap();
+    let second = archiver
+        .archive(7, "https://example.com/articles/1", false)
+        .await
+        .unwrap();
+    assert_ne!(first.file_path, second.file_path);
+    assert_eq!(first.byte_size, PAGE.len() as i64);
+    assert!(first.compressed_size > 0);
+
+    let kept = archiver.path_for(7, 1);
+    archiver.keep(&first, &kept).await.unwrap();
+    archiver.discard(&second).await;
+
+    assert!(kept.ends_with("v1.html.gz"));
+    assert_eq!(archiver.open(&kept).await.unwrap(), PAGE);
+    let files = std::fs::read_dir(root.path().join("7")).unwrap().count();
+    assert_eq!(files, 1);
+}
+
+#[tokio::test]
+async fn single_file_snapshots_inline_the_assets_they_can_fetch() {
+    let (archiver, _root) = archiver();
+
+    let archived = archiver
+        .archive(7, "https://example.com/articles/1", true)
+        .await
+        .unwrap();
+    let html = archiver.open(&archived.file_path).await.unwrap();
+
+    assert!(html.contains("href=\"data:text/css;base64,cHt9\""));
+    assert!(html.contains("src=\"data:image/png;base64,cG5n\""));
+    // Assets that fail to load keep their reference instead of failing the snapshot
+    assert!(html.contains("src=\"missing.png\""));
+    // Reader text is extracted from the page as fetched
+    assert_eq!(archived.page_html, PAGE);
+}
+
+#[tokio::test]
+async fn fetch_failures_are_reported() {
+    let (archiver, _root) = archiver();
+    let error = archiver
+        .archive(7, "https://example.com/gone", false)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error, "Not found: https://example.com/gone");
+}
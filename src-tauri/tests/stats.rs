@@ -0,0 +1,137 @@
+mod common;
+
+use common::{id_of, TestApp};
+use serde_json::{json, Value};
+
+fn workspace_scope(workspace_id: i64) -> Value {
+    json!({ "type": "workspace", "workspace_id": workspace_id })
+}
+
+#[tokio::test]
+async fn organization_and_workspace_counts_are_aggregated() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let rust = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "rust,docs",
+        )
+        .await;
+    app.create_bookmark(seed.group_id, "Untagged", "https://example.com", "")
+        .await;
+    let broken = app
+        .create_bookmark(seed.group_id, "Gone", "https://gone.invalid/page", "docs")
+        .await;
+    app.ok_mutation(
+        "bookmark.update",
+        json!({ "id": id_of(&rust), "is_favorite": true }),
+    )
+    .await;
+
+    // A failed snapshot marks the link as broken
+    let snapshot = app
+        .mutation(
+            "bookmark.snapshot",
+            json!({ "bookmark_id": id_of(&broken), "single_file": false }),
+        )
+        .await;
+    assert!(snapshot.is_err());
+
+    let other = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Archive", "organization_id": seed.organization_id }),
+        )
+        .await;
+
+    let workspace = app
+        .ok_query("stats.workspace", json!(seed.workspace_id))
+        .await;
+    assert_eq!(
+        workspace["counts"],
+        json!({ "groups": 1, "bookmarks": 3, "favorites": 1, "broken_links": 1, "untagged": 1 })
+    );
+
+    let organization = app
+        .ok_query("stats.organization", json!(seed.organization_id))
+        .await;
+    assert_eq!(organization["totals"], workspace["counts"]);
+    let workspaces = organization["workspaces"].as_array().unwrap();
+    assert_eq!(workspaces.len(), 2);
+    assert_eq!(workspaces[0]["workspace_id"], other["id"]);
+    assert_eq!(workspaces[0]["counts"]["groups"], 0);
+    assert_eq!(workspaces[0]["counts"]["bookmarks"], 0);
+}
+
+#[tokio::test]
+async fn top_tags_hosts_weeks_and_visits() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let book = app
+        .create_bookmark(
+            seed.group_id,
+            "Rust book",
+            "https://doc.rust-lang.org/book",
+            "rust, docs",
+        )
+        .await;
+    let std = app
+        .create_bookmark(
+            seed.group_id,
+            "Std",
+            "https://DOC.rust-lang.org/std/",
+            "rust",
+        )
+        .await;
+    app.create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "go")
+        .await;
+    for id in [id_of(&std), id_of(&std), id_of(&book)] {
+        app.ok_mutation("bookmark.markOpened", json!(id)).await;
+    }
+    let scope = workspace_scope(seed.workspace_id);
+
+    let tags = app
+        .ok_query("stats.topTags", json!({ "scope": scope, "limit": 2 }))
+        .await;
+    assert_eq!(
+        tags,
+        json!([{ "tag": "rust", "count": 2 }, { "tag": "docs", "count": 1 }])
+    );
+
+    let hosts = app
+        .ok_query("stats.topHosts", json!({ "scope": scope }))
+        .await;
+    assert_eq!(
+        hosts,
+        json!([{ "host": "doc.rust-lang.org", "count": 2 }, { "host": "go.dev", "count": 1 }])
+    );
+
+    let weeks = app
+        .ok_query("stats.addedPerWeek", json!({ "scope": scope, "weeks": 4 }))
+        .await;
+    let counts: Vec<i64> = weeks
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|week| week["count"].as_i64().unwrap())
+        .collect();
+    assert_eq!(counts, [0, 0, 0, 3]);
+
+    let visited = app
+        .ok_query("stats.mostVisited", json!({ "scope": scope }))
+        .await;
+    assert_eq!(visited.as_array().unwrap().len(), 2);
+    assert_eq!(visited[0]["name"], "Std");
+    assert_eq!(visited[0]["open_count"], 2);
+
+    // Other organizations' workspaces are out of scope
+    let outside = app
+        .ok_query(
+            "stats.topTags",
+            json!({ "scope": { "type": "organization", "organization_id": 999 } }),
+        )
+        .await;
+    assert_eq!(outside, json!([]));
+}
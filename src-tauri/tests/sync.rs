@@ -0,0 +1,413 @@
+mod common;
+
+use std::{path::PathBuf, time::Duration};
+
+use common::{first_page, id_of, TestApp};
+use futures::StreamExt;
+use rayzen_lib::domain::events::ChangeKind;
+use sea_orm::ConnectionTrait;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// One rayzen instance with the workspace it shares
+struct Instance {
+    app: TestApp,
+    organization_id: i64,
+}
+
+fn shared_folder() -> PathBuf {
+    std::env::temp_dir().join(format!("rayzen-sync-{}", Uuid::new_v4()))
+}
+
+async fn instance() -> Instance {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    Instance {
+        app,
+        organization_id: seed.organization_id,
+    }
+}
+
+async fn export(instance: &Instance, workspace_id: i64, folder: &PathBuf) -> Value {
+    instance
+        .app
+        .ok_mutation(
+            "sync.export",
+            json!({ "workspace_id": workspace_id, "folder": folder }),
+        )
+        .await
+}
+
+async fn import(instance: &Instance, folder: &PathBuf) -> Value {
+    instance
+        .app
+        .ok_mutation(
+            "sync.import",
+            json!({ "folder": folder, "organization_id": instance.organization_id }),
+        )
+        .await
+}
+
+/// Ids of the shared workspace and group on an instance, found by name since ids differ
+async fn team_ids(instance: &Instance) -> (i64, i64) {
+    let workspaces = instance
+        .app
+        .ok_query(
+            "workspace.getWorkspaces",
+            json!([instance.organization_id, first_page()]),
+        )
+        .await;
+    let workspace = workspaces["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|workspace| workspace["name"] == "Team")
+        .map(id_of)
+        .expect("Team wasn't shared");
+
+    let groups = instance
+        .app
+        .ok_query("groups.getGroups", json!({ "per_page": 100 }))
+        .await;
+    let group = groups["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|group| group["name"] == "Links" && group["workspace_id"] == workspace)
+        .map(id_of)
+        .expect("Links wasn't shared");
+
+    (workspace, group)
+}
+
+async fn bookmarks(instance: &Instance, group_id: i64) -> Vec<Value> {
+    instance
+        .app
+        .ok_query("bookmark.getByGroup", json!([group_id, first_page()]))
+        .await["items"]
+        .as_array()
+        .unwrap()
+        .clone()
+}
+
+/// `a` shares a Team workspace with one bookmark, `b` merges it
+async fn shared() -> (Instance, Instance, PathBuf) {
+    let a = instance().await;
+    let b = instance().await;
+    let folder = shared_folder();
+
+    let workspace = a
+        .app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": a.organization_id }),
+        )
+        .await;
+    let group = a
+        .app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Links", "workspace_id": workspace["id"] }),
+        )
+        .await;
+    a.app
+        .create_bookmark(id_of(&group), "Rust", "https://www.rust-lang.org", "rust")
+        .await;
+
+    export(&a, id_of(&workspace), &folder).await;
+    let report = import(&b, &folder).await;
+    assert_eq!(report["bundles"], 1);
+    assert_eq!(report["created"], 3);
+    assert_eq!(report["conflicts"], json!([]));
+
+    (a, b, folder)
+}
+
+#[tokio::test]
+async fn a_shared_workspace_is_created_once() {
+    let (a, b, folder) = shared().await;
+
+    let (_, group) = team_ids(&b).await;
+    let shared = bookmarks(&b, group).await;
+    assert_eq!(shared.len(), 1);
+    assert_eq!(shared[0]["name"], "Rust");
+    assert_eq!(shared[0]["url"], "https://www.rust-lang.org");
+    assert_eq!(shared[0]["tags"], "rust");
+
+    // Merging the same bundle again changes nothing
+    let again = import(&b, &folder).await;
+    assert_eq!(again["created"], 0);
+    assert_eq!(again["applied"], 0);
+
+    // Bundles of the instance itself are ignored
+    let own = import(&a, &folder).await;
+    assert_eq!(own["bundles"], 0);
+
+    let status = b.app.ok_query("sync.status", json!(null)).await;
+    assert!(status["changes"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn concurrent_edits_keep_the_newest_value_and_report_a_conflict() {
+    let (a, b, folder) = shared().await;
+    let (a_workspace, a_group) = team_ids(&a).await;
+    let (b_workspace, b_group) = team_ids(&b).await;
+    let a_bookmark = id_of(&bookmarks(&a, a_group).await[0]);
+    let b_bookmark = id_of(&bookmarks(&b, b_group).await[0]);
+
+    a.app
+        .ok_mutation(
+            "bookmark.update",
+            json!({ "id": a_bookmark, "name": "Rust from A" }),
+        )
+        .await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    b.app
+        .ok_mutation(
+            "bookmark.update",
+            json!({ "id": b_bookmark, "name": "Rust from B", "tags": "lang" }),
+        )
+        .await;
+    b.app
+        .create_bookmark(b_group, "Docs", "https://docs.rs", "")
+        .await;
+
+    export(&a, a_workspace, &folder).await;
+    export(&b, b_workspace, &folder).await;
+
+    let at_a = import(&a, &folder).await;
+    assert_eq!(at_a["created"], 1);
+    assert_eq!(at_a["conflicts"].as_array().unwrap().len(), 1);
+    let conflict = &at_a["conflicts"][0];
+    assert_eq!(conflict["entity_type"], "bookmark");
+    assert_eq!(conflict["field"], "name");
+    assert_eq!(conflict["local_value"], "\"Rust from A\"");
+    assert_eq!(conflict["remote_value"], "\"Rust from B\"");
+    assert_eq!(conflict["winner"], "remote");
+
+    let at_b = import(&b, &folder).await;
+    assert_eq!(at_b["conflicts"][0]["winner"], "local");
+
+    // Both sides end up with the same bookmarks
+    for (instance, group) in [(&a, a_group), (&b, b_group)] {
+        let merged = bookmarks(instance, group).await;
+        let names: Vec<&str> = merged
+            .iter()
+            .map(|bookmark| bookmark["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Rust from B", "Docs"]);
+        assert_eq!(merged[0]["tags"], "lang");
+    }
+}
+
+#[tokio::test]
+async fn edits_made_after_a_merge_and_deletes_are_not_conflicts() {
+    let (a, b, folder) = shared().await;
+    let (a_workspace, a_group) = team_ids(&a).await;
+    let (b_workspace, b_group) = team_ids(&b).await;
+    let b_bookmark = id_of(&bookmarks(&b, b_group).await[0]);
+
+    // b already merged what a did, so overwriting it is not a conflict
+    b.app
+        .ok_mutation(
+            "bookmark.update",
+            json!({ "id": b_bookmark, "is_favorite": true }),
+        )
+        .await;
+    export(&b, b_workspace, &folder).await;
+    let at_a = import(&a, &folder).await;
+    assert_eq!(at_a["applied"], 1);
+    assert_eq!(at_a["conflicts"], json!([]));
+    assert_eq!(bookmarks(&a, a_group).await[0]["is_favorite"], true);
+
+    a.app
+        .ok_mutation("groups.deleteGroup", json!(a_group))
+        .await;
+    export(&a, a_workspace, &folder).await;
+    let at_b = import(&b, &folder).await;
+    // The bookmark goes with its group
+    assert_eq!(at_b["deleted"], 2);
+    assert_eq!(at_b["conflicts"], json!([]));
+    assert!(b
+        .app
+        .query("groups.getGroupById", json!(b_group))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn merged_rows_are_published_and_saved_searches_recounted() {
+    let (a, b, folder) = shared().await;
+    let (a_workspace, a_group) = team_ids(&a).await;
+    let (b_workspace, _) = team_ids(&b).await;
+    let saved = b
+        .app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": b_workspace,
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+    assert_eq!(saved["cached_count"], 1);
+
+    let rust = id_of(&bookmarks(&a, a_group).await[0]);
+    a.app
+        .ok_mutation(
+            "bookmark.update",
+            json!({ "id": rust, "name": "Rust lang" }),
+        )
+        .await;
+    a.app
+        .create_bookmark(
+            a_group,
+            "Rustlings",
+            "https://github.com/rust-lang/rustlings",
+            "rust",
+        )
+        .await;
+    export(&a, a_workspace, &folder).await;
+
+    let mut changes = Box::pin(b.app.services.events.bookmarks(b_workspace as i32));
+    import(&b, &folder).await;
+
+    let mut published = Vec::new();
+    for _ in 0..2 {
+        let event = tokio::time::timeout(Duration::from_secs(1), changes.next())
+            .await
+            .expect("No change event was published")
+            .unwrap();
+        published.push((event.kind, event.bookmark.unwrap().name));
+    }
+    assert_eq!(
+        published,
+        [
+            (ChangeKind::Created, "Rustlings".to_string()),
+            (ChangeKind::Updated, "Rust lang".to_string()),
+        ]
+    );
+
+    let saved = b
+        .app
+        .ok_query("savedSearch.getById", saved["id"].clone())
+        .await;
+    assert_eq!(saved["cached_count"], 2);
+}
+
+/// A bookmark whose group hasn't been shared yet waits for it instead of being dropped
+#[tokio::test]
+async fn children_arriving_before_their_parent_are_created_later() {
+    let a = instance().await;
+    let b = instance().await;
+    let workspace = a
+        .app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": a.organization_id }),
+        )
+        .await;
+    let group = a
+        .app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Links", "workspace_id": workspace["id"] }),
+        )
+        .await;
+    a.app
+        .create_bookmark(id_of(&group), "Rust", "https://www.rust-lang.org", "rust")
+        .await;
+
+    let folder = shared_folder();
+    let exported = export(&a, id_of(&workspace), &folder).await;
+    let path = PathBuf::from(exported["path"].as_str().unwrap());
+    let mut bundle: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+    // Only the bookmark's changes have arrived
+    let early = shared_folder();
+    bundle["changes"]
+        .as_array_mut()
+        .unwrap()
+        .retain(|change| change["entity_type"] == "bookmark");
+    std::fs::create_dir_all(&early).unwrap();
+    std::fs::write(
+        early.join(path.file_name().unwrap()),
+        serde_json::to_string(&bundle).unwrap(),
+    )
+    .unwrap();
+
+    let report = import(&b, &early).await;
+    assert_eq!(report["created"], 0);
+    assert!(report["skipped"].as_u64().unwrap() > 0);
+
+    let report = import(&b, &folder).await;
+    assert_eq!(report["created"], 3);
+    let (_, b_group) = team_ids(&b).await;
+    assert_eq!(bookmarks(&b, b_group).await[0]["name"], "Rust");
+}
+
+/// The bookmark already here under the shared UUID is merged into, not inserted again
+#[tokio::test]
+async fn a_local_row_with_the_shared_uuid_is_attached() {
+    let a = instance().await;
+    let b = instance().await;
+    let seed = b.app.ok_query("groups.getGroups", first_page()).await;
+    let copy = b
+        .app
+        .create_bookmark(
+            id_of(&seed["items"][0]),
+            "Rust copy",
+            "https://www.rust-lang.org",
+            "",
+        )
+        .await;
+    // b has synced before, so the copy is already shared under its own UUID
+    let empty = shared_folder();
+    std::fs::create_dir_all(&empty).unwrap();
+    import(&b, &empty).await;
+    // The remote edits are newer than the copy
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    let workspace = a
+        .app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Team", "organization_id": a.organization_id }),
+        )
+        .await;
+    let group = a
+        .app
+        .ok_mutation(
+            "groups.createGroups",
+            json!({ "name": "Links", "workspace_id": workspace["id"] }),
+        )
+        .await;
+    let original = a
+        .app
+        .create_bookmark(id_of(&group), "Rust", "https://www.rust-lang.org", "rust")
+        .await;
+    b.app
+        .db
+        .execute_unprepared(&format!(
+            "UPDATE bookmark SET uuid = '{}' WHERE id = {}",
+            original["uuid"].as_str().unwrap(),
+            id_of(&copy)
+        ))
+        .await
+        .unwrap();
+
+    let folder = shared_folder();
+    export(&a, id_of(&workspace), &folder).await;
+    let report = import(&b, &folder).await;
+    // The workspace and group are new, the bookmark isn't
+    assert_eq!(report["created"], 2);
+
+    let (_, b_group) = team_ids(&b).await;
+    let merged = bookmarks(&b, b_group).await;
+    assert_eq!(merged.len(), 1);
+    assert_eq!(id_of(&merged[0]), id_of(&copy));
+    assert_eq!(merged[0]["name"], "Rust");
+    assert_eq!(merged[0]["uuid"], original["uuid"]);
+}
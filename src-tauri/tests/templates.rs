@@ -0,0 +1,140 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::{json, Value};
+
+fn names(groups: &Value) -> Vec<&str> {
+    groups
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|group| group["name"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn workspaces_are_created_from_saved_templates() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    app.create_bookmark(
+        seed.group_id,
+        "Rust book",
+        "https://doc.rust-lang.org/book",
+        "rust",
+    )
+    .await;
+    app.ok_mutation(
+        "groups.createGroups",
+        json!({ "name": "Runbooks", "workspace_id": seed.workspace_id }),
+    )
+    .await;
+    app.ok_mutation(
+        "groups.createSmartGroup",
+        json!({
+            "name": "Rust",
+            "workspace_id": seed.workspace_id,
+            "filter": { "tags": ["rust"] },
+        }),
+    )
+    .await;
+
+    let layout_only = app
+        .ok_mutation(
+            "workspace.saveAsTemplate",
+            json!({ "workspace_id": seed.workspace_id, "name": "Project", "include_bookmarks": false }),
+        )
+        .await;
+    assert_eq!(layout_only["organization_id"], seed.organization_id);
+    assert_eq!(
+        names(&layout_only["layout"]["groups"]),
+        ["Articles", "Runbooks", "Rust"]
+    );
+    assert_eq!(layout_only["layout"]["groups"][0]["bookmarks"], json!([]));
+
+    let with_bookmarks = app
+        .ok_mutation(
+            "workspace.saveAsTemplate",
+            json!({ "workspace_id": seed.workspace_id, "name": "Onboarding", "include_bookmarks": true }),
+        )
+        .await;
+    let templates = app
+        .ok_query("workspace.listTemplates", json!(seed.organization_id))
+        .await;
+    assert_eq!(names(&templates), ["Onboarding", "Project"]);
+
+    let workspace = app
+        .ok_mutation(
+            "workspace.createFromTemplate",
+            json!({
+                "template_id": id_of(&with_bookmarks),
+                "organization_id": seed.organization_id,
+                "name": "Platform",
+            }),
+        )
+        .await;
+    assert_eq!(workspace["name"], "Platform");
+    let groups = app
+        .ok_query(
+            "groups.getBelongedGroups",
+            json!([workspace["id"], seed.organization_id, first_page()]),
+        )
+        .await;
+    let groups = &groups["items"];
+    assert_eq!(names(groups), ["Articles", "Runbooks", "Rust"]);
+    assert_eq!(groups[2]["kind"], "smart");
+    assert_eq!(groups[2]["filter"]["tags"], json!(["rust"]));
+
+    let bookmarks = app
+        .ok_query("bookmark.getByGroup", json!([groups[0]["id"], first_page()]))
+        .await;
+    assert_eq!(bookmarks["items"].as_array().unwrap().len(), 1);
+    assert_eq!(bookmarks["items"][0]["url"], "https://doc.rust-lang.org/book");
+    let matching = app
+        .ok_query("groups.evaluateSmartGroup", groups[2]["id"].clone())
+        .await;
+    assert_eq!(matching.as_array().unwrap().len(), 1);
+
+    // The JSON export refers to rows by UUID only
+    let export = app
+        .ok_query("workspace.exportTemplate", json!(id_of(&with_bookmarks)))
+        .await;
+    assert_eq!(export["file_name"], "onboarding.json");
+    assert_eq!(export["mime_type"], "application/json");
+    let exported: Value = serde_json::from_str(export["content"].as_str().unwrap()).unwrap();
+    assert_eq!(exported["version"], 1);
+    assert_eq!(exported["uuid"], with_bookmarks["uuid"]);
+    assert!(exported["organization_uuid"].is_string());
+    assert!(exported.get("id").is_none());
+    assert_eq!(
+        exported["layout"]["groups"][0]["bookmarks"][0]["name"],
+        "Rust book"
+    );
+
+    app.ok_mutation("workspace.deleteTemplate", json!(id_of(&layout_only)))
+        .await;
+    let templates = app
+        .ok_query("workspace.listTemplates", json!(seed.organization_id))
+        .await;
+    assert_eq!(names(&templates), ["Onboarding"]);
+}
+
+#[tokio::test]
+async fn templates_need_a_name_and_an_existing_template() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    assert!(app
+        .mutation(
+            "workspace.saveAsTemplate",
+            json!({ "workspace_id": seed.workspace_id, "name": " ", "include_bookmarks": false }),
+        )
+        .await
+        .is_err());
+    assert!(app
+        .mutation(
+            "workspace.createFromTemplate",
+            json!({ "template_id": 42, "organization_id": seed.organization_id, "name": "Nope" }),
+        )
+        .await
+        .is_err());
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use common::{id_of, TestApp};
+use rayzen_lib::domain::tray_menu::{
+    bookmark_item_id, build_tray_menu, parse_bookmark_item_id, TrayBookmark, TrayEntry,
+    OPEN_LAUNCHER_ID, QUIT_ID, SHOW_MAIN_ID,
+};
+use serde_json::json;
+
+fn bookmark(id: i32, name: &str, workspace_id: i32, workspace_name: &str) -> TrayBookmark {
+    TrayBookmark {
+        id,
+        name: name.to_string(),
+        workspace_id,
+        workspace_name: workspace_name.to_string(),
+    }
+}
+
+fn item_ids(entries: &[TrayEntry]) -> Vec<&str> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TrayEntry::Item { id, enabled, .. } if *enabled => Some(id.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn submenu<'a>(entries: &'a [TrayEntry], label: &str) -> &'a [TrayEntry] {
+    entries
+        .iter()
+        .find_map(|entry| match entry {
+            TrayEntry::Submenu {
+                label: submenu_label,
+                entries,
+            } if submenu_label == label => Some(entries.as_slice()),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("No submenu labelled {}", label))
+}
+
+#[test]
+fn empty_menu_only_has_the_fixed_entries() {
+    let menu = build_tray_menu(&[], &[]);
+
+    assert_eq!(item_ids(&menu), [SHOW_MAIN_ID, OPEN_LAUNCHER_ID, QUIT_ID]);
+    assert!(menu.contains(&TrayEntry::Item {
+        id: String::new(),
+        label: "No favorite or recent bookmarks".to_string(),
+        enabled: false,
+    }));
+}
+
+#[test]
+fn bookmarks_are_grouped_by_workspace() {
+    let favorites = [
+        bookmark(1, "Rust book", 2, "Work"),
+        bookmark(2, "Recipes", 1, "Home"),
+    ];
+    let recent = [
+        bookmark(3, "Go tour", 2, "Work"),
+        // Already listed as a favorite
+        bookmark(1, "Rust book", 2, "Work"),
+    ];
+
+    let menu = build_tray_menu(&favorites, &recent);
+
+    let labels: Vec<&str> = menu
+        .iter()
+        .filter_map(|entry| match entry {
+            TrayEntry::Submenu { label, .. } => Some(label.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(labels, ["Home", "Work"]);
+
+    let work = submenu(&menu, "Work");
+    assert_eq!(
+        item_ids(work),
+        [bookmark_item_id(1).as_str(), bookmark_item_id(3).as_str()]
+    );
+    assert!(work.contains(&TrayEntry::Separator));
+
+    let home = submenu(&menu, "Home");
+    assert_eq!(item_ids(home), [bookmark_item_id(2).as_str()]);
+    assert!(!home.contains(&TrayEntry::Separator));
+}
+
+#[test]
+fn long_names_are_shortened() {
+    let name = "a".repeat(80);
+    let menu = build_tray_menu(&[bookmark(1, &name, 1, "Home")], &[]);
+
+    match &submenu(&menu, "Home")[1] {
+        TrayEntry::Item { label, .. } => {
+            assert_eq!(label.chars().count(), 48);
+            assert!(label.ends_with('…'));
+        }
+        entry => panic!("Expected a bookmark item, got {:?}", entry),
+    }
+}
+
+#[test]
+fn bookmark_item_ids_round_trip() {
+    assert_eq!(parse_bookmark_item_id(&bookmark_item_id(42)), Some(42));
+    assert_eq!(parse_bookmark_item_id(SHOW_MAIN_ID), None);
+    assert_eq!(parse_bookmark_item_id("bookmark:nope"), None);
+}
+
+#[tokio::test]
+async fn tray_lists_favorites_and_opened_bookmarks() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let favorite = app
+        .ok_mutation(
+            "bookmark.create",
+            json!({
+                "name": "Rust book",
+                "url": "https://doc.rust-lang.org/book",
+                "tags": "",
+                "is_favorite": true,
+                "group_id": seed.group_id,
+            }),
+        )
+        .await;
+    let opened = app
+        .create_bookmark(seed.group_id, "Go tour", "https://go.dev/tour", "")
+        .await;
+    app.create_bookmark(seed.group_id, "Never opened", "https://example.com", "")
+        .await;
+
+    let marked = app
+        .ok_mutation("bookmark.markOpened", json!(id_of(&opened)))
+        .await;
+    assert!(!marked["opened_at"].is_null());
+
+    let menu = app.services.tray.build_menu(app.context()).await.unwrap();
+
+    let workspace = submenu(&menu, "Reading");
+    assert_eq!(
+        item_ids(workspace),
+        [
+            bookmark_item_id(id_of(&favorite) as i32).as_str(),
+            bookmark_item_id(id_of(&opened) as i32).as_str(),
+        ]
+    );
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::json;
+
+#[tokio::test]
+async fn version_is_reported() {
+    let app = TestApp::new().await;
+
+    assert_eq!(app.ok_query("version", json!(null)).await, json!("1.0.0"));
+}
+
+#[tokio::test]
+async fn user_crud_round_trip() {
+    let app = TestApp::new().await;
+
+    let user = app
+        .ok_mutation(
+            "users.createUser",
+            json!({ "name": "Ada", "email": "ada@example.com" }),
+        )
+        .await;
+    let id = id_of(&user);
+    assert_eq!(user["name"], "Ada");
+
+    let found = app.ok_query("users.getUserById", json!(id)).await;
+    assert_eq!(found["email"], "ada@example.com");
+
+    let updated = app
+        .ok_mutation(
+            "users.updateUser",
+            json!({ "id": id, "name": "Ada Lovelace", "email": null }),
+        )
+        .await;
+    assert_eq!(updated["name"], "Ada Lovelace");
+    assert_eq!(updated["email"], "ada@example.com");
+
+    let page = app.ok_query("users.getUsers", first_page()).await;
+    assert_eq!(page["total"], 1);
+    assert_eq!(page["items"][0]["name"], "Ada Lovelace");
+
+    app.ok_mutation("users.deleteUser", json!(id)).await;
+    assert!(app.query("users.getUserById", json!(id)).await.is_err());
+}
+
+#[tokio::test]
+async fn update_without_id_is_rejected() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .mutation(
+            "users.updateUser",
+            json!({ "id": null, "name": "Nobody", "email": null }),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn users_are_paged_and_sorted() {
+    let app = TestApp::new().await;
+    for name in ["Carol", "Alice", "Bob"] {
+        app.ok_mutation(
+            "users.createUser",
+            json!({ "name": name, "email": format!("{}@example.com", name.to_lowercase()) }),
+        )
+        .await;
+    }
+
+    let first = app
+        .ok_query(
+            "users.getUsers",
+            json!({ "per_page": 2, "sort": { "field": "name", "direction": "asc" } }),
+        )
+        .await;
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["items"].as_array().unwrap().len(), 2);
+    assert_eq!(first["items"][0]["name"], "Alice");
+    assert_eq!(first["next_cursor"], "1");
+
+    let second = app
+        .ok_query(
+            "users.getUsers",
+            json!({
+                "cursor": first["next_cursor"],
+                "per_page": 2,
+                "sort": { "field": "name", "direction": "asc" },
+            }),
+        )
+        .await;
+    assert_eq!(second["items"][0]["name"], "Carol");
+    assert!(second["next_cursor"].is_null());
+
+    let filtered = app
+        .ok_query("users.getUsers", json!({ "filter": { "search": "bo" } }))
+        .await;
+    assert_eq!(filtered["total"], 1);
+    assert_eq!(filtered["items"][0]["name"], "Bob");
+}
+
+#[tokio::test]
+async fn unknown_sort_field_is_an_error() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .query(
+            "users.getUsers",
+            json!({ "sort": { "field": "password", "direction": "asc" } }),
+        )
+        .await;
+
+    assert!(result.unwrap_err().message.contains("password"));
+}
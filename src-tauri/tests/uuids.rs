@@ -0,0 +1,166 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn uuid_of(value: &Value) -> Uuid {
+    value["uuid"]
+        .as_str()
+        .and_then(|uuid| Uuid::parse_str(uuid).ok())
+        .unwrap_or_else(|| panic!("No uuid in {}", value))
+}
+
+#[tokio::test]
+async fn entities_can_be_looked_up_by_uuid() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Rust", "https://www.rust-lang.org", "")
+        .await;
+    let saved_search = app
+        .ok_mutation(
+            "savedSearch.create",
+            json!({
+                "workspace_id": seed.workspace_id,
+                "name": "Rust",
+                "query": "rust",
+                "is_pinned": false,
+            }),
+        )
+        .await;
+
+    let lookups = [
+        ("users.getUserById", seed.user_id),
+        ("organization.getOrganizationById", seed.organization_id),
+        ("workspace.getWorkspaceById", seed.workspace_id),
+        ("groups.getGroupById", seed.group_id),
+        ("bookmark.getById", id_of(&bookmark)),
+        ("savedSearch.getById", id_of(&saved_search)),
+    ];
+    let mut uuids = Vec::new();
+    for (procedure, id) in lookups {
+        let by_id = app.ok_query(procedure, json!(id)).await;
+        let uuid = uuid_of(&by_id);
+        let by_uuid = app.ok_query(procedure, json!(uuid)).await;
+        assert_eq!(by_uuid["id"], id, "{}", procedure);
+        uuids.push(uuid);
+    }
+
+    uuids.sort();
+    uuids.dedup();
+    assert_eq!(uuids.len(), lookups.len());
+    assert!(app
+        .query("bookmark.getById", json!(Uuid::new_v4()))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn reimporting_an_export_matches_bookmarks_by_uuid() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+    let bookmark = app
+        .create_bookmark(seed.group_id, "Rust", "https://www.rust-lang.org", "")
+        .await;
+
+    let export = app
+        .ok_query(
+            "export.run",
+            json!({
+                "format": "csv",
+                "scope": { "type": "workspace", "workspace_id": seed.workspace_id },
+            }),
+        )
+        .await;
+    let csv = export["content"].as_str().unwrap();
+    assert!(csv
+        .lines()
+        .nth(1)
+        .unwrap()
+        .ends_with(&uuid_of(&bookmark).to_string()));
+
+    // The url changed since the export, the UUID still says it is the same bookmark
+    app.ok_mutation(
+        "bookmark.update",
+        json!({
+            "id": id_of(&bookmark),
+            "name": null,
+            "url": "https://rust-lang.org/learn",
+            "tags": null,
+            "is_favorite": null,
+            "group_id": null,
+        }),
+    )
+    .await;
+
+    let path = std::env::temp_dir().join(format!("rayzen-export-{}.csv", Uuid::new_v4()));
+    std::fs::write(&path, csv).unwrap();
+    let import = |workspace_id: i64| {
+        json!({
+            "path": path,
+            "workspace_id": workspace_id,
+            "mapping": { "url": "url", "name": "name", "group": "group", "uuid": "uuid" },
+            "default_group": "Articles",
+        })
+    };
+
+    let again = app
+        .ok_mutation("import.commitCsv", import(seed.workspace_id))
+        .await;
+    assert_eq!(again["bookmarks_created"], 0);
+    assert_eq!(again["conflicts"][0]["reason"]["type"], "already_saved");
+    assert_eq!(
+        again["conflicts"][0]["reason"]["bookmark_id"],
+        id_of(&bookmark)
+    );
+
+    // Another workspace gets a copy with a UUID of its own
+    let other = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Other", "organization_id": seed.organization_id }),
+        )
+        .await;
+    let copied = app
+        .ok_mutation("import.commitCsv", import(id_of(&other)))
+        .await;
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(copied["bookmarks_created"], 1);
+
+    let copies = app
+        .ok_query("bookmark.search", json!(["Rust", first_page()]))
+        .await;
+    let uuids: Vec<Uuid> = copies["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(uuid_of)
+        .collect();
+    assert_eq!(uuids.len(), 2);
+    assert_ne!(uuids[0], uuids[1]);
+}
+
+#[test]
+fn invalid_uuids_are_row_errors() {
+    use rayzen_lib::domain::import::csv_file::{parse_csv_import, CsvColumnMapping};
+
+    let mapping = CsvColumnMapping {
+        url: "url".to_string(),
+        name: None,
+        tags: None,
+        group: None,
+        uuid: Some("uuid".to_string()),
+    };
+    let parsed = parse_csv_import(
+        "url,uuid\nhttps://a.example,not-a-uuid\nhttps://b.example,\n",
+        &mapping,
+        "Inbox",
+    )
+    .unwrap();
+
+    assert_eq!(parsed.errors[0].row, 2);
+    assert_eq!(parsed.errors[0].message, "\"not-a-uuid\" is not a UUID");
+    assert_eq!(parsed.bookmarks.len(), 1);
+    assert_eq!(parsed.bookmarks[0].uuid, None);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::{first_page, id_of, TestApp};
+use serde_json::json;
+
+#[tokio::test]
+async fn workspace_crud_round_trip() {
+    let app = TestApp::new().await;
+    let seed = app.seed().await;
+
+    let found = app
+        .ok_query("workspace.getWorkspaceById", json!(seed.workspace_id))
+        .await;
+    assert_eq!(found["name"], "Reading");
+
+    let updated = app
+        .ok_mutation(
+            "workspace.updateWorkspace",
+            json!({ "id": seed.workspace_id, "name": "Research", "organization_id": null }),
+        )
+        .await;
+    assert_eq!(updated["name"], "Research");
+
+    let extra = app
+        .ok_mutation(
+            "workspace.createWorkspace",
+            json!({ "name": "Archive", "organization_id": seed.organization_id }),
+        )
+        .await;
+
+    let page = app
+        .ok_query(
+            "workspace.getWorkspaces",
+            json!([seed.organization_id, first_page()]),
+        )
+        .await;
+    assert_eq!(page["total"], 2);
+
+    let other_organization = app
+        .ok_query("workspace.getWorkspaces", json!([999, first_page()]))
+        .await;
+    assert_eq!(other_organization["total"], 0);
+
+    app.ok_mutation("workspace.deleteWorkspace", json!(id_of(&extra)))
+        .await;
+    assert!(app
+        .query("workspace.getWorkspaceById", json!(id_of(&extra)))
+        .await
+        .is_err());
+}
+
+/// `update_workspace` used to unwrap the lookup and panic on a missing row
+#[tokio::test]
+async fn updating_missing_workspace_is_an_error() {
+    let app = TestApp::new().await;
+
+    let result = app
+        .mutation(
+            "workspace.updateWorkspace",
+            json!({ "id": 42, "name": "Ghost", "organization_id": null }),
+        )
+        .await;
+
+    assert!(result.unwrap_err().message.contains("not found"));
+}
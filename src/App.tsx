@@ -6,9 +6,17 @@ import {
   onMount,
   createEffect,
 } from 'solid-js';
-import { BookmarkDto, GroupsDto, SearchResult, UserDto } from './types';
-import { api } from './rpc';
+import {
+  BookmarkDto,
+  DeepLinkAction,
+  GroupsDto,
+  SearchResult,
+  UserDto,
+} from './types';
+import { api, firstPage } from './rpc';
 import SearchResults from './components/SearchResult';
+import LoadMore from './components/LoadMore';
+import { createBookmarkSearch } from './stores/search';
 import { SearchInput } from './components/SearchInput';
 import ListOfGroups from './components/ListOfGroups';
 import { useGroupBookmarks } from './hooks/useGroupBookmarks';
@@ -26,6 +34,12 @@ import Settings from './components/Settings';
 import FirstTimeSetup from './components/FirstTimeSetup';
 import NoGroupsFound from './components/NoGroupsFound';
 import { BookmarkContextMenu } from './components/BookmarkContextMenu';
+import DeepLinkConfirm from './components/DeepLinkConfirm';
+import { useDeepLinks } from './hooks/useDeepLinks';
+import { openUrl } from '@tauri-apps/plugin-opener';
+import { toast } from 'solid-sonner';
+
+type DeepLinkAdd = Extract<DeepLinkAction, { type: 'confirm_add' }>;
 
 const App: Component = () => {
   const [isFirstTime, setIsFirstTime] = createSignal<boolean | null>(null);
@@ -38,7 +52,14 @@ const App: Component = () => {
     email: string;
   } | null>(null);
 
-  const [results, setResults] = createSignal<SearchResult[]>([]);
+  const {
+    results,
+    search: searchBookmarks,
+    clear: clearResults,
+    loadMore: loadMoreResults,
+    loadingMore: loadingMoreResults,
+    hasMore: hasMoreResults,
+  } = createBookmarkSearch();
   const [selectedIndex, setSelectedIndex] = createSignal(0);
   const [viewMode, setViewMode] = createSignal<
     'groups' | 'bookmarks' | 'search' | 'settings'
@@ -53,6 +74,9 @@ const App: Component = () => {
     error: bookmarksError,
     loading: bookmarksLoading,
     selectGroup,
+    hasMore: hasMoreBookmarks,
+    loadingMore: loadingMoreBookmarks,
+    loadMore: loadMoreBookmarks,
   } = useGroupBookmarks();
 
   // Updated: Use the enhanced useGroups hook
@@ -70,6 +94,9 @@ const App: Component = () => {
     isCreating,
     isUpdating,
     refetchGroups,
+    hasMore: hasMoreGroups,
+    loadingMore: loadingMoreGroups,
+    loadMore: loadMoreGroups,
   } = useGroups();
 
   const {
@@ -188,7 +215,11 @@ const App: Component = () => {
   const [initializationData] = createResource(async () => {
     try {
       console.log('🔄 Checking for existing users...');
-      const users = await api.query(['users.getUsers']);
+      const { items: users } = await api.query([
+        'users.getUsers',
+        // only the first user is used, one page of one is enough
+        { ...firstPage, per_page: 1 },
+      ]);
 
       if (!users || users.length === 0) {
         console.log('ℹ️ No users found, showing first-time setup');
@@ -283,27 +314,13 @@ const App: Component = () => {
 
   const handleSearch = async (query: string) => {
     if (!query.trim()) {
-      setResults([]);
+      clearResults();
       setSelectedIndex(0);
       handleViewModeChange('groups');
       return;
     }
 
-    const foundBookmarks = await api.query(['bookmark.search', query]);
-    let searchResults: SearchResult[] = [];
-
-    foundBookmarks.forEach((bookmark) => {
-      searchResults.push({
-        id: bookmark.id,
-        title: bookmark.name,
-        tags: bookmark.tags,
-        is_favorite: bookmark.is_favorite,
-        url: bookmark.url,
-        type: 'bookmark',
-      });
-    });
-
-    setResults(searchResults);
+    await searchBookmarks(query);
     setSelectedIndex(0);
     handleViewModeChange('search');
     setSelectedGroup(null);
@@ -341,7 +358,7 @@ const App: Component = () => {
     }
 
     if (viewMode() === 'search') {
-      setResults([]);
+      clearResults();
     }
   };
 
@@ -363,7 +380,60 @@ const App: Component = () => {
     setSelectedGroup(null);
 
     if (viewMode() === 'search') {
-      setResults([]);
+      clearResults();
+    }
+  };
+
+  // Pending `rayzen://add` link waiting for the user's confirmation
+  const [pendingDeepLinkAdd, setPendingDeepLinkAdd] =
+    createSignal<DeepLinkAdd | null>(null);
+
+  const isInCurrentOrganization = (workspaceId: number) =>
+    workspaces()?.some((workspace) => workspace.id === workspaceId) ?? false;
+
+  useDeepLinks(async (action) => {
+    switch (action.type) {
+      case 'confirm_add':
+        setPendingDeepLinkAdd(action);
+        break;
+      case 'open_workspace':
+        if (!isInCurrentOrganization(action.workspace.id)) {
+          toast.error(
+            `${action.workspace.name} belongs to another organization`
+          );
+          return;
+        }
+        handleViewModeChange('groups');
+        handleWorkspaceSelect(action.workspace.id);
+        break;
+      case 'open_group':
+        if (!isInCurrentOrganization(action.group.workspace_id)) {
+          toast.error(
+            `${action.group.name} belongs to another organization`
+          );
+          return;
+        }
+        handleWorkspaceSelect(action.group.workspace_id);
+        handleGroupSelect(action.group);
+        break;
+      case 'open_bookmark':
+        await openUrl(action.bookmark.url);
+        await api.mutation(['bookmark.markOpened', action.bookmark.id]);
+        break;
+    }
+  });
+
+  const handleConfirmDeepLinkAdd = async () => {
+    const pending = pendingDeepLinkAdd();
+    if (!pending) return;
+
+    setPendingDeepLinkAdd(null);
+    try {
+      await api.mutation(['bookmark.create', pending.bookmark]);
+      toast(`Added ${pending.bookmark.name} to ${pending.group.name}`);
+    } catch (error) {
+      console.error('❌ Failed to add bookmark from link:', error);
+      toast.error('Failed to add bookmark');
     }
   };
 
@@ -508,7 +578,7 @@ const App: Component = () => {
 
       case 'groups':
         // clsoe search results if any
-        setResults([]);
+        clearResults();
         setSelectedIndex(0);
         break;
 
@@ -667,6 +737,9 @@ const App: Component = () => {
                       selectedGroupId={selectedGroup()?.id || 0}
                       onRenameGroup={handleRenameGroup}
                       onDeleteGroup={handleDeleteGroup}
+                      hasMore={hasMoreGroups()}
+                      loadingMore={loadingMoreGroups()}
+                      onLoadMore={loadMoreGroups}
                     />
                   </div>
                 </div>
@@ -682,6 +755,11 @@ const App: Component = () => {
                     onSelectionChange={setSelectedIndex}
                     onSelectItem={handleBookmarkSelect}
                   />
+                  <LoadMore
+                    hasMore={hasMoreResults()}
+                    loading={loadingMoreResults()}
+                    onLoadMore={loadMoreResults}
+                  />
                 </Show>
 
                 {/* Group Bookmarks Section */}
@@ -713,6 +791,9 @@ const App: Component = () => {
                       onUpdateBookmark={handleUpdateBookmark}
                       onRenameBookmark={handleRenameBookmark}
                       onDeleteBookmark={handleDeleteBookmark}
+                      hasMore={hasMoreBookmarks()}
+                      loadingMore={loadingMoreBookmarks()}
+                      onLoadMore={loadMoreBookmarks}
                     />
                   </div>
                 </Show>
@@ -760,6 +841,17 @@ const App: Component = () => {
               <div>↑↓ Navigate • Enter Open • Esc Clear</div>
             </div>
 
+            <Show when={pendingDeepLinkAdd()}>
+              {(pending) => (
+                <DeepLinkConfirm
+                  bookmark={pending().bookmark}
+                  group={pending().group}
+                  onConfirm={handleConfirmDeepLinkAdd}
+                  onCancel={() => setPendingDeepLinkAdd(null)}
+                />
+              )}
+            </Show>
+
             <Toaster theme="dark" />
           </div>
         </Show>
@@ -0,0 +1,214 @@
+import { Component, createSignal, For, Show } from 'solid-js';
+import { toast } from 'solid-sonner';
+import { Button } from './ui/button';
+import { api } from '../rpc';
+import { CsvColumnMapping, ImportPreview } from '~/types';
+
+interface CsvBookmarksProps {
+  workspaceId: () => number | null;
+}
+
+const selectClass =
+  'bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none';
+
+// Spreadsheet import with a column picker for url, name, tags and group
+const CsvBookmarks: Component<CsvBookmarksProps> = (props) => {
+  const [path, setPath] = createSignal('');
+  const [columns, setColumns] = createSignal<string[]>([]);
+  const [mapping, setMapping] = createSignal<CsvColumnMapping>({
+    url: '',
+    name: null,
+    tags: null,
+    group: null,
+    uuid: null,
+  });
+  const [defaultGroup, setDefaultGroup] = createSignal('Imported');
+  const [preview, setPreview] = createSignal<ImportPreview | null>(null);
+  const [error, setError] = createSignal<string | null>(null);
+  const [isImporting, setIsImporting] = createSignal(false);
+
+  const guess = (names: string[], candidates: string[]) =>
+    names.find((name) => candidates.includes(name.toLowerCase())) ?? null;
+
+  const handleReadColumns = async () => {
+    if (!path().trim()) return;
+
+    try {
+      setError(null);
+      setPreview(null);
+      const names = await api.query(['import.csvColumns', path().trim()]);
+      setColumns(names);
+      setMapping({
+        url: guess(names, ['url', 'link', 'href']) ?? names[0] ?? '',
+        name: guess(names, ['name', 'title']),
+        tags: guess(names, ['tags', 'labels']),
+        group: guess(names, ['group', 'folder', 'collection']),
+        uuid: guess(names, ['uuid']),
+      });
+    } catch (error) {
+      console.error('Failed to read CSV columns:', error);
+      setColumns([]);
+      setError(String(error));
+    }
+  };
+
+  const input = () => {
+    const workspaceId = props.workspaceId();
+    if (!workspaceId || columns().length === 0 || !mapping().url) return null;
+    return {
+      path: path().trim(),
+      workspace_id: workspaceId,
+      mapping: mapping(),
+      default_group: defaultGroup(),
+    };
+  };
+
+  const handlePreview = async () => {
+    const request = input();
+    if (!request) return;
+
+    try {
+      setError(null);
+      setPreview(await api.query(['import.previewCsv', request]));
+    } catch (error) {
+      console.error('Failed to preview CSV import:', error);
+      setPreview(null);
+      setError(String(error));
+    }
+  };
+
+  const handleImport = async () => {
+    const request = input();
+    if (!request) return;
+
+    try {
+      setIsImporting(true);
+      const result = await api.mutation(['import.commitCsv', request]);
+      toast(
+        `Imported ${result.bookmarks_created} bookmarks, ${result.errors.length} rows had errors`
+      );
+      setPreview(null);
+    } catch (error) {
+      console.error('Failed to import CSV:', error);
+      setError(String(error));
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  const columnSelect = (
+    label: string,
+    key: keyof CsvColumnMapping,
+    optional: boolean
+  ) => (
+    <label class="flex flex-col gap-1 text-sm text-gray-400">
+      {label}
+      <select
+        class={selectClass}
+        value={mapping()[key] ?? ''}
+        onChange={(e) => {
+          const value = e.currentTarget.value;
+          setMapping({ ...mapping(), [key]: value || null });
+          setPreview(null);
+        }}
+      >
+        <Show when={optional}>
+          <option value="">None</option>
+        </Show>
+        <For each={columns()}>
+          {(column) => <option value={column}>{column}</option>}
+        </For>
+      </select>
+    </label>
+  );
+
+  return (
+    <div class="space-y-4">
+      <h3 class="text-xl font-bold">Import from CSV</h3>
+      <div class="flex gap-2">
+        <input
+          type="text"
+          placeholder="Path to a CSV file with a header row"
+          class="flex-1 bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+          value={path()}
+          onInput={(e) => {
+            setPath(e.currentTarget.value);
+            setColumns([]);
+            setPreview(null);
+          }}
+          onKeyPress={(e) => {
+            if (e.key === 'Enter') handleReadColumns();
+          }}
+        />
+        <Button
+          variant="ghost"
+          onclick={handleReadColumns}
+          disabled={!path().trim()}
+        >
+          Read columns
+        </Button>
+      </div>
+
+      <Show when={columns().length > 0}>
+        <div class="grid grid-cols-2 gap-2">
+          {columnSelect('Url', 'url', false)}
+          {columnSelect('Name', 'name', true)}
+          {columnSelect('Tags', 'tags', true)}
+          {columnSelect('Group', 'group', true)}
+          {columnSelect('UUID', 'uuid', true)}
+          <label class="flex flex-col gap-1 text-sm text-gray-400">
+            Default group
+            <input
+              type="text"
+              class={selectClass}
+              value={defaultGroup()}
+              onInput={(e) => {
+                setDefaultGroup(e.currentTarget.value);
+                setPreview(null);
+              }}
+            />
+          </label>
+        </div>
+        <Button variant="ghost" onclick={handlePreview} disabled={!input()}>
+          Preview
+        </Button>
+      </Show>
+
+      <Show when={preview()}>
+        {(preview) => (
+          <div class="space-y-2">
+            <p class="text-sm text-gray-400">
+              {preview().total} bookmarks in {preview().groups.length} groups
+              <Show when={preview().conflicts.length > 0}>
+                , {preview().conflicts.length} already saved or repeated
+              </Show>
+            </p>
+            <Show when={preview().errors.length > 0}>
+              <ul class="max-h-32 overflow-y-auto text-sm text-red-400">
+                <For each={preview().errors}>
+                  {(error) => (
+                    <li>
+                      Row {error.row}: {error.message}
+                    </li>
+                  )}
+                </For>
+              </ul>
+            </Show>
+            <Button
+              onclick={handleImport}
+              disabled={isImporting() || preview().total === 0}
+            >
+              Import {preview().total} bookmarks
+            </Button>
+          </div>
+        )}
+      </Show>
+
+      <Show when={error()}>
+        <p class="text-sm text-red-400">{error()}</p>
+      </Show>
+    </div>
+  );
+};
+
+export default CsvBookmarks;
@@ -0,0 +1,41 @@
+import { Component } from 'solid-js';
+import { Button } from './ui/button';
+import { CreateBookmarkDto, GroupsDto } from '~/types';
+
+interface DeepLinkConfirmProps {
+  bookmark: CreateBookmarkDto;
+  group: GroupsDto;
+  onConfirm: () => void;
+  onCancel: () => void;
+}
+
+// Bookmarks coming from `rayzen://add` links are only saved once the user agrees
+const DeepLinkConfirm: Component<DeepLinkConfirmProps> = (props) => {
+  return (
+    <div class="fixed inset-0 z-50 flex items-center justify-center bg-black/70">
+      <div class="w-[420px] space-y-4 rounded-lg border border-gray-700 bg-gray-900 p-6 text-white">
+        <h3 class="text-lg font-bold">Add bookmark?</h3>
+        <div class="space-y-1 text-sm">
+          <p class="font-medium">{props.bookmark.name}</p>
+          <p class="break-all text-gray-400">{props.bookmark.url}</p>
+          <p class="text-gray-400">
+            Group: <span class="text-white">{props.group.name}</span>
+          </p>
+          {props.bookmark.tags && (
+            <p class="text-gray-400">
+              Tags: <span class="text-white">{props.bookmark.tags}</span>
+            </p>
+          )}
+        </div>
+        <div class="flex justify-end gap-2">
+          <Button variant="ghost" onclick={props.onCancel}>
+            Cancel
+          </Button>
+          <Button onclick={props.onConfirm}>Add</Button>
+        </div>
+      </div>
+    </div>
+  );
+};
+
+export default DeepLinkConfirm;
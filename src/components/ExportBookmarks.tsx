@@ -0,0 +1,76 @@
+import { Component, createResource, createSignal, For } from 'solid-js';
+import { toast } from 'solid-sonner';
+import { Button } from './ui/button';
+import { api } from '../rpc';
+import { ExportScope } from '~/types';
+
+interface ExportBookmarksProps {
+  workspaceId: () => number | null;
+}
+
+const inputClass =
+  'bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none';
+
+// Downloads the workspace, or the bookmarks matching a search, in any registered format
+const ExportBookmarks: Component<ExportBookmarksProps> = (props) => {
+  const [formats] = createResource(() => api.query(['export.formats']));
+  const [format, setFormat] = createSignal('markdown');
+  const [search, setSearch] = createSignal('');
+
+  const handleExport = async () => {
+    const workspaceId = props.workspaceId();
+    if (!workspaceId) return;
+
+    const query = search().trim();
+    const scope: ExportScope = query
+      ? { type: 'search', workspace_id: workspaceId, query }
+      : { type: 'workspace', workspace_id: workspaceId };
+
+    try {
+      const file = await api.query(['export.run', { format: format(), scope }]);
+      const link = document.createElement('a');
+      link.href = URL.createObjectURL(
+        new Blob([file.content], { type: file.mime_type })
+      );
+      link.download = file.file_name;
+      link.click();
+      URL.revokeObjectURL(link.href);
+    } catch (error) {
+      console.error('Failed to export bookmarks:', error);
+      toast('Failed to export bookmarks');
+    }
+  };
+
+  return (
+    <div class="space-y-4">
+      <h3 class="text-xl font-bold">Export</h3>
+      <div class="flex gap-2">
+        <select
+          class={inputClass}
+          value={format()}
+          onChange={(e) => setFormat(e.currentTarget.value)}
+        >
+          <For each={formats() ?? []}>
+            {(option) => <option value={option.name}>{option.label}</option>}
+          </For>
+        </select>
+        <input
+          type="text"
+          placeholder="Only bookmarks matching this search (optional)"
+          class={`flex-1 ${inputClass}`}
+          value={search()}
+          onInput={(e) => setSearch(e.currentTarget.value)}
+        />
+        <Button
+          variant="ghost"
+          onclick={handleExport}
+          disabled={!props.workspaceId()}
+        >
+          Export
+        </Button>
+      </div>
+    </div>
+  );
+};
+
+export default ExportBookmarks;
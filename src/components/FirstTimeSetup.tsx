@@ -7,7 +7,7 @@ import {
 } from 'solid-js';
 import { Button } from './ui/button';
 import { FiUser, FiArrowRight } from 'solid-icons/fi';
-import { api } from '~/rpc';
+import { api, firstPage } from '~/rpc';
 import { UserDto } from '~/types';
 
 interface FirstTimeSetupProps {
@@ -57,7 +57,11 @@ const FirstTimeSetup: Component<FirstTimeSetupProps> = (props) => {
   const checkExistingUser = async () => {
     try {
       console.log('🔄 Checking existing user...');
-      const users = await api.query(['users.getUsers']);
+      const { items: users } = await api.query([
+        'users.getUsers',
+        // only the first user is used, one page of one is enough
+        { ...firstPage, per_page: 1 },
+      ]);
       console.log('✅ Users found:', users);
 
       if (users && users.length > 0) {
@@ -3,6 +3,7 @@ import { SearchResult, GroupsDto, BookmarkDto } from '../types';
 import { FiBookmark, FiStar, FiX, FiLoader, FiCopy } from 'solid-icons/fi';
 import { toast } from 'solid-sonner';
 import { BookmarkContextMenu } from './BookmarkContextMenu';
+import LoadMore from './LoadMore';
 
 interface GroupBookmarksListProps {
   group: GroupsDto | null;
@@ -14,6 +15,9 @@ interface GroupBookmarksListProps {
   onUpdateBookmark: (bookmark: BookmarkDto) => void;
   onRenameBookmark: (id: number, name: string) => void;
   onDeleteBookmark: (id: number) => void;
+  hasMore?: boolean;
+  loadingMore?: boolean;
+  onLoadMore?: () => void;
 }
 
 const GroupBookmarksList: Component<GroupBookmarksListProps> = (props) => {
@@ -120,6 +124,11 @@ const GroupBookmarksList: Component<GroupBookmarksListProps> = (props) => {
                 </BookmarkContextMenu>
               )}
             </For>
+            <LoadMore
+              hasMore={props.hasMore}
+              loading={props.loadingMore}
+              onLoadMore={props.onLoadMore}
+            />
           </div>
         </Show>
       </div>
@@ -0,0 +1,170 @@
+import { Component, createSignal, For, Show } from 'solid-js';
+import { toast } from 'solid-sonner';
+import { Button } from './ui/button';
+import { api } from '../rpc';
+import { ImportPreview, ImportSource } from '~/types';
+
+interface ImportBookmarksProps {
+  workspaceId: () => number | null;
+}
+
+const SOURCES: { value: ImportSource; label: string; placeholder: string }[] = [
+  {
+    value: 'chromium',
+    label: 'Chrome / Edge / Brave',
+    placeholder: 'Path to the Bookmarks file or profile folder',
+  },
+  {
+    value: 'firefox',
+    label: 'Firefox',
+    placeholder: 'Path to places.sqlite or the profile folder',
+  },
+  {
+    value: 'pocket',
+    label: 'Pocket',
+    placeholder: 'Path to the exported CSV or ril_export.html',
+  },
+  {
+    value: 'pinboard',
+    label: 'Pinboard',
+    placeholder: 'Path to the exported JSON',
+  },
+  {
+    value: 'raindrop',
+    label: 'Raindrop',
+    placeholder: 'Path to the exported CSV',
+  },
+];
+
+// Reads a browser profile or export file and only writes on confirm
+const ImportBookmarks: Component<ImportBookmarksProps> = (props) => {
+  const [source, setSource] = createSignal<ImportSource>('chromium');
+  const [path, setPath] = createSignal('');
+  const [preview, setPreview] = createSignal<ImportPreview | null>(null);
+  const [error, setError] = createSignal<string | null>(null);
+  const [isImporting, setIsImporting] = createSignal(false);
+
+  const input = () => {
+    const workspaceId = props.workspaceId();
+    if (!workspaceId || !path().trim()) return null;
+    return {
+      source: source(),
+      path: path().trim(),
+      workspace_id: workspaceId,
+    };
+  };
+
+  const handlePreview = async () => {
+    const request = input();
+    if (!request) return;
+
+    try {
+      setError(null);
+      setPreview(await api.query(['import.preview', request]));
+    } catch (error) {
+      console.error('Failed to preview import:', error);
+      setPreview(null);
+      setError(String(error));
+    }
+  };
+
+  const handleImport = async () => {
+    const request = input();
+    if (!request) return;
+
+    try {
+      setIsImporting(true);
+      const result = await api.mutation(['import.commit', request]);
+      toast(
+        `Imported ${result.bookmarks_created} bookmarks into ${result.groups_created} new groups`
+      );
+      setPreview(null);
+    } catch (error) {
+      console.error('Failed to import bookmarks:', error);
+      setError(String(error));
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  return (
+    <div class="space-y-4">
+      <h3 class="text-xl font-bold">Import bookmarks</h3>
+      <div class="flex gap-2">
+        <select
+          class="bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+          value={source()}
+          onChange={(e) => {
+            setSource(e.currentTarget.value as ImportSource);
+            setPreview(null);
+          }}
+        >
+          <For each={SOURCES}>
+            {(option) => <option value={option.value}>{option.label}</option>}
+          </For>
+        </select>
+        <input
+          type="text"
+          placeholder={
+            SOURCES.find((option) => option.value === source())?.placeholder
+          }
+          class="flex-1 bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+          value={path()}
+          onInput={(e) => {
+            setPath(e.currentTarget.value);
+            setPreview(null);
+          }}
+          onKeyPress={(e) => {
+            if (e.key === 'Enter') handlePreview();
+          }}
+        />
+        <Button variant="ghost" onclick={handlePreview} disabled={!input()}>
+          Preview
+        </Button>
+      </div>
+
+      <Show when={preview()}>
+        {(preview) => (
+          <div class="space-y-2">
+            <p class="text-sm text-gray-400">
+              {preview().total} bookmarks in {preview().groups.length} groups
+              <Show when={preview().skipped.length > 0}>
+                , {preview().skipped.length} non-web links skipped
+              </Show>
+              <Show when={preview().conflicts.length > 0}>
+                , {preview().conflicts.length} already saved or repeated
+              </Show>
+            </p>
+            <ul class="max-h-48 overflow-y-auto text-sm">
+              <For each={preview().groups}>
+                {(group) => (
+                  <li class="flex justify-between py-1">
+                    <span>
+                      {group.name}
+                      <Show when={group.existing_group_id !== null}>
+                        <span class="text-gray-500"> (existing)</span>
+                      </Show>
+                    </span>
+                    <span class="text-gray-400">{group.bookmarks.length}</span>
+                  </li>
+                )}
+              </For>
+            </ul>
+            <Button
+              onclick={handleImport}
+              disabled={isImporting() || preview().total === 0}
+            >
+              Import {preview().total} bookmarks
+            </Button>
+          </div>
+        )}
+      </Show>
+
+      <Show when={error()}>
+        <p class="text-sm text-red-400">{error()}</p>
+      </Show>
+    </div>
+  );
+};
+
+export default ImportBookmarks;
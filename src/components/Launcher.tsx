@@ -0,0 +1,73 @@
+import { Component, createSignal } from 'solid-js';
+import { invoke } from '@tauri-apps/api/core';
+import { openUrl } from '@tauri-apps/plugin-opener';
+import { SearchResult } from '../types';
+import { api } from '../rpc';
+import SearchResults from './SearchResult';
+import { SearchInput } from './SearchInput';
+import LoadMore from './LoadMore';
+import { createBookmarkSearch } from '../stores/search';
+
+// Frameless window toggled by the global shortcut: search, open the pick, hide
+const Launcher: Component = () => {
+  const { results, search, clear, loadMore, loadingMore, hasMore } =
+    createBookmarkSearch();
+  const [selectedIndex, setSelectedIndex] = createSignal(0);
+
+  const hide = () => invoke('hide_launcher');
+
+  const handleSearch = async (query: string) => {
+    await search(query);
+    setSelectedIndex(0);
+  };
+
+  const handleNavigate = (direction: 'up' | 'down') => {
+    const count = results().length;
+    if (count === 0) return;
+
+    setSelectedIndex((prev) =>
+      direction === 'down' ? (prev + 1) % count : (prev - 1 + count) % count
+    );
+  };
+
+  const openResult = async (result: SearchResult) => {
+    try {
+      await openUrl(result.url);
+      await api.mutation(['bookmark.markOpened', result.id]);
+    } catch (error) {
+      console.error('❌ Error opening bookmark from launcher:', error);
+    }
+    clear();
+    await hide();
+  };
+
+  const handleEnter = () => {
+    const result = results()[selectedIndex()];
+    if (result) openResult(result);
+  };
+
+  return (
+    <div class="h-screen w-screen p-4 bg-black/90 text-white overflow-y-auto">
+      <SearchInput
+        placeholder="Search bookmarks..."
+        onSearch={handleSearch}
+        onNavigate={handleNavigate}
+        onEnter={handleEnter}
+        onBack={hide}
+      />
+      <SearchResults
+        results={results()}
+        selectedIndex={selectedIndex()}
+        onSelectionChange={setSelectedIndex}
+        onSelectItem={openResult}
+      />
+      <LoadMore
+        hasMore={hasMore()}
+        loading={loadingMore()}
+        onLoadMore={loadMore}
+      />
+    </div>
+  );
+};
+
+export default Launcher;
@@ -3,6 +3,7 @@ import { Card, CardContent } from './ui/card';
 import { FiFolder, FiLoader, FiCheck, FiX } from 'solid-icons/fi';
 import { GroupsDto as Group, GroupsDto } from '../types';
 import NoGroupsFound from './NoGroupsFound';
+import LoadMore from './LoadMore';
 
 interface ListOfGroupsProps {
   groups?: Group[];
@@ -12,6 +13,9 @@ interface ListOfGroupsProps {
   selectedGroupId?: number | null;
   onRenameGroup?: (group: GroupsDto) => void;
   onDeleteGroup?: (groupId: number) => void;
+  hasMore?: boolean;
+  loadingMore?: boolean;
+  onLoadMore?: () => void;
 }
 
 const ListOfGroups = (props: ListOfGroupsProps) => {
@@ -200,6 +204,11 @@ const ListOfGroups = (props: ListOfGroupsProps) => {
                 );
               }}
             </For>
+            <LoadMore
+              hasMore={props.hasMore}
+              loading={props.loadingMore}
+              onLoadMore={props.onLoadMore}
+            />
           </div>
         </Show>
       </Show>
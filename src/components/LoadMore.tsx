@@ -0,0 +1,31 @@
+import { Show } from 'solid-js';
+import { FiLoader } from 'solid-icons/fi';
+
+interface LoadMoreProps {
+  hasMore?: boolean;
+  loading?: boolean;
+  onLoadMore?: () => void;
+}
+
+// Shown under a paged list while the backend has more items after the last loaded page
+const LoadMore = (props: LoadMoreProps) => {
+  return (
+    <Show when={props.hasMore}>
+      <button
+        class="flex w-full items-center justify-center gap-2 p-3 text-sm text-gray-300 hover:bg-gray-500/10 rounded transition-colors disabled:opacity-50"
+        disabled={props.loading}
+        onClick={(e) => {
+          e.stopPropagation();
+          props.onLoadMore?.();
+        }}
+      >
+        <Show when={props.loading}>
+          <FiLoader class="animate-spin" size={14} />
+        </Show>
+        {props.loading ? 'Loading...' : 'Load more'}
+      </button>
+    </Show>
+  );
+};
+
+export default LoadMore;
@@ -0,0 +1,192 @@
+import { Component, createEffect, createSignal, For, Show } from 'solid-js';
+import { toast } from 'solid-sonner';
+import { FiTrash2, FiUserPlus } from 'solid-icons/fi';
+import { Button } from './ui/button';
+import { api } from '../rpc';
+import { MemberRole, OrganizationMemberDto, UserDto } from '~/types';
+
+interface OrganizationMembersProps {
+  organizationId: () => number;
+}
+
+const ROLES: MemberRole[] = ['owner', 'editor', 'viewer'];
+
+const selectClass =
+  'bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none';
+
+// Members of the active organization and the user rayzen acts as
+const OrganizationMembers: Component<OrganizationMembersProps> = (props) => {
+  const [members, setMembers] = createSignal<OrganizationMemberDto[]>([]);
+  const [currentUser, setCurrentUser] = createSignal<UserDto | null>(null);
+  const [email, setEmail] = createSignal('');
+  const [role, setRole] = createSignal<MemberRole>('viewer');
+  const [error, setError] = createSignal<string | null>(null);
+
+  const loadMembers = async () => {
+    try {
+      setError(null);
+      setMembers(
+        await api.query(['organization.listMembers', props.organizationId()])
+      );
+      setCurrentUser(await api.query(['session.current']));
+    } catch (error) {
+      console.error('Failed to load members:', error);
+      setMembers([]);
+      setError(String(error));
+    }
+  };
+
+  createEffect(() => {
+    if (props.organizationId()) loadMembers();
+  });
+
+  const run = async (action: () => Promise<unknown>, message?: string) => {
+    try {
+      setError(null);
+      await action();
+      if (message) toast(message);
+      await loadMembers();
+    } catch (error) {
+      console.error('Failed to update members:', error);
+      setError(String(error));
+    }
+  };
+
+  const handleInvite = () => {
+    if (!email().trim()) return;
+
+    run(async () => {
+      await api.mutation([
+        'organization.inviteMember',
+        {
+          organization_id: props.organizationId(),
+          email: email().trim(),
+          role: role(),
+        },
+      ]);
+      setEmail('');
+    }, `Invited ${email().trim()}`);
+  };
+
+  return (
+    <div class="space-y-4">
+      <h3 class="text-xl font-bold">Members</h3>
+      <p class="text-sm text-gray-400">
+        <Show
+          when={currentUser()}
+          fallback="Not signed in, every organization can be changed"
+        >
+          {(user) => (
+            <>
+              Signed in as {user().name}{' '}
+              <Button
+                variant="ghost"
+                size="sm"
+                onclick={() =>
+                  run(() => api.mutation(['session.signOut']), 'Signed out')
+                }
+              >
+                Sign out
+              </Button>
+            </>
+          )}
+        </Show>
+      </p>
+
+      <div class="space-y-2">
+        <For each={members()}>
+          {(member) => (
+            <div class="bg-gray-800/30 p-3 rounded-lg flex items-center justify-between gap-2">
+              <div class="flex flex-col">
+                <span class="text-white font-medium">{member.name}</span>
+                <span class="text-sm text-gray-400">{member.email}</span>
+              </div>
+              <div class="flex gap-2 items-center">
+                <select
+                  class={selectClass}
+                  value={member.role}
+                  onChange={(e) =>
+                    run(() =>
+                      api.mutation([
+                        'organization.updateMemberRole',
+                        {
+                          id: member.id,
+                          role: e.currentTarget.value as MemberRole,
+                        },
+                      ])
+                    )
+                  }
+                >
+                  <For each={ROLES}>
+                    {(role) => <option value={role}>{role}</option>}
+                  </For>
+                </select>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  disabled={currentUser()?.id === member.user_id}
+                  onclick={() =>
+                    run(
+                      () => api.mutation(['session.signIn', member.user_id]),
+                      `Signed in as ${member.name}`
+                    )
+                  }
+                >
+                  Sign in
+                </Button>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  class="text-red-400 hover:text-red-300"
+                  onclick={() =>
+                    run(() =>
+                      api.mutation(['organization.removeMember', member.id])
+                    )
+                  }
+                >
+                  <FiTrash2 size={16} />
+                </Button>
+              </div>
+            </div>
+          )}
+        </For>
+      </div>
+
+      <div class="flex gap-2">
+        <input
+          type="email"
+          placeholder="Email of an existing user"
+          class="flex-1 bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+          value={email()}
+          onInput={(e) => setEmail(e.currentTarget.value)}
+          onKeyPress={(e) => {
+            if (e.key === 'Enter') handleInvite();
+          }}
+        />
+        <select
+          class={selectClass}
+          value={role()}
+          onChange={(e) => setRole(e.currentTarget.value as MemberRole)}
+        >
+          <For each={ROLES}>
+            {(role) => <option value={role}>{role}</option>}
+          </For>
+        </select>
+        <Button
+          variant="ghost"
+          onclick={handleInvite}
+          disabled={!email().trim()}
+        >
+          <FiUserPlus />
+          Invite
+        </Button>
+      </div>
+
+      <Show when={error()}>
+        <p class="text-sm text-red-400">{error()}</p>
+      </Show>
+    </div>
+  );
+};
+
+export default OrganizationMembers;
@@ -3,33 +3,16 @@ import { SearchResult } from '~/types';
 import { api } from '~/rpc';
 import { createSignal, Show } from 'solid-js';
 import SearchResults from './SearchResult';
+import LoadMore from './LoadMore';
+import { createBookmarkSearch } from '~/stores/search';
 
 const PageWrapper = () => {
-  const [results, setResults] = createSignal<SearchResult[]>([]);
+  const { results, search, loadMore, loadingMore, hasMore } =
+    createBookmarkSearch();
   const [selectedIndex, setSelectedIndex] = createSignal(0);
 
   const handleSearch = async (query: string) => {
-    if (!query.trim()) {
-      setResults([]);
-      setSelectedIndex(0);
-      return;
-    }
-
-    const foundBookmarks = await api.query(['bookmark.search', query]);
-    let searchResults: SearchResult[] = [];
-
-    foundBookmarks.forEach((bookmark) => {
-      searchResults.push({
-        id: bookmark.id,
-        title: bookmark.name,
-        tags: bookmark.tags,
-        is_favorite: bookmark.is_favorite,
-        url: bookmark.url,
-        type: 'bookmark',
-      });
-    });
-
-    setResults(searchResults);
+    await search(query);
     setSelectedIndex(0); // Reset selection when new results come in
   };
 
@@ -48,17 +31,25 @@ const PageWrapper = () => {
     }
   };
 
+  const openResult = (result: SearchResult) => {
+    window.open(result.url, '_blank');
+    // Recently opened bookmarks show up in the tray menu
+    api.mutation(['bookmark.markOpened', result.id]).catch((error) => {
+      console.error('❌ Error marking bookmark as opened:', error);
+    });
+  };
+
   const handleEnter = () => {
     const resultsArray = results();
     const selected = resultsArray[selectedIndex()];
     if (selected?.url) {
-      window.open(selected.url, '_blank');
+      openResult(selected);
     }
   };
 
   const handleSelectItem = (result: SearchResult) => {
     if (result.url) {
-      window.open(result.url, '_blank');
+      openResult(result);
     }
   };
 
@@ -77,6 +68,11 @@ const PageWrapper = () => {
           onSelectionChange={setSelectedIndex}
           onSelectItem={handleSelectItem}
         />
+        <LoadMore
+          hasMore={hasMore()}
+          loading={loadingMore()}
+          onLoadMore={loadMore}
+        />
       </Show>
     </div>
   );
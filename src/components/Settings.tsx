@@ -1,14 +1,36 @@
-import { Component, createEffect, createSignal, For, Show } from 'solid-js';
+import {
+  Component,
+  createEffect,
+  createSignal,
+  For,
+  onMount,
+  Show,
+} from 'solid-js';
+import { invoke } from '@tauri-apps/api/core';
 import { Button } from './ui/button';
 import {
   FiCheck,
   FiEdit2,
   FiPlus,
+  FiRefreshCw,
   FiSave,
   FiTrash2,
   FiX,
 } from 'solid-icons/fi';
 import { WorkspaceDto } from '~/types';
+import ImportBookmarks from './ImportBookmarks';
+import CsvBookmarks from './CsvBookmarks';
+import ExportBookmarks from './ExportBookmarks';
+import OrganizationMembers from './OrganizationMembers';
+import SyncWorkspace from './SyncWorkspace';
+
+interface HttpApiStatus {
+  enabled: boolean;
+  running: boolean;
+  port: number;
+  token: string;
+  address: string | null;
+}
 
 interface SettingsProps {
   selectedWorkspaceId: () => number | null;
@@ -42,6 +64,70 @@ const Settings: Component<SettingsProps> = (props) => {
   const [editOrgName, setEditOrgName] = createSignal('');
   const [editingOrgId, setEditingOrgId] = createSignal<number | null>(null);
 
+  // Launcher shortcut signals
+  const [launcherShortcut, setLauncherShortcut] = createSignal('');
+  const [shortcutError, setShortcutError] = createSignal<string | null>(null);
+
+  // HTTP API signals
+  const [httpApi, setHttpApi] = createSignal<HttpApiStatus | null>(null);
+  const [httpApiPort, setHttpApiPort] = createSignal('');
+  const [httpApiError, setHttpApiError] = createSignal<string | null>(null);
+
+  onMount(async () => {
+    try {
+      setLauncherShortcut(await invoke<string>('get_launcher_shortcut'));
+    } catch (error) {
+      console.error('Failed to load launcher shortcut:', error);
+    }
+
+    try {
+      const status = await invoke<HttpApiStatus>('get_http_api_status');
+      setHttpApi(status);
+      setHttpApiPort(String(status.port));
+    } catch (error) {
+      console.error('Failed to load HTTP API status:', error);
+    }
+  });
+
+  const updateHttpApi = async (
+    command: string,
+    args: Record<string, unknown> = {}
+  ) => {
+    try {
+      setHttpApiError(null);
+      const status = await invoke<HttpApiStatus>(command, args);
+      setHttpApi(status);
+      setHttpApiPort(String(status.port));
+    } catch (error) {
+      console.error('Failed to update HTTP API:', error);
+      setHttpApiError(String(error));
+    }
+  };
+
+  const handleSaveHttpApiPort = () => {
+    const port = Number(httpApiPort().trim());
+    if (!Number.isInteger(port) || port < 1 || port > 65535) {
+      setHttpApiError('Port must be between 1 and 65535');
+      return;
+    }
+    updateHttpApi('set_http_api_port', { port });
+  };
+
+  const handleSaveShortcut = async () => {
+    const accelerator = launcherShortcut().trim();
+    if (!accelerator) return;
+
+    try {
+      setShortcutError(null);
+      setLauncherShortcut(
+        await invoke<string>('set_launcher_shortcut', { accelerator })
+      );
+    } catch (error) {
+      console.error('Failed to save launcher shortcut:', error);
+      setShortcutError(String(error));
+    }
+  };
+
   const handleUpdateWorkspace = async (id: number) => {
     const updatedWorkspaceName = editWorkspaceName().trim();
 
@@ -322,6 +408,8 @@ const Settings: Component<SettingsProps> = (props) => {
         </div>
       </div>
 
+      <OrganizationMembers organizationId={() => props.organizationId} />
+
       {/* Workspace Settings Section */}
       <div class="bg-gray-900/50 p-6 rounded-xl border border-gray-700/50 space-y-3">
         <h3 class="text-xl font-bold mb-4">Workspace Management</h3>
@@ -415,6 +503,110 @@ const Settings: Component<SettingsProps> = (props) => {
           )}
         </For>
       </div>
+
+      {/* Launcher Section */}
+      <div class="space-y-4">
+        <h3 class="text-xl font-bold">Launcher</h3>
+        <div class="flex gap-2">
+          <input
+            type="text"
+            placeholder="CommandOrControl+Shift+Space"
+            class="flex-1 bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+            value={launcherShortcut()}
+            onInput={(e) => setLauncherShortcut(e.currentTarget.value)}
+            onKeyPress={(e) => {
+              if (e.key === 'Enter') handleSaveShortcut();
+            }}
+          />
+          <Button
+            variant="ghost"
+            size="sm"
+            onclick={handleSaveShortcut}
+            disabled={!launcherShortcut().trim()}
+          >
+            <FiSave size={16} />
+          </Button>
+        </div>
+        <Show when={shortcutError()}>
+          <p class="text-sm text-red-400">{shortcutError()}</p>
+        </Show>
+      </div>
+
+      {/* Import Section */}
+      <ImportBookmarks workspaceId={props.selectedWorkspaceId} />
+      <CsvBookmarks workspaceId={props.selectedWorkspaceId} />
+      <ExportBookmarks workspaceId={props.selectedWorkspaceId} />
+      <SyncWorkspace
+        organizationId={() => props.organizationId}
+        workspaceId={props.selectedWorkspaceId}
+      />
+
+      {/* HTTP API Section */}
+      <div class="space-y-4">
+        <h3 class="text-xl font-bold">HTTP API</h3>
+        <Show when={httpApi()}>
+          {(status) => (
+            <>
+              <label class="flex items-center gap-2">
+                <input
+                  type="checkbox"
+                  checked={status().enabled}
+                  onChange={(e) =>
+                    updateHttpApi('set_http_api_enabled', {
+                      enabled: e.currentTarget.checked,
+                    })
+                  }
+                />
+                <span>Serve bookmarks on localhost</span>
+              </label>
+              <p class="text-sm text-gray-400">
+                {status().running
+                  ? `Listening on ${status().address}`
+                  : 'Stopped'}
+              </p>
+              <div class="flex gap-2">
+                <input
+                  type="number"
+                  min="1"
+                  max="65535"
+                  class="w-32 bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+                  value={httpApiPort()}
+                  onInput={(e) => setHttpApiPort(e.currentTarget.value)}
+                  onKeyPress={(e) => {
+                    if (e.key === 'Enter') handleSaveHttpApiPort();
+                  }}
+                />
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onclick={handleSaveHttpApiPort}
+                >
+                  <FiSave size={16} />
+                </Button>
+              </div>
+              <div class="flex gap-2 items-center">
+                <input
+                  type="text"
+                  readonly
+                  class="flex-1 bg-gray-700 text-gray-300 px-3 py-2 rounded border border-gray-600 font-mono text-sm"
+                  value={status().token}
+                />
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  title="Generate a new token"
+                  onclick={() => updateHttpApi('regenerate_http_api_token')}
+                >
+                  <FiRefreshCw size={16} />
+                </Button>
+              </div>
+            </>
+          )}
+        </Show>
+        <Show when={httpApiError()}>
+          <p class="text-sm text-red-400">{httpApiError()}</p>
+        </Show>
+      </div>
     </div>
   );
 };
@@ -0,0 +1,105 @@
+import { Component, createSignal, For, Show } from 'solid-js';
+import { toast } from 'solid-sonner';
+import { Button } from './ui/button';
+import { api } from '../rpc';
+import { SyncReport } from '~/types';
+
+interface SyncWorkspaceProps {
+  organizationId: () => number;
+  workspaceId: () => number | null;
+}
+
+const inputClass =
+  'bg-gray-700 text-white px-3 py-2 rounded border border-gray-600 focus:border-blue-500 focus:outline-none';
+
+// Shares the selected workspace through a folder every instance can read
+const SyncWorkspace: Component<SyncWorkspaceProps> = (props) => {
+  const [folder, setFolder] = createSignal('');
+  const [report, setReport] = createSignal<SyncReport | null>(null);
+
+  const handleExport = async () => {
+    const workspaceId = props.workspaceId();
+    if (!workspaceId || !folder().trim()) return;
+
+    try {
+      const result = await api.mutation([
+        'sync.export',
+        { workspace_id: workspaceId, folder: folder().trim() },
+      ]);
+      toast(`Shared ${result.changes} changes`);
+    } catch (error) {
+      console.error('Failed to share workspace:', error);
+      toast('Failed to share workspace');
+    }
+  };
+
+  const handleImport = async () => {
+    if (!folder().trim()) return;
+
+    try {
+      const result = await api.mutation([
+        'sync.import',
+        { folder: folder().trim(), organization_id: props.organizationId() },
+      ]);
+      setReport(result);
+      toast(
+        `Merged ${result.bundles} bundles, ${result.conflicts.length} conflicts`
+      );
+    } catch (error) {
+      console.error('Failed to merge changes:', error);
+      toast('Failed to merge changes');
+    }
+  };
+
+  return (
+    <div class="space-y-4">
+      <h3 class="text-xl font-bold">Sync</h3>
+      <div class="flex gap-2">
+        <input
+          type="text"
+          placeholder="Shared folder, like a synced drive"
+          class={`flex-1 ${inputClass}`}
+          value={folder()}
+          onInput={(e) => setFolder(e.currentTarget.value)}
+        />
+        <Button
+          variant="ghost"
+          onclick={handleExport}
+          disabled={!props.workspaceId() || !folder().trim()}
+        >
+          Share workspace
+        </Button>
+        <Button
+          variant="ghost"
+          onclick={handleImport}
+          disabled={!folder().trim()}
+        >
+          Merge changes
+        </Button>
+      </div>
+
+      <Show when={report()}>
+        {(report) => (
+          <div class="text-sm text-gray-400 space-y-1">
+            <p>
+              {report().created} created, {report().applied} updated,{' '}
+              {report().deleted} deleted, {report().skipped} skipped
+            </p>
+            <For each={report().conflicts}>
+              {(conflict) => (
+                <p>
+                  {conflict.entity_type} {conflict.field ?? 'deleted'}:{' '}
+                  {conflict.winner === 'remote'
+                    ? `${conflict.local_value ?? ''} was replaced by ${conflict.remote_value ?? 'a delete'}`
+                    : `kept ${conflict.local_value ?? ''} over ${conflict.remote_value ?? ''}`}
+                </p>
+              )}
+            </For>
+          </div>
+        )}
+      </Show>
+    </div>
+  );
+};
+
+export default SyncWorkspace;
@@ -0,0 +1,29 @@
+import { onCleanup, onMount } from 'solid-js';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'solid-sonner';
+import { api } from '../rpc';
+import { DeepLinkAction } from '../types';
+
+// Resolves `rayzen://` links handed over by the backend, both the ones that
+// launched the app and the ones received while it is running
+export function useDeepLinks(onAction: (action: DeepLinkAction) => void) {
+  const handleLink = async (link: string) => {
+    try {
+      onAction(await api.query(['deepLink.resolve', link]));
+    } catch (error) {
+      console.error('❌ Failed to handle deep link:', link, error);
+      toast.error(`Cannot open ${link}`);
+    }
+  };
+
+  onMount(async () => {
+    const unlisten = await listen<string>('deep-link', (event) =>
+      handleLink(event.payload)
+    );
+    onCleanup(unlisten);
+
+    const pending = await invoke<string[]>('take_pending_deep_links');
+    pending.forEach(handleLink);
+  });
+}
@@ -1,5 +1,7 @@
-import { createSignal, createResource } from 'solid-js';
+import { createSignal } from 'solid-js';
 import { api } from '../rpc';
+import { createChangeSubscription } from '../stores/changes';
+import { createPagedResource } from '../stores/paged';
 import { SearchResult } from '../types';
 
 const logstring = '@useGroupBookmarks';
@@ -11,34 +13,41 @@ export function useGroupBookmarks() {
   const [workspaceId, setWorkspaceId] = createSignal<number | null>(null);
 
   // Resource untuk fetch bookmarks berdasarkan group yang dipilih
-  const [bookmarks, { refetch }] = createResource(
-    selectedGroupId,
-    async (groupId) => {
-      if (groupId === null) return [];
-
+  const [bookmarks, { refetch, loadMore, loadingMore, hasMore }] =
+    createPagedResource(selectedGroupId, async (groupId, page) => {
       console.log('🔍 Fetching bookmarks for group:', groupId);
       try {
         // Sesuaikan dengan API endpoint
-        const bookmarks = await api.query(['bookmark.getByGroup', groupId]);
-        console.log('✅ Group bookmarks fetched:', bookmarks.length);
+        const result = await api.query([
+          'bookmark.getByGroup',
+          [groupId, page],
+        ]);
+        console.log('✅ Group bookmarks fetched:', result.items.length);
 
         // Transform ke format SearchResult untuk consistency
-        return bookmarks.map(
-          (bookmark: any): SearchResult => ({
-            id: bookmark.id,
-            title: bookmark.name || bookmark.title || 'Untitled',
-            tags: Array.isArray(bookmark.tags) ? bookmark.tags : [],
-            is_favorite: Boolean(bookmark.is_favorite),
-            url: bookmark.url || '',
-            type: 'bookmark',
-          })
-        );
+        return {
+          ...result,
+          items: result.items.map(
+            (bookmark: any): SearchResult => ({
+              id: bookmark.id,
+              title: bookmark.name || bookmark.title || 'Untitled',
+              tags: Array.isArray(bookmark.tags) ? bookmark.tags : [],
+              is_favorite: Boolean(bookmark.is_favorite),
+              url: bookmark.url || '',
+              type: 'bookmark',
+            })
+          ),
+        };
       } catch (error) {
         console.error('❌ Error fetching group bookmarks:', error);
         throw error;
       }
-    }
-  );
+    });
+
+  // Bookmarks changed elsewhere in the workspace (other windows, background jobs)
+  createChangeSubscription('bookmark.onChanged', workspaceId, () => {
+    if (selectedGroupId() !== null) refetch();
+  });
 
   const createBookmark = async (
     name: string,
@@ -139,6 +148,9 @@ export function useGroupBookmarks() {
     loading: bookmarks.loading,
     error: bookmarks.error,
     hasBookmarks: () => (bookmarks || []).length > 0,
+    loadMore,
+    loadingMore,
+    hasMore,
     createBookmark,
     updateBookmark,
     deleteBookmark,
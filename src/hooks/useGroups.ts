@@ -1,5 +1,7 @@
-import { createSignal, createResource } from 'solid-js';
+import { createSignal } from 'solid-js';
 import { api } from '../rpc';
+import { createChangeSubscription } from '../stores/changes';
+import { createPagedResource } from '../stores/paged';
 import { GroupsDto } from '~/types';
 
 export function useGroups() {
@@ -19,7 +21,10 @@ export function useGroups() {
   const [error, setError] = createSignal<string | null>(null);
 
   // Updated groups resource to handle null workspace
-  const [groups, { refetch: refetchGroups }] = createResource(
+  const [
+    groups,
+    { refetch: refetchGroups, loadMore, loadingMore, hasMore },
+  ] = createPagedResource(
     () => {
       const workspaceId = selectedWorkspaceId();
       const organizationId = selectedOrganizationId();
@@ -33,7 +38,7 @@ export function useGroups() {
         organizationId,
       };
     },
-    async ({ workspaceId, organizationId }) => {
+    async ({ workspaceId, organizationId }, page) => {
       // setLoading(true);
       setError(null);
       // If no workspace selected, return empty array
@@ -41,18 +46,18 @@ export function useGroups() {
         console.log(
           'ℹ️ No workspace or organization selected, returning empty groups'
         );
-        return [];
+        return { items: [], total: 0, next_cursor: null };
       }
 
       try {
         console.log('🔄 Fetching groups for workspace:', workspaceId);
-        const groups = await api.query([
+        const result = await api.query([
           'groups.getBelongedGroups',
-          [workspaceId, organizationId],
+          [workspaceId, organizationId, page],
         ]);
-        console.log('✅ Groups fetched:', groups);
+        console.log('✅ Groups fetched:', result.items);
         // setLoading(false);
-        return groups;
+        return result;
       } catch (error) {
         console.error('❌ Error getting groups:', error);
         setError(
@@ -63,6 +68,11 @@ export function useGroups() {
     }
   );
 
+  // Groups changed from another window stay in sync without polling
+  createChangeSubscription('groups.onChanged', selectedWorkspaceId, () =>
+    refetchGroups()
+  );
+
   const createGroup = async () => {
     const workspaceId = selectedWorkspaceId();
     if (!workspaceId) {
@@ -159,6 +169,9 @@ export function useGroups() {
     isDeleting,
     groups,
     loading: groups.loading,
+    loadMore,
+    loadingMore,
+    hasMore,
     error,
     selectedGroup,
     setSelectedGroup,
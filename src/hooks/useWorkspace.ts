@@ -1,5 +1,6 @@
 import { createSignal, createResource } from 'solid-js';
-import { api } from '../rpc';
+import { api, fetchAllPages } from '../rpc';
+import { createChangeSubscription } from '../stores/changes';
 
 export function useWorkspace() {
   const [selectedWorkspaceId, setSelectedWorkspaceId] = createSignal<
@@ -22,7 +23,10 @@ export function useWorkspace() {
       try {
         console.log('🔄 Fetching workspaces for organization:', orgId);
 
-        const workspaces = await api.query(['workspace.getWorkspaces', orgId]);
+        // the workspace picker lists every workspace, so follow the cursor to the end
+        const workspaces = await fetchAllPages((page) =>
+          api.query(['workspace.getWorkspaces', [orgId, page]])
+        );
 
         console.log('✅ Workspaces fetched:', workspaces);
 
@@ -34,6 +38,10 @@ export function useWorkspace() {
     }
   );
 
+  createChangeSubscription('workspace.onChanged', selectedOrgId, () =>
+    refetchWorkspaces()
+  );
+
   const createWorkspace = async (name: string, organizationId: number) => {
     try {
       setIsCreating(true);
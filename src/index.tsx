@@ -2,6 +2,7 @@
 import { render } from 'solid-js/web';
 import { Route, Router } from '@solidjs/router';
 import App from './App';
+import Launcher from './components/Launcher';
 import './app.css';
 
 render(
@@ -10,6 +11,7 @@ render(
       <Route path="/" component={App} />
       <Route path="/groups/:groupId" component={App} />
       <Route path="/search" component={App} />
+      <Route path="/launcher" component={Launcher} />
     </Router>
   ),
   document.getElementById('root') as HTMLElement
@@ -1,5 +1,5 @@
 import { GroupsDto } from '~/types';
-import { api } from '../rpc';
+import { api, firstPage } from '../rpc';
 
 export class ApiDataProcessor {
   static safeJsonParse<T>(data: any, fallback: T): T {
@@ -140,7 +140,7 @@ export class RSPCQueryBuilder {
   }
 
   static buildBookmarksByGroupQuery(groupId: number) {
-    return ['bookmark.getByGroup', groupId] as const;
+    return ['bookmark.getByGroup', [groupId, firstPage]] as const;
   }
 
   // Generic query builder
@@ -7,3 +7,43 @@ import type { Procedures } from './types/binding';
 export const api = createClient<Procedures>({
   transport: new TauriTransport(),
 });
+
+// largest page the backend serves, bigger `per_page` values are clamped to it
+export const MAX_PER_PAGE = 500;
+
+export type PageInput = {
+  cursor: string | null;
+  page: number | null;
+  per_page: number | null;
+  sort: { field: string; direction: 'asc' | 'desc' } | null;
+  filter: null;
+};
+
+export type PageOf<T> = {
+  items: T[];
+  total: number;
+  next_cursor: string | null;
+};
+
+// default paging input for list procedures, the backend falls back to its own page size
+export const firstPage: PageInput = {
+  cursor: null,
+  page: null,
+  per_page: null,
+  sort: null,
+  filter: null,
+};
+
+// follows `next_cursor` until the last page, for pickers that need every option at once
+export async function fetchAllPages<T>(
+  fetchPage: (page: PageInput) => Promise<PageOf<T>>
+): Promise<T[]> {
+  const items: T[] = [];
+  let page: PageInput = { ...firstPage, per_page: MAX_PER_PAGE };
+  for (;;) {
+    const result = await fetchPage(page);
+    items.push(...result.items);
+    if (result.next_cursor === null) return items;
+    page = { ...page, cursor: result.next_cursor };
+  }
+}
@@ -0,0 +1,28 @@
+import { createEffect, onCleanup } from 'solid-js';
+import { api } from '../rpc';
+
+type ChangeProcedure =
+  | 'bookmark.onChanged'
+  | 'groups.onChanged'
+  | 'workspace.onChanged';
+
+// Calls `onChange` whenever the backend reports a change in the current scope,
+// resubscribing when the scope id changes and unsubscribing on cleanup
+export function createChangeSubscription(
+  procedure: ChangeProcedure,
+  scope: () => number | null,
+  onChange: () => void
+) {
+  createEffect(() => {
+    const id = scope();
+    if (!id) return;
+
+    const unsubscribe = api.addSubscription([procedure, id], {
+      onData: () => onChange(),
+      onError: (error) =>
+        console.error(`❌ ${procedure} subscription failed:`, error),
+    });
+
+    onCleanup(unsubscribe);
+  });
+}
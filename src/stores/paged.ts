@@ -0,0 +1,62 @@
+import { createResource, createSignal, ResourceSource } from 'solid-js';
+import { firstPage, MAX_PER_PAGE, PageInput, PageOf } from '../rpc';
+
+// A list resource loaded one page at a time. The resource holds every item loaded so far,
+// `loadMore` appends the page after `next_cursor`. A refetch reloads as many items as were
+// showing so a change event doesn't collapse a long list back to its first page
+export function createPagedResource<S, T>(
+  source: ResourceSource<S>,
+  fetchPage: (source: S, page: PageInput) => Promise<PageOf<T>>
+) {
+  const [cursor, setCursor] = createSignal<string | null>(null);
+  const [loadingMore, setLoadingMore] = createSignal(false);
+  // Pages still arriving for a source the user already moved away from are dropped
+  let generation = 0;
+  let loaded = 0;
+
+  const [items, { refetch, mutate }] = createResource(
+    source,
+    async (value, { refetching }) => {
+      const request = ++generation;
+      const perPage =
+        refetching && loaded > 0 ? Math.min(loaded, MAX_PER_PAGE) : null;
+      const page = await fetchPage(value, { ...firstPage, per_page: perPage });
+      if (request === generation) {
+        setCursor(page.next_cursor);
+        loaded = page.items.length;
+      }
+      return page.items;
+    }
+  );
+
+  const loadMore = async () => {
+    const after = cursor();
+    const value = typeof source === 'function' ? (source as () => S)() : source;
+    if (after === null || loadingMore() || value == null || value === false) {
+      return;
+    }
+
+    const request = generation;
+    setLoadingMore(true);
+    try {
+      const page = await fetchPage(value as S, { ...firstPage, cursor: after });
+      if (request !== generation) return;
+      mutate((previous) => [...(previous ?? []), ...page.items]);
+      setCursor(page.next_cursor);
+      loaded += page.items.length;
+    } finally {
+      setLoadingMore(false);
+    }
+  };
+
+  return [
+    items,
+    {
+      refetch,
+      mutate,
+      loadMore,
+      loadingMore,
+      hasMore: () => cursor() !== null,
+    },
+  ] as const;
+}
@@ -0,0 +1,78 @@
+import { createSignal } from 'solid-js';
+import { api, firstPage } from '../rpc';
+import { SearchResult } from '../types';
+
+// Bookmark search results that follow `next_cursor`, shared by the main window, the search
+// page and the launcher. Results of a search the user already replaced are dropped
+export function createBookmarkSearch() {
+  const [results, setResults] = createSignal<SearchResult[]>([]);
+  const [cursor, setCursor] = createSignal<string | null>(null);
+  const [loadingMore, setLoadingMore] = createSignal(false);
+  let query = '';
+  let generation = 0;
+
+  const fetchPage = async (after: string | null) => {
+    const page = await api.query([
+      'bookmark.search',
+      [query, { ...firstPage, cursor: after }],
+    ]);
+
+    return {
+      next_cursor: page.next_cursor,
+      items: page.items.map(
+        (bookmark): SearchResult => ({
+          id: bookmark.id,
+          title: bookmark.name,
+          tags: bookmark.tags,
+          is_favorite: bookmark.is_favorite,
+          url: bookmark.url,
+          type: 'bookmark',
+        })
+      ),
+    };
+  };
+
+  const clear = () => {
+    generation++;
+    query = '';
+    setResults([]);
+    setCursor(null);
+  };
+
+  const search = async (value: string) => {
+    clear();
+    if (!value.trim()) return;
+
+    query = value;
+    const request = generation;
+    const page = await fetchPage(null);
+    if (request !== generation) return;
+    setResults(page.items);
+    setCursor(page.next_cursor);
+  };
+
+  const loadMore = async () => {
+    const after = cursor();
+    if (after === null || loadingMore()) return;
+
+    const request = generation;
+    setLoadingMore(true);
+    try {
+      const page = await fetchPage(after);
+      if (request !== generation) return;
+      setResults((previous) => [...previous, ...page.items]);
+      setCursor(page.next_cursor);
+    } finally {
+      setLoadingMore(false);
+    }
+  };
+
+  return {
+    results,
+    search,
+    clear,
+    loadMore,
+    loadingMore,
+    hasMore: () => cursor() !== null,
+  };
+}